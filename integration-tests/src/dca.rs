@@ -174,6 +174,7 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				price_trigger: None,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -686,6 +687,7 @@ mod omnipool {
 						asset_out: DAI,
 					}]),
 				},
+				price_trigger: None,
 			};
 			create_schedule(ALICE, schedule1);
 
@@ -1432,6 +1434,7 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -1712,6 +1715,7 @@ mod stableswap {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2020,6 +2024,7 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2201,6 +2206,7 @@ mod stableswap {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(trades),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2454,6 +2460,7 @@ mod all_pools {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(trades),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2602,6 +2609,7 @@ mod with_onchain_route {
 						max_amount_in: Balance::MAX,
 						route: create_bounded_vec(vec![]),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2716,6 +2724,7 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					price_trigger: None,
 				};
 
 				create_schedule(ALICE, schedule);
@@ -2800,6 +2809,7 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				price_trigger: None,
 			};
 
 			//We verify the price diff between hdx and stable asset.
@@ -2947,6 +2957,7 @@ mod with_onchain_route {
 						min_amount_out: Balance::MIN,
 						route: create_bounded_vec(vec![]),
 					},
+					price_trigger: None,
 				};
 
 				//We verify the price diff between hdx and stable asset.
@@ -3067,6 +3078,7 @@ mod with_onchain_route {
 					min_amount_out: Balance::MIN,
 					route: create_bounded_vec(vec![]),
 				},
+				price_trigger: None,
 			};
 
 			//Just to verify the price difference between HDX and DOT
@@ -3172,6 +3184,7 @@ fn schedule_fake_with_buy_order_with_route(
 			max_amount_in: Balance::MAX,
 			route: create_bounded_vec(route),
 		},
+		price_trigger: None,
 	}
 }
 
@@ -3219,6 +3232,7 @@ fn schedule_fake_with_sell_order_with_route(
 			min_amount_out: Balance::MIN,
 			route: create_bounded_vec(route),
 		},
+		price_trigger: None,
 	}
 }
 
@@ -16,6 +16,7 @@ use hydradx_traits::{
 };
 
 use pallet_ema_oracle::OracleError;
+use pallet_ema_oracle_rpc_runtime_api::runtime_decl_for_ema_oracle_api::EmaOracleApiV1;
 use primitives::constants::chain::{OMNIPOOL_SOURCE, XYK_SOURCE};
 use xcm_emulator::TestExt;
 
@@ -246,3 +247,86 @@ fn xyk_trades_with_insufficient_asset_are_tracked_by_oracle_when_asset_is_whitel
 		}
 	});
 }
+
+#[test]
+fn tracked_pairs_should_include_pairs_whitelisted_by_registry_and_by_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_next_block();
+
+		init_omnipool();
+
+		assert_ok!(hydradx_runtime::Omnipool::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DAI,
+			5 * UNITS,
+			0,
+		));
+
+		assert_ok!(hydradx_runtime::Tokens::mint_into(
+			INSUFFICIENT_ASSET,
+			&ALICE.into(),
+			200 * UNITS,
+		));
+		assert_ok!(hydradx_runtime::XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			100 * UNITS,
+			INSUFFICIENT_ASSET,
+			100 * UNITS,
+		));
+		assert_ok!(EmaOracle::add_oracle(
+			RuntimeOrigin::root(),
+			XYK_SOURCE,
+			(HDX, INSUFFICIENT_ASSET)
+		));
+		assert_ok!(hydradx_runtime::XYK::buy(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			INSUFFICIENT_ASSET,
+			2 * UNITS,
+			200 * UNITS,
+			false,
+		));
+
+		// act
+		hydradx_run_to_next_block();
+
+		// assert
+		let tracked = hydradx_runtime::Runtime::tracked_pairs();
+		// omnipool pairs are whitelisted by the asset registry, as both assets are sufficient
+		assert!(tracked.contains(&(OMNIPOOL_SOURCE, (HDX, LRNA))));
+		assert!(tracked.contains(&(OMNIPOOL_SOURCE, (LRNA, DAI))));
+		// xyk pair with insufficient asset is whitelisted by the oracle
+		assert!(tracked.contains(&(XYK_SOURCE, (HDX, INSUFFICIENT_ASSET))));
+		// each pair is listed once, not once per period
+		let mut unique = tracked.clone();
+		unique.dedup();
+		assert_eq!(unique, tracked);
+	});
+}
+
+#[test]
+fn tracked_pairs_should_not_include_whitelisted_pairs_without_oracle_entry() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_next_block();
+
+		assert_ok!(EmaOracle::add_oracle(
+			RuntimeOrigin::root(),
+			XYK_SOURCE,
+			(HDX, INSUFFICIENT_ASSET)
+		));
+
+		// act
+		hydradx_run_to_next_block();
+
+		// assert
+		assert!(!hydradx_runtime::Runtime::tracked_pairs().contains(&(XYK_SOURCE, (HDX, INSUFFICIENT_ASSET))));
+	});
+}
@@ -36,7 +36,8 @@ fn place_order_should_work() {
 			HDX,
 			20 * UNITS,
 			100 * UNITS,
-			false,
+			false,,
+			None
 		));
 
 		// Assert
@@ -60,7 +61,8 @@ fn partial_fill_order_should_work() {
 			HDX,
 			20 * UNITS,
 			100 * UNITS,
-			true,
+			true,,
+			None
 		));
 
 		// Act
@@ -91,7 +93,8 @@ fn fill_order_should_work() {
 			HDX,
 			20 * UNITS,
 			100 * UNITS,
-			true,
+			true,,
+			None
 		));
 
 		// Act
@@ -121,7 +124,8 @@ fn cancel_order_should_work() {
 			HDX,
 			20 * UNITS,
 			100 * UNITS,
-			true,
+			true,,
+			None
 		));
 
 		// Act
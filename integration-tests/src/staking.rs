@@ -2473,6 +2473,115 @@ fn voting_on_next_referenda_should_process_votes() {
 	});
 }
 
+#[test]
+fn democracy_vote_should_be_split_between_staking_positions_when_user_has_multiple_positions() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		init_omnipool();
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+
+		let staking_account = pallet_staking::Pallet::<hydradx_runtime::Runtime>::pot_account_id();
+		assert_ok!(Currencies::update_balance(
+			RawOrigin::Root.into(),
+			staking_account,
+			HDX,
+			(10_000 * UNITS) as i128,
+		));
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+		));
+		let r = begin_referendum();
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			100_000 * UNITS
+		));
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			50_000 * UNITS
+		));
+
+		let position_ids =
+			pallet_staking::Pallet::<hydradx_runtime::Runtime>::get_user_position_ids(&AccountId::from(ALICE));
+		assert_eq!(position_ids.len(), 2);
+		assert_lock(&ALICE.into(), 150_000 * UNITS, pallet_staking::STAKING_LOCK_ID);
+
+		//Act
+		assert_ok!(Democracy::vote(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			r,
+			AccountVote::Standard {
+				vote: Vote {
+					aye: true,
+					conviction: Conviction::Locked6x,
+				},
+				balance: 120_000 * UNITS,
+			}
+		));
+
+		//Assert
+		let first_position_votes =
+			pallet_staking::Pallet::<hydradx_runtime::Runtime>::get_position_votes(position_ids[0]).votes;
+		let second_position_votes =
+			pallet_staking::Pallet::<hydradx_runtime::Runtime>::get_position_votes(position_ids[1]).votes;
+
+		assert_eq!(
+			first_position_votes[0].1,
+			pallet_staking::types::Vote::new(100_000 * UNITS, pallet_staking::types::Conviction::Locked6x)
+		);
+		assert_eq!(
+			second_position_votes[0].1,
+			pallet_staking::types::Vote::new(20_000 * UNITS, pallet_staking::types::Conviction::Locked6x)
+		);
+	});
+}
+
+#[test]
+fn partial_unstake_should_unlock_part_of_the_stake() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		init_omnipool();
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+
+		let staking_account = pallet_staking::Pallet::<hydradx_runtime::Runtime>::pot_account_id();
+		assert_ok!(Currencies::update_balance(
+			RawOrigin::Root.into(),
+			staking_account,
+			HDX,
+			(10_000 * UNITS) as i128,
+		));
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+		));
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			100_000 * UNITS
+		));
+		let position_id = pallet_staking::Pallet::<hydradx_runtime::Runtime>::get_user_position_id(
+			&sp_runtime::AccountId32::from(ALICE),
+		)
+		.unwrap()
+		.unwrap();
+
+		fast_forward_to(DAYS);
+
+		//Act
+		assert_ok!(Staking::partial_unstake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			position_id,
+			40_000 * UNITS
+		));
+
+		//Assert
+		let position = pallet_staking::Pallet::<hydradx_runtime::Runtime>::get_position(position_id).unwrap();
+		assert_eq!(position.get_total_locked().unwrap(), 60_000 * UNITS);
+		assert_lock(&ALICE.into(), 60_000 * UNITS, pallet_staking::STAKING_LOCK_ID);
+	});
+}
+
 const DEMOCRACY_ID: LockIdentifier = *b"democrac";
 fn assert_lock(who: &AccountId, amount: Balance, lock_id: LockIdentifier) {
 	let locks = Balances::locks(who);
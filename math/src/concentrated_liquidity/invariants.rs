@@ -0,0 +1,202 @@
+use crate::concentrated_liquidity::*;
+use crate::types::Balance;
+use primitive_types::U512;
+use proptest::prelude::*;
+use sp_arithmetic::Permill;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+fn tick() -> impl Strategy<Value = Tick> {
+	MIN_TICK..=MAX_TICK
+}
+
+fn tick_in_range() -> impl Strategy<Value = Tick> {
+	-100_000..100_000
+}
+
+fn range_width() -> impl Strategy<Value = Tick> {
+	1..50_000
+}
+
+fn asset_amount() -> impl Strategy<Value = Balance> {
+	ONE..10_000_000 * ONE
+}
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	1_000..1_000 * ONE
+}
+
+fn liquidity() -> impl Strategy<Value = Liquidity> {
+	ONE..1_000_000_000 * ONE
+}
+
+fn fee() -> impl Strategy<Value = Permill> {
+	(0..100_000u32).prop_map(Permill::from_parts)
+}
+
+/// Value of amount of asset 0 denominated in asset 1 at square root of price, in Q128.
+fn value_in_asset_1(amount_0: Balance, sqrt_price: SqrtPrice) -> U512 {
+	U512::from(amount_0) * U512::from(sqrt_price) * U512::from(sqrt_price)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn tick_should_round_trip_through_sqrt_price(tick in tick()) {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+
+		prop_assert_eq!(tick_at_sqrt_price(sqrt_price).unwrap(), tick);
+		if tick > MIN_TICK {
+			prop_assert_eq!(tick_at_sqrt_price(sqrt_price - 1).unwrap(), tick - 1);
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sqrt_price_should_increase_with_tick(tick in MIN_TICK..MAX_TICK) {
+		prop_assert!(sqrt_price_at_tick(tick).unwrap() < sqrt_price_at_tick(tick + 1).unwrap());
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn added_amounts_should_not_exceed_provided_amounts(
+		current_tick in tick_in_range(),
+		lower_tick in tick_in_range(),
+		width in range_width(),
+		amount_0 in asset_amount(),
+		amount_1 in asset_amount(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current_tick).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(lower_tick).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(lower_tick + width).unwrap();
+
+		let liquidity = liquidity_for_amounts(sqrt_price, sqrt_price_lower, sqrt_price_upper, amount_0, amount_1).unwrap();
+		let (added_0, added_1) =
+			amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+
+		prop_assert!(added_0 <= amount_0);
+		prop_assert!(added_1 <= amount_1);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn removed_amounts_should_not_exceed_added_amounts(
+		current_tick in tick_in_range(),
+		lower_tick in tick_in_range(),
+		width in range_width(),
+		liquidity in liquidity(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current_tick).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(lower_tick).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(lower_tick + width).unwrap();
+
+		let (added_0, added_1) =
+			amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+		let (removed_0, removed_1) =
+			amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, false).unwrap();
+
+		prop_assert!(removed_0 <= added_0);
+		prop_assert!(removed_1 <= added_1);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_step_should_not_give_better_than_spot_price(
+		current_tick in tick_in_range(),
+		width in range_width(),
+		liquidity in liquidity(),
+		amount in trade_amount(),
+		fee in fee(),
+		zero_for_one in any::<bool>(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current_tick).unwrap();
+		let target_tick = if zero_for_one { current_tick - width } else { current_tick + width };
+		let sqrt_price_target = sqrt_price_at_tick(target_tick).unwrap();
+
+		let step = compute_swap_step(sqrt_price, sqrt_price_target, liquidity, amount, fee, true).unwrap();
+
+		prop_assert!(step.amount_in.saturating_add(step.fee_amount) <= amount);
+		if zero_for_one {
+			prop_assert!(step.sqrt_price_next >= sqrt_price_target && step.sqrt_price_next <= sqrt_price);
+			// amount_out of asset 1 <= amount_in of asset 0 * price
+			prop_assert!(U512::from(step.amount_out) << 128 <= value_in_asset_1(step.amount_in, sqrt_price));
+		} else {
+			prop_assert!(step.sqrt_price_next <= sqrt_price_target && step.sqrt_price_next >= sqrt_price);
+			// amount_out of asset 0 * price <= amount_in of asset 1
+			prop_assert!(value_in_asset_1(step.amount_out, sqrt_price) <= U512::from(step.amount_in) << 128);
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn buy_step_should_not_give_more_than_requested(
+		current_tick in tick_in_range(),
+		width in range_width(),
+		liquidity in liquidity(),
+		amount in trade_amount(),
+		fee in fee(),
+		zero_for_one in any::<bool>(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current_tick).unwrap();
+		let target_tick = if zero_for_one { current_tick - width } else { current_tick + width };
+		let sqrt_price_target = sqrt_price_at_tick(target_tick).unwrap();
+
+		let step = compute_swap_step(sqrt_price, sqrt_price_target, liquidity, amount, fee, false).unwrap();
+
+		prop_assert!(step.amount_out <= amount);
+		if zero_for_one {
+			prop_assert!(U512::from(step.amount_out) << 128 <= value_in_asset_1(step.amount_in, sqrt_price));
+		} else {
+			prop_assert!(value_in_asset_1(step.amount_out, sqrt_price) <= U512::from(step.amount_in) << 128);
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_and_buy_back_should_not_be_profitable(
+		current_tick in tick_in_range(),
+		liquidity in liquidity(),
+		amount in trade_amount(),
+		fee in fee(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current_tick).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(MIN_TICK).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(MAX_TICK).unwrap();
+
+		// sell asset 0 for asset 1
+		let sell = compute_swap_step(sqrt_price, sqrt_price_lower, liquidity, amount, fee, true).unwrap();
+		// sell received asset 1 back for asset 0
+		let sell_back =
+			compute_swap_step(sell.sqrt_price_next, sqrt_price_upper, liquidity, sell.amount_out, fee, true).unwrap();
+
+		prop_assert!(sell_back.amount_out <= amount);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn accrued_fees_should_not_exceed_distributed_fees(
+		liquidity in liquidity(),
+		fee_amount in trade_amount(),
+		fee_growth_before in any::<u128>(),
+	) {
+		let fee_growth = fee_growth_before.wrapping_add(fee_growth_delta(fee_amount, liquidity));
+
+		let accrued = fees_accrued(liquidity, fee_growth, fee_growth_before).unwrap();
+
+		prop_assert!(accrued <= fee_amount);
+	}
+}
@@ -0,0 +1,639 @@
+use crate::types::Balance;
+use crate::{
+	ensure, to_balance, MathError,
+	MathError::{DivisionByZero, InsufficientOutReserve, Overflow, ZeroReserve},
+};
+use core::convert::TryFrom;
+use primitive_types::{U256, U512};
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_arithmetic::{FixedPointNumber, FixedU128, PerThing, Permill, Rounding};
+
+/// Square root of a price in Q64.64 fixed point format.
+///
+/// Price is the amount of asset 1 per one unit of asset 0.
+pub type SqrtPrice = u128;
+
+/// Virtual liquidity of a price range - `sqrt(x * y)`.
+pub type Liquidity = u128;
+
+/// Index of a price tick. Price at tick `i` is `1.0001^i`.
+pub type Tick = i32;
+
+/// Fee accumulated per unit of liquidity in Q64.64 fixed point format.
+///
+/// Fee growth values are allowed to overflow and wrap around. Only differences between two values are meaningful.
+pub type FeeGrowth = u128;
+
+/// Number of fractional bits of `SqrtPrice` and `FeeGrowth`.
+pub const RESOLUTION: u32 = 64;
+
+/// One in Q64.64 fixed point format.
+pub const Q64: u128 = 1 << RESOLUTION;
+
+/// Minimum tick. Price at this tick is approximately `2^-64`.
+pub const MIN_TICK: Tick = -443_636;
+
+/// Maximum tick. Price at this tick is approximately `2^64`.
+pub const MAX_TICK: Tick = 443_636;
+
+/// Square root of price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: SqrtPrice = 4_295_048_017;
+
+/// Square root of price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: SqrtPrice = 79_226_673_515_401_279_992_447_579_062;
+
+/// Number of ticks stored in one word of a tick bitmap.
+pub const TICKS_PER_WORD: i32 = 128;
+
+/// `2^128 / sqrt(1.0001)^(2^i)` for each bit `i` of the absolute tick value.
+const TICK_RATIOS: [u128; 19] = [
+	0xfffcb933bd6fad37aa2d162d1a594001,
+	0xfff97272373d413259a46990580e213a,
+	0xfff2e50f5f656932ef12357cf3c7fdcc,
+	0xffe5caca7e10e4e61c3624eaa0941cd0,
+	0xffcb9843d60f6159c9db58835c926644,
+	0xff973b41fa98c081472e6896dfb254c0,
+	0xff2ea16466c96a3843ec78b326b52861,
+	0xfe5dee046a99a2a811c461f1969c3053,
+	0xfcbe86c7900a88aedcffc83b479aa3a4,
+	0xf987a7253ac413176f2b074cf7815e54,
+	0xf3392b0822b70005940c7a398e4b70f3,
+	0xe7159475a2c29b7443b29c7fa6e889d9,
+	0xd097f3bdfd2022b8845ad8f792aa5825,
+	0xa9f746462d870fdf8a65dc1f90e061e5,
+	0x70d869a156d2a1b890bb3df62baf32f7,
+	0x31be135f97d08fd981231505542fcfa6,
+	0x09aa508b5b7a84e1c677de54f3e99bc9,
+	0x005d6af8dedb81196699c329225ee604,
+	0x00002216e584f5fa1ea926041bedfe98,
+];
+
+/// Result of a single swap step within one price range of constant liquidity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapStep {
+	/// Square root of price after the step.
+	pub sqrt_price_next: SqrtPrice,
+	/// Amount of asset in, without the fee.
+	pub amount_in: Balance,
+	/// Amount of asset out.
+	pub amount_out: Balance,
+	/// Fee amount paid in asset in.
+	pub fee_amount: Balance,
+}
+
+/// Calculates square root of price at given tick - `sqrt(1.0001^tick)`.
+///
+/// Returns `Overflow` if tick is outside of `[MIN_TICK, MAX_TICK]`.
+pub fn sqrt_price_at_tick(tick: Tick) -> Result<SqrtPrice, MathError> {
+	ensure!((MIN_TICK..=MAX_TICK).contains(&tick), Overflow);
+
+	let abs_tick = tick.unsigned_abs();
+
+	// Q128.128 ratio of sqrt price at -abs_tick
+	let mut ratio = if abs_tick & 1 != 0 {
+		U256::from(TICK_RATIOS[0])
+	} else {
+		U256::one() << 128
+	};
+
+	for (bit, tick_ratio) in TICK_RATIOS.iter().enumerate().skip(1) {
+		if abs_tick & (1 << bit) != 0 {
+			ratio = ratio.checked_mul(U256::from(*tick_ratio)).ok_or(Overflow)? >> 128;
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX / ratio;
+	}
+
+	// Round up, so that tick_at_sqrt_price(sqrt_price_at_tick(tick)) == tick
+	let remainder = ratio & U256::from(u64::MAX);
+	let sqrt_price = (ratio >> RESOLUTION) + if remainder.is_zero() { U256::zero() } else { U256::one() };
+
+	to_balance!(sqrt_price)
+}
+
+/// Calculates the greatest tick whose square root of price is less than or equal to given square root of price.
+///
+/// Returns `Overflow` if square root of price is outside of `[MIN_SQRT_PRICE, MAX_SQRT_PRICE]`.
+pub fn tick_at_sqrt_price(sqrt_price: SqrtPrice) -> Result<Tick, MathError> {
+	ensure!((MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price), Overflow);
+
+	let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+	while low < high {
+		let mid = low + (high - low + 1) / 2;
+		if sqrt_price_at_tick(mid)? <= sqrt_price {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+
+	Ok(low)
+}
+
+/// Converts price of asset 0 denominated in asset 1 to square root of price.
+pub fn sqrt_price_from_price(price: FixedU128) -> Result<SqrtPrice, MathError> {
+	let price_q128 = (U256::from(price.into_inner()) << 128) / U256::from(FixedU128::DIV);
+	let sqrt_price = to_balance!(price_q128.integer_sqrt())?;
+
+	ensure!((MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price), Overflow);
+
+	Ok(sqrt_price)
+}
+
+/// Converts square root of price to price of asset 0 denominated in asset 1.
+pub fn price_from_sqrt_price(sqrt_price: SqrtPrice) -> Result<FixedU128, MathError> {
+	let price = U256::from(sqrt_price)
+		.checked_mul(U256::from(sqrt_price))
+		.and_then(|p| p.checked_mul(U256::from(FixedU128::DIV)))
+		.ok_or(Overflow)?
+		>> 128;
+
+	Ok(FixedU128::from_inner(to_balance!(price)?))
+}
+
+fn sort_sqrt_prices(sqrt_price_a: SqrtPrice, sqrt_price_b: SqrtPrice) -> (SqrtPrice, SqrtPrice) {
+	if sqrt_price_a > sqrt_price_b {
+		(sqrt_price_b, sqrt_price_a)
+	} else {
+		(sqrt_price_a, sqrt_price_b)
+	}
+}
+
+fn div_rounding_u256(numerator: U256, denominator: U256, round_up: bool) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (quotient, remainder) = numerator.div_mod(denominator);
+	if round_up && !remainder.is_zero() {
+		quotient.checked_add(U256::one()).ok_or(Overflow)
+	} else {
+		Ok(quotient)
+	}
+}
+
+fn div_rounding_u512(numerator: U512, denominator: U512, round_up: bool) -> Result<U512, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (quotient, remainder) = numerator.div_mod(denominator);
+	if round_up && !remainder.is_zero() {
+		quotient.checked_add(U512::one()).ok_or(Overflow)
+	} else {
+		Ok(quotient)
+	}
+}
+
+fn amount_0_delta_u256(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<U256, MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_a, sqrt_price_b);
+	ensure!(lower > 0, DivisionByZero);
+
+	let numerator = (U512::from(liquidity) << RESOLUTION) * U512::from(upper - lower);
+	let denominator = U512::from(lower) * U512::from(upper);
+
+	U256::try_from(div_rounding_u512(numerator, denominator, round_up)?).map_err(|_| Overflow)
+}
+
+fn amount_1_delta_u256(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<U256, MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_a, sqrt_price_b);
+
+	let numerator = U256::from(liquidity) * U256::from(upper - lower);
+
+	div_rounding_u256(numerator, U256::from(Q64), round_up)
+}
+
+/// Calculates amount of asset 0 between two prices.
+/// Formula: L * (sqrt_price_upper - sqrt_price_lower) / (sqrt_price_upper * sqrt_price_lower)
+pub fn amount_0_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	to_balance!(amount_0_delta_u256(sqrt_price_a, sqrt_price_b, liquidity, round_up)?)
+}
+
+/// Calculates amount of asset 1 between two prices.
+/// Formula: L * (sqrt_price_upper - sqrt_price_lower)
+pub fn amount_1_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	to_balance!(amount_1_delta_u256(sqrt_price_a, sqrt_price_b, liquidity, round_up)?)
+}
+
+/// Calculates amounts of asset 0 and asset 1 of given liquidity in price range at the current price.
+///
+/// Amounts are rounded up when `round_up` is true - used when liquidity is added to the pool.
+pub fn amounts_for_liquidity(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<(Balance, Balance), MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_lower, sqrt_price_upper);
+
+	if sqrt_price <= lower {
+		Ok((amount_0_delta(lower, upper, liquidity, round_up)?, 0))
+	} else if sqrt_price < upper {
+		Ok((
+			amount_0_delta(sqrt_price, upper, liquidity, round_up)?,
+			amount_1_delta(lower, sqrt_price, liquidity, round_up)?,
+		))
+	} else {
+		Ok((0, amount_1_delta(lower, upper, liquidity, round_up)?))
+	}
+}
+
+/// Calculates liquidity provided by amount of asset 0 in price range.
+/// Formula: amount_0 * sqrt_price_upper * sqrt_price_lower / (sqrt_price_upper - sqrt_price_lower)
+pub fn liquidity_for_amount_0(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_0: Balance,
+) -> Result<Liquidity, MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_a, sqrt_price_b);
+	ensure!(upper > lower, DivisionByZero);
+
+	let numerator = U512::from(amount_0) * U512::from(lower) * U512::from(upper);
+	let denominator = U512::from(upper - lower) << RESOLUTION;
+
+	let liquidity = div_rounding_u512(numerator, denominator, false)?;
+	to_balance!(U256::try_from(liquidity).map_err(|_| Overflow)?)
+}
+
+/// Calculates liquidity provided by amount of asset 1 in price range.
+/// Formula: amount_1 / (sqrt_price_upper - sqrt_price_lower)
+pub fn liquidity_for_amount_1(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_a, sqrt_price_b);
+	ensure!(upper > lower, DivisionByZero);
+
+	let liquidity = div_rounding_u256(U256::from(amount_1) << RESOLUTION, U256::from(upper - lower), false)?;
+	to_balance!(liquidity)
+}
+
+/// Calculates maximum liquidity which can be provided by given amounts in price range at the current price.
+pub fn liquidity_for_amounts(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	let (lower, upper) = sort_sqrt_prices(sqrt_price_lower, sqrt_price_upper);
+
+	if sqrt_price <= lower {
+		liquidity_for_amount_0(lower, upper, amount_0)
+	} else if sqrt_price < upper {
+		let liquidity_0 = liquidity_for_amount_0(sqrt_price, upper, amount_0)?;
+		let liquidity_1 = liquidity_for_amount_1(lower, sqrt_price, amount_1)?;
+		Ok(liquidity_0.min(liquidity_1))
+	} else {
+		liquidity_for_amount_1(lower, upper, amount_1)
+	}
+}
+
+/// Calculates virtual reserves of asset 0 and asset 1 of liquidity at the current price.
+///
+/// Virtual reserves are reserves of a constant product pool with the same liquidity and price.
+pub fn virtual_reserves(sqrt_price: SqrtPrice, liquidity: Liquidity) -> Result<(Balance, Balance), MathError> {
+	ensure!(sqrt_price > 0, DivisionByZero);
+
+	let reserve_0 = (U256::from(liquidity) << RESOLUTION) / U256::from(sqrt_price);
+	let reserve_1 = (U256::from(liquidity) * U256::from(sqrt_price)) >> RESOLUTION;
+
+	Ok((to_balance!(reserve_0)?, to_balance!(reserve_1)?))
+}
+
+/// Applies signed liquidity delta to liquidity.
+pub fn add_liquidity_delta(liquidity: Liquidity, delta: i128) -> Result<Liquidity, MathError> {
+	if delta < 0 {
+		liquidity
+			.checked_sub(delta.unsigned_abs())
+			.ok_or(InsufficientOutReserve)
+	} else {
+		liquidity.checked_add(delta.unsigned_abs()).ok_or(Overflow)
+	}
+}
+
+/// Maximum liquidity referencing a single tick, such that the total active liquidity can never overflow.
+pub fn max_liquidity_per_tick(tick_spacing: Tick) -> Result<Liquidity, MathError> {
+	ensure!(tick_spacing > 0, DivisionByZero);
+
+	let min_tick = (MIN_TICK / tick_spacing) * tick_spacing;
+	let max_tick = (MAX_TICK / tick_spacing) * tick_spacing;
+	let num_ticks = ((max_tick - min_tick) / tick_spacing) as u128 + 1;
+
+	Ok(Liquidity::MAX / num_ticks)
+}
+
+/// Price of asset 0 after amount of asset 0 is added to (or removed from) the pool. Rounded up.
+/// Formula: L * sqrt_price / (L +- amount * sqrt_price)
+fn next_sqrt_price_from_amount_0(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	if amount == 0 {
+		return Ok(sqrt_price);
+	}
+
+	let numerator = U512::from(liquidity) << RESOLUTION;
+	let product = U512::from(amount) * U512::from(sqrt_price);
+
+	let denominator = if add {
+		numerator + product
+	} else {
+		ensure!(numerator > product, InsufficientOutReserve);
+		numerator - product
+	};
+
+	let next = div_rounding_u512(numerator * U512::from(sqrt_price), denominator, true)?;
+	to_balance!(U256::try_from(next).map_err(|_| Overflow)?)
+}
+
+/// Price of asset 0 after amount of asset 1 is added to (or removed from) the pool. Rounded down.
+/// Formula: sqrt_price +- amount / L
+fn next_sqrt_price_from_amount_1(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	let quotient = to_balance!(div_rounding_u256(
+		U256::from(amount) << RESOLUTION,
+		U256::from(liquidity),
+		!add
+	)?)?;
+
+	if add {
+		sqrt_price.checked_add(quotient).ok_or(Overflow)
+	} else {
+		ensure!(sqrt_price > quotient, InsufficientOutReserve);
+		Ok(sqrt_price - quotient)
+	}
+}
+
+/// Calculates square root of price after `amount_in` is sold to the pool.
+///
+/// `zero_for_one` is true when asset 0 is sold for asset 1.
+pub fn next_sqrt_price_from_input(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_in: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	ensure!(sqrt_price > 0 && liquidity > 0, ZeroReserve);
+
+	if zero_for_one {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculates square root of price after `amount_out` is bought from the pool.
+///
+/// `zero_for_one` is true when asset 0 is sold for asset 1.
+pub fn next_sqrt_price_from_output(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_out: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	ensure!(sqrt_price > 0 && liquidity > 0, ZeroReserve);
+
+	if zero_for_one {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+/// Calculates result of swapping within a single price range of constant liquidity.
+///
+/// Swap moves the price from `sqrt_price_current` towards `sqrt_price_target`. Target is reached if the
+/// remaining amount is sufficient.
+///
+/// - `amount_remaining` - remaining amount in (including fee) when `exact_in` is true, remaining amount out otherwise
+/// - `fee` - fee rate charged on amount in
+///
+/// Returns `SwapStep` with amounts swapped and the fee amount.
+pub fn compute_swap_step(
+	sqrt_price_current: SqrtPrice,
+	sqrt_price_target: SqrtPrice,
+	liquidity: Liquidity,
+	amount_remaining: Balance,
+	fee: Permill,
+	exact_in: bool,
+) -> Result<SwapStep, MathError> {
+	let zero_for_one = sqrt_price_current >= sqrt_price_target;
+
+	let mut amount_in = U256::zero();
+	let mut amount_out = U256::zero();
+
+	let sqrt_price_next = if exact_in {
+		let amount_remaining_less_fee = fee.left_from_one().mul_floor(amount_remaining);
+		amount_in = if zero_for_one {
+			amount_0_delta_u256(sqrt_price_target, sqrt_price_current, liquidity, true)?
+		} else {
+			amount_1_delta_u256(sqrt_price_current, sqrt_price_target, liquidity, true)?
+		};
+		if U256::from(amount_remaining_less_fee) >= amount_in {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_input(sqrt_price_current, liquidity, amount_remaining_less_fee, zero_for_one)?
+		}
+	} else {
+		amount_out = if zero_for_one {
+			amount_1_delta_u256(sqrt_price_target, sqrt_price_current, liquidity, false)?
+		} else {
+			amount_0_delta_u256(sqrt_price_current, sqrt_price_target, liquidity, false)?
+		};
+		if U256::from(amount_remaining) >= amount_out {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining, zero_for_one)?
+		}
+	};
+
+	let target_reached = sqrt_price_next == sqrt_price_target;
+
+	if zero_for_one {
+		if !target_reached || !exact_in {
+			amount_in = amount_0_delta_u256(sqrt_price_next, sqrt_price_current, liquidity, true)?;
+		}
+		if !target_reached || exact_in {
+			amount_out = amount_1_delta_u256(sqrt_price_next, sqrt_price_current, liquidity, false)?;
+		}
+	} else {
+		if !target_reached || !exact_in {
+			amount_in = amount_1_delta_u256(sqrt_price_current, sqrt_price_next, liquidity, true)?;
+		}
+		if !target_reached || exact_in {
+			amount_out = amount_0_delta_u256(sqrt_price_current, sqrt_price_next, liquidity, false)?;
+		}
+	}
+
+	// Rounding of price may result in slightly more than requested
+	if !exact_in && amount_out > U256::from(amount_remaining) {
+		amount_out = U256::from(amount_remaining);
+	}
+
+	let amount_in = to_balance!(amount_in)?;
+	let amount_out = to_balance!(amount_out)?;
+
+	let fee_amount = if exact_in && !target_reached {
+		// Whole remaining amount is used, rest of it is the fee
+		amount_remaining.saturating_sub(amount_in)
+	} else {
+		multiply_by_rational_with_rounding(
+			amount_in,
+			fee.deconstruct().into(),
+			fee.left_from_one().deconstruct().into(),
+			Rounding::Up,
+		)
+		.ok_or(Overflow)?
+	};
+
+	Ok(SwapStep {
+		sqrt_price_next,
+		amount_in,
+		amount_out,
+		fee_amount,
+	})
+}
+
+/// Calculates increase of fee growth when fee amount is distributed to liquidity.
+pub fn fee_growth_delta(fee_amount: Balance, liquidity: Liquidity) -> FeeGrowth {
+	if liquidity == 0 {
+		return 0;
+	}
+
+	// Fee growth wraps around on overflow
+	((U256::from(fee_amount) << RESOLUTION) / U256::from(liquidity)).low_u128()
+}
+
+/// Calculates fee growth inside of price range given by two ticks.
+///
+/// - `fee_growth_outside_lower` / `fee_growth_outside_upper` - fee growth tracked by the lower and upper tick
+///   on the other side of the tick from the current tick
+pub fn fee_growth_inside(
+	tick_lower: Tick,
+	tick_upper: Tick,
+	tick_current: Tick,
+	fee_growth_global: FeeGrowth,
+	fee_growth_outside_lower: FeeGrowth,
+	fee_growth_outside_upper: FeeGrowth,
+) -> FeeGrowth {
+	let fee_growth_below = if tick_current >= tick_lower {
+		fee_growth_outside_lower
+	} else {
+		fee_growth_global.wrapping_sub(fee_growth_outside_lower)
+	};
+
+	let fee_growth_above = if tick_current < tick_upper {
+		fee_growth_outside_upper
+	} else {
+		fee_growth_global.wrapping_sub(fee_growth_outside_upper)
+	};
+
+	fee_growth_global
+		.wrapping_sub(fee_growth_below)
+		.wrapping_sub(fee_growth_above)
+}
+
+/// Calculates fee amount accrued by liquidity since the fee growth inside of its range was last recorded.
+pub fn fees_accrued(
+	liquidity: Liquidity,
+	fee_growth_inside: FeeGrowth,
+	fee_growth_inside_last: FeeGrowth,
+) -> Result<Balance, MathError> {
+	let fee_growth = fee_growth_inside.wrapping_sub(fee_growth_inside_last);
+	let fees = (U256::from(fee_growth) * U256::from(liquidity)) >> RESOLUTION;
+	to_balance!(fees)
+}
+
+/// Compresses tick to tick spacing, rounding towards negative infinity.
+pub fn compress_tick(tick: Tick, tick_spacing: Tick) -> Tick {
+	let compressed = tick / tick_spacing;
+	if tick < 0 && tick % tick_spacing != 0 {
+		compressed - 1
+	} else {
+		compressed
+	}
+}
+
+/// Position of compressed tick in tick bitmap - index of word and index of bit in the word.
+pub fn tick_bitmap_position(compressed_tick: Tick) -> (i32, u8) {
+	(
+		compressed_tick.div_euclid(TICKS_PER_WORD),
+		compressed_tick.rem_euclid(TICKS_PER_WORD) as u8,
+	)
+}
+
+/// Finds next initialized bit in the word, starting at `bit` and searching to lower bits when `lte` is true
+/// or to higher bits otherwise.
+///
+/// Returns the bit found and whether it is initialized. If there is no initialized bit, returns the last bit
+/// searched.
+pub fn next_initialized_bit(word: u128, bit: u8, lte: bool) -> (u8, bool) {
+	if lte {
+		let mask = if bit >= 127 {
+			u128::MAX
+		} else {
+			(1u128 << (bit + 1)) - 1
+		};
+		let masked = word & mask;
+		if masked != 0 {
+			(127 - masked.leading_zeros() as u8, true)
+		} else {
+			(0, false)
+		}
+	} else {
+		let mask = !((1u128 << bit) - 1);
+		let masked = word & mask;
+		if masked != 0 {
+			(masked.trailing_zeros() as u8, true)
+		} else {
+			(127, false)
+		}
+	}
+}
+
+/// Finds next initialized tick within one word of tick bitmap.
+///
+/// Searches ticks lower than or equal to `tick` when `lte` is true, greater than `tick` otherwise.
+/// `word_at` provides word of tick bitmap at given index.
+///
+/// Returns the next tick and whether it is initialized. If no initialized tick is found in the word,
+/// returns the last tick of the word in direction of the search.
+pub fn next_initialized_tick_within_one_word(
+	tick: Tick,
+	tick_spacing: Tick,
+	lte: bool,
+	word_at: impl FnOnce(i32) -> u128,
+) -> (Tick, bool) {
+	let compressed = compress_tick(tick, tick_spacing);
+	let compressed = if lte { compressed } else { compressed + 1 };
+
+	let (word_pos, bit_pos) = tick_bitmap_position(compressed);
+	let (bit, initialized) = next_initialized_bit(word_at(word_pos), bit_pos, lte);
+
+	((word_pos * TICKS_PER_WORD + bit as i32) * tick_spacing, initialized)
+}
@@ -0,0 +1,8 @@
+mod math;
+
+#[cfg(test)]
+mod invariants;
+#[cfg(test)]
+mod tests;
+
+pub use math::*;
@@ -0,0 +1,416 @@
+#![allow(clippy::type_complexity)]
+use crate::concentrated_liquidity::*;
+use crate::types::{Balance, HYDRA_ONE as ONE};
+use crate::MathError::{InsufficientOutReserve, Overflow, ZeroReserve};
+
+use sp_arithmetic::{FixedU128, Permill};
+use std::vec;
+
+const SQRT_PRICE_TICK_MINUS_1000: SqrtPrice = 17_547_129_613_991_598_782;
+const SQRT_PRICE_TICK_1000: SqrtPrice = 19_392_480_388_906_836_278;
+
+/// Liquidity of 100 ONE of each asset in range [-1000, 1000] at price 1.
+const LIQUIDITY: Liquidity = 2_050_516_626_810_664;
+
+#[test]
+fn sqrt_price_at_tick_should_work() {
+	let cases = vec![
+		(0, Ok(Q64), "Zero tick"),
+		(1, Ok(18_447_666_387_855_959_851), "Tick 1"),
+		(-1, Ok(18_445_821_805_675_392_312), "Tick -1"),
+		(1000, Ok(SQRT_PRICE_TICK_1000), "Tick 1000"),
+		(-1000, Ok(SQRT_PRICE_TICK_MINUS_1000), "Tick -1000"),
+		(6932, Ok(26_087_872_550_306_729_022), "Price close to 2"),
+		(-6932, Ok(13_043_699_376_587_823_078), "Price close to 1/2"),
+		(MIN_TICK, Ok(MIN_SQRT_PRICE), "Min tick"),
+		(MAX_TICK, Ok(MAX_SQRT_PRICE), "Max tick"),
+		(MIN_TICK - 1, Err(Overflow), "Below min tick"),
+		(MAX_TICK + 1, Err(Overflow), "Above max tick"),
+	];
+
+	for case in cases {
+		assert_eq!(sqrt_price_at_tick(case.0), case.1, "{}", case.2);
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_work() {
+	let cases = vec![
+		(Q64, Ok(0), "Price 1"),
+		(Q64 - 1, Ok(-1), "Just below price 1"),
+		(18_447_666_387_855_959_851, Ok(1), "Exact tick 1"),
+		(18_447_666_387_855_959_850, Ok(0), "Just below tick 1"),
+		(SQRT_PRICE_TICK_MINUS_1000, Ok(-1000), "Tick -1000"),
+		(MIN_SQRT_PRICE, Ok(MIN_TICK), "Min sqrt price"),
+		(MAX_SQRT_PRICE, Ok(MAX_TICK), "Max sqrt price"),
+		(MIN_SQRT_PRICE - 1, Err(Overflow), "Below min sqrt price"),
+		(MAX_SQRT_PRICE + 1, Err(Overflow), "Above max sqrt price"),
+	];
+
+	for case in cases {
+		assert_eq!(tick_at_sqrt_price(case.0), case.1, "{}", case.2);
+	}
+}
+
+#[test]
+fn sqrt_price_from_price_should_work() {
+	assert_eq!(sqrt_price_from_price(FixedU128::from(1)), Ok(Q64));
+	assert_eq!(
+		sqrt_price_from_price(FixedU128::from(2)),
+		Ok(26_087_635_650_665_564_424)
+	);
+	assert_eq!(sqrt_price_from_price(FixedU128::from(0)), Err(Overflow));
+}
+
+#[test]
+fn price_from_sqrt_price_should_work() {
+	assert_eq!(price_from_sqrt_price(Q64), Ok(FixedU128::from(1)));
+	assert_eq!(
+		price_from_sqrt_price(26_087_872_550_306_729_022),
+		Ok(FixedU128::from_inner(2_000_036_323_830_947_322))
+	);
+	assert_eq!(
+		price_from_sqrt_price(13_043_699_376_587_823_078),
+		Ok(FixedU128::from_inner(499_990_919_207_187_761))
+	);
+}
+
+#[test]
+fn liquidity_for_amounts_should_work() {
+	let cases = vec![
+		(Q64, 100 * ONE, 100 * ONE, Ok(LIQUIDITY), "Price in range"),
+		(
+			sqrt_price_at_tick(-2000).unwrap(),
+			100 * ONE,
+			0,
+			Ok(999_633_474_809_517),
+			"Price below range",
+		),
+		(
+			sqrt_price_at_tick(2000).unwrap(),
+			0,
+			100 * ONE,
+			Ok(999_633_474_809_517),
+			"Price above range",
+		),
+		(Q64, 100 * ONE, 0, Ok(0), "Price in range and only asset 0 provided"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			liquidity_for_amounts(case.0, SQRT_PRICE_TICK_MINUS_1000, SQRT_PRICE_TICK_1000, case.1, case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+	}
+}
+
+#[test]
+fn amounts_for_liquidity_should_work() {
+	let cases: Vec<(SqrtPrice, bool, Result<(Balance, Balance), _>, &str)> = vec![
+		(Q64, true, Ok((100 * ONE, 100 * ONE)), "Price in range rounded up"),
+		(
+			Q64,
+			false,
+			Ok((100 * ONE - 1, 100 * ONE - 1)),
+			"Price in range rounded down",
+		),
+		(
+			sqrt_price_at_tick(-2000).unwrap(),
+			true,
+			Ok((205_126_846_837_677, 0)),
+			"Price below range",
+		),
+		(
+			sqrt_price_at_tick(2000).unwrap(),
+			true,
+			Ok((0, 205_126_846_837_677)),
+			"Price above range",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			amounts_for_liquidity(
+				case.0,
+				SQRT_PRICE_TICK_MINUS_1000,
+				SQRT_PRICE_TICK_1000,
+				LIQUIDITY,
+				case.1
+			),
+			case.2,
+			"{}",
+			case.3
+		);
+	}
+}
+
+#[test]
+fn next_sqrt_price_should_work() {
+	assert_eq!(
+		next_sqrt_price_from_input(Q64, LIQUIDITY, 10 * ONE, true),
+		Ok(18_357_219_224_292_238_085)
+	);
+	assert_eq!(
+		next_sqrt_price_from_input(Q64, LIQUIDITY, 10 * ONE, false),
+		Ok(18_536_705_519_681_346_926)
+	);
+	assert_eq!(
+		next_sqrt_price_from_output(Q64, LIQUIDITY, 10 * ONE, true),
+		Ok(18_356_782_627_737_756_305)
+	);
+	assert_eq!(
+		next_sqrt_price_from_output(Q64, LIQUIDITY, 10 * ONE, false),
+		Ok(18_537_146_395_510_486_361)
+	);
+	assert_eq!(next_sqrt_price_from_input(Q64, LIQUIDITY, 0, true), Ok(Q64));
+	assert_eq!(next_sqrt_price_from_input(Q64, 0, ONE, true), Err(ZeroReserve));
+	assert_eq!(
+		next_sqrt_price_from_output(Q64, 1000, Balance::MAX, true),
+		Err(Overflow)
+	);
+	assert_eq!(
+		next_sqrt_price_from_output(Q64, LIQUIDITY, 10_000 * ONE, false),
+		Err(InsufficientOutReserve)
+	);
+}
+
+#[test]
+fn compute_swap_step_should_work() {
+	let fee = Permill::from_rational(3u32, 1000u32);
+	let cases = vec![
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			LIQUIDITY,
+			10 * ONE,
+			fee,
+			true,
+			SwapStep {
+				sqrt_price_next: 18_357_486_499_298_813_205,
+				amount_in: 9_970_000_000_000,
+				amount_out: 9_921_758_531_840,
+				fee_amount: 30_000_000_000,
+			},
+			"Sell asset 0 within range",
+		),
+		(
+			SQRT_PRICE_TICK_1000,
+			LIQUIDITY,
+			10 * ONE,
+			fee,
+			true,
+			SwapStep {
+				sqrt_price_next: 18_536_435_635_343_431_540,
+				amount_in: 9_970_000_000_000,
+				amount_out: 9_921_758_531_840,
+				fee_amount: 30_000_000_000,
+			},
+			"Sell asset 1 within range",
+		),
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			LIQUIDITY,
+			10 * ONE,
+			fee,
+			false,
+			SwapStep {
+				sqrt_price_next: 18_356_782_627_737_756_305,
+				amount_in: 10_049_007_196_848,
+				amount_out: 10_000_000_000_000,
+				fee_amount: 30_237_734_795,
+			},
+			"Buy asset 1 within range",
+		),
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			LIQUIDITY,
+			1_000_000 * ONE,
+			fee,
+			true,
+			SwapStep {
+				sqrt_price_next: SQRT_PRICE_TICK_MINUS_1000,
+				amount_in: 105_126_846_837_677,
+				amount_out: 99_999_999_999_999,
+				fee_amount: 316_329_529_101,
+			},
+			"Sell reaches target price",
+		),
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			LIQUIDITY,
+			1_000_000 * ONE,
+			fee,
+			false,
+			SwapStep {
+				sqrt_price_next: SQRT_PRICE_TICK_MINUS_1000,
+				amount_in: 105_126_846_837_677,
+				amount_out: 99_999_999_999_999,
+				fee_amount: 316_329_529_101,
+			},
+			"Buy reaches target price",
+		),
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			LIQUIDITY,
+			10 * ONE,
+			Permill::zero(),
+			true,
+			SwapStep {
+				sqrt_price_next: 18_357_219_224_292_238_085,
+				amount_in: 10_000_000_000_000,
+				amount_out: 9_951_468_481_885,
+				fee_amount: 0,
+			},
+			"Sell without fee",
+		),
+		(
+			SQRT_PRICE_TICK_MINUS_1000,
+			0,
+			10 * ONE,
+			fee,
+			true,
+			SwapStep {
+				sqrt_price_next: SQRT_PRICE_TICK_MINUS_1000,
+				amount_in: 0,
+				amount_out: 0,
+				fee_amount: 0,
+			},
+			"Range without liquidity is crossed",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			compute_swap_step(Q64, case.0, case.1, case.2, case.3, case.4),
+			Ok(case.5),
+			"{}",
+			case.6
+		);
+	}
+}
+
+#[test]
+fn fee_growth_should_work() {
+	let fee_growth = fee_growth_delta(3 * ONE, LIQUIDITY);
+
+	assert_eq!(fee_growth, 26_988_433_791_538_593);
+	assert_eq!(fee_growth_delta(3 * ONE, 0), 0);
+	assert_eq!(fees_accrued(LIQUIDITY, fee_growth, 0), Ok(3 * ONE - 1));
+	assert_eq!(
+		fees_accrued(LIQUIDITY, 5, FeeGrowth::MAX - fee_growth + 6),
+		Ok(3 * ONE - 1)
+	);
+}
+
+#[test]
+fn fee_growth_inside_should_work() {
+	let global = 1_000;
+	let outside_lower = 100;
+	let outside_upper = 200;
+
+	// current tick in range
+	assert_eq!(fee_growth_inside(-10, 10, 0, global, outside_lower, outside_upper), 700);
+	// current tick below range - everything outside of lower tick is inside
+	assert_eq!(
+		fee_growth_inside(-10, 10, -20, global, outside_lower, outside_upper),
+		FeeGrowth::MAX - 99
+	);
+	// current tick above range
+	assert_eq!(fee_growth_inside(-10, 10, 20, global, 100, 700), 600);
+	// wraps around
+	assert_eq!(fee_growth_inside(-10, 10, 0, 5, FeeGrowth::MAX, 1), 5);
+}
+
+#[test]
+fn virtual_reserves_should_work() {
+	assert_eq!(virtual_reserves(Q64, LIQUIDITY), Ok((LIQUIDITY, LIQUIDITY)));
+	assert_eq!(
+		virtual_reserves(26_087_872_550_306_729_022, 1_000_000 * ONE),
+		Ok((707_100_360_067_216_880, 1_414_226_404_728_375_633))
+	);
+	assert_eq!(virtual_reserves(Q64, 0), Ok((0, 0)));
+}
+
+#[test]
+fn add_liquidity_delta_should_work() {
+	assert_eq!(add_liquidity_delta(100, 50), Ok(150));
+	assert_eq!(add_liquidity_delta(100, -50), Ok(50));
+	assert_eq!(add_liquidity_delta(100, -101), Err(InsufficientOutReserve));
+	assert_eq!(add_liquidity_delta(Liquidity::MAX, 1), Err(Overflow));
+}
+
+#[test]
+fn max_liquidity_per_tick_should_work() {
+	assert_eq!(
+		max_liquidity_per_tick(1),
+		Ok(Liquidity::MAX / (2 * MAX_TICK as u128 + 1))
+	);
+	assert_eq!(max_liquidity_per_tick(60), Ok(Liquidity::MAX / 14_787));
+	assert!(max_liquidity_per_tick(0).is_err());
+}
+
+#[test]
+fn compress_tick_should_round_towards_negative_infinity() {
+	assert_eq!(compress_tick(120, 60), 2);
+	assert_eq!(compress_tick(119, 60), 1);
+	assert_eq!(compress_tick(-120, 60), -2);
+	assert_eq!(compress_tick(-119, 60), -2);
+	assert_eq!(compress_tick(-1, 60), -1);
+}
+
+#[test]
+fn tick_bitmap_position_should_work() {
+	assert_eq!(tick_bitmap_position(0), (0, 0));
+	assert_eq!(tick_bitmap_position(127), (0, 127));
+	assert_eq!(tick_bitmap_position(128), (1, 0));
+	assert_eq!(tick_bitmap_position(-1), (-1, 127));
+	assert_eq!(tick_bitmap_position(-129), (-2, 127));
+}
+
+#[test]
+fn next_initialized_bit_should_work() {
+	let word: u128 = (1 << 5) | (1 << 100);
+
+	assert_eq!(next_initialized_bit(word, 50, true), (5, true));
+	assert_eq!(next_initialized_bit(word, 5, true), (5, true));
+	assert_eq!(next_initialized_bit(word, 4, true), (0, false));
+	assert_eq!(next_initialized_bit(word, 127, true), (100, true));
+	assert_eq!(next_initialized_bit(word, 50, false), (100, true));
+	assert_eq!(next_initialized_bit(word, 0, false), (5, true));
+	assert_eq!(next_initialized_bit(word, 101, false), (127, false));
+}
+
+#[test]
+fn next_initialized_tick_within_one_word_should_work() {
+	// initialized ticks -60, 120 with tick spacing 60
+	let word_at = |word_pos: i32| -> u128 {
+		match word_pos {
+			-1 => 1 << 127,
+			0 => 1 << 2,
+			_ => 0,
+		}
+	};
+
+	assert_eq!(next_initialized_tick_within_one_word(0, 60, true, word_at), (0, false));
+	assert_eq!(
+		next_initialized_tick_within_one_word(-1, 60, true, word_at),
+		(-60, true)
+	);
+	assert_eq!(
+		next_initialized_tick_within_one_word(0, 60, false, word_at),
+		(120, true)
+	);
+	assert_eq!(
+		next_initialized_tick_within_one_word(120, 60, true, word_at),
+		(120, true)
+	);
+	assert_eq!(
+		next_initialized_tick_within_one_word(120, 60, false, word_at),
+		(127 * 60, false)
+	);
+	assert_eq!(
+		next_initialized_tick_within_one_word(-60, 60, false, word_at),
+		(120, true)
+	);
+}
@@ -1,5 +1,6 @@
 use super::types::*;
 use crate::dynamic_fees::types::NetVolumeDirection::{InOut, OutIn};
+use crate::types::Balance;
 use num_traits::Zero;
 use sp_arithmetic::traits::Saturating;
 use sp_arithmetic::{FixedPointOperand, FixedU128, PerThing};
@@ -40,6 +41,39 @@ where
 	recalculate_fee(volume, previous_fee, last_block_diff, params, InOut)
 }
 
+/// Recalculate minimum fee given the liquidity of an asset.
+///
+/// Shallow pools get a higher minimum fee. The premium added to `min_fee` is
+/// `max_premium * reference_liquidity / (reference_liquidity + liquidity)`, so it is `max_premium` for an empty pool
+/// and half of it when the liquidity equals `reference_liquidity`.
+///
+/// The result is capped at `max_fee`.
+pub fn liquidity_adjusted_min_fee<Fee: PerThing>(
+	liquidity: Balance,
+	min_fee: Fee,
+	max_fee: Fee,
+	params: LiquidityDepthParams<Fee>,
+) -> Fee
+where
+	<Fee as PerThing>::Inner: FixedPointOperand,
+{
+	if params.reference_liquidity.is_zero() {
+		return min_fee;
+	}
+
+	let depth = FixedU128::from_rational(
+		params.reference_liquidity,
+		params.reference_liquidity.saturating_add(liquidity),
+	);
+	let premium = FixedU128::from(params.max_premium).saturating_mul(depth);
+
+	FixedU128::from(min_fee)
+		.saturating_add(premium)
+		.into_clamped_perthing::<Fee>()
+		.min(max_fee)
+		.max(min_fee)
+}
+
 fn recalculate_fee<Fee: PerThing>(
 	volume: OracleEntry,
 	previous_fee: Fee,
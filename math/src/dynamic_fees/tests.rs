@@ -1,5 +1,5 @@
 use super::types::*;
-use crate::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
+use crate::dynamic_fees::{liquidity_adjusted_min_fee, recalculate_asset_fee, recalculate_protocol_fee};
 use num_traits::Zero;
 use sp_arithmetic::{FixedU128, Permill};
 
@@ -232,3 +232,70 @@ fn protocol_fee_should_clamp_to_max_fee() {
 	let calculated_fee = recalculate_protocol_fee(volume, previous_fee, last_block_diff, params);
 	assert_eq!(calculated_fee, expected_fee);
 }
+
+#[test]
+fn liquidity_adjusted_min_fee_should_add_half_of_premium_when_liquidity_equals_reference() {
+	let params = LiquidityDepthParams {
+		reference_liquidity: 1000,
+		max_premium: Permill::from_percent(2),
+	};
+
+	let min_fee = liquidity_adjusted_min_fee(1000, Permill::from_percent(1), Permill::from_percent(10), params);
+
+	assert_eq!(min_fee, Permill::from_percent(2));
+}
+
+#[test]
+fn liquidity_adjusted_min_fee_should_add_max_premium_when_liquidity_is_zero() {
+	let params = LiquidityDepthParams {
+		reference_liquidity: 1000,
+		max_premium: Permill::from_percent(2),
+	};
+
+	let min_fee = liquidity_adjusted_min_fee(0, Permill::from_percent(1), Permill::from_percent(10), params);
+
+	assert_eq!(min_fee, Permill::from_percent(3));
+}
+
+#[test]
+fn liquidity_adjusted_min_fee_should_decrease_when_liquidity_increases() {
+	let params = LiquidityDepthParams {
+		reference_liquidity: 1000,
+		max_premium: Permill::from_percent(2),
+	};
+
+	let shallow = liquidity_adjusted_min_fee(
+		1_000,
+		Permill::from_percent(1),
+		Permill::from_percent(10),
+		params.clone(),
+	);
+	let deep = liquidity_adjusted_min_fee(1_000_000, Permill::from_percent(1), Permill::from_percent(10), params);
+
+	assert!(deep < shallow);
+	assert!(deep >= Permill::from_percent(1));
+}
+
+#[test]
+fn liquidity_adjusted_min_fee_should_clamp_to_max_fee() {
+	let params = LiquidityDepthParams {
+		reference_liquidity: 1000,
+		max_premium: Permill::from_percent(20),
+	};
+
+	let min_fee = liquidity_adjusted_min_fee(0, Permill::from_percent(1), Permill::from_percent(10), params);
+
+	assert_eq!(min_fee, Permill::from_percent(10));
+}
+
+#[test]
+fn liquidity_adjusted_min_fee_should_not_change_min_fee_when_reference_liquidity_is_zero() {
+	let params = LiquidityDepthParams {
+		reference_liquidity: 0,
+		max_premium: Permill::from_percent(2),
+	};
+
+	let min_fee = liquidity_adjusted_min_fee(0, Permill::from_percent(1), Permill::from_percent(10), params);
+
+	assert_eq!(min_fee, Permill::from_percent(1));
+}
@@ -10,6 +10,13 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+/// Liquidity depth parameters - liquidity at which half of the maximum premium is added to the minimum fee.
+#[derive(Debug, Clone)]
+pub struct LiquidityDepthParams<Fee> {
+	pub reference_liquidity: Balance,
+	pub max_premium: Fee,
+}
+
 /// Oracle entry data for an asset, providing amount in and out and total liquidity of an asset.
 #[derive(Debug, Clone)]
 pub struct OracleEntry {
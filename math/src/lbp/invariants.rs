@@ -3,6 +3,7 @@ use crate::lbp::lbp;
 use primitive_types::U256;
 use proptest::prelude::*;
 use rand::Rng;
+use sp_arithmetic::FixedU128;
 
 const MIN_START_BLOCK: u32 = 0;
 const MAX_START_BLOCK: u32 = 10_000_000;
@@ -52,3 +53,123 @@ proptest! {
 		assert_approx_eq!(a1*a2, b1*b2, max_delta, "The invariant does not hold")
 	}
 }
+
+fn steepness() -> impl Strategy<Value = FixedU128> {
+	(1u128..2_000u128).prop_map(|v| FixedU128::from_rational(v, 100))
+}
+
+fn steps() -> impl Strategy<Value = u32> {
+	1u32..200u32
+}
+
+fn assert_weight_curve_invariants<F>(
+	calculate: F,
+	start_x_block: u32,
+	lbp_length: u32,
+	start_y_weight: u32,
+	end_y_weight: u32,
+) -> (u32, u32, u32)
+where
+	F: Fn(u32, u32, u32, u32, u32) -> u32,
+{
+	let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+	let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+	let weight = calculate(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block);
+	let next_weight = calculate(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block + 1);
+	let reversed_weight = calculate(start_x_block, end_x_block, end_y_weight, start_y_weight, at_block);
+
+	// initial and final weights are reached
+	assert_eq!(
+		calculate(start_x_block, end_x_block, start_y_weight, end_y_weight, start_x_block),
+		start_y_weight
+	);
+	assert_eq!(
+		calculate(start_x_block, end_x_block, start_y_weight, end_y_weight, end_x_block),
+		end_y_weight
+	);
+
+	// weight stays within the range and moves only towards the final weight
+	assert!(start_y_weight <= weight && weight <= end_y_weight);
+	assert!(weight <= next_weight);
+	assert!(start_y_weight <= reversed_weight && reversed_weight <= end_y_weight);
+
+	let linear_weight =
+		lbp::calculate_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+
+	(weight, reversed_weight, linear_weight)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn calculate_exponential_weights_invariants(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		steepness in steepness()) {
+		let (weight, reversed_weight, linear_weight) = assert_weight_curve_invariants(
+			|start, end, initial, last, at| {
+				lbp::calculate_exponential_weights(start, end, initial, last, at, steepness).unwrap()
+			},
+			start_x_block,
+			lbp_length,
+			start_y_weight,
+			end_y_weight,
+		);
+
+		// weight changes faster than with linear curve at any point of the sale
+		assert!(weight + 1 >= linear_weight, "Exponential curve is below the linear one");
+		assert!(
+			reversed_weight <= start_y_weight + end_y_weight - linear_weight + 1,
+			"Exponential curve is above the linear one"
+		);
+	}
+
+	#[test]
+	fn calculate_logarithmic_weights_invariants(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		steepness in steepness()) {
+		let (weight, reversed_weight, linear_weight) = assert_weight_curve_invariants(
+			|start, end, initial, last, at| {
+				lbp::calculate_logarithmic_weights(start, end, initial, last, at, steepness).unwrap()
+			},
+			start_x_block,
+			lbp_length,
+			start_y_weight,
+			end_y_weight,
+		);
+
+		// weight changes faster than with linear curve at any point of the sale
+		assert!(weight + 1 >= linear_weight, "Logarithmic curve is below the linear one");
+		assert!(
+			reversed_weight <= start_y_weight + end_y_weight - linear_weight + 1,
+			"Logarithmic curve is above the linear one"
+		);
+	}
+
+	#[test]
+	fn calculate_step_weights_invariants(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		steps in steps()) {
+		let (weight, _, linear_weight) = assert_weight_curve_invariants(
+			|start, end, initial, last, at| lbp::calculate_step_weights(start, end, initial, last, at, steps).unwrap(),
+			start_x_block,
+			lbp_length,
+			start_y_weight,
+			end_y_weight,
+		);
+
+		// weight lags behind the linear curve by at most one step
+		let step_size = (end_y_weight - start_y_weight) / steps;
+		assert!(weight <= linear_weight + 1, "Step curve is above the linear one");
+		assert!(weight + step_size + 2 >= linear_weight, "Step curve lags more than one step");
+	}
+}
@@ -4,7 +4,7 @@ use primitive_types::U256;
 use crate::types::{AssetId, Balance, LBPWeight};
 use crate::{
 	ensure, to_balance, to_lbp_weight, to_u256, MathError,
-	MathError::{Overflow, ZeroDuration, ZeroReserve},
+	MathError::{DivisionByZero, Overflow, ZeroDuration, ZeroReserve},
 };
 
 use core::convert::From;
@@ -105,7 +105,6 @@ pub fn calculate_spot_price_with_fee(
 	Ok(spot_price_without_fee)
 }
 
-use crate::MathError::DivisionByZero;
 use num_traits::One;
 
 /// Calculating selling price given reserve of selling asset and reserve of buying asset.
@@ -211,6 +210,169 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 	to_lbp_weight!(result)
 }
 
+/// Calculating weight at any given block in an interval using exponential curve.
+/// The rate of change is proportional to e^(-k * t), so most of the change happens early in the interval
+/// and the weight approaches the final weight exponentially.
+/// Formula : start_y + (end_y - start_y) * (1 - e^(-k * t)) / (1 - e^(-k))
+/// where t = (at - start_x) / (end_x - start_x)
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `at` - block number at which to calculate the weight
+/// - `steepness` - `k` parameter of the curve. Zero steepness results in linear weights.
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	at: BlockNumber,
+	steepness: FixedU128,
+) -> Result<LBPWeight, MathError> {
+	if steepness.is_zero() {
+		return calculate_linear_weights(start_x, end_x, start_y, end_y, at);
+	}
+
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+	if elapsed == duration {
+		return Ok(end_y);
+	}
+
+	let t = div_to_fixed(elapsed, duration, Rounding::Down).ok_or(Overflow)?;
+	let k = div_to_fixed(steepness.into_inner(), FixedU128::DIV, Rounding::Down).ok_or(Overflow)?;
+	let kt = k.checked_mul(t).ok_or(Overflow)?;
+
+	let e_kt: U32F96 = crate::transcendental::exp(kt, true).map_err(|_| Overflow)?;
+	let e_k: U32F96 = crate::transcendental::exp(k, true).map_err(|_| Overflow)?;
+
+	let num = U32F96::one().checked_sub(e_kt).ok_or(Overflow)?;
+	let denom = U32F96::one().checked_sub(e_k).ok_or(Overflow)?;
+	let progress = num.checked_div(denom).ok_or(Overflow)?;
+
+	interpolate_weights(start_y, end_y, progress)
+}
+
+/// Calculating weight at any given block in an interval using logarithmic curve.
+/// The rate of change is proportional to 1 / (1 + k * t). It slows down more gradually than the exponential
+/// curve with the same `k`, so the change is spread more evenly over the interval.
+/// Formula : start_y + (end_y - start_y) * ln(1 + k * t) / ln(1 + k)
+/// where t = (at - start_x) / (end_x - start_x)
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `at` - block number at which to calculate the weight
+/// - `steepness` - `k` parameter of the curve. Zero steepness results in linear weights.
+pub fn calculate_logarithmic_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	at: BlockNumber,
+	steepness: FixedU128,
+) -> Result<LBPWeight, MathError> {
+	if steepness.is_zero() {
+		return calculate_linear_weights(start_x, end_x, start_y, end_y, at);
+	}
+
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+	if elapsed == duration {
+		return Ok(end_y);
+	}
+
+	let t = div_to_fixed(elapsed, duration, Rounding::Down).ok_or(Overflow)?;
+	let k = div_to_fixed(steepness.into_inner(), FixedU128::DIV, Rounding::Down).ok_or(Overflow)?;
+	let kt = k.checked_mul(t).ok_or(Overflow)?;
+
+	let (num, _): (U32F96, bool) =
+		crate::transcendental::ln(U32F96::one().checked_add(kt).ok_or(Overflow)?).map_err(|_| Overflow)?;
+	let (denom, _): (U32F96, bool) =
+		crate::transcendental::ln(U32F96::one().checked_add(k).ok_or(Overflow)?).map_err(|_| Overflow)?;
+	let progress = num.checked_div(denom).ok_or(Overflow)?;
+
+	interpolate_weights(start_y, end_y, progress)
+}
+
+/// Calculating weight at any given block in an interval using step function.
+/// The interval is split into `steps` equal parts and weight changes by the same amount at the end of each part.
+/// Formula : start_y + (end_y - start_y) * floor(t * steps) / steps
+/// where t = (at - start_x) / (end_x - start_x)
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `at` - block number at which to calculate the weight
+/// - `steps` - number of steps
+pub fn calculate_step_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	at: BlockNumber,
+	steps: u32,
+) -> Result<LBPWeight, MathError> {
+	ensure!(steps != 0, DivisionByZero);
+
+	let (elapsed, duration) = interval_progress(start_x, end_x, at)?;
+
+	// elapsed fits into u32, so this can not overflow
+	let completed_steps = elapsed
+		.checked_mul(steps.into())
+		.ok_or(Overflow)?
+		.checked_div(duration)
+		.ok_or(Overflow)?;
+
+	let delta: u128 = start_y.abs_diff(end_y).into();
+	let delta = delta
+		.checked_mul(completed_steps)
+		.ok_or(Overflow)?
+		.checked_div(steps.into())
+		.ok_or(Overflow)?;
+	let delta = to_lbp_weight!(delta)?;
+
+	if end_y >= start_y {
+		start_y.checked_add(delta).ok_or(Overflow)
+	} else {
+		start_y.checked_sub(delta).ok_or(Overflow)
+	}
+}
+
+/// Returns number of blocks elapsed since the beginning of an interval and the length of the interval.
+fn interval_progress<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<(u128, u128), MathError> {
+	// `at` must be within the interval
+	end_x.checked_sub(&at).ok_or(Overflow)?;
+	let elapsed = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let dx = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let dx: u32 = dx.try_into().map_err(|_| Overflow)?;
+	let elapsed: u128 = elapsed.try_into().map_err(|_| Overflow)?;
+
+	ensure!(dx != 0, ZeroDuration);
+
+	Ok((elapsed, dx.into()))
+}
+
+/// Moves weight from `start_y` towards `end_y` by `progress`, which is capped at 1.
+fn interpolate_weights(start_y: LBPWeight, end_y: LBPWeight, progress: U32F96) -> Result<LBPWeight, MathError> {
+	let progress = progress.min(U32F96::one());
+
+	let delta = mul_to_balance(start_y.abs_diff(end_y).into(), progress, Rounding::Down).ok_or(Overflow)?;
+	let delta = to_lbp_weight!(delta)?;
+
+	if end_y >= start_y {
+		start_y.checked_add(delta).ok_or(Overflow)
+	} else {
+		start_y.checked_sub(delta).ok_or(Overflow)
+	}
+}
+
 /// Create a fixed point number based on two `u128` values. Divides the values and rounds according to `r`.
 pub(crate) fn div_to_fixed(num: u128, denom: u128, r: Rounding) -> Option<U32F96> {
 	let bits = multiply_by_rational_with_rounding(num, U32F96::one().to_bits(), denom, r)?;
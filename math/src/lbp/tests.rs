@@ -2,8 +2,9 @@
 use crate::lbp::lbp;
 
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
-use crate::MathError::{Overflow, ZeroDuration, ZeroReserve};
+use crate::MathError::{DivisionByZero, Overflow, ZeroDuration, ZeroReserve};
 
+use sp_arithmetic::FixedU128;
 use std::vec;
 
 #[test]
@@ -219,3 +220,396 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let one = FixedU128::from(1);
+	let five = FixedU128::from(5);
+	let cases = vec![
+		(100u32, 200u32, 1_000u32, 2_000u32, 150u32, one, Ok(1_622), "Easy case"),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Ok(1_796),
+			"Later block",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			150u32,
+			one,
+			Ok(1_378),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			150u32,
+			five,
+			Ok(1_924),
+			"Steep curve",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			110u32,
+			five,
+			Ok(1_396),
+			"Steep curve early block",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			FixedU128::from(0),
+			Ok(1_700),
+			"Zero steepness",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Ok(2_000),
+			"Constant case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			one,
+			Ok(1_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			200u32,
+			one,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			200u32,
+			five,
+			Ok(1_000),
+			"Final decreasing weight",
+		),
+		(
+			200u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Err(Overflow),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			one,
+			Err(ZeroDuration),
+			"Zero interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			210u32,
+			one,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn logarithmic_weights_should_work() {
+	let one = FixedU128::from(1);
+	let five = FixedU128::from(5);
+	let cases = vec![
+		(100u32, 200u32, 1_000u32, 2_000u32, 150u32, one, Ok(1_584), "Easy case"),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Ok(1_765),
+			"Later block",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			150u32,
+			one,
+			Ok(1_416),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			150u32,
+			five,
+			Ok(1_699),
+			"Steep curve",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			110u32,
+			five,
+			Ok(1_226),
+			"Steep curve early block",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			FixedU128::from(0),
+			Ok(1_700),
+			"Zero steepness",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Ok(2_000),
+			"Constant case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			one,
+			Ok(1_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			200u32,
+			one,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			200u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			one,
+			Err(Overflow),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			one,
+			Err(ZeroDuration),
+			"Zero interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			10u32,
+			one,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_logarithmic_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn step_weights_should_work() {
+	let cases = vec![
+		(100u32, 200u32, 1_000u32, 2_000u32, 150u32, 4u32, Ok(1_500), "Easy case"),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			174u32,
+			4u32,
+			Ok(1_500),
+			"Weight is constant within a step",
+		),
+		(100u32, 200u32, 1_000u32, 2_000u32, 175u32, 4u32, Ok(1_750), "Next step"),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			175u32,
+			4u32,
+			Ok(1_250),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			124u32,
+			4u32,
+			Ok(1_000),
+			"First step",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			199u32,
+			1u32,
+			Ok(1_000),
+			"Single step",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			200u32,
+			1u32,
+			Ok(2_000),
+			"Single step final weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			100u32,
+			Ok(1_700),
+			"Step per block",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			200u32,
+			3u32,
+			Ok(2_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			150u32,
+			0u32,
+			Err(DivisionByZero),
+			"Zero steps",
+		),
+		(
+			200u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			170u32,
+			4u32,
+			Err(Overflow),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			100u32,
+			1_000u32,
+			2_000u32,
+			100u32,
+			4u32,
+			Err(ZeroDuration),
+			"Zero interval",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			210u32,
+			4u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_step_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
@@ -223,3 +223,139 @@ proptest! {
 		);
 	}
 }
+
+fn vesting_periods() -> impl Strategy<Value = u32> {
+	1..=u32::MAX / 2
+}
+
+fn step_increase() -> impl Strategy<Value = u128> {
+	1_000_000..=1_000_000_000_000_000_000_u128 //0.000_000_000_001 - 1
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1_000))]
+	#[test]
+	fn calculate_linear_loyalty_multiplier_should_be_bounded_and_non_decreasing(
+		periods in 0..u32::MAX - 1,
+		vesting_periods in vesting_periods(),
+		initial_reward_percentage in initial_reward_percentage(),
+	) {
+		let b = FixedU128::from_inner(initial_reward_percentage);
+
+		let multiplier = crate::liquidity_mining::calculate_linear_loyalty_multiplier(
+			periods,
+			b,
+			vesting_periods,
+		).unwrap();
+		let next_multiplier = crate::liquidity_mining::calculate_linear_loyalty_multiplier(
+			periods + 1,
+			b,
+			vesting_periods,
+		).unwrap();
+
+		//multiplier is between b and 1
+		assert!(multiplier >= b && multiplier <= FixedU128::one());
+
+		//multiplier never decreases in time
+		assert!(next_multiplier >= multiplier, "m(t + 1) >= m(t)");
+
+		//multiplier is 1 when vesting is over and < 1 before it
+		if periods >= vesting_periods {
+			assert_eq!(multiplier, FixedU128::one());
+		} else {
+			assert!(multiplier.lt(&FixedU128::one()), "Loyalty multiplier must be < one before vesting is over");
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1_000))]
+	#[test]
+	fn calculate_cliff_linear_loyalty_multiplier_should_be_bounded_and_non_decreasing(
+		periods in 0..u32::MAX - 1,
+		cliff_periods in 0..u32::MAX / 2,
+		vesting_periods in vesting_periods(),
+		initial_reward_percentage in initial_reward_percentage(),
+	) {
+		let b = FixedU128::from_inner(initial_reward_percentage);
+
+		let multiplier = crate::liquidity_mining::calculate_cliff_linear_loyalty_multiplier(
+			periods,
+			b,
+			cliff_periods,
+			vesting_periods,
+		).unwrap();
+		let next_multiplier = crate::liquidity_mining::calculate_cliff_linear_loyalty_multiplier(
+			periods + 1,
+			b,
+			cliff_periods,
+			vesting_periods,
+		).unwrap();
+
+		//multiplier is between b and 1
+		assert!(multiplier >= b && multiplier <= FixedU128::one());
+
+		//multiplier never decreases in time
+		assert!(next_multiplier >= multiplier, "m(t + 1) >= m(t)");
+
+		//multiplier is b during the cliff
+		if periods <= cliff_periods {
+			assert_eq!(multiplier, b);
+		}
+
+		//multiplier is same as linear multiplier shifted by cliff
+		if periods >= cliff_periods {
+			assert_eq!(
+				multiplier,
+				crate::liquidity_mining::calculate_linear_loyalty_multiplier(
+					periods - cliff_periods,
+					b,
+					vesting_periods
+				).unwrap()
+			);
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1_000))]
+	#[test]
+	fn calculate_step_loyalty_multiplier_should_be_bounded_and_non_decreasing(
+		periods in 0..u32::MAX - 1,
+		step_periods in 1..=u32::MAX,
+		step_increase in step_increase(),
+		initial_reward_percentage in initial_reward_percentage(),
+	) {
+		let b = FixedU128::from_inner(initial_reward_percentage);
+		let step_increase = FixedU128::from_inner(step_increase);
+
+		let multiplier = crate::liquidity_mining::calculate_step_loyalty_multiplier(
+			periods,
+			b,
+			step_periods,
+			step_increase,
+		).unwrap();
+		let next_multiplier = crate::liquidity_mining::calculate_step_loyalty_multiplier(
+			periods + 1,
+			b,
+			step_periods,
+			step_increase,
+		).unwrap();
+
+		//multiplier is between b and 1
+		assert!(multiplier >= b && multiplier <= FixedU128::one());
+
+		//multiplier never decreases in time
+		assert!(next_multiplier >= multiplier, "m(t + 1) >= m(t)");
+
+		//multiplier changes only at step boundaries
+		if (periods + 1) % step_periods != 0 {
+			assert_eq!(next_multiplier, multiplier);
+		}
+
+		//multiplier is b during the first step
+		if periods < step_periods {
+			assert_eq!(multiplier, b);
+		}
+	}
+}
@@ -2,7 +2,7 @@ use crate::MathError;
 use crate::MathError::Overflow;
 
 use sp_arithmetic::{
-	traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub},
+	traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Saturating},
 	FixedPointNumber, FixedU128,
 };
 
@@ -40,6 +40,86 @@ pub fn calculate_loyalty_multiplier<Period: num_traits::CheckedSub + TryInto<u32
 	num.checked_div(&denom).ok_or(MathError::Overflow)
 }
 
+/// This function calculate linear vesting loyalty multiplier or error.
+///
+/// `t = periodNow - periodAdded`
+///
+/// `loyalty_multiplier = initial_reward_percentage + (1 - initial_reward_percentage) * t / vesting_periods`
+///
+/// Loyalty multiplier is `1` if `t >= vesting_periods`.
+pub fn calculate_linear_loyalty_multiplier<Period: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	periods: Period,
+	initial_reward_percentage: FixedU128,
+	vesting_periods: u32,
+) -> Result<FixedU128, MathError> {
+	let periods = TryInto::<u128>::try_into(periods).map_err(|_e| MathError::Overflow)?;
+
+	if periods >= vesting_periods as u128 {
+		return Ok(FixedU128::one());
+	}
+
+	let vested = FixedU128::checked_from_rational(periods, vesting_periods as u128).ok_or(MathError::Overflow)?;
+
+	//(1 - initial_reward_percentage) * t / vesting_periods
+	let vested_rewards = FixedU128::one()
+		.checked_sub(&initial_reward_percentage)
+		.ok_or(MathError::Overflow)?
+		.checked_mul(&vested)
+		.ok_or(MathError::Overflow)?;
+
+	initial_reward_percentage
+		.checked_add(&vested_rewards)
+		.ok_or(MathError::Overflow)
+}
+
+/// This function calculate cliff plus linear vesting loyalty multiplier or error.
+///
+/// `t = periodNow - periodAdded`
+///
+/// Loyalty multiplier is `initial_reward_percentage` if `t < cliff_periods`, otherwise
+/// linear vesting loyalty multiplier is calculated for `t - cliff_periods`.
+pub fn calculate_cliff_linear_loyalty_multiplier<Period: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	periods: Period,
+	initial_reward_percentage: FixedU128,
+	cliff_periods: u32,
+	vesting_periods: u32,
+) -> Result<FixedU128, MathError> {
+	let periods = TryInto::<u128>::try_into(periods).map_err(|_e| MathError::Overflow)?;
+
+	if periods < cliff_periods as u128 {
+		return Ok(initial_reward_percentage);
+	}
+
+	calculate_linear_loyalty_multiplier(
+		periods.saturating_sub(cliff_periods as u128),
+		initial_reward_percentage,
+		vesting_periods,
+	)
+}
+
+/// This function calculate step loyalty multiplier or error.
+///
+/// `t = periodNow - periodAdded`
+///
+/// `loyalty_multiplier = min(1, initial_reward_percentage + step_increase * floor(t / step_periods))`
+pub fn calculate_step_loyalty_multiplier<Period: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	periods: Period,
+	initial_reward_percentage: FixedU128,
+	step_periods: u32,
+	step_increase: FixedU128,
+) -> Result<FixedU128, MathError> {
+	let periods = TryInto::<u128>::try_into(periods).map_err(|_e| MathError::Overflow)?;
+
+	let steps = periods
+		.checked_div(step_periods as u128)
+		.ok_or(MathError::DivisionByZero)?;
+
+	Ok(step_increase
+		.saturating_mul(FixedU128::saturating_from_integer(steps))
+		.saturating_add(initial_reward_percentage)
+		.min(FixedU128::one()))
+}
+
 /// This function calculate and return reward per share or error.
 pub fn calculate_accumulated_rps(
 	accumulated_rps_now: FixedU128,
@@ -152,3 +232,42 @@ pub fn calculate_global_farm_rewards<Period: num_traits::CheckedSub + TryInto<u3
 
 	Ok(rewards.min(max_reward_for_periods))
 }
+
+/// This function calculates global-farm's annual percentage rate [`FixedU128`] or error.
+///
+/// APR is calculated from rewards for one period relative to the farm's total stake(`total_shares_z`)
+/// valued in reward currency. Farm without stake returns max. APR(`yield_per_period * periods_per_year`).
+///
+/// `apr = rewards_per_period / (total_shares_z * price_adjustment) * periods_per_year`
+pub fn calculate_global_farm_apr<Period: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	total_shares_z: Balance,
+	price_adjustment: FixedU128,
+	yield_per_period: FixedU128,
+	max_reward_per_period: Balance,
+	periods_per_year: Period,
+) -> Result<FixedU128, MathError> {
+	let periods_per_year = TryInto::<u128>::try_into(periods_per_year).map_err(|_e| MathError::Overflow)?;
+
+	let total_shares_z_adjusted = price_adjustment
+		.checked_mul_int(total_shares_z)
+		.ok_or(MathError::Overflow)?;
+
+	if total_shares_z_adjusted == 0 {
+		return yield_per_period
+			.checked_mul(&FixedU128::from(periods_per_year))
+			.ok_or(MathError::Overflow);
+	}
+
+	let rewards_per_period = calculate_global_farm_rewards(
+		total_shares_z,
+		price_adjustment,
+		yield_per_period,
+		max_reward_per_period,
+		1_u128,
+	)?;
+
+	FixedU128::checked_from_rational(rewards_per_period, total_shares_z_adjusted)
+		.ok_or(MathError::Overflow)?
+		.checked_mul(&FixedU128::from(periods_per_year))
+		.ok_or(MathError::Overflow)
+}
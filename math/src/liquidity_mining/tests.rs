@@ -145,6 +145,81 @@ fn calculate_loyalty_multiplier_should_work() {
 	}
 }
 
+#[test]
+fn calculate_linear_loyalty_multiplier_should_work() {
+	let b = FixedU128::from_inner(200_000_000_000_000_000); //0.2
+
+	let testing_values = vec![
+		(0_u32, b),
+		(25, FixedU128::from_inner(400_000_000_000_000_000)),      //0.4
+		(50, FixedU128::from_inner(600_000_000_000_000_000)),      //0.6
+		(99, FixedU128::from_inner(992_000_000_000_000_000)),      //0.992
+		(100, FixedU128::from_inner(1_000_000_000_000_000_000)),   //1
+		(1_000, FixedU128::from_inner(1_000_000_000_000_000_000)), //1
+	];
+
+	for (periods, expected_multiplier) in testing_values.iter() {
+		assert_eq!(
+			calculate_linear_loyalty_multiplier(*periods, b, 100).unwrap(),
+			*expected_multiplier
+		);
+	}
+
+	//Zero vesting periods means full rewards from the start.
+	assert_eq!(
+		calculate_linear_loyalty_multiplier(0_u32, b, 0).unwrap(),
+		FixedU128::from_inner(1_000_000_000_000_000_000)
+	);
+}
+
+#[test]
+fn calculate_cliff_linear_loyalty_multiplier_should_work() {
+	let b = FixedU128::from_inner(0);
+
+	let testing_values = vec![
+		(0_u32, b),
+		(9, b),
+		(10, b),
+		(35, FixedU128::from_inner(250_000_000_000_000_000)),      //0.25
+		(60, FixedU128::from_inner(500_000_000_000_000_000)),      //0.5
+		(110, FixedU128::from_inner(1_000_000_000_000_000_000)),   //1
+		(1_000, FixedU128::from_inner(1_000_000_000_000_000_000)), //1
+	];
+
+	for (periods, expected_multiplier) in testing_values.iter() {
+		assert_eq!(
+			calculate_cliff_linear_loyalty_multiplier(*periods, b, 10, 100).unwrap(),
+			*expected_multiplier
+		);
+	}
+}
+
+#[test]
+fn calculate_step_loyalty_multiplier_should_work() {
+	let b = FixedU128::from_inner(100_000_000_000_000_000); //0.1
+	let step_increase = FixedU128::from_inner(250_000_000_000_000_000); //0.25
+
+	let testing_values = vec![
+		(0_u32, b),
+		(9, b),
+		(10, FixedU128::from_inner(350_000_000_000_000_000)),          //0.35
+		(29, FixedU128::from_inner(600_000_000_000_000_000)),          //0.6
+		(30, FixedU128::from_inner(850_000_000_000_000_000)),          //0.85
+		(40, FixedU128::from_inner(1_000_000_000_000_000_000)),        //1
+		(1_000_000, FixedU128::from_inner(1_000_000_000_000_000_000)), //1
+	];
+
+	for (periods, expected_multiplier) in testing_values.iter() {
+		assert_eq!(
+			calculate_step_loyalty_multiplier(*periods, b, 10, step_increase).unwrap(),
+			*expected_multiplier
+		);
+	}
+
+	//Zero step periods is not valid.
+	assert!(calculate_step_loyalty_multiplier(10_u32, b, 0, step_increase).is_err());
+}
+
 #[test]
 fn calculate_accumulated_rps_should_work() {
 	let testing_values = vec![
@@ -954,3 +1029,76 @@ fn calculate_global_farm_rewards_should_work() {
 		);
 	}
 }
+
+#[test]
+fn calculate_global_farm_apr_should_work() {
+	let testing_values = vec![
+		//(yield_per_period, total_shares_z, max_reward_per_period, price_adjustment, periods_per_year, expected_apr)
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			1_000_000_u128,
+			1_000_000_u128,
+			FixedU128::from(1),
+			365_u128,
+			FixedU128::from_inner(365_000_000_000_000_000), //0.365
+		),
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			1_000_000_u128,
+			1_000_000_u128,
+			FixedU128::from_inner(500_000_000_000_000_000), //0.5
+			365_u128,
+			FixedU128::from_inner(365_000_000_000_000_000), //0.365
+		),
+		//max reward per period is reached
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			1_000_000_u128,
+			500_u128,
+			FixedU128::from(1),
+			365_u128,
+			FixedU128::from_inner(182_500_000_000_000_000), //0.1825
+		),
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			1_000_000_u128,
+			250_u128,
+			FixedU128::from(2),
+			8_760_u128,
+			FixedU128::from_inner(1_095_000_000_000_000_000), //1.095
+		),
+		//empty farm
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			0_u128,
+			250_u128,
+			FixedU128::from(2),
+			8_760_u128,
+			FixedU128::from_inner(8_760_000_000_000_000_000), //8.76
+		),
+		(
+			FixedU128::from_inner(1_000_000_000_000_000), //0.001
+			1_000_000_u128,
+			1_000_000_u128,
+			FixedU128::from(1),
+			0_u128,
+			FixedU128::from(0),
+		),
+	];
+
+	for (yield_per_period, total_shares_z, max_reward_per_period, price_adjustment, periods_per_year, expected_apr) in
+		testing_values.iter()
+	{
+		assert_eq!(
+			calculate_global_farm_apr(
+				*total_shares_z,
+				*price_adjustment,
+				*yield_per_period,
+				*max_reward_per_period,
+				*periods_per_year
+			)
+			.unwrap(),
+			*expected_apr
+		);
+	}
+}
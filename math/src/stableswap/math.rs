@@ -1,3 +1,5 @@
+use crate::dynamic_fees::recalculate_asset_fee;
+use crate::dynamic_fees::types::{FeeParams, OracleEntry};
 use crate::stableswap::types::AssetReserve;
 
 use crate::support::rational::round_to_rational;
@@ -5,6 +7,7 @@ use crate::to_u256;
 use crate::types::{AssetId, Balance};
 use num_traits::{CheckedDiv, CheckedMul, CheckedSub, One, Zero};
 use primitive_types::U256;
+use sp_arithmetic::traits::Saturating;
 use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_std::ops::Div;
 use sp_std::prelude::*;
@@ -34,21 +37,11 @@ pub fn calculate_out_given_in<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_in = normalize_value(
-		amount_in,
-		initial_reserves[idx_in].decimals,
-		TARGET_PRECISION,
-		Rounding::Up,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_in = normalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	let new_reserve_out = calculate_y_given_in::<D, Y>(amount_in, idx_in, idx_out, &reserves, amplification)?;
 	let amount_out = reserves[idx_out].checked_sub(new_reserve_out)?;
-	let amount_out = normalize_value(
-		amount_out,
-		TARGET_PRECISION,
-		initial_reserves[idx_out].decimals,
-		Rounding::Down,
-	);
+	let amount_out = denormalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	Some(amount_out.saturating_sub(1u128))
 }
 
@@ -68,21 +61,11 @@ pub fn calculate_in_given_out<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_out = normalize_value(
-		amount_out,
-		initial_reserves[idx_out].decimals,
-		TARGET_PRECISION,
-		Rounding::Down,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_out = normalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	let new_reserve_in = calculate_y_given_out::<D, Y>(amount_out, idx_in, idx_out, &reserves, amplification)?;
 	let amount_in = new_reserve_in.checked_sub(reserves[idx_in])?;
-	let amount_in = normalize_value(
-		amount_in,
-		TARGET_PRECISION,
-		initial_reserves[idx_in].decimals,
-		Rounding::Up,
-	);
+	let amount_in = denormalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	Some(amount_in.saturating_add(1u128))
 }
 
@@ -161,10 +144,7 @@ pub fn calculate_shares<const D: u8>(
 				let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 				let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 				let fee_amount = fee.checked_mul_int(diff)?;
-				Some(AssetReserve::new(
-					asset_reserve.amount.saturating_sub(fee_amount),
-					asset_reserve.decimals,
-				))
+				Some(asset_reserve.with_amount(asset_reserve.amount.saturating_sub(fee_amount)))
 			})
 			.collect::<Option<Vec<AssetReserve>>>()?
 	} else {
@@ -208,7 +188,7 @@ pub fn calculate_shares_for_amount<const D: u8>(
 		.enumerate()
 		.map(|(idx, v)| -> Option<AssetReserve> {
 			if idx == asset_idx {
-				Some(AssetReserve::new(v.amount.checked_sub(amount)?, v.decimals))
+				Some(v.with_amount(v.amount.checked_sub(amount)?))
 			} else {
 				Some(*v)
 			}
@@ -226,10 +206,7 @@ pub fn calculate_shares_for_amount<const D: u8>(
 			let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 			let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 			let fee_amount = fee.checked_mul_int(diff)?;
-			Some(AssetReserve::new(
-				asset_reserve.amount.saturating_sub(fee_amount),
-				asset_reserve.decimals,
-			))
+			Some(asset_reserve.with_amount(asset_reserve.amount.saturating_sub(fee_amount)))
 		})
 		.collect::<Option<Vec<AssetReserve>>>()?;
 
@@ -268,8 +245,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	if n_coins <= 1 {
 		return None;
 	}
-	let asset_out_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_out_reserve = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let fixed_fee = FixedU128::from(fee);
 	let fee = fixed_fee
@@ -321,8 +298,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	let dy_0 = reserves[asset_index].checked_sub(y)?;
 	let fee = dy_0.checked_sub(dy)?;
 
-	let amount_out = normalize_value(dy, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
+	let amount_out = denormalize_amount(dy, &asset_out_reserve, Rounding::Down)?;
+	let fee = denormalize_amount(fee, &asset_out_reserve, Rounding::Down)?;
 	Some((amount_out, fee))
 }
 
@@ -349,8 +326,8 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 		return None;
 	}
 
-	let asset_in_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_in_reserve = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let initial_d = calculate_d_internal::<D>(&reserves, amplification)?;
 	let (shares_hp, issuance_hp, d_hp) = to_u256!(shares, share_asset_issuance, initial_d);
@@ -400,12 +377,12 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	let dy = y1.checked_sub(asset_reserve)?;
 	let dy_0 = y.checked_sub(asset_reserve)?;
 	let fee = dy.checked_sub(dy_0)?;
-	let amount_in = normalize_value(dy, TARGET_PRECISION, asset_in_decimals, Rounding::Up);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_in_decimals, Rounding::Down);
+	let amount_in = denormalize_amount(dy, &asset_in_reserve, Rounding::Up)?;
+	let fee = denormalize_amount(fee, &asset_in_reserve, Rounding::Down)?;
 	Some((amount_in, fee))
 }
 pub fn calculate_d<const D: u8>(reserves: &[AssetReserve], amplification: Balance) -> Option<Balance> {
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 	calculate_d_internal::<D>(&n_reserves, amplification)
 }
 
@@ -620,6 +597,7 @@ fn abs_diff(d0: U256, d1: U256) -> U256 {
 	}
 }
 
+#[derive(Clone, Copy)]
 pub(crate) enum Rounding {
 	Down,
 	Up,
@@ -632,13 +610,119 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+/// Calculate imbalance of a pool.
+///
+/// Returns `(imbalance, liquidity)` where `liquidity` is the sum of normalized reserves and `imbalance` is the amount
+/// which would have to be moved between the reserves to make them equal.
+pub fn calculate_pool_imbalance(reserves: &[AssetReserve]) -> Option<(Balance, Balance)> {
+	if reserves.is_empty() {
+		return Some((Balance::zero(), Balance::zero()));
+	}
+	let normalized_reserves = normalize_reserves(reserves)?;
+	let liquidity = normalized_reserves
+		.iter()
+		.try_fold(Balance::zero(), |acc, v| acc.checked_add(*v))?;
+	let average = liquidity.checked_div(normalized_reserves.len() as Balance)?;
+	let imbalance = normalized_reserves
+		.iter()
+		.filter(|v| **v > average)
+		.try_fold(Balance::zero(), |acc, v| acc.checked_add(v.saturating_sub(average)))?;
+	Some((imbalance, liquidity))
+}
+
+/// Recalculate dynamic fee of a pool.
+///
+/// The fee is the greater of two components:
+/// - volume fee - increases with the net outflow of pool assets relative to the pool liquidity and decays towards
+///   the minimum fee otherwise. Uses the same fee curve as Omnipool's dynamic asset fee.
+/// - imbalance fee - minimum fee increased by `amplification * imbalance / liquidity`. It depends only on the
+///   current imbalance of the pool, so it does not grow while the pool stays imbalanced.
+///
+/// `volumes` are the volumes of pool assets going in and out of the pool, provided by an oracle, in the same order
+/// as `reserves`.
+/// `previous_fee` is the previously calculated fee.
+/// `last_block_diff` is the difference between the current block and the block when the fee was calculated.
+pub fn recalculate_pool_fee(
+	reserves: &[AssetReserve],
+	volumes: &[(Balance, Balance)],
+	previous_fee: Permill,
+	last_block_diff: u128,
+	params: FeeParams<Permill>,
+) -> Option<Permill> {
+	if reserves.len() != volumes.len() {
+		return None;
+	}
+	let (imbalance, liquidity) = calculate_pool_imbalance(reserves)?;
+
+	// Volume traded in both directions of an asset cancels out, only the net outflow is counted.
+	let net_outflow =
+		volumes
+			.iter()
+			.zip(reserves.iter())
+			.try_fold(Balance::zero(), |acc, ((volume_in, volume_out), reserve)| {
+				let volume_in = normalize_amount(*volume_in, reserve, Rounding::Down)?;
+				let volume_out = normalize_amount(*volume_out, reserve, Rounding::Down)?;
+				Some(acc.saturating_add(volume_out.saturating_sub(volume_in)))
+			})?;
+
+	let entry = OracleEntry {
+		amount_in: Balance::zero(),
+		amount_out: net_outflow,
+		liquidity,
+	};
+	let imbalance_fee = calculate_imbalance_fee(imbalance, liquidity, &params);
+	let volume_fee = recalculate_asset_fee(entry, previous_fee, last_block_diff, params);
+
+	Some(volume_fee.max(imbalance_fee))
+}
+
+/// Calculate fee of a pool given its imbalance, `min_fee + amplification * imbalance / liquidity`.
+/// The result is clamped to the fee bounds.
+pub fn calculate_imbalance_fee(imbalance: Balance, liquidity: Balance, params: &FeeParams<Permill>) -> Permill {
+	if liquidity.is_zero() {
+		return params.min_fee;
+	}
+	let x = FixedU128::from_rational(imbalance, liquidity);
+	FixedU128::from(params.min_fee)
+		.saturating_add(params.amplification.saturating_mul(x))
+		.into_clamped_perthing::<Permill>()
+		.clamp(params.min_fee, params.max_fee)
+}
+
+pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Option<Vec<Balance>> {
 	reserves
 		.iter()
-		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
+		.map(|v| normalize_amount(v.amount, v, Rounding::Down))
 		.collect()
 }
 
+/// Convert amount of an asset to target precision and scale it by the asset's rate.
+pub(crate) fn normalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = normalize_value(amount, reserve.decimals, TARGET_PRECISION, rounding);
+	apply_rate(amount, reserve.rate, rounding)
+}
+
+/// Convert normalized amount back to asset's precision and remove the rate scaling.
+pub(crate) fn denormalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = apply_rate(amount, (reserve.rate.1, reserve.rate.0), rounding)?;
+	Some(normalize_value(amount, TARGET_PRECISION, reserve.decimals, rounding))
+}
+
+pub(crate) fn apply_rate(amount: Balance, (n, d): (Balance, Balance), rounding: Rounding) -> Option<Balance> {
+	if n == d || d.is_zero() {
+		return Some(amount);
+	}
+	let (amount_hp, n_hp, d_hp) = to_u256!(amount, n, d);
+	let numerator = amount_hp.checked_mul(n_hp)?;
+	let result = match rounding {
+		Rounding::Down => numerator.checked_div(d_hp)?,
+		Rounding::Up => numerator
+			.checked_add(d_hp.checked_sub(U256::one())?)?
+			.checked_div(d_hp)?,
+	};
+	Balance::try_from(result).ok()
+}
+
 pub(crate) fn normalize_value(amount: Balance, decimals: u8, target_decimals: u8, rounding: Rounding) -> Balance {
 	if target_decimals == decimals {
 		return amount;
@@ -693,7 +777,7 @@ pub fn calculate_share_price<const D: u8>(
 	} else {
 		calculate_d::<D>(reserves, amplification)?
 	};
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 
 	let c = n_reserves
 		.iter()
@@ -727,7 +811,17 @@ pub fn calculate_share_price<const D: u8>(
 	};
 	let (num, denom) = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
 	//dbg!(FixedU128::checked_from_rational(num, denom));
-	Some((num, denom))
+
+	// Share price is denominated in the asset itself, not in its underlying value, so remove the rate scaling.
+	let (rate_n, rate_d) = reserves[asset_idx].rate;
+	if rate_n == rate_d {
+		return Some((num, denom));
+	}
+	let (num, denom, rate_n, rate_d) = to_u256!(num, denom, rate_n, rate_d);
+	Some(round_to_rational(
+		(num.checked_mul(rate_d)?, denom.checked_mul(rate_n)?),
+		crate::support::rational::Rounding::Down,
+	))
 }
 
 const STABLE_ASSET: bool = false;
@@ -843,7 +937,7 @@ pub fn calculate_spot_price_between_two_stable_assets(
 	}
 	let ann = calculate_ann(n, amplification)?;
 
-	let mut n_reserves = normalize_reserves(reserves);
+	let mut n_reserves = normalize_reserves(reserves)?;
 
 	let x0 = n_reserves[asset_in_idx];
 	let xi = n_reserves[asset_out_idx];
@@ -861,6 +955,20 @@ pub fn calculate_spot_price_between_two_stable_assets(
 
 	let mut spot_price = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
 
+	// Price of normalized reserves is scaled by rates, convert it back to the price of the assets.
+	let (rate_in, rate_out) = (reserves[asset_in_idx].rate, reserves[asset_out_idx].rate);
+	if rate_in.0 != rate_in.1 || rate_out.0 != rate_out.1 {
+		let (num, denom, in_n, in_d, out_n, out_d) =
+			to_u256!(spot_price.0, spot_price.1, rate_in.0, rate_in.1, rate_out.0, rate_out.1);
+		spot_price = round_to_rational(
+			(
+				num.checked_mul(out_n)?.checked_mul(in_d)?,
+				denom.checked_mul(out_d)?.checked_mul(in_n)?,
+			),
+			crate::support::rational::Rounding::Down,
+		);
+	}
+
 	if let Some(fee) = fee {
 		// Amount_out is reduced by fee in SELL, making asset_out more expensive, so the asset_in/asset_out spot price should be increased.
 		// So divide spot-price-without-fee by (1-fee) to reflect correct amount out after the fee deduction
@@ -0,0 +1,177 @@
+use super::*;
+use crate::dynamic_fees::types::FeeParams;
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use num_traits::{One, Zero};
+use sp_arithmetic::{FixedU128, Permill};
+
+fn fee_params() -> FeeParams<Permill> {
+	FeeParams {
+		min_fee: Permill::from_percent(1),
+		max_fee: Permill::from_percent(10),
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+#[test]
+fn calculate_pool_imbalance_should_be_zero_when_pool_is_balanced() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let (imbalance, liquidity) = calculate_pool_imbalance(&reserves).unwrap();
+
+	assert_eq!(imbalance, 0);
+	assert_eq!(liquidity, 2_000 * ONE * 1_000_000);
+}
+
+#[test]
+fn calculate_pool_imbalance_should_normalize_decimals() {
+	let reserves = vec![
+		AssetReserve::new(1_000 * ONE, 12),
+		AssetReserve::new(1_000 * 1_000_000, 6),
+	];
+
+	let (imbalance, _) = calculate_pool_imbalance(&reserves).unwrap();
+
+	assert_eq!(imbalance, 0);
+}
+
+#[test]
+fn calculate_pool_imbalance_should_return_amount_to_rebalance_pool() {
+	let reserves = vec![
+		AssetReserve::new(1_500 * ONE, 12),
+		AssetReserve::new(500 * ONE, 12),
+		AssetReserve::new(1_000 * ONE, 12),
+	];
+
+	let (imbalance, liquidity) = calculate_pool_imbalance(&reserves).unwrap();
+
+	assert_eq!(imbalance, 500 * ONE * 1_000_000);
+	assert_eq!(liquidity, 3_000 * ONE * 1_000_000);
+}
+
+#[test]
+fn recalculate_pool_fee_should_not_change_fee_when_pool_is_balanced_and_no_volume() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], Permill::from_percent(2), 1, fee_params()).unwrap();
+
+	assert_eq!(fee, Permill::from_percent(2));
+}
+
+#[test]
+fn recalculate_pool_fee_should_increase_fee_when_net_outflow_increases() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let fee = recalculate_pool_fee(
+		&reserves,
+		&[(20 * ONE, 0), (0, 20 * ONE)],
+		Permill::from_percent(1),
+		1,
+		fee_params(),
+	)
+	.unwrap();
+
+	// x = 20 / 2000
+	assert_eq!(fee, Permill::from_percent(2));
+}
+
+#[test]
+fn recalculate_pool_fee_should_not_increase_fee_when_volume_cancels_out() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let fee = recalculate_pool_fee(
+		&reserves,
+		&[(20 * ONE, 20 * ONE), (20 * ONE, 20 * ONE)],
+		Permill::from_percent(1),
+		1,
+		fee_params(),
+	)
+	.unwrap();
+
+	assert_eq!(fee, Permill::from_percent(1));
+}
+
+#[test]
+fn recalculate_pool_fee_should_normalize_volumes() {
+	let reserves = vec![
+		AssetReserve::new(1_000 * ONE, 12),
+		AssetReserve::new(1_000 * 1_000_000, 6),
+	];
+
+	let fee = recalculate_pool_fee(
+		&reserves,
+		&[(20 * ONE, 0), (0, 20 * 1_000_000)],
+		Permill::from_percent(1),
+		1,
+		fee_params(),
+	)
+	.unwrap();
+
+	// x = 20 / 2000
+	assert_eq!(fee, Permill::from_percent(2));
+}
+
+#[test]
+fn recalculate_pool_fee_should_increase_fee_when_pool_is_imbalanced() {
+	let reserves = vec![AssetReserve::new(1_040 * ONE, 12), AssetReserve::new(960 * ONE, 12)];
+
+	let fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], Permill::from_percent(1), 1, fee_params()).unwrap();
+
+	// x = 40 / 2000
+	assert_eq!(fee, Permill::from_percent(3));
+}
+
+#[test]
+fn recalculate_pool_fee_should_not_increase_fee_again_when_pool_stays_imbalanced() {
+	let reserves = vec![AssetReserve::new(1_040 * ONE, 12), AssetReserve::new(960 * ONE, 12)];
+
+	let mut fee = Permill::from_percent(1);
+	for _ in 0..10 {
+		fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], fee, 1, fee_params()).unwrap();
+	}
+
+	assert_eq!(fee, Permill::from_percent(3));
+}
+
+#[test]
+fn recalculate_pool_fee_should_not_exceed_max_fee() {
+	let reserves = vec![AssetReserve::new(1_900 * ONE, 12), AssetReserve::new(100 * ONE, 12)];
+
+	let fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], Permill::from_percent(1), 1, fee_params()).unwrap();
+
+	assert_eq!(fee, Permill::from_percent(10));
+}
+
+#[test]
+fn recalculate_pool_fee_should_decay_to_min_fee_when_pool_is_balanced() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let params = FeeParams {
+		decay: FixedU128::from_rational(1, 100),
+		..fee_params()
+	};
+
+	let fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], Permill::from_percent(5), 10, params).unwrap();
+
+	assert_eq!(fee, Permill::from_percent(1));
+}
+
+#[test]
+fn recalculate_pool_fee_should_decay_to_imbalance_fee_when_pool_is_imbalanced() {
+	let reserves = vec![AssetReserve::new(1_040 * ONE, 12), AssetReserve::new(960 * ONE, 12)];
+	let params = FeeParams {
+		decay: FixedU128::from_rational(1, 100),
+		..fee_params()
+	};
+
+	let fee = recalculate_pool_fee(&reserves, &[(0, 0), (0, 0)], Permill::from_percent(10), 10, params).unwrap();
+
+	assert_eq!(fee, Permill::from_percent(3));
+}
+
+#[test]
+fn recalculate_pool_fee_should_fail_when_volumes_do_not_match_reserves() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	assert!(recalculate_pool_fee(&reserves, &[(0, 0)], Permill::from_percent(1), 1, fee_params()).is_none());
+}
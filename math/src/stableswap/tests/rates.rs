@@ -0,0 +1,190 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use super::*;
+use crate::assert_approx_eq;
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::{FixedU128, Permill};
+
+const AMP: Balance = 100;
+
+// Pool with yield bearing asset ( worth 2 units of underlying ) and its underlying asset.
+fn pool_with_rate() -> Vec<AssetReserve> {
+	vec![
+		AssetReserve::new(1_000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2_000 * ONE, 12),
+	]
+}
+
+// Equivalent pool where the yield bearing asset is replaced by its underlying value.
+fn equivalent_pool_without_rate() -> Vec<AssetReserve> {
+	vec![AssetReserve::new(2_000 * ONE, 12), AssetReserve::new(2_000 * ONE, 12)]
+}
+
+#[test]
+fn calculate_d_should_scale_reserves_by_rate() {
+	let d_with_rate = calculate_d::<D_ITERATIONS>(&pool_with_rate(), AMP).unwrap();
+	let d_without_rate = calculate_d::<D_ITERATIONS>(&equivalent_pool_without_rate(), AMP).unwrap();
+
+	assert_eq!(d_with_rate, d_without_rate);
+}
+
+#[test]
+fn calculate_d_should_not_change_when_rate_is_one() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(2_000 * ONE, 12)];
+	let rated_reserves: Vec<AssetReserve> = reserves.iter().map(|r| r.with_rate((5, 5))).collect();
+
+	assert_eq!(
+		calculate_d::<D_ITERATIONS>(&reserves, AMP),
+		calculate_d::<D_ITERATIONS>(&rated_reserves, AMP)
+	);
+}
+
+#[test]
+fn out_given_in_should_account_for_rate_of_asset_in() {
+	let amount_out =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&pool_with_rate(), 0, 1, 10 * ONE, AMP).unwrap();
+	let expected =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&equivalent_pool_without_rate(), 0, 1, 20 * ONE, AMP)
+			.unwrap();
+
+	assert_eq!(amount_out, expected);
+	assert!(amount_out > 19 * ONE);
+}
+
+#[test]
+fn out_given_in_should_account_for_rate_of_asset_out() {
+	let amount_out =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&pool_with_rate(), 1, 0, 20 * ONE, AMP).unwrap();
+	let expected =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&equivalent_pool_without_rate(), 1, 0, 20 * ONE, AMP)
+			.unwrap();
+
+	// received amount of yield bearing asset is half of its underlying value
+	assert_approx_eq!(amount_out * 2, expected, 2u128);
+	assert!(amount_out < 10 * ONE);
+}
+
+#[test]
+fn in_given_out_should_account_for_rate_of_asset_in() {
+	let amount_in =
+		calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&pool_with_rate(), 0, 1, 20 * ONE, AMP).unwrap();
+	let expected =
+		calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&equivalent_pool_without_rate(), 0, 1, 20 * ONE, AMP)
+			.unwrap();
+
+	// rounding is in favour of the pool
+	assert!(amount_in * 2 >= expected);
+	assert!(amount_in * 2 <= expected + 2);
+	assert!(amount_in > 10 * ONE);
+}
+
+#[test]
+fn calculate_shares_should_account_for_rate() {
+	let issuance = 4_000 * ONE;
+	let initial = pool_with_rate();
+	let updated = vec![initial[0].with_amount(1_010 * ONE), initial[1]];
+	let shares = calculate_shares::<D_ITERATIONS>(&initial, &updated, AMP, issuance, Permill::zero()).unwrap();
+
+	let initial = equivalent_pool_without_rate();
+	let updated = vec![AssetReserve::new(2_020 * ONE, 12), initial[1]];
+	let expected = calculate_shares::<D_ITERATIONS>(&initial, &updated, AMP, issuance, Permill::zero()).unwrap();
+
+	assert_eq!(shares, expected);
+}
+
+#[test]
+fn withdraw_one_asset_should_account_for_rate() {
+	let issuance = 4_000 * ONE;
+	let (amount, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&pool_with_rate(),
+		20 * ONE,
+		0,
+		issuance,
+		AMP,
+		Permill::zero(),
+	)
+	.unwrap();
+	let (expected, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&equivalent_pool_without_rate(),
+		20 * ONE,
+		0,
+		issuance,
+		AMP,
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert!(amount * 2 <= expected);
+	assert!(amount * 2 + 2 >= expected);
+}
+
+#[test]
+fn add_one_asset_should_account_for_rate() {
+	let issuance = 4_000 * ONE;
+	let (amount, _) = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&pool_with_rate(),
+		20 * ONE,
+		0,
+		issuance,
+		AMP,
+		Permill::zero(),
+	)
+	.unwrap();
+	let (expected, _) = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&equivalent_pool_without_rate(),
+		20 * ONE,
+		0,
+		issuance,
+		AMP,
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert_approx_eq!(amount * 2, expected, 2u128);
+}
+
+#[test]
+fn spot_price_should_account_for_rate() {
+	let reserves = pool_with_rate();
+	let d = calculate_d::<D_ITERATIONS>(&reserves, AMP).unwrap();
+
+	// price of yield bearing asset denominated in underlying asset
+	let price = calculate_spot_price_between_two_stable_assets(&reserves, AMP, d, 1, 0, None).unwrap();
+	assert_approx_eq!(price, FixedU128::from(2), FixedU128::from_rational(1, 1_000_000_000));
+
+	let price = calculate_spot_price_between_two_stable_assets(&reserves, AMP, d, 0, 1, None).unwrap();
+	assert_approx_eq!(
+		price,
+		FixedU128::from_rational(1, 2),
+		FixedU128::from_rational(1, 1_000_000_000)
+	);
+}
+
+#[test]
+fn share_price_should_account_for_rate() {
+	let issuance = 4_000 * ONE;
+	let price = calculate_share_price::<D_ITERATIONS>(&pool_with_rate(), AMP, issuance, 0, None).unwrap();
+	let expected =
+		calculate_share_price::<D_ITERATIONS>(&equivalent_pool_without_rate(), AMP, issuance, 0, None).unwrap();
+
+	let price = FixedU128::from_rational(price.0, price.1);
+	let expected = FixedU128::from_rational(expected.0, expected.1);
+	assert_approx_eq!(
+		price.saturating_mul(FixedU128::from(2)),
+		expected,
+		FixedU128::from_rational(1, 1_000_000_000)
+	);
+}
+
+#[test]
+fn calculate_d_should_fail_when_rate_overflows() {
+	let reserves = vec![
+		AssetReserve::new(1_000 * ONE, 12).with_rate((Balance::MAX, 1)),
+		AssetReserve::new(2_000 * ONE, 12),
+	];
+
+	assert!(calculate_d::<D_ITERATIONS>(&reserves, AMP).is_none());
+}
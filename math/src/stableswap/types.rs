@@ -1,15 +1,30 @@
 use crate::types::Balance;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AssetReserve {
 	pub amount: Balance,
 	pub decimals: u8,
+	/// Rate of the asset expressed as (numerator, denominator).
+	/// Normalized reserve is multiplied by the rate, so yield-bearing assets are priced by their underlying value.
+	pub rate: (Balance, Balance),
 }
 
 impl AssetReserve {
 	pub fn new(amount: Balance, decimals: u8) -> Self {
-		Self { amount, decimals }
+		Self {
+			amount,
+			decimals,
+			rate: (Balance::one(), Balance::one()),
+		}
+	}
+
+	pub fn with_rate(self, rate: (Balance, Balance)) -> Self {
+		Self { rate, ..self }
+	}
+
+	pub fn with_amount(self, amount: Balance) -> Self {
+		Self { amount, ..self }
 	}
 
 	pub fn is_zero(&self) -> bool {
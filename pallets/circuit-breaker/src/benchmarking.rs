@@ -176,6 +176,18 @@ benchmarks! {
 		let asset_out_id = T::AssetId::from(3u32);
 		let asset_out_reserve = 200_000_000_000_000u128;
 		let amount_out = 10_000_000_000_000u128;
+
+		// worst case: rolling window limits are enforced for both assets
+		crate::Pallet::<T>::set_rolling_window_trade_volume_limit(RawOrigin::Root.into(), asset_in_id, Some((crate::MAX_LIMIT_VALUE, 1)))?;
+		crate::Pallet::<T>::set_rolling_window_trade_volume_limit(RawOrigin::Root.into(), asset_out_id, Some((crate::MAX_LIMIT_VALUE, 1)))?;
+
+		// and the tracked windows have to be moved forward by all but one bucket
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		crate::Pallet::<T>::ensure_pool_state_change_limit(asset_in_id, asset_in_reserve.into(), amount_in.into(), asset_out_id, asset_out_reserve.into(), amount_out.into())?;
+		crate::Pallet::<T>::on_finalize(1u32.into());
+
+		let block_num = T::RollingWindowLength::get().saturating_sub(1);
+		frame_system::Pallet::<T>::set_block_number(block_num.into());
 		let before_in = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_in_id);
 		let before_out = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_out_id);
 	}: {
@@ -187,6 +199,74 @@ benchmarks! {
 
 		assert!(before_in != after_in);
 		assert!(before_out != after_out);
+		assert!(RollingWindowTradeVolumePerAsset::<T>::get(asset_in_id).is_some());
+		assert!(RollingWindowTradeVolumePerAsset::<T>::get(asset_out_id).is_some());
+	}
+
+	set_rolling_window_trade_volume_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let trade_limit = Some((crate::MAX_LIMIT_VALUE, 1));
+
+	}: _(RawOrigin::Root, asset_id, trade_limit)
+	verify {
+		assert_eq!(RollingWindowTradeVolumeLimitPerAsset::<T>::get(asset_id), trade_limit);
+	}
+
+	set_max_price_deviation {
+		let asset_id = T::AssetId::from(2u32);
+		let max_price_deviation = Some((crate::MAX_LIMIT_VALUE, 1));
+
+	}: _(RawOrigin::Root, asset_id, max_price_deviation)
+	verify {
+		assert_eq!(MaxPriceDeviationPerAsset::<T>::get(asset_id), max_price_deviation);
+	}
+
+	ensure_price_deviation_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let max_price_deviation = Some((1_000, 10_000));
+		let spot_price = (105u128, 100u128);
+		let oracle_price = (100u128, 100u128);
+
+		crate::Pallet::<T>::set_max_price_deviation(RawOrigin::Root.into(), asset_id, max_price_deviation)?;
+	}: {
+		crate::Pallet::<T>::ensure_price_deviation_limit(asset_id, (spot_price.0.into(), spot_price.1.into()), (oracle_price.0.into(), oracle_price.1.into()))?
+	}
+	verify {}
+
+	record_limit_breach {
+		let c in 1 .. T::BreachThreshold::get();
+		let asset_id = T::AssetId::from(2u32);
+
+		// Worst case is the breach which triggers emergency mode and pauses `c` distinct calls
+		for i in 1..T::BreachThreshold::get() {
+			frame_system::Pallet::<T>::set_block_number(i.into());
+			let breacher: T::AccountId = account("breacher", i, 1);
+			crate::Pallet::<T>::record_limit_breach(asset_id, &breacher, b"Omnipool", &(i % c).to_le_bytes());
+		}
+		frame_system::Pallet::<T>::set_block_number(T::BreachThreshold::get().into());
+		let breacher: T::AccountId = account("breacher", 0, 1);
+		let function_name = (T::BreachThreshold::get() % c).to_le_bytes();
+	}: {
+		crate::Pallet::<T>::record_limit_breach(asset_id, &breacher, b"Omnipool", &function_name);
+	}
+	verify {
+		assert_eq!(EmergencyAssets::<T>::get(asset_id).map(|info| info.paused_calls.len()), Some(c as usize));
+	}
+
+	lift_emergency_mode {
+		let c in 1 .. T::BreachThreshold::get();
+		let asset_id = T::AssetId::from(2u32);
+
+		for i in 1..=T::BreachThreshold::get() {
+			frame_system::Pallet::<T>::set_block_number(i.into());
+			let breacher: T::AccountId = account("breacher", i, 1);
+			crate::Pallet::<T>::record_limit_breach(asset_id, &breacher, b"Omnipool", &(i % c).to_le_bytes());
+		}
+		assert_eq!(EmergencyAssets::<T>::get(asset_id).map(|info| info.paused_calls.len()), Some(c as usize));
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(!EmergencyAssets::<T>::contains_key(asset_id));
+		assert_eq!(EmergencyPausedCalls::<T>::iter().count(), 0);
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
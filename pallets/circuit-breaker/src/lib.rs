@@ -18,18 +18,30 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::traits::{Contains, EnsureOrigin};
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::{CallMetadata, ConstU32, Contains, EnsureOrigin, GetCallMetadata};
 use frame_support::weights::Weight;
+use frame_support::BoundedVec;
 use frame_support::{ensure, pallet_prelude::DispatchResult, traits::Get};
 use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use scale_info::TypeInfo;
-use sp_core::MaxEncodedLen;
-use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+use sp_core::{MaxEncodedLen, U512};
+use sp_runtime::traits::{
+	AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, DispatchInfoOf, Dispatchable,
+	PostDispatchInfoOf, Saturating, SignedExtension, UniqueSaturatedInto, Zero,
+};
+use sp_runtime::transaction_validity::TransactionValidityError;
 use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
+use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
 
+pub mod traits;
 pub mod weights;
 
+pub use traits::{AffectedAssets, EmergencyHandler};
+
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarking;
 
@@ -39,6 +51,9 @@ mod tests;
 /// Max trade volume limit multiplier of liquidity that can be traded in a block
 pub const MAX_LIMIT_VALUE: u32 = 10_000;
 
+/// Number of buckets the rolling window is split into.
+pub const ROLLING_WINDOW_BUCKETS: usize = 10;
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct TradeVolumeLimit<T: Config> {
@@ -120,6 +135,124 @@ where
 	}
 }
 
+/// Max number of distinct accounts which can be counted towards the emergency mode of an asset.
+pub const MAX_BREACH_THRESHOLD: u32 = 20;
+
+/// Max length of a pallet name or function name of a call paused in emergency mode.
+pub const MAX_NAME_LENGTH: u32 = 40;
+pub type BoundedName = BoundedVec<u8, ConstU32<MAX_NAME_LENGTH>>;
+
+/// Call identified by its pallet name and function name.
+pub type CallName = (BoundedName, BoundedName);
+
+/// Distinct calls which breached the limits of an asset. At most one call is added per counted breach.
+pub type BreachingCalls = BoundedVec<CallName, ConstU32<MAX_BREACH_THRESHOLD>>;
+
+/// Limit breaches of an asset counted within the breach window.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct BreachRecord<AccountId, BlockNumber> {
+	/// Distinct accounts which breached the limits within the window.
+	pub accounts: BoundedVec<AccountId, ConstU32<MAX_BREACH_THRESHOLD>>,
+	/// Distinct calls which breached the limits within the window.
+	pub calls: BreachingCalls,
+	pub window_start: BlockNumber,
+	/// Block of the last counted breach. At most one breach is counted per block.
+	pub last_breach_at: BlockNumber,
+}
+
+/// Emergency mode of an asset.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct EmergencyInfo<BlockNumber, AssetState> {
+	pub triggered_at: BlockNumber,
+	/// State of the asset before it was frozen, restored when the emergency mode is lifted.
+	pub previous_state: Option<AssetState>,
+	/// Calls paused by the emergency mode, unpaused when the emergency mode is lifted.
+	pub paused_calls: BreachingCalls,
+}
+
+pub type AssetStateOf<T> = <<T as Config>::EmergencyHandler as EmergencyHandler<<T as Config>::AssetId>>::AssetState;
+
+/// Net trade volume of an asset tracked over multiple blocks.
+///
+/// The window is split into `ROLLING_WINDOW_BUCKETS` buckets of equal length which are used as a ring buffer.
+/// Buckets that fall out of the window are reset when the window is moved forward.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct RollingWindowVolume<T: Config> {
+	/// Volume in and volume out per bucket.
+	pub buckets: [(T::Balance, T::Balance); ROLLING_WINDOW_BUCKETS],
+	/// Index of the most recently updated bucket.
+	pub last_bucket: u32,
+	/// Limit calculated from the asset reserve at the most recent update.
+	pub limit: T::Balance,
+}
+
+impl<T: Config> RollingWindowVolume<T>
+where
+	T::Balance: PartialOrd,
+{
+	pub fn new(limit: T::Balance, bucket: u32) -> Self {
+		Self {
+			buckets: [(Zero::zero(), Zero::zero()); ROLLING_WINDOW_BUCKETS],
+			last_bucket: bucket,
+			limit,
+		}
+	}
+
+	/// Returns true if none of the tracked buckets are part of the window ending with `bucket`.
+	pub fn is_expired(&self, bucket: u32) -> bool {
+		bucket.saturating_sub(self.last_bucket) >= ROLLING_WINDOW_BUCKETS as u32
+	}
+
+	/// Moves the window forward so it ends with `bucket`, resetting buckets that fell out of the window.
+	pub fn advance(&mut self, bucket: u32) {
+		if bucket <= self.last_bucket {
+			return;
+		}
+		if self.is_expired(bucket) {
+			self.buckets = [(Zero::zero(), Zero::zero()); ROLLING_WINDOW_BUCKETS];
+		} else {
+			for b in self.last_bucket.saturating_add(1)..=bucket {
+				self.buckets[b as usize % ROLLING_WINDOW_BUCKETS] = (Zero::zero(), Zero::zero());
+			}
+		}
+		self.last_bucket = bucket;
+	}
+
+	pub fn update_amounts(&mut self, amount_in: T::Balance, amount_out: T::Balance) -> DispatchResult {
+		let (volume_in, volume_out) = &mut self.buckets[self.last_bucket as usize % ROLLING_WINDOW_BUCKETS];
+		*volume_in = volume_in.checked_add(&amount_in).ok_or(ArithmeticError::Overflow)?;
+		*volume_out = volume_out.checked_add(&amount_out).ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	pub fn check_limits(&self) -> DispatchResult {
+		let (volume_in, volume_out) = self
+			.buckets
+			.iter()
+			.try_fold(
+				(T::Balance::zero(), T::Balance::zero()),
+				|(total_in, total_out), (volume_in, volume_out)| {
+					Some((total_in.checked_add(volume_in)?, total_out.checked_add(volume_out)?))
+				},
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if volume_out > volume_in {
+			ensure!(
+				volume_out.saturating_sub(volume_in) <= self.limit,
+				Error::<T>::RollingWindowOutflowLimitReached
+			);
+		} else {
+			ensure!(
+				volume_in.saturating_sub(volume_out) <= self.limit,
+				Error::<T>::RollingWindowInfluxLimitReached
+			);
+		}
+		Ok(())
+	}
+}
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -162,6 +295,36 @@ pub mod pallet {
 					"Circuit Breaker: Max remove liquidity limit per block is set to invalid value."
 				);
 			}
+
+			if let Some(price_deviation) = T::DefaultMaxPriceDeviation::get() {
+				assert!(
+					Self::validate_limit(price_deviation).is_ok(),
+					"Circuit Breaker: Max price deviation is set to invalid value."
+				);
+			}
+
+			if let Some(trade_volume_limit) = T::DefaultMaxNetTradeVolumeLimitPerWindow::get() {
+				assert!(
+					Self::validate_limit(trade_volume_limit).is_ok(),
+					"Circuit Breaker: Max net trade volume limit per window is set to invalid value."
+				);
+			}
+
+			assert!(
+				T::RollingWindowLength::get() >= ROLLING_WINDOW_BUCKETS as u32,
+				"Circuit Breaker: Rolling window length must be at least the number of buckets."
+			);
+
+			assert!(
+				T::BreachThreshold::get() > 0 && T::BreachThreshold::get() <= MAX_BREACH_THRESHOLD,
+				"Circuit Breaker: Breach threshold is set to invalid value."
+			);
+
+			// At most one breach is counted per block.
+			assert!(
+				T::BreachWindow::get() >= T::BreachThreshold::get().into(),
+				"Circuit Breaker: Breach window must be at least the breach threshold."
+			);
 		}
 	}
 
@@ -217,6 +380,37 @@ pub mod pallet {
 		#[pallet::constant]
 		type DefaultMaxRemoveLiquidityLimitPerBlock: Get<Option<(u32, u32)>>;
 
+		/// The maximum allowed deviation of an asset's post-trade spot price from its short-period oracle price.
+		/// Represented as an optional non-zero fraction (nominator, denominator) with the max value being 10_000.
+		/// If set to None, the deviation is not enforced.
+		#[pallet::constant]
+		type DefaultMaxPriceDeviation: Get<Option<(u32, u32)>>;
+
+		/// The maximum percentage of a pool's liquidity that can be traded (net volume) within the rolling window.
+		/// Represented as an optional non-zero fraction (nominator, denominator) with the max value being 10_000.
+		/// If set to None, the limits are not enforced.
+		#[pallet::constant]
+		type DefaultMaxNetTradeVolumeLimitPerWindow: Get<Option<(u32, u32)>>;
+
+		/// Length of the rolling window in blocks.
+		#[pallet::constant]
+		type RollingWindowLength: Get<u32>;
+
+		/// Number of distinct accounts breaching the limits of an asset within `BreachWindow` which triggers
+		/// emergency mode for the asset. Must not exceed `MAX_BREACH_THRESHOLD`.
+		#[pallet::constant]
+		type BreachThreshold: Get<u32>;
+
+		/// Length of the window in blocks in which limit breaches are counted.
+		#[pallet::constant]
+		type BreachWindow: Get<BlockNumberFor<Self>>;
+
+		/// Provides assets affected by a call. Used to attribute limit breaches to assets.
+		type AffectedAssets: AffectedAssets<<Self as frame_system::Config>::RuntimeCall, Self::AssetId>;
+
+		/// Freezes assets and pauses the breaching calls when emergency mode is triggered.
+		type EmergencyHandler: EmergencyHandler<Self::AssetId>;
+
 		/// Omnipool's hub asset id. The limits are not tracked for this asset.
 		type OmnipoolHubAsset: Get<Self::AssetId>;
 
@@ -284,6 +478,62 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	/// Default maximum price deviation from the oracle price
+	#[pallet::type_value]
+	pub fn DefaultPriceDeviation<T: Config>() -> Option<(u32, u32)> {
+		T::DefaultMaxPriceDeviation::get()
+	}
+
+	#[pallet::storage]
+	/// Maximum allowed deviation of the spot price from the oracle price per asset.
+	/// If not set, returns the default deviation.
+	#[pallet::getter(fn max_price_deviation_per_asset)]
+	pub type MaxPriceDeviationPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, Option<(u32, u32)>, ValueQuery, DefaultPriceDeviation<T>>;
+
+	/// Default maximum net trade volume limit per rolling window
+	#[pallet::type_value]
+	pub fn DefaultRollingWindowTradeVolumeLimit<T: Config>() -> Option<(u32, u32)> {
+		T::DefaultMaxNetTradeVolumeLimitPerWindow::get()
+	}
+
+	#[pallet::storage]
+	/// Trade volume limits of assets over the rolling window.
+	/// If not set, returns the default limit.
+	#[pallet::getter(fn rolling_window_trade_volume_limit_per_asset)]
+	pub type RollingWindowTradeVolumeLimitPerAsset<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Option<(u32, u32)>,
+		ValueQuery,
+		DefaultRollingWindowTradeVolumeLimit<T>,
+	>;
+
+	#[pallet::storage]
+	/// Trade volumes per asset tracked over the rolling window.
+	#[pallet::getter(fn rolling_window_trade_volume_per_asset)]
+	pub type RollingWindowTradeVolumePerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, RollingWindowVolume<T>>;
+
+	#[pallet::storage]
+	/// Limit breaches per asset counted within the breach window.
+	#[pallet::getter(fn limit_breaches)]
+	pub type LimitBreaches<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BreachRecord<T::AccountId, BlockNumberFor<T>>>;
+
+	#[pallet::storage]
+	/// Assets in emergency mode.
+	#[pallet::getter(fn emergency_assets)]
+	pub type EmergencyAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, EmergencyInfo<BlockNumberFor<T>, AssetStateOf<T>>>;
+
+	#[pallet::storage]
+	/// Calls paused by emergency mode with the number of assets in emergency mode which paused the call.
+	/// A call is unpaused when emergency mode of the last of these assets is lifted.
+	#[pallet::getter(fn emergency_paused_calls)]
+	pub type EmergencyPausedCalls<T: Config> = StorageMap<_, Blake2_128Concat, CallName, u32>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +552,32 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Max price deviation of an asset was changed.
+		MaxPriceDeviationChanged {
+			asset_id: T::AssetId,
+			max_price_deviation: Option<(u32, u32)>,
+		},
+		/// Rolling window trade volume limit of an asset was changed.
+		RollingWindowTradeVolumeLimitChanged {
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		},
+		/// A limit of an asset was breached.
+		LimitBreachRecorded {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			breaches: u32,
+		},
+		/// Emergency mode was triggered for an asset. The asset was frozen and the breaching calls were paused.
+		EmergencyModeTriggered {
+			asset_id: T::AssetId,
+			paused_calls: BreachingCalls,
+		},
+		/// Emergency mode could not be triggered for an asset because the asset could not be frozen
+		/// or a breaching call could not be paused.
+		EmergencyModeTriggerFailed { asset_id: T::AssetId, error: DispatchError },
+		/// Emergency mode of an asset was lifted.
+		EmergencyModeLifted { asset_id: T::AssetId },
 	}
 
 	#[pallet::error]
@@ -319,6 +595,14 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Spot price deviates from the oracle price more than allowed
+		MaxPriceDeviationReached,
+		/// Token trade outflow over the rolling window has been reached
+		RollingWindowOutflowLimitReached,
+		/// Token trade influx over the rolling window has been reached
+		RollingWindowInfluxLimitReached,
+		/// Emergency mode is not active for the asset
+		EmergencyModeNotActive,
 	}
 
 	#[pallet::call]
@@ -422,6 +706,118 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set max price deviation for an asset.
+		///
+		/// Trades moving the spot price of the asset further from its oracle price are rejected.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `max_price_deviation`: Optional max price deviation represented as a percentage
+		///
+		/// Emits `MaxPriceDeviationChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_max_price_deviation())]
+		pub fn set_max_price_deviation(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			max_price_deviation: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			if let Some(deviation) = max_price_deviation {
+				Self::validate_limit(deviation)?;
+			}
+
+			<MaxPriceDeviationPerAsset<T>>::insert(asset_id, max_price_deviation);
+
+			Self::deposit_event(Event::MaxPriceDeviationChanged {
+				asset_id,
+				max_price_deviation,
+			});
+
+			Ok(())
+		}
+
+		/// Set trade volume limit over the rolling window for an asset.
+		///
+		/// Volumes tracked so far for the asset are reset, so the new limit applies to a fresh window.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `trade_volume_limit`: Optional trade volume limit represented as a percentage
+		///
+		/// Emits `RollingWindowTradeVolumeLimitChanged` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_rolling_window_trade_volume_limit())]
+		pub fn set_rolling_window_trade_volume_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			if let Some(limit) = trade_volume_limit {
+				Self::validate_limit(limit)?;
+			}
+
+			<RollingWindowTradeVolumeLimitPerAsset<T>>::insert(asset_id, trade_volume_limit);
+			<RollingWindowTradeVolumePerAsset<T>>::remove(asset_id);
+
+			Self::deposit_event(Event::RollingWindowTradeVolumeLimitChanged {
+				asset_id,
+				trade_volume_limit,
+			});
+
+			Ok(())
+		}
+
+		/// Lift emergency mode of an asset.
+		///
+		/// Restores the state the asset had before it was frozen, unpauses the calls paused by the emergency mode
+		/// (unless they are paused by the emergency mode of another asset) and resets the breach counter of the asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		///
+		/// Emits `EmergencyModeLifted` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::lift_emergency_mode_weight())]
+		pub fn lift_emergency_mode(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			let info = <EmergencyAssets<T>>::take(asset_id).ok_or(Error::<T>::EmergencyModeNotActive)?;
+			<LimitBreaches<T>>::remove(asset_id);
+
+			if let Some(previous_state) = info.previous_state {
+				T::EmergencyHandler::unfreeze_asset(asset_id, previous_state)?;
+			}
+
+			for call in info.paused_calls {
+				let paused_by_other_assets = <EmergencyPausedCalls<T>>::mutate_exists(&call, |maybe_count| {
+					let count = maybe_count.unwrap_or_default().saturating_sub(1);
+					*maybe_count = (!count.is_zero()).then_some(count);
+					!count.is_zero()
+				});
+				if !paused_by_other_assets {
+					T::EmergencyHandler::unpause_call(&call.0, &call.1)?;
+				}
+			}
+
+			Self::deposit_event(Event::EmergencyModeLifted { asset_id });
+
+			Ok(())
+		}
 	}
 }
 
@@ -515,6 +911,49 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_and_update_rolling_window_limit(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		let Some(limit) = Pallet::<T>::rolling_window_trade_volume_limit_per_asset(asset_id) else {
+			return Ok(());
+		};
+
+		let bucket = Self::current_bucket();
+		// the limit follows the current reserve, so it can't be bypassed by draining the pool over multiple blocks
+		let limit = Self::calculate_limit(asset_reserve, limit)?;
+
+		let mut window = match Pallet::<T>::rolling_window_trade_volume_per_asset(asset_id) {
+			Some(window) if !window.is_expired(bucket) => window,
+			_ => RollingWindowVolume::<T>::new(limit, bucket),
+		};
+
+		window.limit = limit;
+		window.advance(bucket);
+		window.update_amounts(amount_in, amount_out)?;
+		window.check_limits()?;
+
+		<RollingWindowTradeVolumePerAsset<T>>::insert(asset_id, window);
+
+		Ok(())
+	}
+
+	/// Index of the rolling window bucket the current block belongs to.
+	fn current_bucket() -> u32 {
+		let block_number: u32 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+		let bucket_length = T::RollingWindowLength::get()
+			.checked_div(ROLLING_WINDOW_BUCKETS as u32)
+			.unwrap_or_default()
+			.max(1);
+		block_number / bucket_length
+	}
+
 	fn ensure_and_update_add_liquidity_limit(asset_id: T::AssetId, added_liquidity: T::Balance) -> DispatchResult {
 		if asset_id != T::OmnipoolHubAsset::get() && Pallet::<T>::add_liquidity_limit_per_asset(asset_id).is_some() {
 			let mut allowed_liquidity_limit = Pallet::<T>::allowed_add_liquidity_limit_per_asset(asset_id)
@@ -579,6 +1018,8 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::initialize_trade_limit(asset_in, asset_in_reserve)?;
 		Pallet::<T>::initialize_trade_limit(asset_out, asset_out_reserve)?;
 		Pallet::<T>::ensure_and_update_trade_volume_limit(asset_in, amount_in, asset_out, amount_out)?;
+		Pallet::<T>::ensure_and_update_rolling_window_limit(asset_in, asset_in_reserve, amount_in, Zero::zero())?;
+		Pallet::<T>::ensure_and_update_rolling_window_limit(asset_out, asset_out_reserve, Zero::zero(), amount_out)?;
 
 		Ok(T::WeightInfo::ensure_pool_state_change_limit())
 	}
@@ -617,6 +1058,193 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
 	}
 
+	/// Ensure that the spot price of an asset does not deviate from its oracle price more than allowed.
+	///
+	/// Both prices are given as (nominator, denominator) of the same asset pair. If the oracle price is not
+	/// available (zero), the check is skipped.
+	pub fn ensure_price_deviation_limit(
+		asset_id: T::AssetId,
+		spot_price: (T::Balance, T::Balance),
+		oracle_price: (T::Balance, T::Balance),
+	) -> Result<Weight, DispatchError> {
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(Weight::zero());
+		}
+
+		if let Some(max_deviation) = Pallet::<T>::max_price_deviation_per_asset(asset_id) {
+			ensure!(
+				!Self::exceeds_price_deviation(spot_price, oracle_price, max_deviation),
+				Error::<T>::MaxPriceDeviationReached
+			);
+		}
+
+		Ok(T::WeightInfo::ensure_price_deviation_limit())
+	}
+
+	/// Returns true if `|spot - oracle| / oracle > max_deviation`.
+	pub(crate) fn exceeds_price_deviation(
+		spot_price: (T::Balance, T::Balance),
+		oracle_price: (T::Balance, T::Balance),
+		max_deviation: (u32, u32),
+	) -> bool {
+		let to_u512 = |b: T::Balance| U512::from(UniqueSaturatedInto::<u128>::unique_saturated_into(b));
+		let (spot_n, spot_d) = (to_u512(spot_price.0), to_u512(spot_price.1));
+		let (oracle_n, oracle_d) = (to_u512(oracle_price.0), to_u512(oracle_price.1));
+
+		if oracle_n.is_zero() || oracle_d.is_zero() || spot_d.is_zero() {
+			return false;
+		}
+
+		let spot = spot_n.saturating_mul(oracle_d);
+		let oracle = oracle_n.saturating_mul(spot_d);
+		let diff = if spot > oracle { spot - oracle } else { oracle - spot };
+
+		diff.saturating_mul(U512::from(max_deviation.1)) > oracle.saturating_mul(U512::from(max_deviation.0))
+	}
+
+	/// Record a limit breach of an asset caused by the given account and call.
+	///
+	/// Triggers emergency mode for the asset when the number of distinct accounts breaching the limits within
+	/// the breach window reaches `BreachThreshold`. At most one breach is counted per asset and block.
+	pub fn record_limit_breach(asset_id: T::AssetId, who: &T::AccountId, pallet_name: &[u8], function_name: &[u8]) {
+		if asset_id == T::OmnipoolHubAsset::get() || <EmergencyAssets<T>>::contains_key(asset_id) {
+			return;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let mut record = match Pallet::<T>::limit_breaches(asset_id) {
+			Some(record) if now < record.window_start.saturating_add(T::BreachWindow::get()) => {
+				if record.last_breach_at == now || record.accounts.contains(who) {
+					return;
+				}
+				record
+			}
+			_ => BreachRecord {
+				accounts: BoundedVec::default(),
+				calls: BoundedVec::default(),
+				window_start: now,
+				last_breach_at: now,
+			},
+		};
+		// Cannot fail, the emergency mode is triggered before the list is full.
+		if record.accounts.try_push(who.clone()).is_err() {
+			return;
+		}
+		record.last_breach_at = now;
+
+		// Calls with names which cannot be paused are not recorded.
+		if let (Ok(pallet), Ok(function)) = (
+			BoundedName::try_from(pallet_name.to_vec()),
+			BoundedName::try_from(function_name.to_vec()),
+		) {
+			let call = (pallet, function);
+			if !record.calls.contains(&call) {
+				// Cannot fail, at most one call is added per counted breach.
+				let _ = record.calls.try_push(call);
+			}
+		}
+
+		let breaches = record.accounts.len() as u32;
+		Self::deposit_event(Event::LimitBreachRecorded {
+			asset_id,
+			who: who.clone(),
+			breaches,
+		});
+
+		if breaches < T::BreachThreshold::get() {
+			<LimitBreaches<T>>::insert(asset_id, record);
+			return;
+		}
+
+		<LimitBreaches<T>>::remove(asset_id);
+		Self::trigger_emergency_mode(asset_id, record.calls);
+	}
+
+	fn trigger_emergency_mode(asset_id: T::AssetId, breaching_calls: BreachingCalls) {
+		// Changes of a failed handler are reverted so the asset is not left partially frozen
+		// and no call is left paused.
+		let result = with_storage_layer(|| -> Result<_, DispatchError> {
+			let previous_state = T::EmergencyHandler::freeze_asset(asset_id)?;
+			let paused_calls = Self::pause_calls(breaching_calls)?;
+			Ok((previous_state, paused_calls))
+		});
+
+		match result {
+			Ok((previous_state, paused_calls)) => {
+				<EmergencyAssets<T>>::insert(
+					asset_id,
+					EmergencyInfo {
+						triggered_at: frame_system::Pallet::<T>::block_number(),
+						previous_state,
+						paused_calls: paused_calls.clone(),
+					},
+				);
+				Self::deposit_event(Event::EmergencyModeTriggered { asset_id, paused_calls });
+			}
+			Err(error) => {
+				Self::deposit_event(Event::EmergencyModeTriggerFailed { asset_id, error });
+			}
+		}
+	}
+
+	/// Pauses the breaching calls. Calls already paused by the emergency mode of another asset are only counted.
+	///
+	/// Returns the calls paused by the emergency mode. Calls paused by other means are not included,
+	/// so they stay paused when the emergency mode is lifted.
+	fn pause_calls(breaching_calls: BreachingCalls) -> Result<BreachingCalls, DispatchError> {
+		let mut paused_calls = BreachingCalls::default();
+		for call in breaching_calls {
+			if <EmergencyPausedCalls<T>>::contains_key(&call) || T::EmergencyHandler::pause_call(&call.0, &call.1)? {
+				<EmergencyPausedCalls<T>>::mutate(&call, |count| {
+					*count = Some(count.unwrap_or_default().saturating_add(1));
+				});
+				// Cannot fail, there are at most as many paused calls as breaching calls.
+				let _ = paused_calls.try_push(call);
+			}
+		}
+		Ok(paused_calls)
+	}
+
+	/// Weight of recording a limit breach of one asset, including triggering the emergency mode.
+	pub fn record_limit_breach_weight() -> Weight {
+		let calls = T::BreachThreshold::get();
+		T::WeightInfo::record_limit_breach(calls)
+			.saturating_add(T::EmergencyHandler::freeze_asset_weight())
+			.saturating_add(T::EmergencyHandler::pause_call_weight().saturating_mul(calls.into()))
+	}
+
+	/// Weight of `lift_emergency_mode`, including unpausing all calls paused by the emergency mode.
+	pub fn lift_emergency_mode_weight() -> Weight {
+		let calls = T::BreachThreshold::get();
+		T::WeightInfo::lift_emergency_mode(calls)
+			.saturating_add(T::EmergencyHandler::unfreeze_asset_weight())
+			.saturating_add(T::EmergencyHandler::unpause_call_weight().saturating_mul(calls.into()))
+	}
+
+	/// Returns assets to which a failed call's error should be attributed, if the error is a limit breach.
+	pub(crate) fn breaching_assets(
+		error: &DispatchError,
+		assets_in: Vec<T::AssetId>,
+		assets_out: Vec<T::AssetId>,
+	) -> Vec<T::AssetId> {
+		let is = |e: Error<T>| *error == DispatchError::from(e);
+
+		let mut assets = if is(Error::<T>::TokenOutflowLimitReached) || is(Error::<T>::RollingWindowOutflowLimitReached)
+		{
+			assets_out
+		} else if is(Error::<T>::TokenInfluxLimitReached) || is(Error::<T>::RollingWindowInfluxLimitReached) {
+			assets_in
+		} else if is(Error::<T>::MaxLiquidityLimitPerBlockReached) || is(Error::<T>::MaxPriceDeviationReached) {
+			assets_in.into_iter().chain(assets_out).collect()
+		} else {
+			Vec::new()
+		};
+		assets.sort();
+		assets.dedup();
+		assets
+	}
+
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
 		let who = ensure_signed_or_root(origin)?;
 		match who {
@@ -635,3 +1263,76 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+/// Signed extension recording limit breaches of failed calls.
+///
+/// A call breaching the circuit breaker limits fails and all its storage changes are reverted, so the breach
+/// is recorded after the dispatch. Repeated breaches trigger emergency mode of the affected asset.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RecordLimitBreach<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for RecordLimitBreach<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "RecordLimitBreach")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for RecordLimitBreach<T>
+where
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "RecordLimitBreach";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = Option<(T::AccountId, Vec<T::AssetId>, Vec<T::AssetId>, CallMetadata)>;
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let (assets_in, assets_out) = T::AffectedAssets::affected_assets(call);
+		if assets_in.is_empty() && assets_out.is_empty() {
+			return Ok(None);
+		}
+		Ok(Some((who.clone(), assets_in, assets_out, call.get_call_metadata())))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		// `record_limit_breach_weight` is included in the weight of the limit checks of the breaching calls,
+		// so recording the breaches is already paid for by the failed call.
+		if let (Some(Some((who, assets_in, assets_out, call))), Err(error)) = (pre, result) {
+			for asset_id in Pallet::<T>::breaching_assets(error, assets_in, assets_out) {
+				Pallet::<T>::record_limit_breach(
+					asset_id,
+					&who,
+					call.pallet_name.as_bytes(),
+					call.function_name.as_bytes(),
+				);
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> RecordLimitBreach<T> {
+	#[cfg_attr(feature = "cargo-clippy", allow(clippy::new_without_default))]
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
@@ -0,0 +1,481 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_noop;
+use frame_support::dispatch::GetDispatchInfo;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::traits::SignedExtension;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn record_breach(asset_id: AssetId, who: AccountId) {
+	record_breach_by_call(asset_id, who, b"Omnipool", b"sell");
+}
+
+fn record_breach_by_call(asset_id: AssetId, who: AccountId, pallet_name: &[u8], function_name: &[u8]) {
+	CircuitBreaker::record_limit_breach(asset_id, &who, pallet_name, function_name);
+}
+
+fn call_name(pallet_name: &[u8], function_name: &[u8]) -> CallName {
+	(
+		pallet_name.to_vec().try_into().unwrap(),
+		function_name.to_vec().try_into().unwrap(),
+	)
+}
+
+fn omnipool_sell() -> CallName {
+	call_name(b"Omnipool", b"sell")
+}
+
+/// Records a breach of a different account in each block, starting at account 10.
+fn breach_in_consecutive_blocks(asset_id: AssetId, breaches: u64) {
+	let now = System::block_number();
+	for i in 0..breaches {
+		System::set_block_number(now + i);
+		record_breach(asset_id, 10 + i);
+	}
+}
+
+fn frozen_assets() -> Vec<AssetId> {
+	FROZEN_ASSETS.with(|v| v.borrow().clone())
+}
+
+fn restored_assets() -> Vec<(AssetId, u8)> {
+	RESTORED_ASSETS.with(|v| v.borrow().clone())
+}
+
+fn paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+	PAUSED_CALLS.with(|v| v.borrow().clone())
+}
+
+fn sell_call(asset_in: AssetId, asset_out: AssetId) -> RuntimeCall {
+	RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+		asset_in,
+		asset_out,
+		amount: 1_000 * ONE,
+		min_buy_amount: 0,
+	})
+}
+
+fn dispatch_with_extension(who: AccountId, call: RuntimeCall, result: DispatchResult) {
+	let info = call.get_dispatch_info();
+	let pre = RecordLimitBreach::<Test>::new()
+		.pre_dispatch(&who, &call, &info, 0)
+		.unwrap();
+	assert_ok!(RecordLimitBreach::<Test>::post_dispatch(
+		Some(pre),
+		&info,
+		&Default::default(),
+		0,
+		&result
+	));
+}
+
+#[test]
+fn record_limit_breach_should_count_breaches() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		breach_in_consecutive_blocks(DOT, 2);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::limit_breaches(DOT),
+			Some(BreachRecord {
+				accounts: vec![10, 11].try_into().unwrap(),
+				calls: vec![omnipool_sell()].try_into().unwrap(),
+				window_start: 1,
+				last_breach_at: 2,
+			})
+		);
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+		assert!(frozen_assets().is_empty());
+		expect_events(vec![crate::Event::LimitBreachRecorded {
+			asset_id: DOT,
+			who: 11,
+			breaches: 2,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn record_limit_breach_should_count_only_one_breach_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		record_breach(DOT, 10);
+		record_breach(DOT, 11);
+		record_breach(DOT, 12);
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_breaches(DOT).map(|r| r.accounts.len()), Some(1));
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+	});
+}
+
+#[test]
+fn record_limit_breach_should_count_only_distinct_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		for block in 1..=BreachWindow::get() {
+			System::set_block_number(block);
+			record_breach(DOT, ALICE);
+		}
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::limit_breaches(DOT),
+			Some(BreachRecord {
+				accounts: vec![ALICE].try_into().unwrap(),
+				calls: vec![omnipool_sell()].try_into().unwrap(),
+				window_start: 1,
+				last_breach_at: 1,
+			})
+		);
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+		assert!(frozen_assets().is_empty());
+	});
+}
+
+#[test]
+fn record_limit_breach_should_reset_count_when_window_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, 2);
+
+		// Act
+		System::set_block_number(1 + BreachWindow::get());
+		record_breach(DOT, 10);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::limit_breaches(DOT),
+			Some(BreachRecord {
+				accounts: vec![10].try_into().unwrap(),
+				calls: vec![omnipool_sell()].try_into().unwrap(),
+				window_start: 1 + BreachWindow::get(),
+				last_breach_at: 1 + BreachWindow::get(),
+			})
+		);
+	});
+}
+
+#[test]
+fn record_limit_breach_should_ignore_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		breach_in_consecutive_blocks(LRNA, BreachThreshold::get() as u64);
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_breaches(LRNA), None);
+		assert_eq!(CircuitBreaker::emergency_assets(LRNA), None);
+	});
+}
+
+#[test]
+fn emergency_mode_should_be_triggered_when_threshold_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::emergency_assets(DOT),
+			Some(EmergencyInfo {
+				triggered_at: 3,
+				previous_state: Some(ASSET_STATE_BEFORE_FREEZE),
+				paused_calls: vec![omnipool_sell()].try_into().unwrap(),
+			})
+		);
+		assert_eq!(CircuitBreaker::limit_breaches(DOT), None);
+		assert_eq!(frozen_assets(), vec![DOT]);
+		assert_eq!(paused_calls(), vec![(b"Omnipool".to_vec(), b"sell".to_vec())]);
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), Some(1));
+		expect_events(vec![
+			crate::Event::LimitBreachRecorded {
+				asset_id: DOT,
+				who: 12,
+				breaches: 3,
+			}
+			.into(),
+			crate::Event::EmergencyModeTriggered {
+				asset_id: DOT,
+				paused_calls: vec![omnipool_sell()].try_into().unwrap(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn emergency_mode_should_pause_only_breaching_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		System::set_block_number(1);
+		record_breach_by_call(DOT, 10, b"Omnipool", b"sell");
+		System::set_block_number(2);
+		record_breach_by_call(DOT, 11, b"Omnipool", b"buy");
+		System::set_block_number(3);
+		record_breach_by_call(DOT, 12, b"Omnipool", b"sell");
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::emergency_assets(DOT).map(|info| info.paused_calls.into_inner()),
+			Some(vec![omnipool_sell(), call_name(b"Omnipool", b"buy")])
+		);
+		assert_eq!(
+			paused_calls(),
+			vec![
+				(b"Omnipool".to_vec(), b"sell".to_vec()),
+				(b"Omnipool".to_vec(), b"buy".to_vec())
+			]
+		);
+	});
+}
+
+#[test]
+fn emergency_mode_should_not_take_over_calls_which_are_already_paused() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		PAUSED_CALLS.with(|v| v.borrow_mut().push((b"Omnipool".to_vec(), b"sell".to_vec())));
+
+		// Act
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+		assert_ok!(CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), DOT));
+
+		// Assert
+		assert_eq!(paused_calls(), vec![(b"Omnipool".to_vec(), b"sell".to_vec())]);
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), None);
+		expect_events(vec![crate::Event::EmergencyModeLifted { asset_id: DOT }.into()]);
+	});
+}
+
+#[test]
+fn emergency_mode_should_not_be_recorded_when_asset_cannot_be_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		FREEZE_FAILS.with(|v| *v.borrow_mut() = true);
+
+		// Act
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+
+		// Assert
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+		assert_eq!(CircuitBreaker::limit_breaches(DOT), None);
+		assert!(frozen_assets().is_empty());
+		assert!(paused_calls().is_empty());
+		expect_events(vec![crate::Event::EmergencyModeTriggerFailed {
+			asset_id: DOT,
+			error: DispatchError::Other("freeze failed"),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn breaches_should_not_be_counted_when_emergency_mode_is_active() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+
+		// Act
+		System::set_block_number(10);
+		record_breach(DOT, ALICE);
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_breaches(DOT), None);
+		assert_eq!(frozen_assets(), vec![DOT]);
+	});
+}
+
+#[test]
+fn lift_emergency_mode_should_restore_previous_asset_state() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+
+		// Act
+		assert_ok!(CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), DOT));
+
+		// Assert
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+		assert!(frozen_assets().is_empty());
+		assert_eq!(restored_assets(), vec![(DOT, ASSET_STATE_BEFORE_FREEZE)]);
+		assert!(paused_calls().is_empty());
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), None);
+		expect_events(vec![crate::Event::EmergencyModeLifted { asset_id: DOT }.into()]);
+	});
+}
+
+#[test]
+fn lift_emergency_mode_should_not_unfreeze_other_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+		System::set_block_number(1);
+		breach_in_consecutive_blocks(HDX, BreachThreshold::get() as u64);
+
+		// Act
+		assert_ok!(CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), DOT));
+
+		// Assert
+		assert_eq!(frozen_assets(), vec![HDX]);
+		assert!(CircuitBreaker::emergency_assets(HDX).is_some());
+	});
+}
+
+#[test]
+fn lift_emergency_mode_should_keep_calls_paused_by_other_assets_paused() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+		System::set_block_number(1);
+		breach_in_consecutive_blocks(HDX, BreachThreshold::get() as u64);
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), Some(2));
+
+		// Act
+		assert_ok!(CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), DOT));
+
+		// Assert
+		assert_eq!(paused_calls(), vec![(b"Omnipool".to_vec(), b"sell".to_vec())]);
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), Some(1));
+
+		// Act
+		assert_ok!(CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), HDX));
+
+		// Assert
+		assert!(paused_calls().is_empty());
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), None);
+	});
+}
+
+#[test]
+fn lift_emergency_mode_should_fail_when_not_active() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::lift_emergency_mode(RuntimeOrigin::root(), DOT),
+			Error::<Test>::EmergencyModeNotActive
+		);
+	});
+}
+
+#[test]
+fn lift_emergency_mode_should_fail_when_called_by_invalid_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		breach_in_consecutive_blocks(DOT, BreachThreshold::get() as u64);
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::lift_emergency_mode(RuntimeOrigin::signed(ALICE), DOT),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn signed_extension_should_record_breach_of_asset_out_when_outflow_limit_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		dispatch_with_extension(
+			ALICE,
+			sell_call(HDX, DOT),
+			Err(Error::<Test>::TokenOutflowLimitReached.into()),
+		);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::limit_breaches(DOT).map(|r| r.accounts.into_inner()),
+			Some(vec![ALICE])
+		);
+		assert_eq!(CircuitBreaker::limit_breaches(HDX), None);
+	});
+}
+
+#[test]
+fn signed_extension_should_record_breach_of_asset_in_when_influx_limit_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		dispatch_with_extension(
+			ALICE,
+			sell_call(HDX, DOT),
+			Err(Error::<Test>::RollingWindowInfluxLimitReached.into()),
+		);
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::limit_breaches(HDX).map(|r| r.accounts.into_inner()),
+			Some(vec![ALICE])
+		);
+		assert_eq!(CircuitBreaker::limit_breaches(DOT), None);
+	});
+}
+
+#[test]
+fn signed_extension_should_not_record_breach_when_call_fails_with_other_error() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		dispatch_with_extension(ALICE, sell_call(HDX, DOT), Err(BadOrigin.into()));
+		dispatch_with_extension(ALICE, sell_call(HDX, DOT), Ok(()));
+
+		// Assert
+		assert_eq!(CircuitBreaker::limit_breaches(HDX), None);
+		assert_eq!(CircuitBreaker::limit_breaches(DOT), None);
+	});
+}
+
+#[test]
+fn signed_extension_should_trigger_emergency_mode_when_distinct_accounts_breach_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		for block in 1..=BreachThreshold::get() as u64 {
+			System::set_block_number(block);
+			dispatch_with_extension(
+				10 + block,
+				sell_call(HDX, DOT),
+				Err(Error::<Test>::MaxPriceDeviationReached.into()),
+			);
+		}
+
+		// Assert
+		assert!(CircuitBreaker::emergency_assets(DOT).is_some());
+		assert!(CircuitBreaker::emergency_assets(HDX).is_some());
+		assert_eq!(frozen_assets(), vec![HDX, DOT]);
+		assert_eq!(paused_calls(), vec![(b"Omnipool".to_vec(), b"sell".to_vec())]);
+		assert_eq!(CircuitBreaker::emergency_paused_calls(omnipool_sell()), Some(2));
+	});
+}
+
+#[test]
+fn signed_extension_should_not_trigger_emergency_mode_when_single_account_breaches_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		for block in 1..=BreachWindow::get() {
+			System::set_block_number(block);
+			dispatch_with_extension(
+				ALICE,
+				sell_call(HDX, DOT),
+				Err(Error::<Test>::MaxPriceDeviationReached.into()),
+			);
+		}
+
+		// Assert
+		assert_eq!(CircuitBreaker::emergency_assets(DOT), None);
+		assert_eq!(CircuitBreaker::emergency_assets(HDX), None);
+		assert!(frozen_assets().is_empty());
+	});
+}
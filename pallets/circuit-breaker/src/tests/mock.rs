@@ -18,6 +18,7 @@
 pub use crate as pallet_circuit_breaker;
 use frame_support::traits::Contains;
 pub use frame_support::traits::{Everything, OnFinalize};
+use frame_support::weights::Weight;
 pub use frame_support::{assert_noop, assert_ok, parameter_types};
 
 use frame_system::EnsureRoot;
@@ -76,6 +77,10 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = RefCell::new((2_000, 10_000)); // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((4_000, 10_000))); // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((2_000, 10_000))); // 20%
+	pub static FROZEN_ASSETS: RefCell<Vec<AssetId>> = RefCell::new(Vec::new());
+	pub static RESTORED_ASSETS: RefCell<Vec<(AssetId, u8)>> = RefCell::new(Vec::new());
+	pub static FREEZE_FAILS: RefCell<bool> = RefCell::new(false);
+	pub static PAUSED_CALLS: RefCell<Vec<(Vec<u8>, Vec<u8>)>> = RefCell::new(Vec::new());
 }
 
 frame_support::construct_runtime!(
@@ -125,6 +130,11 @@ parameter_types! {
 	pub DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
+	pub const DefaultMaxPriceDeviation: Option<(u32, u32)> = None;
+	pub const DefaultMaxNetTradeVolumeLimitPerWindow: Option<(u32, u32)> = None;
+	pub const RollingWindowLength: u32 = 100;
+	pub const BreachThreshold: u32 = 3;
+	pub const BreachWindow: u64 = 10;
 	pub const OmnipoolHubAsset: AssetId = LRNA;
 }
 
@@ -137,10 +147,89 @@ impl pallet_circuit_breaker::Config for Test {
 	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxAddLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxRemoveLiquidityLimitPerBlock;
+	type DefaultMaxPriceDeviation = DefaultMaxPriceDeviation;
+	type DefaultMaxNetTradeVolumeLimitPerWindow = DefaultMaxNetTradeVolumeLimitPerWindow;
+	type RollingWindowLength = RollingWindowLength;
+	type BreachThreshold = BreachThreshold;
+	type BreachWindow = BreachWindow;
+	type AffectedAssets = OmnipoolAffectedAssets;
+	type EmergencyHandler = MockEmergencyHandler;
 	type OmnipoolHubAsset = OmnipoolHubAsset;
 	type WeightInfo = ();
 }
 
+pub struct OmnipoolAffectedAssets;
+
+impl pallet_circuit_breaker::AffectedAssets<RuntimeCall, AssetId> for OmnipoolAffectedAssets {
+	fn affected_assets(call: &RuntimeCall) -> (Vec<AssetId>, Vec<AssetId>) {
+		match call {
+			RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::buy {
+				asset_in, asset_out, ..
+			}) => (vec![*asset_in], vec![*asset_out]),
+			_ => (vec![], vec![]),
+		}
+	}
+}
+
+pub struct MockEmergencyHandler;
+
+/// State of an asset returned by the mock handler when the asset is frozen.
+pub const ASSET_STATE_BEFORE_FREEZE: u8 = 0b0000_0101;
+
+impl pallet_circuit_breaker::EmergencyHandler<AssetId> for MockEmergencyHandler {
+	type AssetState = u8;
+
+	fn freeze_asset(asset_id: AssetId) -> Result<Option<u8>, DispatchError> {
+		if FREEZE_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("freeze failed"));
+		}
+		FROZEN_ASSETS.with(|v| v.borrow_mut().push(asset_id));
+		Ok(Some(ASSET_STATE_BEFORE_FREEZE))
+	}
+
+	fn unfreeze_asset(asset_id: AssetId, previous_state: u8) -> DispatchResult {
+		FROZEN_ASSETS.with(|v| v.borrow_mut().retain(|a| *a != asset_id));
+		RESTORED_ASSETS.with(|v| v.borrow_mut().push((asset_id, previous_state)));
+		Ok(())
+	}
+
+	fn pause_call(pallet_name: &[u8], function_name: &[u8]) -> Result<bool, DispatchError> {
+		let call = (pallet_name.to_vec(), function_name.to_vec());
+		PAUSED_CALLS.with(|v| {
+			if v.borrow().contains(&call) {
+				return Ok(false);
+			}
+			v.borrow_mut().push(call);
+			Ok(true)
+		})
+	}
+
+	fn unpause_call(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		let call = (pallet_name.to_vec(), function_name.to_vec());
+		PAUSED_CALLS.with(|v| v.borrow_mut().retain(|c| *c != call));
+		Ok(())
+	}
+
+	fn freeze_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn unfreeze_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn pause_call_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn unpause_call_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct CircuitBreakerWhitelist;
 
 impl Contains<AccountId> for CircuitBreakerWhitelist {
@@ -547,6 +636,10 @@ impl ExtBuilder {
 		ASSET_FEE.with(|v| {
 			*v.borrow_mut() = self.asset_fee;
 		});
+		FROZEN_ASSETS.with(|v| v.borrow_mut().clear());
+		RESTORED_ASSETS.with(|v| v.borrow_mut().clear());
+		FREEZE_FAILS.with(|v| *v.borrow_mut() = false);
+		PAUSED_CALLS.with(|v| v.borrow_mut().clear());
 		ASSET_WEIGHT_CAP.with(|v| {
 			*v.borrow_mut() = self.asset_weight_cap;
 		});
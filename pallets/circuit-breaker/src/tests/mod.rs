@@ -1,7 +1,10 @@
 mod add_liquidity_limit;
+mod emergency;
 mod ensure_add_liquidity_limit;
 mod ensure_remove_liquidity_limit;
 pub(crate) mod mock;
 mod omnipool;
+mod price_deviation;
 mod remove_liquidity_limit;
+mod rolling_window;
 mod trade_volume;
@@ -0,0 +1,221 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_noop;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const ORACLE_PRICE: (Balance, Balance) = (2_000, 1_000);
+
+#[test]
+fn max_price_deviation_should_not_be_enforced_by_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_eq!(CircuitBreaker::max_price_deviation_per_asset(HDX), None);
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(10_000, 1_000),
+			ORACLE_PRICE
+		));
+	});
+}
+
+#[test]
+fn set_max_price_deviation_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT)
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::max_price_deviation_per_asset(HDX), Some(TEN_PERCENT));
+		expect_events(vec![crate::Event::MaxPriceDeviationChanged {
+			asset_id: HDX,
+			max_price_deviation: Some(TEN_PERCENT),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_max_price_deviation_should_fail_when_called_by_invalid_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_max_price_deviation(RuntimeOrigin::signed(ALICE), HDX, Some(TEN_PERCENT)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_max_price_deviation_should_fail_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_max_price_deviation(RuntimeOrigin::root(), LRNA, Some(TEN_PERCENT)),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_max_price_deviation_should_fail_when_deviation_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_max_price_deviation(RuntimeOrigin::root(), HDX, Some((0, 100))),
+			Error::<Test>::InvalidLimitValue
+		);
+		assert_noop!(
+			CircuitBreaker::set_max_price_deviation(RuntimeOrigin::root(), HDX, Some((MAX_LIMIT_VALUE + 1, 100))),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_pass_when_price_is_within_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT)
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			ORACLE_PRICE,
+			ORACLE_PRICE
+		));
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(2_200, 1_000),
+			ORACLE_PRICE
+		));
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(1_800, 1_000),
+			ORACLE_PRICE
+		));
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_fail_when_price_moves_too_much() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT)
+		));
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::ensure_price_deviation_limit(HDX, (2_201, 1_000), ORACLE_PRICE),
+			Error::<Test>::MaxPriceDeviationReached
+		);
+		assert_noop!(
+			CircuitBreaker::ensure_price_deviation_limit(HDX, (1_799, 1_000), ORACLE_PRICE),
+			Error::<Test>::MaxPriceDeviationReached
+		);
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_compare_prices_with_different_denominators() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(FIVE_PERCENT)
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(4 * ONE, 2 * ONE),
+			ORACLE_PRICE
+		));
+		assert_noop!(
+			CircuitBreaker::ensure_price_deviation_limit(HDX, (u128::MAX, u128::MAX / 3), ORACLE_PRICE),
+			Error::<Test>::MaxPriceDeviationReached
+		);
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_be_skipped_when_oracle_price_is_not_available() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT)
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(10_000, 1_000),
+			(0, 0)
+		));
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_be_skipped_when_turned_off() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT)
+		));
+		assert_ok!(CircuitBreaker::set_max_price_deviation(
+			RuntimeOrigin::root(),
+			HDX,
+			None
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			HDX,
+			(10_000, 1_000),
+			ORACLE_PRICE
+		));
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_ignore_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			LRNA,
+			(10_000, 1_000),
+			ORACLE_PRICE
+		));
+	});
+}
@@ -0,0 +1,301 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::traits::Hooks;
+use frame_support::{assert_noop, assert_storage_noop};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const THIRTY_PERCENT: (u32, u32) = (3_000, 10_000);
+
+fn set_rolling_window_limit(asset_id: AssetId, limit: Option<(u32, u32)>) {
+	assert_ok!(CircuitBreaker::set_rolling_window_trade_volume_limit(
+		RuntimeOrigin::root(),
+		asset_id,
+		limit
+	));
+}
+
+fn sell(asset_id: AssetId, amount: Balance) -> DispatchResult {
+	CircuitBreaker::ensure_and_update_rolling_window_limit(asset_id, INITIAL_LIQUIDITY, amount, 0)
+}
+
+fn buy(asset_id: AssetId, amount: Balance) -> DispatchResult {
+	CircuitBreaker::ensure_and_update_rolling_window_limit(asset_id, INITIAL_LIQUIDITY, 0, amount)
+}
+
+#[test]
+fn rolling_window_limit_should_not_be_enforced_by_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_eq!(CircuitBreaker::rolling_window_trade_volume_limit_per_asset(DOT), None);
+
+		// Act & Assert
+		assert_storage_noop!(buy(DOT, INITIAL_LIQUIDITY).unwrap());
+	});
+}
+
+#[test]
+fn set_rolling_window_trade_volume_limit_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::rolling_window_trade_volume_limit_per_asset(DOT),
+			Some(THIRTY_PERCENT)
+		);
+		expect_events(vec![crate::Event::RollingWindowTradeVolumeLimitChanged {
+			asset_id: DOT,
+			trade_volume_limit: Some(THIRTY_PERCENT),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_rolling_window_trade_volume_limit_should_fail_when_called_by_invalid_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_window_trade_volume_limit(RuntimeOrigin::signed(ALICE), DOT, Some(TEN_PERCENT)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_rolling_window_trade_volume_limit_should_fail_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_window_trade_volume_limit(RuntimeOrigin::root(), LRNA, Some(TEN_PERCENT)),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_rolling_window_trade_volume_limit_should_fail_when_limit_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_window_trade_volume_limit(RuntimeOrigin::root(), DOT, Some((0, 100))),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_be_stored_when_called_first_time() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		// Act
+		assert_ok!(buy(DOT, 100_000));
+
+		// Assert
+		let window = CircuitBreaker::rolling_window_trade_volume_per_asset(DOT).unwrap();
+		assert_eq!(window.limit, 300_000);
+		assert_eq!(window.last_bucket, 0);
+		assert_eq!(window.buckets[0], (0, 100_000));
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_fail_when_outflow_over_multiple_blocks_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		assert_ok!(buy(DOT, 150_000));
+		System::set_block_number(2);
+		assert_ok!(buy(DOT, 150_000));
+		System::set_block_number(3);
+
+		// Act & Assert
+		assert_noop!(buy(DOT, 1), Error::<Test>::RollingWindowOutflowLimitReached);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_fail_when_influx_over_multiple_blocks_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		assert_ok!(sell(DOT, 150_000));
+		System::set_block_number(2);
+		assert_ok!(sell(DOT, 150_000));
+		System::set_block_number(3);
+
+		// Act & Assert
+		assert_noop!(sell(DOT, 1), Error::<Test>::RollingWindowInfluxLimitReached);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_track_net_volume() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		// Act & Assert
+		assert_ok!(buy(DOT, 250_000));
+		System::set_block_number(2);
+		assert_ok!(sell(DOT, 250_000));
+		System::set_block_number(3);
+		assert_ok!(buy(DOT, 300_000));
+		System::set_block_number(4);
+		assert_noop!(buy(DOT, 1), Error::<Test>::RollingWindowOutflowLimitReached);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_release_volume_of_expired_buckets() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		// window of 100 blocks is split into 10 buckets of 10 blocks
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		assert_ok!(buy(DOT, 250_000));
+		System::set_block_number(95);
+		assert_ok!(buy(DOT, 40_000));
+		assert_noop!(buy(DOT, 20_000), Error::<Test>::RollingWindowOutflowLimitReached);
+
+		// Act
+		// the first bucket falls out of the window
+		System::set_block_number(100);
+
+		// Assert
+		assert_ok!(buy(DOT, 250_000));
+		assert_noop!(buy(DOT, 20_000), Error::<Test>::RollingWindowOutflowLimitReached);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_be_recalculated_when_window_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+		assert_ok!(buy(DOT, 300_000));
+
+		// Act
+		System::set_block_number(200);
+		assert_ok!(CircuitBreaker::ensure_and_update_rolling_window_limit(
+			DOT,
+			2 * INITIAL_LIQUIDITY,
+			0,
+			500_000
+		));
+
+		// Assert
+		let window = CircuitBreaker::rolling_window_trade_volume_per_asset(DOT).unwrap();
+		assert_eq!(window.limit, 600_000);
+		assert_eq!(window.last_bucket, 20);
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_be_recalculated_from_current_reserve() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+		assert_ok!(buy(DOT, 200_000));
+
+		// Act
+		System::set_block_number(2);
+		let reserve = INITIAL_LIQUIDITY - 200_000;
+
+		// Assert
+		// 30% of the current reserve is 240_000, so the tracked outflow can't reach 300_000 anymore
+		assert_noop!(
+			CircuitBreaker::ensure_and_update_rolling_window_limit(DOT, reserve, 0, 50_000),
+			Error::<Test>::RollingWindowOutflowLimitReached
+		);
+		assert_ok!(CircuitBreaker::ensure_and_update_rolling_window_limit(
+			DOT, reserve, 0, 40_000
+		));
+		let window = CircuitBreaker::rolling_window_trade_volume_per_asset(DOT).unwrap();
+		assert_eq!(window.limit, 240_000);
+	});
+}
+
+#[test]
+fn set_rolling_window_trade_volume_limit_should_reset_tracked_volume() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+		assert_ok!(buy(DOT, 300_000));
+
+		// Act
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		// Assert
+		assert!(CircuitBreaker::rolling_window_trade_volume_per_asset(DOT).is_none());
+		assert_ok!(buy(DOT, 300_000));
+	});
+}
+
+#[test]
+fn rolling_window_limit_should_not_be_tracked_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(buy(LRNA, INITIAL_LIQUIDITY));
+		assert!(CircuitBreaker::rolling_window_trade_volume_per_asset(LRNA).is_none());
+	});
+}
+
+#[test]
+fn ensure_pool_state_change_limit_should_check_per_block_and_rolling_window_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_rolling_window_limit(DOT, Some(THIRTY_PERCENT));
+
+		// per block limit (20%) is reached first
+		assert_noop!(
+			CircuitBreaker::ensure_pool_state_change_limit(HDX, INITIAL_LIQUIDITY, 0, DOT, INITIAL_LIQUIDITY, 250_000),
+			Error::<Test>::TokenOutflowLimitReached
+		);
+
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			HDX,
+			INITIAL_LIQUIDITY,
+			0,
+			DOT,
+			INITIAL_LIQUIDITY,
+			200_000
+		));
+		CircuitBreaker::on_finalize(1);
+		System::set_block_number(2);
+
+		// Act & Assert
+		// rolling window limit (30%) is reached over two blocks
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			HDX,
+			INITIAL_LIQUIDITY,
+			0,
+			DOT,
+			INITIAL_LIQUIDITY,
+			100_000
+		));
+		assert_eq!(
+			CircuitBreaker::ensure_pool_state_change_limit(HDX, INITIAL_LIQUIDITY, 0, DOT, INITIAL_LIQUIDITY, 1),
+			Err(Error::<Test>::RollingWindowOutflowLimitReached.into())
+		);
+	});
+}
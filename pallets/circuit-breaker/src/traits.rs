@@ -0,0 +1,104 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::MaxEncodedLen;
+use frame_support::weights::Weight;
+use frame_support::Parameter;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::vec::Vec;
+
+/// Handler used to contain an exploit in progress when emergency mode is triggered for an asset.
+pub trait EmergencyHandler<AssetId> {
+	/// State of an asset saved when the asset is frozen.
+	type AssetState: Parameter + MaxEncodedLen;
+
+	/// Stop all trading and liquidity operations of the asset.
+	///
+	/// Returns the state of the asset before it was frozen, or `None` if there was nothing to freeze.
+	fn freeze_asset(asset_id: AssetId) -> Result<Option<Self::AssetState>, DispatchError>;
+
+	/// Restore the state the asset had before it was frozen.
+	fn unfreeze_asset(asset_id: AssetId, previous_state: Self::AssetState) -> DispatchResult;
+
+	/// Pause a call which breached the limits of the asset.
+	///
+	/// Returns `false` if the call was already paused, so it is not unpaused when the emergency mode is lifted.
+	fn pause_call(pallet_name: &[u8], function_name: &[u8]) -> Result<bool, DispatchError>;
+
+	/// Unpause a call paused by `pause_call`.
+	fn unpause_call(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult;
+
+	/// Weight of `freeze_asset`.
+	fn freeze_asset_weight() -> Weight;
+
+	/// Weight of `unfreeze_asset`.
+	fn unfreeze_asset_weight() -> Weight;
+
+	/// Weight of `pause_call`.
+	fn pause_call_weight() -> Weight;
+
+	/// Weight of `unpause_call`.
+	fn unpause_call_weight() -> Weight;
+}
+
+impl<AssetId> EmergencyHandler<AssetId> for () {
+	type AssetState = ();
+
+	fn freeze_asset(_asset_id: AssetId) -> Result<Option<()>, DispatchError> {
+		Ok(None)
+	}
+
+	fn unfreeze_asset(_asset_id: AssetId, _previous_state: ()) -> DispatchResult {
+		Ok(())
+	}
+
+	fn pause_call(_pallet_name: &[u8], _function_name: &[u8]) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn unpause_call(_pallet_name: &[u8], _function_name: &[u8]) -> DispatchResult {
+		Ok(())
+	}
+
+	fn freeze_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn unfreeze_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn pause_call_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn unpause_call_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Provides assets affected by a call, so limit breaches can be attributed to assets.
+pub trait AffectedAssets<Call, AssetId> {
+	/// Returns assets going into pools and assets going out of pools in the call.
+	fn affected_assets(call: &Call) -> (Vec<AssetId>, Vec<AssetId>);
+}
+
+impl<Call, AssetId> AffectedAssets<Call, AssetId> for () {
+	fn affected_assets(_call: &Call) -> (Vec<AssetId>, Vec<AssetId>) {
+		(Vec::new(), Vec::new())
+	}
+}
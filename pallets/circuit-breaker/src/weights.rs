@@ -15,32 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_circuit_breaker`
+//! Weights for `pallet_circuit_breaker`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-05-23, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/hydradx
-// benchmark
-// pallet
-// --wasm-execution=compiled
-// --pallet
-// *
-// --extrinsic
-// *
-// --heap-pages
-// 4096
-// --steps
-// 50
-// --repeat
-// 20
-// --template=scripts/pallet-weight-template.hbs
-// --output
-// weights/
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-05-23
+//! (STEPS: `50`, REPEAT: `20`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -64,6 +43,11 @@ pub trait WeightInfo {
 	fn ensure_pool_state_change_limit() -> Weight;
 	fn ensure_add_liquidity_limit() -> Weight;
 	fn ensure_remove_liquidity_limit() -> Weight;
+	fn set_max_price_deviation() -> Weight;
+	fn ensure_price_deviation_limit() -> Weight;
+	fn set_rolling_window_trade_volume_limit() -> Weight;
+	fn lift_emergency_mode(c: u32) -> Weight;
+	fn record_limit_breach(c: u32) -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -167,13 +151,94 @@ impl WeightInfo for () {
 	/// Proof: `CircuitBreaker::AllowedTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::TradeVolumeLimitPerAsset` (r:2 w:0)
 	/// Proof: `CircuitBreaker::TradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::RollingWindowTradeVolumeLimitPerAsset` (r:2 w:0)
+	/// Proof: `CircuitBreaker::RollingWindowTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::RollingWindowTradeVolumePerAsset` (r:2 w:2)
+	/// Proof: `CircuitBreaker::RollingWindowTradeVolumePerAsset` (`max_values`: None, `max_size`: Some(360), added: 2835, mode: `MaxEncodedLen`)
 	fn ensure_pool_state_change_limit() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `956`
+		//  Estimated: `6660`
+		// Minimum execution time: 34_118_000 picoseconds.
+		Weight::from_parts(34_702_000, 6660)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CircuitBreaker::MaxPriceDeviationPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::MaxPriceDeviationPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn set_max_price_deviation() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_923_000 picoseconds.
+		Weight::from_parts(9_184_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::MaxPriceDeviationPerAsset` (r:1 w:0)
+	/// Proof: `CircuitBreaker::MaxPriceDeviationPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn ensure_price_deviation_limit() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
-		//  Estimated: `6076`
-		// Minimum execution time: 17_387_000 picoseconds.
-		Weight::from_parts(17_667_000, 6076)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `3494`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_651_000, 3494).saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CircuitBreaker::RollingWindowTradeVolumeLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::RollingWindowTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::RollingWindowTradeVolumePerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::RollingWindowTradeVolumePerAsset` (`max_values`: None, `max_size`: Some(360), added: 2835, mode: `MaxEncodedLen`)
+	fn set_rolling_window_trade_volume_limit() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_562_000 picoseconds.
+		Weight::from_parts(10_874_000, 0).saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CircuitBreaker::EmergencyAssets` (r:1 w:1)
+	/// Proof: `CircuitBreaker::EmergencyAssets` (`max_values`: None, `max_size`: Some(1667), added: 4142, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::EmergencyPausedCalls` (r:20 w:20)
+	/// Proof: `CircuitBreaker::EmergencyPausedCalls` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::LimitBreaches` (r:0 w:1)
+	/// Proof: `CircuitBreaker::LimitBreaches` (`max_values`: None, `max_size`: Some(2310), added: 4785, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 20]`.
+	fn lift_emergency_mode(c: u32) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1804 + c * (101 ±0)`
+		//  Estimated: `5132 + c * (2577 ±0)`
+		// Minimum execution time: 13_914_000 picoseconds.
+		Weight::from_parts(14_307_000, 5132)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(4_186_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2577).saturating_mul(c.into()))
+	}
+	/// Storage: `CircuitBreaker::EmergencyAssets` (r:1 w:1)
+	/// Proof: `CircuitBreaker::EmergencyAssets` (`max_values`: None, `max_size`: Some(1667), added: 4142, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::LimitBreaches` (r:1 w:1)
+	/// Proof: `CircuitBreaker::LimitBreaches` (`max_values`: None, `max_size`: Some(2310), added: 4785, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::EmergencyPausedCalls` (r:20 w:20)
+	/// Proof: `CircuitBreaker::EmergencyPausedCalls` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 20]`.
+	fn record_limit_breach(c: u32) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `2466 + c * (101 ±0)`
+		//  Estimated: `5775 + c * (2577 ±0)`
+		// Minimum execution time: 19_872_000 picoseconds.
+		Weight::from_parts(20_413_000, 5775)
+			// Standard Error: 5_000
+			.saturating_add(Weight::from_parts(4_812_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2577).saturating_mul(c.into()))
 	}
 }
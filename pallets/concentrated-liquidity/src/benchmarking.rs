@@ -0,0 +1,310 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ASSET_0: u32 = 1_000;
+const ASSET_1: u32 = 2_000;
+
+const TICK_SPACING: Tick = 10;
+
+const INITIAL_LIQUIDITY: Balance = 1_000_000_000_000_000_000;
+const TRADE_AMOUNT: Balance = 10_000_000_000_000;
+
+/// Initial price of the pool - slightly below 1, so the current tick (-6) is not initialized
+/// and all ticks down to -1280 are tracked by the same word of tick bitmap.
+fn initial_price() -> FixedU128 {
+	FixedU128::from_rational(9_995, 10_000)
+}
+
+fn fee() -> Permill {
+	Permill::from_parts(3_000)
+}
+
+fn register_assets<T: Config>() -> Result<(T::AssetId, T::AssetId), DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	T::BenchmarkHelper::register_asset(ASSET_0.into(), 12)?;
+	T::BenchmarkHelper::register_asset(ASSET_1.into(), 12)?;
+
+	Ok((ASSET_0.into(), ASSET_1.into()))
+}
+
+/// Funds `who` with given amounts and adds liquidity in range `[tick_lower, tick_upper]`.
+fn add_position<T: Config>(
+	who: &T::AccountId,
+	tick_lower: Tick,
+	tick_upper: Tick,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> Result<T::PositionItemId, DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	T::Currency::update_balance(ASSET_0.into(), who, amount_0 as i128)?;
+	T::Currency::update_balance(ASSET_1.into(), who, amount_1 as i128)?;
+
+	let position_id = NextPositionId::<T>::get();
+	Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(who.clone()).into(),
+		ASSET_0.into(),
+		ASSET_1.into(),
+		tick_lower,
+		tick_upper,
+		amount_0,
+		amount_1,
+	)?;
+
+	Ok(position_id)
+}
+
+/// Creates a pool with liquidity provided in the full price range.
+fn create_pool_with_liquidity<T: Config>() -> Result<(T::AssetId, T::AssetId), DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let (asset_0, asset_1) = register_assets::<T>()?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::create_pool(
+		successful_origin,
+		asset_0,
+		asset_1,
+		fee(),
+		TICK_SPACING,
+		initial_price(),
+	)?;
+
+	let provider: T::AccountId = account("provider", 0, 1);
+	add_position::<T>(
+		&provider,
+		MIN_TICK / TICK_SPACING * TICK_SPACING,
+		MAX_TICK / TICK_SPACING * TICK_SPACING,
+		INITIAL_LIQUIDITY,
+		INITIAL_LIQUIDITY,
+	)?;
+
+	Ok((asset_0, asset_1))
+}
+
+/// Sells both pool assets, so the fees are accrued by positions in range in both assets.
+fn generate_fees<T: Config>(asset_0: T::AssetId, asset_1: T::AssetId) -> DispatchResult
+where
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let trader: T::AccountId = account("trader", 0, 1);
+	for (asset_in, asset_out) in [(asset_0, asset_1), (asset_1, asset_0)] {
+		T::Currency::update_balance(asset_in, &trader, TRADE_AMOUNT as i128)?;
+		Pallet::<T>::sell(
+			RawOrigin::Signed(trader.clone()).into(),
+			asset_in,
+			asset_out,
+			TRADE_AMOUNT,
+			0,
+		)?;
+	}
+	Ok(())
+}
+
+benchmarks! {
+	where_clause { where T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+
+	create_pool {
+		let (asset_0, asset_1) = register_assets::<T>()?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, asset_0, asset_1, fee(), TICK_SPACING, initial_price())
+	verify {
+		assert!(Pools::<T>::get((asset_0, asset_1)).is_some());
+	}
+
+	update_pool_fee {
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, asset_0, asset_1, Permill::from_percent(1))
+	verify {
+		assert_eq!(Pools::<T>::get((asset_0, asset_1)).unwrap().fee, Permill::from_percent(1));
+	}
+
+	add_liquidity {
+		// Worst case is adding liquidity to a pool with active liquidity, initializing both ticks
+		// in tick bitmap words which are not used yet.
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let lp_provider: T::AccountId = account("provider", 1, 1);
+		T::Currency::update_balance(asset_0, &lp_provider, TRADE_AMOUNT as i128)?;
+		T::Currency::update_balance(asset_1, &lp_provider, TRADE_AMOUNT as i128)?;
+		let position_id = NextPositionId::<T>::get();
+	}: _(RawOrigin::Signed(lp_provider.clone()), asset_0, asset_1, -100, 100, TRADE_AMOUNT, TRADE_AMOUNT)
+	verify {
+		assert!(Positions::<T>::contains_key(position_id));
+	}
+
+	remove_liquidity {
+		// Worst case is removing all liquidity of a position with accrued fees - ticks are cleared
+		// and the NFT instance is burned.
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let lp_provider: T::AccountId = account("provider", 1, 1);
+		let position_id = add_position::<T>(&lp_provider, -100, 100, TRADE_AMOUNT, TRADE_AMOUNT)?;
+		generate_fees::<T>(asset_0, asset_1)?;
+
+		let liquidity = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?.liquidity;
+	}: _(RawOrigin::Signed(lp_provider.clone()), position_id, liquidity)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_none());
+	}
+
+	collect_fees {
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let lp_provider: T::AccountId = account("provider", 1, 1);
+		let position_id = add_position::<T>(&lp_provider, -100, 100, TRADE_AMOUNT, TRADE_AMOUNT)?;
+		generate_fees::<T>(asset_0, asset_1)?;
+
+		let balance_0 = T::Currency::free_balance(asset_0, &lp_provider);
+		let balance_1 = T::Currency::free_balance(asset_1, &lp_provider);
+	}: _(RawOrigin::Signed(lp_provider.clone()), position_id)
+	verify {
+		assert!(T::Currency::free_balance(asset_0, &lp_provider) > balance_0);
+		assert!(T::Currency::free_balance(asset_1, &lp_provider) > balance_1);
+	}
+
+	sell {
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(asset_0, &seller, TRADE_AMOUNT as i128)?;
+		let buy_min_amount = 1_000u128;
+	}: _(RawOrigin::Signed(seller.clone()), asset_0, asset_1, TRADE_AMOUNT, buy_min_amount)
+	verify {
+		assert!(T::Currency::free_balance(asset_0, &seller).is_zero());
+		assert!(T::Currency::free_balance(asset_1, &seller) >= buy_min_amount);
+	}
+
+	buy {
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(asset_0, &buyer, (2 * TRADE_AMOUNT) as i128)?;
+	}: _(RawOrigin::Signed(buyer.clone()), asset_1, asset_0, TRADE_AMOUNT, 2 * TRADE_AMOUNT)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_1, &buyer), TRADE_AMOUNT);
+	}
+
+	swap_step {
+		// Number of initialized ticks crossed by the trade. Trade needs one more step to swap
+		// the remaining amount after the last crossed tick.
+		let s in 0..(T::MaxSwapSteps::get() - 1);
+
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+		let pool_key = Pallet::<T>::pool_key(asset_0, asset_1);
+
+		// Positions in ranges [-10 * k, 10] initialize ticks -10, -20, ... below the current price.
+		// Liquidity of each position is small compared to the full range liquidity, so selling amount
+		// which moves the active liquidity to the tick below the last one crosses all of them.
+		let lp_provider: T::AccountId = account("provider", 1, 1);
+		let pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+		let position_liquidity = pool.liquidity / 1_000;
+		for k in 1..=s {
+			let tick_lower = -(k as Tick) * TICK_SPACING;
+			let (amount_0, amount_1) = cl_math::amounts_for_liquidity(
+				pool.sqrt_price,
+				Pallet::<T>::sqrt_price_at_tick(tick_lower)?,
+				Pallet::<T>::sqrt_price_at_tick(TICK_SPACING)?,
+				position_liquidity,
+				true,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+			add_position::<T>(&lp_provider, tick_lower, TICK_SPACING, amount_0, amount_1)?;
+		}
+
+		let pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+		let amount_in = cl_math::amount_0_delta(
+			Pallet::<T>::sqrt_price_at_tick(-(s as Tick + 1) * TICK_SPACING)?,
+			pool.sqrt_price,
+			pool.liquidity,
+			true,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(asset_0, &seller, amount_in as i128)?;
+	}: sell(RawOrigin::Signed(seller.clone()), asset_0, asset_1, amount_in, 0)
+	verify {
+		assert!(Pools::<T>::get(pool_key).unwrap().tick < -(s as Tick) * TICK_SPACING);
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// number of times calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(asset_0, &seller, TRADE_AMOUNT as i128)?;
+		let buy_min_amount = 1_000u128;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::Concentrated, asset_0, asset_1, TRADE_AMOUNT).is_ok());
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::Concentrated, asset_0, asset_1, TRADE_AMOUNT, buy_min_amount).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert!(T::Currency::free_balance(asset_0, &seller).is_zero());
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..3;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(asset_0, &buyer, (2 * TRADE_AMOUNT) as i128)?;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Concentrated, asset_0, asset_1, TRADE_AMOUNT).is_ok());
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::Concentrated, asset_0, asset_1, TRADE_AMOUNT, 2 * TRADE_AMOUNT).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(asset_1, &buyer), TRADE_AMOUNT);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let (asset_0, asset_1) = create_pool_with_liquidity::<T>()?;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Concentrated, asset_0, asset_1).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1273 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! Uniswap V3 style AMM where liquidity is provided in price ranges.
+//!
+//! ## Overview
+//!
+//! Each pool holds a pair of assets. Price axis of the pool is divided into ticks, where price at tick `i`
+//! is `1.0001^i`. LP provides liquidity in a range between two ticks, which are multiples of the pool's tick spacing.
+//! Within the range, the position behaves like a constant product pool with liquidity `L`. When the price leaves
+//! the range, the position is fully converted to one of the assets and does not participate in trades.
+//!
+//! Each position is represented by a NFT minted by `NFTHandler`. Position can be only removed by the owner
+//! of the NFT.
+//!
+//! Trade fee is paid in asset in and distributed to the liquidity active at the time of the trade. Fees are
+//! tracked per unit of liquidity by fee growth accumulators - globally and outside of each initialized tick.
+//! Fees accrued by a position are paid out when liquidity is removed or by `collect_fees`.
+//!
+//! All math is implemented in `hydra_dx_math::concentrated_liquidity`.
+//!
+//! ### Oracle
+//!
+//! Pool feeds the EMA oracle via `AMMHandler` with virtual reserves of the liquidity active at the current price.
+//!
+//! ### Terminology
+//!
+//! * **LP** - liquidity provider
+//! * **Tick** - point on the price axis, price at tick `i` is `1.0001^i`
+//! * **Tick Spacing** - only ticks which are multiples of tick spacing can be used as position bounds
+//! * **Liquidity** - `sqrt(x * y)` of virtual reserves of a position in its range
+//!
+//! ## Assumptions
+//!
+//! A pool can be created only by allowed `AuthorityOrigin`.
+//!
+//! There is at most one pool of each asset pair. Assets of a pool are ordered - `asset_0 < asset_1`,
+//! and pool price is the price of asset 0 denominated in asset 1.
+//!
+//! Number of initialized ticks crossed in a single trade is bounded by `MaxSwapSteps`.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::{ensure, require_transactional, transactional, PalletId};
+use hydra_dx_math::concentrated_liquidity as cl_math;
+use hydra_dx_math::concentrated_liquidity::{MAX_SQRT_PRICE, MAX_TICK, MIN_SQRT_PRICE, MIN_TICK};
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{
+	registry::Inspect as RegistryInspect, AssetPairAccountIdFor, OnLiquidityChangedHandler, OnTradeHandler, Source,
+};
+use orml_traits::MultiCurrency;
+pub use pallet::*;
+use sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedDiv, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedU128, Permill};
+use sp_std::prelude::*;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{Balance, Liquidity, PoolInfo, Position, SqrtPrice, SwapResult, Tick, TickInfo};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// Concentrated liquidity pool account id identifier.
+/// Used as identifier to create pool account id.
+pub const POOL_IDENTIFIER: &str = "clp";
+
+/// Maximum tick spacing of a pool.
+pub const MAX_TICK_SPACING: Tick = 16_384;
+
+/// NFT class id type of provided nft implementation
+pub type NFTCollectionIdOf<T> =
+	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+
+/// Pool identifier - ordered pair of pool assets.
+pub type PoolKeyOf<T> = (<T as Config>::AssetId, <T as Config>::AssetId);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::AtLeast32BitUnsigned;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Asset registry mechanism to check if asset is registered.
+		type AssetInspection: RegistryInspect<AssetId = Self::AssetId>;
+
+		/// The origin which can create a new pool and update its fee.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// AMM handler executed on trades and liquidity changes. Used to feed the oracle.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Account ID constructor - pool accounts are derived from pool assets
+		type AssetPairAccountId: AssetPairAccountIdFor<Self::AssetId, Self::AccountId>;
+
+		/// Pallet id - used to derive the owner of the position NFT collection.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Position identifier type
+		type PositionItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Collection id type
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible class id
+		#[pallet::constant]
+		type NFTCollectionId: Get<NFTCollectionIdOf<Self>>;
+
+		/// Non fungible handling - mint,burn, check owner
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Maximum number of swap steps in a single trade. Each step crosses at most one initialized tick
+		/// or one word of tick bitmap.
+		#[pallet::constant]
+		type MaxSwapSteps: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Existing pools
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, PoolKeyOf<T>, PoolInfo>;
+
+	/// Initialized ticks of a pool
+	#[pallet::storage]
+	#[pallet::getter(fn ticks)]
+	pub type Ticks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKeyOf<T>, Twox64Concat, Tick, TickInfo, ValueQuery>;
+
+	/// Bitmap of initialized ticks of a pool. Each word tracks `TICKS_PER_WORD` compressed ticks.
+	#[pallet::storage]
+	#[pallet::getter(fn tick_bitmap)]
+	pub type TickBitmap<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolKeyOf<T>, Twox64Concat, i32, u128, ValueQuery>;
+
+	/// LP positions. Maps NFT instance id to corresponding position
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Position<T::AssetId>>;
+
+	#[pallet::storage]
+	/// Position ids sequencer
+	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			fee: Permill,
+			tick_spacing: Tick,
+			sqrt_price: SqrtPrice,
+			tick: Tick,
+		},
+		/// Pool fee has been updated.
+		FeeUpdated {
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			fee: Permill,
+		},
+		/// Liquidity was added to a pool and position NFT instance minted.
+		LiquidityAdded {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Liquidity was removed from a position.
+		LiquidityRemoved {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Fees accrued by a position were paid out to the owner.
+		FeesCollected {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// All liquidity was removed from a position. Position was destroyed and NFT instance burned.
+		PositionDestroyed {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+		},
+		/// Sell trade executed. Trade fee paid in asset entering the pool (already included in amount_in).
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+		/// Buy trade executed. Trade fee paid in asset entering the pool (already included in amount_in).
+		BuyExecuted {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool or trading with the same asset is not allowed.
+		IncorrectAssets,
+
+		/// One or more assets are not registered in AssetRegistry
+		AssetNotRegistered,
+
+		/// A pool with given assets already exists.
+		PoolExists,
+
+		/// A pool with given assets does not exist.
+		PoolNotFound,
+
+		/// Pool fee must be less than 100%.
+		InvalidFee,
+
+		/// Tick spacing must be between 1 and `MAX_TICK_SPACING`.
+		InvalidTickSpacing,
+
+		/// Initial price is zero or out of the supported price range.
+		InvalidInitialPrice,
+
+		/// Lower tick must be less than upper tick, both within the tick bounds and multiples of tick spacing.
+		InvalidTickRange,
+
+		/// Liquidity amount is zero or exceeds the liquidity of the position.
+		InvalidLiquidityAmount,
+
+		/// Liquidity referencing a single tick would exceed the maximum.
+		MaxLiquidityPerTickExceeded,
+
+		/// Position does not exist.
+		PositionNotFound,
+
+		/// Signer is not an owner of the position.
+		Forbidden,
+
+		/// Balance of an asset is not sufficient to perform the operation.
+		InsufficientBalance,
+
+		/// Amount is less than the minimum trading amount configured.
+		InsufficientTradingAmount,
+
+		/// Pool does not have enough liquidity to fill the trade.
+		InsufficientLiquidity,
+
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+
+		/// Trade would take more than `MaxSwapSteps` swap steps.
+		MaxSwapStepsExceeded,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a pool of given asset pair with initial price.
+		///
+		/// Both assets must be correctly registered in `T::AssetRegistry`.
+		/// Note that this does not seed the pool with liquidity. Use `add_liquidity` to provide liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `asset_a`: First asset of the pair
+		/// - `asset_b`: Second asset of the pair
+		/// - `fee`: fee to be applied on trades
+		/// - `tick_spacing`: only ticks which are multiples of tick spacing can be used as position bounds
+		/// - `initial_price`: price of `asset_a` denominated in `asset_b`
+		///
+		/// Emits `PoolCreated` event if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			fee: Permill,
+			tick_spacing: Tick,
+			initial_price: FixedU128,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::IncorrectAssets);
+			ensure!(T::AssetInspection::exists(asset_a), Error::<T>::AssetNotRegistered);
+			ensure!(T::AssetInspection::exists(asset_b), Error::<T>::AssetNotRegistered);
+
+			let pool_key = Self::pool_key(asset_a, asset_b);
+			ensure!(!Pools::<T>::contains_key(pool_key), Error::<T>::PoolExists);
+
+			ensure!(fee < Permill::one(), Error::<T>::InvalidFee);
+			ensure!(
+				(1..=MAX_TICK_SPACING).contains(&tick_spacing),
+				Error::<T>::InvalidTickSpacing
+			);
+
+			ensure!(!initial_price.is_zero(), Error::<T>::InvalidInitialPrice);
+			let price = if asset_a == pool_key.0 {
+				initial_price
+			} else {
+				FixedU128::one()
+					.checked_div(&initial_price)
+					.ok_or(Error::<T>::InvalidInitialPrice)?
+			};
+			let sqrt_price = cl_math::sqrt_price_from_price(price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+			ensure!(
+				sqrt_price > MIN_SQRT_PRICE && sqrt_price < MAX_SQRT_PRICE,
+				Error::<T>::InvalidInitialPrice
+			);
+			let tick = cl_math::tick_at_sqrt_price(sqrt_price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+
+			Pools::<T>::insert(
+				pool_key,
+				PoolInfo {
+					fee,
+					tick_spacing,
+					sqrt_price,
+					tick,
+					liquidity: 0,
+					fee_growth_global_0: 0,
+					fee_growth_global_1: 0,
+				},
+			);
+			T::DustAccountHandler::add_account(&Self::pool_account(pool_key))?;
+
+			// ensure collection is created, we can simply ignore the error if it was already created.
+			let _ = T::NFTHandler::create_collection(
+				&T::NFTCollectionId::get(),
+				&Self::pallet_account(),
+				&Self::pallet_account(),
+			);
+
+			Self::deposit_event(Event::PoolCreated {
+				asset_0: pool_key.0,
+				asset_1: pool_key.1,
+				fee,
+				tick_spacing,
+				sqrt_price,
+				tick,
+			});
+
+			Ok(())
+		}
+
+		/// Update pool's fee.
+		///
+		/// if pool does not exist, `PoolNotFound` is returned.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `asset_a`: First asset of the pool
+		/// - `asset_b`: Second asset of the pool
+		/// - `fee`: new pool fee
+		///
+		/// Emits `FeeUpdated` event if successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		#[transactional]
+		pub fn update_pool_fee(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			fee: Permill,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(fee < Permill::one(), Error::<T>::InvalidFee);
+
+			let pool_key = Self::pool_key(asset_a, asset_b);
+			Pools::<T>::try_mutate(pool_key, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				pool.fee = fee;
+				Self::deposit_event(Event::FeeUpdated {
+					asset_0: pool_key.0,
+					asset_1: pool_key.1,
+					fee,
+				});
+				Ok(())
+			})
+		}
+
+		/// Add liquidity to selected pool in price range between `tick_lower` and `tick_upper`.
+		///
+		/// Maximum liquidity which can be provided by given amounts at the current price is added.
+		/// If the current price is below the range, only `asset_0` is added. If the current price is above
+		/// the range, only `asset_1` is added.
+		///
+		/// LP must have sufficient amount of both assets.
+		///
+		/// Position NFT instance is minted for the origin.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `asset_a`: First asset of the pool
+		/// - `asset_b`: Second asset of the pool
+		/// - `tick_lower`: lower bound of the price range
+		/// - `tick_upper`: upper bound of the price range
+		/// - `amount_a`: maximum amount of `asset_a` to add
+		/// - `amount_b`: maximum amount of `asset_b` to add
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+							.saturating_add(Pallet::<T>::on_liquidity_changed_weight()))]
+		#[transactional]
+		#[allow(clippy::too_many_arguments)]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			amount_a: Balance,
+			amount_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::IncorrectAssets);
+			let pool_key = Self::pool_key(asset_a, asset_b);
+			let mut pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+
+			Self::ensure_valid_tick_range(&pool, tick_lower, tick_upper)?;
+
+			let (amount_0, amount_1) = if asset_a == pool_key.0 {
+				(amount_a, amount_b)
+			} else {
+				(amount_b, amount_a)
+			};
+
+			let liquidity = cl_math::liquidity_for_amounts(
+				pool.sqrt_price,
+				Self::sqrt_price_at_tick(tick_lower)?,
+				Self::sqrt_price_at_tick(tick_upper)?,
+				amount_0,
+				amount_1,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+			ensure!(!liquidity.is_zero(), Error::<T>::InvalidLiquidityAmount);
+
+			let mut position = Position::new(pool_key, tick_lower, tick_upper);
+			let (added_0, added_1) = Self::modify_position(
+				pool_key,
+				&mut pool,
+				&mut position,
+				Self::liquidity_delta(liquidity, true)?,
+			)?;
+
+			let pool_account = Self::pool_account(pool_key);
+			for (asset, amount) in [(pool_key.0, added_0), (pool_key.1, added_1)] {
+				ensure!(
+					T::Currency::free_balance(asset, &who) >= amount,
+					Error::<T>::InsufficientBalance
+				);
+				T::Currency::transfer(asset, &who, &pool_account, amount)?;
+			}
+
+			let position_id = Self::create_and_mint_position_instance(&who)?;
+			Positions::<T>::insert(position_id, position);
+			Pools::<T>::insert(pool_key, pool.clone());
+
+			Self::call_on_liquidity_change_hook(pool_key, &pool, added_0, added_1)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				position_id,
+				asset_0: pool_key.0,
+				asset_1: pool_key.1,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_0: added_0,
+				amount_1: added_1,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a position.
+		///
+		/// LP receives amounts of both assets corresponding to the liquidity at the current price,
+		/// together with all fees accrued by the position.
+		///
+		/// If all liquidity is removed, position is destroyed and NFT instance is burned.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: position NFT instance id
+		/// - `liquidity`: amount of liquidity to remove
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		/// Emits `FeesCollected` event if position accrued any fees.
+		/// Emits `PositionDestroyed` event if all liquidity was removed.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(Pallet::<T>::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::load_position(&who, position_id)?;
+			ensure!(
+				!liquidity.is_zero() && liquidity <= position.liquidity,
+				Error::<T>::InvalidLiquidityAmount
+			);
+
+			let pool_key = position.pool();
+			let mut pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+
+			let (amount_0, amount_1) = Self::modify_position(
+				pool_key,
+				&mut pool,
+				&mut position,
+				Self::liquidity_delta(liquidity, false)?,
+			)?;
+
+			let pool_account = Self::pool_account(pool_key);
+			T::Currency::transfer(pool_key.0, &pool_account, &who, amount_0)?;
+			T::Currency::transfer(pool_key.1, &pool_account, &who, amount_1)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who: who.clone(),
+				position_id,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			Self::pay_fees(&who, position_id, &mut position)?;
+
+			if position.liquidity.is_zero() {
+				// All liquidity removed, remove position and burn NFT instance
+				Positions::<T>::remove(position_id);
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id,
+					owner: who,
+				});
+			} else {
+				Positions::<T>::insert(position_id, position);
+			}
+
+			Pools::<T>::insert(pool_key, pool.clone());
+
+			Self::call_on_liquidity_change_hook(pool_key, &pool, amount_0, amount_1)?;
+
+			Ok(())
+		}
+
+		/// Pay out fees accrued by a position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: position NFT instance id
+		///
+		/// Emits `FeesCollected` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		#[transactional]
+		pub fn collect_fees(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::load_position(&who, position_id)?;
+
+			let pool_key = position.pool();
+			let mut pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+
+			// Zero liquidity delta only accrues fees of the position
+			Self::modify_position(pool_key, &mut pool, &mut position, 0)?;
+
+			Self::pay_fees(&who, position_id, &mut position)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to put in.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset to be sold to the pool
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+							.saturating_add(Pallet::<T>::max_swap_steps_weight())
+							.saturating_add(Pallet::<T>::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let (pool_key, mut pool, result) = Self::calculate_swap(asset_in, asset_out, amount_in, true)?;
+			ensure!(result.amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			Self::execute_swap(&who, pool_key, &mut pool, asset_in, asset_out, &result)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to get out.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: Amount of asset to receive from the pool
+		/// - `max_sell_amount`: Maximum amount allowed to be sold
+		///
+		/// Emits `BuyExecuted` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+							.saturating_add(Pallet::<T>::max_swap_steps_weight())
+							.saturating_add(Pallet::<T>::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool_key, mut pool, result) = Self::calculate_swap(asset_in, asset_out, amount_out, false)?;
+			ensure!(result.amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= result.amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			Self::execute_swap(&who, pool_key, &mut pool, asset_in, asset_out, &result)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Pool identifier of an asset pair - assets ordered ascending.
+	pub fn pool_key(asset_a: T::AssetId, asset_b: T::AssetId) -> PoolKeyOf<T> {
+		if asset_a < asset_b {
+			(asset_a, asset_b)
+		} else {
+			(asset_b, asset_a)
+		}
+	}
+
+	#[inline]
+	pub fn pool_account(pool_key: PoolKeyOf<T>) -> T::AccountId {
+		T::AssetPairAccountId::from_assets(pool_key.0, pool_key.1, POOL_IDENTIFIER)
+	}
+
+	/// Account which owns the position NFT collection.
+	#[inline]
+	pub fn pallet_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Virtual reserves of both pool assets of the liquidity active at the current price.
+	pub fn virtual_reserves(pool: &PoolInfo) -> Result<(Balance, Balance), DispatchError> {
+		Ok(cl_math::virtual_reserves(pool.sqrt_price, pool.liquidity).map_err(|_| ArithmeticError::Overflow)?)
+	}
+
+	/// Amounts of both pool assets of a position at the current price, without accrued fees.
+	pub fn position_amounts(position: &Position<T::AssetId>) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(position.pool()).ok_or(Error::<T>::PoolNotFound)?;
+		Ok(cl_math::amounts_for_liquidity(
+			pool.sqrt_price,
+			Self::sqrt_price_at_tick(position.tick_lower)?,
+			Self::sqrt_price_at_tick(position.tick_upper)?,
+			position.liquidity,
+			false,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?)
+	}
+
+	fn sqrt_price_at_tick(tick: Tick) -> Result<SqrtPrice, DispatchError> {
+		Ok(cl_math::sqrt_price_at_tick(tick).map_err(|_| ArithmeticError::Overflow)?)
+	}
+
+	fn liquidity_delta(liquidity: Liquidity, add: bool) -> Result<i128, DispatchError> {
+		let delta = i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow)?;
+		Ok(if add { delta } else { -delta })
+	}
+
+	fn ensure_valid_tick_range(pool: &PoolInfo, tick_lower: Tick, tick_upper: Tick) -> DispatchResult {
+		ensure!(
+			tick_lower < tick_upper
+				&& tick_lower >= MIN_TICK
+				&& tick_upper <= MAX_TICK
+				&& tick_lower % pool.tick_spacing == 0
+				&& tick_upper % pool.tick_spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+		Ok(())
+	}
+
+	/// Loads position and ensures that `who` is the owner of the position NFT.
+	fn load_position(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+	) -> Result<Position<T::AssetId>, DispatchError> {
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+			Error::<T>::Forbidden
+		);
+		Ok(Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?)
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// Pays out fees owed to the position and resets them.
+	fn pay_fees(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		position: &mut Position<T::AssetId>,
+	) -> DispatchResult {
+		let (amount_0, amount_1) = (position.fees_owed_0, position.fees_owed_1);
+		if amount_0.is_zero() && amount_1.is_zero() {
+			return Ok(());
+		}
+
+		let pool_account = Self::pool_account(position.pool());
+		T::Currency::transfer(position.asset_0, &pool_account, who, amount_0)?;
+		T::Currency::transfer(position.asset_1, &pool_account, who, amount_1)?;
+
+		position.fees_owed_0 = 0;
+		position.fees_owed_1 = 0;
+
+		Self::deposit_event(Event::FeesCollected {
+			who: who.clone(),
+			position_id,
+			amount_0,
+			amount_1,
+		});
+
+		Ok(())
+	}
+
+	/// Applies liquidity delta to a position, its ticks and the pool.
+	/// Fees accrued by the position since the last update are added to the fees owed.
+	///
+	/// Returns amounts of both assets corresponding to the liquidity delta - rounded up when liquidity
+	/// is added and rounded down when liquidity is removed.
+	#[require_transactional]
+	fn modify_position(
+		pool_key: PoolKeyOf<T>,
+		pool: &mut PoolInfo,
+		position: &mut Position<T::AssetId>,
+		liquidity_delta: i128,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (tick_lower, tick_upper) = (position.tick_lower, position.tick_upper);
+
+		let (mut flipped_lower, mut flipped_upper) = (false, false);
+		if liquidity_delta != 0 {
+			let max_liquidity =
+				cl_math::max_liquidity_per_tick(pool.tick_spacing).map_err(|_| ArithmeticError::Overflow)?;
+			flipped_lower = Self::update_tick(pool_key, pool, tick_lower, liquidity_delta, false, max_liquidity)?;
+			flipped_upper = Self::update_tick(pool_key, pool, tick_upper, liquidity_delta, true, max_liquidity)?;
+		}
+
+		let lower = Ticks::<T>::get(pool_key, tick_lower);
+		let upper = Ticks::<T>::get(pool_key, tick_upper);
+		let fee_growth_inside_0 = cl_math::fee_growth_inside(
+			tick_lower,
+			tick_upper,
+			pool.tick,
+			pool.fee_growth_global_0,
+			lower.fee_growth_outside_0,
+			upper.fee_growth_outside_0,
+		);
+		let fee_growth_inside_1 = cl_math::fee_growth_inside(
+			tick_lower,
+			tick_upper,
+			pool.tick,
+			pool.fee_growth_global_1,
+			lower.fee_growth_outside_1,
+			upper.fee_growth_outside_1,
+		);
+
+		let fees_0 = cl_math::fees_accrued(
+			position.liquidity,
+			fee_growth_inside_0,
+			position.fee_growth_inside_0_last,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+		let fees_1 = cl_math::fees_accrued(
+			position.liquidity,
+			fee_growth_inside_1,
+			position.fee_growth_inside_1_last,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		position.fees_owed_0 = position
+			.fees_owed_0
+			.checked_add(fees_0)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fees_owed_1 = position
+			.fees_owed_1
+			.checked_add(fees_1)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fee_growth_inside_0_last = fee_growth_inside_0;
+		position.fee_growth_inside_1_last = fee_growth_inside_1;
+		position.liquidity = cl_math::add_liquidity_delta(position.liquidity, liquidity_delta)
+			.map_err(|_| Error::<T>::InvalidLiquidityAmount)?;
+
+		for (tick, flipped) in [(tick_lower, flipped_lower), (tick_upper, flipped_upper)] {
+			if flipped {
+				Self::flip_tick(pool_key, tick, pool.tick_spacing);
+				if liquidity_delta < 0 {
+					Ticks::<T>::remove(pool_key, tick);
+				}
+			}
+		}
+
+		if (tick_lower..tick_upper).contains(&pool.tick) {
+			pool.liquidity =
+				cl_math::add_liquidity_delta(pool.liquidity, liquidity_delta).map_err(|_| ArithmeticError::Overflow)?;
+		}
+
+		let amounts = cl_math::amounts_for_liquidity(
+			pool.sqrt_price,
+			Self::sqrt_price_at_tick(tick_lower)?,
+			Self::sqrt_price_at_tick(tick_upper)?,
+			liquidity_delta.unsigned_abs(),
+			liquidity_delta > 0,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		Ok(amounts)
+	}
+
+	/// Applies liquidity delta to a tick. Tick which was not initialized tracks the current fee growth
+	/// as fee growth below the tick if the current tick is at or above the tick.
+	///
+	/// Returns true if the tick was initialized or cleared by the update.
+	fn update_tick(
+		pool_key: PoolKeyOf<T>,
+		pool: &PoolInfo,
+		tick: Tick,
+		liquidity_delta: i128,
+		upper: bool,
+		max_liquidity: Liquidity,
+	) -> Result<bool, DispatchError> {
+		Ticks::<T>::try_mutate(pool_key, tick, |info| -> Result<bool, DispatchError> {
+			let liquidity_gross_before = info.liquidity_gross;
+			let liquidity_gross_after = cl_math::add_liquidity_delta(liquidity_gross_before, liquidity_delta)
+				.map_err(|_| ArithmeticError::Overflow)?;
+			ensure!(
+				liquidity_gross_after <= max_liquidity,
+				Error::<T>::MaxLiquidityPerTickExceeded
+			);
+
+			if liquidity_gross_before.is_zero() && tick <= pool.tick {
+				info.fee_growth_outside_0 = pool.fee_growth_global_0;
+				info.fee_growth_outside_1 = pool.fee_growth_global_1;
+			}
+
+			info.liquidity_gross = liquidity_gross_after;
+			info.liquidity_net = if upper {
+				info.liquidity_net.checked_sub(liquidity_delta)
+			} else {
+				info.liquidity_net.checked_add(liquidity_delta)
+			}
+			.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(liquidity_gross_before.is_zero() != liquidity_gross_after.is_zero())
+		})
+	}
+
+	fn flip_tick(pool_key: PoolKeyOf<T>, tick: Tick, tick_spacing: Tick) {
+		let (word_pos, bit_pos) = cl_math::tick_bitmap_position(cl_math::compress_tick(tick, tick_spacing));
+		let word = TickBitmap::<T>::get(pool_key, word_pos) ^ (1u128 << bit_pos);
+		if word.is_zero() {
+			TickBitmap::<T>::remove(pool_key, word_pos);
+		} else {
+			TickBitmap::<T>::insert(pool_key, word_pos, word);
+		}
+	}
+
+	/// Calculates a trade of `asset_in` for `asset_out` without changing any state.
+	/// `amount` is the amount in when `exact_in` is true, amount out otherwise.
+	pub(crate) fn calculate_swap(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<(PoolKeyOf<T>, PoolInfo, SwapResult), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::IncorrectAssets);
+
+		let pool_key = Self::pool_key(asset_in, asset_out);
+		let pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+
+		let result = Self::compute_swap(pool_key, &pool, asset_in == pool_key.0, amount, exact_in)?;
+
+		Ok((pool_key, pool, result))
+	}
+
+	/// Calculates a swap step by step. Each step moves the price to the next initialized tick, the end of
+	/// current bitmap word or the price at which the remaining amount is swapped.
+	fn compute_swap(
+		pool_key: PoolKeyOf<T>,
+		pool: &PoolInfo,
+		zero_for_one: bool,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<SwapResult, DispatchError> {
+		let sqrt_price_limit = if zero_for_one {
+			MIN_SQRT_PRICE + 1
+		} else {
+			MAX_SQRT_PRICE - 1
+		};
+
+		let mut result = SwapResult {
+			amount_in: 0,
+			amount_out: 0,
+			fee_amount: 0,
+			sqrt_price: pool.sqrt_price,
+			tick: pool.tick,
+			liquidity: pool.liquidity,
+			fee_growth_global: if zero_for_one {
+				pool.fee_growth_global_0
+			} else {
+				pool.fee_growth_global_1
+			},
+			crossed_ticks: Vec::new(),
+		};
+
+		let mut amount_remaining = amount;
+		let mut steps = 0u32;
+		while !amount_remaining.is_zero() && result.sqrt_price != sqrt_price_limit {
+			steps = steps.saturating_add(1);
+			ensure!(steps <= T::MaxSwapSteps::get(), Error::<T>::MaxSwapStepsExceeded);
+
+			let (tick_next, initialized) =
+				cl_math::next_initialized_tick_within_one_word(result.tick, pool.tick_spacing, zero_for_one, |word| {
+					TickBitmap::<T>::get(pool_key, word)
+				});
+			let tick_next = tick_next.clamp(MIN_TICK, MAX_TICK);
+			let sqrt_price_next = Self::sqrt_price_at_tick(tick_next)?;
+
+			let sqrt_price_target = if zero_for_one {
+				sqrt_price_next.max(sqrt_price_limit)
+			} else {
+				sqrt_price_next.min(sqrt_price_limit)
+			};
+
+			let step = cl_math::compute_swap_step(
+				result.sqrt_price,
+				sqrt_price_target,
+				result.liquidity,
+				amount_remaining,
+				pool.fee,
+				exact_in,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			let step_amount_in = step
+				.amount_in
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			amount_remaining = amount_remaining
+				.checked_sub(if exact_in { step_amount_in } else { step.amount_out })
+				.ok_or(ArithmeticError::Overflow)?;
+
+			result.amount_in = result
+				.amount_in
+				.checked_add(step_amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.amount_out = result
+				.amount_out
+				.checked_add(step.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.fee_amount = result
+				.fee_amount
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.fee_growth_global = result
+				.fee_growth_global
+				.wrapping_add(cl_math::fee_growth_delta(step.fee_amount, result.liquidity));
+
+			if step.sqrt_price_next == sqrt_price_next {
+				if initialized {
+					let (fee_growth_0, fee_growth_1) = if zero_for_one {
+						(result.fee_growth_global, pool.fee_growth_global_1)
+					} else {
+						(pool.fee_growth_global_0, result.fee_growth_global)
+					};
+					result.crossed_ticks.push((tick_next, fee_growth_0, fee_growth_1));
+
+					let liquidity_net = Ticks::<T>::get(pool_key, tick_next).liquidity_net;
+					let liquidity_net = if zero_for_one {
+						liquidity_net.checked_neg().ok_or(ArithmeticError::Overflow)?
+					} else {
+						liquidity_net
+					};
+					result.liquidity = cl_math::add_liquidity_delta(result.liquidity, liquidity_net)
+						.map_err(|_| ArithmeticError::Overflow)?;
+				}
+				result.tick = if zero_for_one { tick_next - 1 } else { tick_next };
+			} else if step.sqrt_price_next != result.sqrt_price {
+				result.tick =
+					cl_math::tick_at_sqrt_price(step.sqrt_price_next).map_err(|_| ArithmeticError::Overflow)?;
+			}
+			result.sqrt_price = step.sqrt_price_next;
+		}
+
+		ensure!(amount_remaining.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		Ok(result)
+	}
+
+	/// Transfers assets of a calculated swap, crosses ticks and updates the pool state.
+	#[require_transactional]
+	fn execute_swap(
+		who: &T::AccountId,
+		pool_key: PoolKeyOf<T>,
+		pool: &mut PoolInfo,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		result: &SwapResult,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_key);
+		T::Currency::transfer(asset_in, who, &pool_account, result.amount_in)?;
+		T::Currency::transfer(asset_out, &pool_account, who, result.amount_out)?;
+
+		for (tick, fee_growth_0, fee_growth_1) in result.crossed_ticks.iter() {
+			Ticks::<T>::mutate(pool_key, tick, |info| {
+				info.fee_growth_outside_0 = fee_growth_0.wrapping_sub(info.fee_growth_outside_0);
+				info.fee_growth_outside_1 = fee_growth_1.wrapping_sub(info.fee_growth_outside_1);
+			});
+		}
+
+		pool.sqrt_price = result.sqrt_price;
+		pool.tick = result.tick;
+		pool.liquidity = result.liquidity;
+		if asset_in == pool_key.0 {
+			pool.fee_growth_global_0 = result.fee_growth_global;
+		} else {
+			pool.fee_growth_global_1 = result.fee_growth_global;
+		}
+		Pools::<T>::insert(pool_key, pool.clone());
+
+		Self::call_on_trade_hook(pool, asset_in, asset_out, result.amount_in, result.amount_out)
+	}
+
+	fn call_on_trade_hook(
+		pool: &PoolInfo,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		let (reserve_0, reserve_1) = Self::virtual_reserves(pool)?;
+		// Price is not defined when there is no liquidity at the current price.
+		if reserve_0.is_zero() || reserve_1.is_zero() {
+			return Ok(());
+		}
+
+		let (liquidity_in, liquidity_out) = if asset_in < asset_out {
+			(reserve_0, reserve_1)
+		} else {
+			(reserve_1, reserve_0)
+		};
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			Ratio::new(liquidity_in, liquidity_out),
+		)
+		.map_err(|(_, e)| e)?;
+
+		Ok(())
+	}
+
+	fn call_on_liquidity_change_hook(
+		pool_key: PoolKeyOf<T>,
+		pool: &PoolInfo,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		let (reserve_0, reserve_1) = Self::virtual_reserves(pool)?;
+		// Price is not defined when there is no liquidity at the current price.
+		if reserve_0.is_zero() || reserve_1.is_zero() {
+			return Ok(());
+		}
+
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			pool_key.0,
+			pool_key.1,
+			amount_0,
+			amount_1,
+			reserve_0,
+			reserve_1,
+			Ratio::new(reserve_0, reserve_1),
+		)
+		.map_err(|(_, e)| e)?;
+
+		Ok(())
+	}
+
+	/// Weight of the maximum number of swap steps of a single trade, on top of the weight of a trade
+	/// which does not cross any tick.
+	pub fn max_swap_steps_weight() -> frame_support::weights::Weight {
+		T::WeightInfo::swap_step(T::MaxSwapSteps::get()).saturating_sub(T::WeightInfo::swap_step(0))
+	}
+
+	pub(crate) fn on_trade_weight() -> frame_support::weights::Weight {
+		<T::AMMHandler as OnTradeHandler<T::AssetId, Balance, Ratio>>::on_trade_weight()
+	}
+
+	pub(crate) fn on_liquidity_changed_weight() -> frame_support::weights::Weight {
+		<T::AMMHandler as OnLiquidityChangedHandler<T::AssetId, Balance, Ratio>>::on_liquidity_changed_weight()
+	}
+}
@@ -0,0 +1,212 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{Error, Event, Pools, MAX_TICK_SPACING};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, FixedU128, Permill};
+
+#[test]
+fn create_pool_should_work_when_assets_and_price_are_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DAI,
+			DOT,
+			default_fee(),
+			10,
+			FixedU128::from(1),
+		));
+
+		assert_eq!(
+			Pools::<Test>::get((DAI, DOT)).unwrap(),
+			PoolInfo {
+				fee: default_fee(),
+				tick_spacing: 10,
+				sqrt_price: SQRT_PRICE_ONE,
+				tick: 0,
+				liquidity: 0,
+				fee_growth_global_0: 0,
+				fee_growth_global_1: 0,
+			}
+		);
+		assert!(DUSTER_WHITELIST.with(|v| v.borrow().contains(&pool_account())));
+
+		expect_events(vec![Event::PoolCreated {
+			asset_0: DAI,
+			asset_1: DOT,
+			fee: default_fee(),
+			tick_spacing: 10,
+			sqrt_price: SQRT_PRICE_ONE,
+			tick: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_set_price_of_first_pool_asset_when_assets_are_not_ordered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// price of DOT is 2 DAI, so the pool price of DAI is 0.5 DOT
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DOT,
+			DAI,
+			default_fee(),
+			10,
+			FixedU128::from(2),
+		));
+
+		let pool = Pools::<Test>::get((DAI, DOT)).unwrap();
+		assert_eq!(pool.sqrt_price, 13_043_817_825_332_782_212);
+		assert_eq!(pool.tick, -6932);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				DOT,
+				default_fee(),
+				10,
+				FixedU128::from(1)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, DAI, default_fee(), 10, FixedU128::from(1)),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, 1234, default_fee(), 10, FixedU128::from(1)),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_of_asset_pair_exists() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				DOT,
+				DAI,
+				Permill::from_percent(1),
+				60,
+				FixedU128::from(1)
+			),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_100_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, DOT, Permill::one(), 10, FixedU128::from(1)),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_tick_spacing_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		for tick_spacing in [0, -10, MAX_TICK_SPACING + 1] {
+			assert_noop!(
+				ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					DAI,
+					DOT,
+					default_fee(),
+					tick_spacing,
+					FixedU128::from(1)
+				),
+				Error::<Test>::InvalidTickSpacing
+			);
+		}
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), DAI, DOT, default_fee(), 10, FixedU128::from(0)),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				DAI,
+				DOT,
+				default_fee(),
+				10,
+				FixedU128::from_inner(1)
+			),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work_when_pool_exists() {
+	pool_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::update_pool_fee(
+			RuntimeOrigin::root(),
+			DOT,
+			DAI,
+			Permill::from_percent(1)
+		));
+
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().fee, Permill::from_percent(1));
+		expect_events(vec![Event::FeeUpdated {
+			asset_0: DAI,
+			asset_1: DOT,
+			fee: Permill::from_percent(1),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_pool_does_not_exist() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::update_pool_fee(RuntimeOrigin::root(), DAI, ETH, Permill::from_percent(1)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_called_by_non_authority() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::update_pool_fee(RuntimeOrigin::signed(ALICE), DAI, DOT, Permill::from_percent(1)),
+			BadOrigin
+		);
+	});
+}
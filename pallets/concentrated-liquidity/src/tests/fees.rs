@@ -0,0 +1,169 @@
+use crate::tests::mock::*;
+use crate::{assert_balance, Error, Event, Positions};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn collect_fees_should_pay_fees_accrued_by_position_in_range() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			0
+		));
+
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		// 0.3% of 10 DAI, rounded down
+		assert_balance!(ALICE, DAI, 900 * ONE + 29_999_999_999);
+		assert_balance!(ALICE, DOT, 900 * ONE);
+
+		let position = Positions::<Test>::get(0).unwrap();
+		assert_eq!(position.fees_owed_0, 0);
+		assert_eq!(position.fees_owed_1, 0);
+
+		expect_events(vec![Event::FeesCollected {
+			who: ALICE,
+			position_id: 0,
+			amount_0: 29_999_999_999,
+			amount_1: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn collect_fees_should_not_pay_fees_twice() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			0
+		));
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_balance!(ALICE, DAI, 900 * ONE + 29_999_999_999);
+	});
+}
+
+#[test]
+fn fees_should_be_distributed_in_proportion_to_liquidity() {
+	default_builder()
+		.with_liquidity((BOB, DAI, DOT, (-1000, 1000), 50 * ONE, 50 * ONE))
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(CHARLIE),
+				DAI,
+				DOT,
+				10 * ONE,
+				0
+			));
+
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 1));
+
+			assert_balance!(ALICE, DAI, 900 * ONE + 19_999_999_999);
+			assert_balance!(BOB, DAI, 950 * ONE + 9_999_999_999);
+		});
+}
+
+#[test]
+fn fees_should_not_accrue_to_position_out_of_range() {
+	default_builder()
+		.with_liquidity((CHARLIE, DAI, DOT, (2000, 3000), 10 * ONE, 0))
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				DAI,
+				DOT,
+				10 * ONE,
+				0
+			));
+
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(CHARLIE), 1));
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+			assert_balance!(CHARLIE, DAI, 990 * ONE);
+			assert_balance!(ALICE, DAI, 900 * ONE + 29_999_999_999);
+		});
+}
+
+#[test]
+fn fees_should_stop_accruing_when_price_leaves_position_range() {
+	default_builder()
+		.with_liquidity((CHARLIE, DAI, DOT, (-200, 200), 50 * ONE, 50 * ONE))
+		.build()
+		.execute_with(|| {
+			// price moves below tick -200 - CHARLIE earns fees only on the part of the trade in the range
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				DAI,
+				DOT,
+				100 * ONE,
+				0
+			));
+
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(CHARLIE), 1));
+
+			assert_balance!(ALICE, DAI, 900 * ONE + 148_036_660_668);
+			assert_balance!(CHARLIE, DAI, 950 * ONE + 151_963_339_332);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_pay_accrued_fees() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			0
+		));
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY
+		));
+
+		assert_balance!(ALICE, DAI, 900 * ONE + 109_969_999_999_999 + 29_999_999_999);
+		assert_balance!(ALICE, DOT, 900 * ONE + 90_078_241_468_159);
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				who: ALICE,
+				position_id: 0,
+				liquidity: DEFAULT_LIQUIDITY,
+				amount_0: 109_969_999_999_999,
+				amount_1: 90_078_241_468_159,
+			}
+			.into(),
+			Event::FeesCollected {
+				who: ALICE,
+				position_id: 0,
+				amount_0: 29_999_999_999,
+				amount_1: 0,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn collect_fees_should_fail_when_origin_is_not_position_owner() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
@@ -0,0 +1,293 @@
+use crate::tests::mock::*;
+use crate::types::{Position, TickInfo};
+use crate::{assert_balance, Error, Event, Pools, Positions, TickBitmap, Ticks};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn add_liquidity_should_work_when_price_is_in_range() {
+	pool_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			DOT,
+			-1000,
+			1000,
+			100 * ONE,
+			100 * ONE
+		));
+
+		assert_balance!(ALICE, DAI, 900 * ONE);
+		assert_balance!(ALICE, DOT, 900 * ONE);
+		assert_balance!(pool_account(), DAI, 100 * ONE);
+		assert_balance!(pool_account(), DOT, 100 * ONE);
+
+		assert_eq!(
+			Positions::<Test>::get(0).unwrap(),
+			Position {
+				asset_0: DAI,
+				asset_1: DOT,
+				tick_lower: -1000,
+				tick_upper: 1000,
+				liquidity: DEFAULT_LIQUIDITY,
+				fee_growth_inside_0_last: 0,
+				fee_growth_inside_1_last: 0,
+				fees_owed_0: 0,
+				fees_owed_1: 0,
+			}
+		);
+		assert_eq!(position_owner(0), Some(ALICE));
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().liquidity, DEFAULT_LIQUIDITY);
+
+		assert_eq!(
+			Ticks::<Test>::get((DAI, DOT), -1000),
+			TickInfo {
+				liquidity_gross: DEFAULT_LIQUIDITY,
+				liquidity_net: DEFAULT_LIQUIDITY as i128,
+				fee_growth_outside_0: 0,
+				fee_growth_outside_1: 0,
+			}
+		);
+		assert_eq!(
+			Ticks::<Test>::get((DAI, DOT), 1000),
+			TickInfo {
+				liquidity_gross: DEFAULT_LIQUIDITY,
+				liquidity_net: -(DEFAULT_LIQUIDITY as i128),
+				fee_growth_outside_0: 0,
+				fee_growth_outside_1: 0,
+			}
+		);
+		// compressed ticks -100 and 100
+		assert_eq!(TickBitmap::<Test>::get((DAI, DOT), -1), 1 << 28);
+		assert_eq!(TickBitmap::<Test>::get((DAI, DOT), 0), 1 << 100);
+
+		expect_events(vec![Event::LiquidityAdded {
+			who: ALICE,
+			position_id: 0,
+			asset_0: DAI,
+			asset_1: DOT,
+			tick_lower: -1000,
+			tick_upper: 1000,
+			liquidity: DEFAULT_LIQUIDITY,
+			amount_0: 100 * ONE,
+			amount_1: 100 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_add_only_asset_0_when_price_is_below_range() {
+	pool_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			DAI,
+			2000,
+			3000,
+			100 * ONE,
+			100 * ONE
+		));
+
+		assert_balance!(ALICE, DAI, 900 * ONE);
+		assert_balance!(ALICE, DOT, 1_000 * ONE);
+		assert_eq!(Positions::<Test>::get(0).unwrap().liquidity, 2_266_160_012_908_664);
+		// position is not active at the current price
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_add_only_asset_1_when_price_is_above_range() {
+	pool_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			DOT,
+			-3000,
+			-2000,
+			100 * ONE,
+			100 * ONE
+		));
+
+		assert_balance!(ALICE, DAI, 1_000 * ONE);
+		assert_balance!(ALICE, DOT, 900 * ONE);
+		assert_eq!(Positions::<Test>::get(0).unwrap().liquidity, 2_266_160_012_908_664);
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_new_position_for_each_provision() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			-1000,
+			1000,
+			50 * ONE,
+			50 * ONE
+		));
+
+		assert_eq!(position_owner(1), Some(BOB));
+		assert_eq!(Positions::<Test>::get(1).unwrap().liquidity, DEFAULT_LIQUIDITY / 2);
+		assert_eq!(
+			Pools::<Test>::get((DAI, DOT)).unwrap().liquidity,
+			DEFAULT_LIQUIDITY + DEFAULT_LIQUIDITY / 2
+		);
+		assert_eq!(
+			Ticks::<Test>::get((DAI, DOT), -1000).liquidity_gross,
+			DEFAULT_LIQUIDITY + DEFAULT_LIQUIDITY / 2
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_tick_range_is_invalid() {
+	pool_builder().build().execute_with(|| {
+		for (tick_lower, tick_upper) in [(1000, -1000), (1000, 1000), (-1005, 1000), (-1000, 443_640)] {
+			assert_noop!(
+				ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					DAI,
+					DOT,
+					tick_lower,
+					tick_upper,
+					100 * ONE,
+					100 * ONE
+				),
+				Error::<Test>::InvalidTickRange
+			);
+		}
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_amount_of_asset_is_zero_and_price_is_in_range() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), DAI, DOT, -1000, 1000, 100 * ONE, 0),
+			Error::<Test>::InvalidLiquidityAmount
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_lp_has_insufficient_balance() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				DOT,
+				-1000,
+				1000,
+				2_000 * ONE,
+				2_000 * ONE
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	pool_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				ETH,
+				-1000,
+				1000,
+				100 * ONE,
+				100 * ONE
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_destroy_position_when_all_liquidity_is_removed() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY
+		));
+
+		// amounts are rounded down in favor of the pool
+		assert_balance!(ALICE, DAI, 1_000 * ONE - 1);
+		assert_balance!(ALICE, DOT, 1_000 * ONE - 1);
+		assert_balance!(pool_account(), DAI, 1);
+		assert_balance!(pool_account(), DOT, 1);
+
+		assert!(Positions::<Test>::get(0).is_none());
+		assert_eq!(position_owner(0), None);
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().liquidity, 0);
+		assert!(!Ticks::<Test>::contains_key((DAI, DOT), -1000));
+		assert!(!Ticks::<Test>::contains_key((DAI, DOT), 1000));
+		assert_eq!(TickBitmap::<Test>::iter_prefix((DAI, DOT)).count(), 0);
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				who: ALICE,
+				position_id: 0,
+				liquidity: DEFAULT_LIQUIDITY,
+				amount_0: 100 * ONE - 1,
+				amount_1: 100 * ONE - 1,
+			}
+			.into(),
+			Event::PositionDestroyed {
+				position_id: 0,
+				owner: ALICE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_update_position_when_part_of_liquidity_is_removed() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY / 2
+		));
+
+		assert_balance!(ALICE, DAI, 950 * ONE - 1);
+		assert_balance!(ALICE, DOT, 950 * ONE - 1);
+
+		let remaining = DEFAULT_LIQUIDITY - DEFAULT_LIQUIDITY / 2;
+		assert_eq!(Positions::<Test>::get(0).unwrap().liquidity, remaining);
+		assert_eq!(position_owner(0), Some(ALICE));
+		assert_eq!(Pools::<Test>::get((DAI, DOT)).unwrap().liquidity, remaining);
+		assert_eq!(Ticks::<Test>::get((DAI, DOT), -1000).liquidity_gross, remaining);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_origin_is_not_position_owner() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(BOB), 0, DEFAULT_LIQUIDITY),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_exceeds_position_liquidity() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, DEFAULT_LIQUIDITY + 1),
+			Error::<Test>::InvalidLiquidityAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, 0),
+			Error::<Test>::InvalidLiquidityAmount
+		);
+	});
+}
@@ -0,0 +1,474 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Concentrated liquidity pallet.
+#![allow(clippy::type_complexity)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_concentrated_liquidity;
+use crate::types::Tick;
+use crate::Config;
+
+use frame_support::assert_ok;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::PalletId;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{
+	AssetPairAccountIdFor, Inspect as InspectRegistry, OnLiquidityChangedHandler, OnTradeHandler, Source,
+};
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, FixedU128, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 1;
+pub const DOT: AssetId = 2;
+pub const ETH: AssetId = 3;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const CONCENTRATED_SOURCE: Source = *b"concentr";
+
+/// Liquidity of the default position - 100 DAI and 100 DOT in range [-1000, 1000] at price 1.
+pub const DEFAULT_LIQUIDITY: u128 = 2_050_516_626_810_664;
+
+/// Square root of price 1 in Q64.64.
+pub const SQRT_PRICE_ONE: u128 = 1 << 64;
+
+/// Oracle activity recorded by `OracleHandlerMock` - (asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price)
+pub type OracleEntry = (AssetId, AssetId, Balance, Balance, Balance, Ratio);
+
+/// Liquidity added when the test externalities are built - (who, asset_a, asset_b, range, amount_a, amount_b)
+pub type InitialLiquidity = (AccountId, AssetId, AssetId, (Tick, Tick), Balance, Balance);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static ORACLE_TRADES: RefCell<Vec<OracleEntry>> = RefCell::new(Vec::new());
+	pub static ORACLE_LIQUIDITY: RefCell<Vec<OracleEntry>> = RefCell::new(Vec::new());
+	pub static POSITIONS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::default());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		ConcentratedLiquidity: pallet_concentrated_liquidity,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub const MaxSwapSteps: u32 = 20;
+	pub const OracleSource: Source = CONCENTRATED_SOURCE;
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"clpool__");
+	pub const CollectionId: u32 = 4242;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Whitelist;
+	type AMMHandler = OracleHandlerMock;
+	type OracleSource = OracleSource;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type PositionItemId = u32;
+	type CollectionId = u32;
+	type NFTCollectionId = CollectionId;
+	type NFTHandler = DummyNFT;
+	type MinTradingLimit = MinimumTradingLimit;
+	type MaxSwapSteps = MaxSwapSteps;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AssetId, AssetId, Permill, Tick, FixedU128)>,
+	liquidity: Vec<InitialLiquidity>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		ORACLE_TRADES.with(|v| v.borrow_mut().clear());
+		ORACLE_LIQUIDITY.with(|v| v.borrow_mut().clear());
+		POSITIONS.with(|v| v.borrow_mut().clear());
+		Self {
+			endowed_accounts: vec![],
+			pools: vec![],
+			liquidity: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	/// Creates pool of `asset_a` and `asset_b` with initial price of `asset_a` denominated in `asset_b`.
+	pub fn with_pool(
+		mut self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		fee: Permill,
+		tick_spacing: Tick,
+		initial_price: FixedU128,
+	) -> Self {
+		self.pools.push((asset_a, asset_b, fee, tick_spacing, initial_price));
+		self
+	}
+
+	pub fn with_liquidity(mut self, liquidity: InitialLiquidity) -> Self {
+		self.liquidity.push(liquidity);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		for asset in [HDX, DAI, DOT, ETH] {
+			REGISTERED_ASSETS.with(|v| {
+				v.borrow_mut().insert(asset, 12);
+			});
+		}
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for (asset_a, asset_b, fee, tick_spacing, initial_price) in self.pools {
+				assert_ok!(ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					asset_a,
+					asset_b,
+					fee,
+					tick_spacing,
+					initial_price
+				));
+			}
+
+			for (who, asset_a, asset_b, (tick_lower, tick_upper), amount_a, amount_b) in self.liquidity {
+				assert_ok!(ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(who),
+					asset_a,
+					asset_b,
+					tick_lower,
+					tick_upper,
+					amount_a,
+					amount_b
+				));
+			}
+
+			ORACLE_TRADES.with(|v| v.borrow_mut().clear());
+			ORACLE_LIQUIDITY.with(|v| v.borrow_mut().clear());
+		});
+
+		r
+	}
+}
+
+/// Fee of the default pool - 0.3%.
+pub fn default_fee() -> Permill {
+	Permill::from_parts(3_000)
+}
+
+/// DAI/DOT pool with tick spacing 10 at price 1.
+pub fn pool_builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DAI, 1_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+			(BOB, DOT, 1_000 * ONE),
+			(CHARLIE, DAI, 1_000 * ONE),
+			(CHARLIE, DOT, 1_000 * ONE),
+		])
+		.with_pool(DAI, DOT, default_fee(), 10, FixedU128::from(1))
+}
+
+/// Default pool with 100 DAI and 100 DOT provided by ALICE in range [-1000, 1000].
+pub fn default_builder() -> ExtBuilder {
+	pool_builder().with_liquidity((ALICE, DAI, DOT, (-1000, 1000), 100 * ONE, 100 * ONE))
+}
+
+pub fn pool_account() -> AccountId {
+	ConcentratedLiquidity::pool_account((DAI, DOT))
+}
+
+pub fn position_owner(position_id: u32) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+pub struct AssetPairAccountIdTest;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			std::mem::swap(&mut a, &mut b)
+		}
+		(a * 1000 + b + 1000) as u64
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|x| x != account));
+		Ok(())
+	}
+}
+
+pub struct OracleHandlerMock;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for OracleHandlerMock {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		_amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		assert_eq!(source, CONCENTRATED_SOURCE);
+		ORACLE_TRADES.with(|v| {
+			v.borrow_mut()
+				.push((asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price))
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, Ratio> for OracleHandlerMock {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		_amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		assert_eq!(source, CONCENTRATED_SOURCE);
+		ORACLE_LIQUIDITY.with(|v| {
+			v.borrow_mut()
+				.push((asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price))
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<hydradx_traits::AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+		Ok(())
+	}
+}
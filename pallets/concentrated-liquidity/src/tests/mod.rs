@@ -0,0 +1,14 @@
+mod creation;
+mod fees;
+mod liquidity;
+pub(crate) mod mock;
+mod oracle;
+mod trade_execution;
+mod trades;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
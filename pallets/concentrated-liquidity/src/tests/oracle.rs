@@ -0,0 +1,111 @@
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use hydra_dx_math::ratio::Ratio;
+
+#[test]
+fn sell_should_update_oracle_with_virtual_reserves() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			0
+		));
+
+		let (reserve_dai, reserve_dot) = (2_060_486_626_810_663, 2_040_594_868_278_823);
+		let trades = ORACLE_TRADES.with(|v| v.borrow().clone());
+		assert_eq!(
+			trades,
+			vec![(
+				DAI,
+				DOT,
+				10 * ONE,
+				reserve_dai,
+				reserve_dot,
+				Ratio::new(reserve_dai, reserve_dot)
+			)]
+		);
+	});
+}
+
+#[test]
+fn buy_should_update_oracle_with_asset_in_first() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			u128::MAX
+		));
+
+		let trades = ORACLE_TRADES.with(|v| v.borrow().clone());
+		assert_eq!(trades.len(), 1);
+		let (asset_in, asset_out, _, liquidity_in, liquidity_out, price) = trades[0];
+		assert_eq!((asset_in, asset_out), (DOT, DAI));
+		assert_eq!(price, Ratio::new(liquidity_in, liquidity_out));
+		// DAI was bought, so there is more DOT at the current price
+		assert!(liquidity_in > liquidity_out);
+	});
+}
+
+#[test]
+fn add_liquidity_should_update_oracle_when_liquidity_is_active() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			-1000,
+			1000,
+			50 * ONE,
+			50 * ONE
+		));
+
+		// virtual reserves equal to liquidity at price 1
+		let liquidity = DEFAULT_LIQUIDITY + DEFAULT_LIQUIDITY / 2;
+		let entries = ORACLE_LIQUIDITY.with(|v| v.borrow().clone());
+		assert_eq!(
+			entries,
+			vec![(
+				DAI,
+				DOT,
+				50 * ONE,
+				liquidity,
+				liquidity,
+				Ratio::new(liquidity, liquidity)
+			)]
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_not_update_oracle_when_there_is_no_active_liquidity() {
+	pool_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			DOT,
+			2000,
+			3000,
+			100 * ONE,
+			0
+		));
+
+		assert!(ORACLE_LIQUIDITY.with(|v| v.borrow().is_empty()));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_update_oracle_when_last_active_liquidity_is_removed() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY
+		));
+
+		assert!(ORACLE_LIQUIDITY.with(|v| v.borrow().is_empty()));
+	});
+}
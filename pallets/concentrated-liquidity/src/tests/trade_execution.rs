@@ -0,0 +1,167 @@
+use crate::assert_balance;
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
+use sp_runtime::traits::CheckedDiv;
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
+
+#[test]
+fn calculate_sell_should_return_same_amount_as_executed_sell() {
+	default_builder().build().execute_with(|| {
+		let expected =
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				PoolType::Concentrated,
+				DAI,
+				DOT,
+				10 * ONE,
+			)
+			.unwrap();
+
+		assert_ok!(<ConcentratedLiquidity as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated,
+			DAI,
+			DOT,
+			10 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, DOT, 1_000 * ONE + expected);
+		assert_balance!(BOB, DAI, 990 * ONE);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_same_amount_as_executed_buy() {
+	default_builder().build().execute_with(|| {
+		let expected =
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+				PoolType::Concentrated,
+				DAI,
+				DOT,
+				10 * ONE,
+			)
+			.unwrap();
+
+		assert_ok!(<ConcentratedLiquidity as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated,
+			DAI,
+			DOT,
+			10 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, DOT, 1_010 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE - expected);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	default_builder().build().execute_with(|| {
+		for pool_type in [
+			PoolType::XYK,
+			PoolType::LBP,
+			PoolType::Omnipool,
+			PoolType::Stableswap(DAI),
+			PoolType::Weighted(DAI),
+		] {
+			assert_eq!(
+				<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+					pool_type,
+					DAI,
+					DOT,
+					10 * ONE,
+				),
+				Err(ExecutorError::NotSupported)
+			);
+			assert_eq!(
+				<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::execute_sell(
+					RuntimeOrigin::signed(BOB),
+					pool_type,
+					DAI,
+					DOT,
+					10 * ONE,
+					0,
+				),
+				Err(ExecutorError::NotSupported)
+			);
+		}
+	});
+}
+
+#[test]
+fn pools_should_return_pool_with_its_assets() {
+	default_builder().build().execute_with(|| {
+		assert_eq!(
+			<ConcentratedLiquidity as PoolsProvider<AssetId>>::pools(),
+			vec![(PoolType::Concentrated, vec![DAI, DOT])]
+		);
+	});
+}
+
+#[test]
+fn liquidity_depth_should_be_virtual_reserve_at_current_price() {
+	default_builder().build().execute_with(|| {
+		assert_eq!(
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::get_liquidity_depth(
+				PoolType::Concentrated,
+				DOT,
+				DAI,
+			),
+			Ok(DEFAULT_LIQUIDITY)
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_be_price_of_asset_b_denominated_in_asset_a() {
+	ExtBuilder::default()
+		.with_pool(DOT, DAI, Permill::zero(), 10, FixedU128::from(4))
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+					PoolType::Concentrated,
+					DAI,
+					DOT,
+				),
+				Ok(FixedU128::from(4))
+			);
+			assert_eq!(
+				<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+					PoolType::Concentrated,
+					DOT,
+					DAI,
+				),
+				Ok(FixedU128::from_rational(1, 4))
+			);
+		});
+}
+
+#[test]
+fn spot_price_should_include_fee() {
+	default_builder().build().execute_with(|| {
+		assert_eq!(
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+				PoolType::Concentrated,
+				DAI,
+				DOT,
+			),
+			Ok(FixedU128::from(1)
+				.checked_div(&FixedU128::from_rational(997, 1000))
+				.unwrap())
+		);
+	});
+}
@@ -0,0 +1,214 @@
+use crate::tests::mock::*;
+use crate::{assert_balance, Error, Event, Pools, Ticks};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::FixedU128;
+
+#[test]
+fn sell_should_work_when_selling_asset_0() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			DOT,
+			10 * ONE,
+			9 * ONE
+		));
+
+		let expected_out = 9_921_758_531_840;
+		assert_balance!(BOB, DAI, 990 * ONE);
+		assert_balance!(BOB, DOT, 1_000 * ONE + expected_out);
+		assert_balance!(pool_account(), DAI, 110 * ONE);
+		assert_balance!(pool_account(), DOT, 100 * ONE - expected_out);
+
+		let pool = Pools::<Test>::get((DAI, DOT)).unwrap();
+		assert_eq!(pool.sqrt_price, 18_357_486_499_298_813_205);
+		assert_eq!(pool.tick, -98);
+		assert_eq!(pool.liquidity, DEFAULT_LIQUIDITY);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: DOT,
+			amount_in: 10 * ONE,
+			amount_out: expected_out,
+			fee: 30_000_000_000,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_asset_1() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			DAI,
+			10 * ONE,
+			9 * ONE
+		));
+
+		// pool is symmetric at price 1
+		let expected_out = 9_921_758_531_840;
+		assert_balance!(BOB, DOT, 990 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE + expected_out);
+
+		let pool = Pools::<Test>::get((DAI, DOT)).unwrap();
+		assert_eq!(pool.sqrt_price, 18_536_435_635_343_431_540);
+		assert_eq!(pool.tick, 97);
+	});
+}
+
+#[test]
+fn buy_should_work_when_buying_asset_1() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			DAI,
+			10 * ONE,
+			11 * ONE
+		));
+
+		let expected_in = 10_079_244_931_643;
+		assert_balance!(BOB, DAI, 1_000 * ONE - expected_in);
+		assert_balance!(BOB, DOT, 1_010 * ONE);
+		assert_balance!(pool_account(), DAI, 100 * ONE + expected_in);
+		assert_balance!(pool_account(), DOT, 90 * ONE);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: DOT,
+			amount_in: expected_in,
+			amount_out: 10 * ONE,
+			fee: 30_237_734_795,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_change_active_liquidity_when_initialized_tick_is_crossed() {
+	default_builder()
+		.with_liquidity((CHARLIE, DAI, DOT, (-200, 200), 50 * ONE, 50 * ONE))
+		.build()
+		.execute_with(|| {
+			let charlie_liquidity = 5_025_291_660_347_579;
+			assert_eq!(
+				Pools::<Test>::get((DAI, DOT)).unwrap().liquidity,
+				DEFAULT_LIQUIDITY + charlie_liquidity
+			);
+
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				DAI,
+				DOT,
+				80 * ONE,
+				0
+			));
+
+			assert_balance!(BOB, DOT, 1_000 * ONE + 78_845_932_638_730);
+
+			let pool = Pools::<Test>::get((DAI, DOT)).unwrap();
+			assert_eq!(pool.tick, -284);
+			assert_eq!(pool.liquidity, DEFAULT_LIQUIDITY);
+			// fee growth of asset in below the crossed tick is tracked outside of the tick
+			assert_eq!(
+				Ticks::<Test>::get((DAI, DOT), -200).fee_growth_outside_0,
+				557_824_106_282_902
+			);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, DOT, 10 * ONE, 10 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DOT, DAI, 10 * ONE, 10 * ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_minimum_trading_limit() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, DOT, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DOT, DAI, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_seller_has_insufficient_balance() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, DOT, 2_000 * ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_assets_are_same_or_pool_does_not_exist() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, DAI, 10 * ONE, 0),
+			Error::<Test>::IncorrectAssets
+		);
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, ETH, 10 * ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_insufficient_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DAI, 1_000 * ONE),
+			(ALICE, ETH, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(DAI, ETH, default_fee(), 1000, FixedU128::from(1))
+		.with_liquidity((ALICE, DAI, ETH, (-1000, 1000), 100 * ONE, 100 * ONE))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, ETH, 1_000 * ONE, 0),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_noop!(
+				ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), ETH, DAI, 200 * ONE, u128::MAX),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn trade_should_fail_when_max_swap_steps_are_exceeded() {
+	default_builder().build().execute_with(|| {
+		// price moves through empty bitmap words after all liquidity is used
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), DAI, DOT, 1_000 * ONE, 0),
+			Error::<Test>::MaxSwapStepsExceeded
+		);
+	});
+}
@@ -0,0 +1,149 @@
+use crate::types::Balance;
+use crate::{Config, Error, Pallet, Pools};
+use hydra_dx_math::concentrated_liquidity::price_from_sqrt_price;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
+use sp_runtime::traits::{CheckedDiv, CheckedSub, One};
+use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				let (_, _, result) =
+					Self::calculate_swap(asset_in, asset_out, amount_in, true).map_err(ExecutorError::Error)?;
+
+				Ok(result.amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				let (_, _, result) =
+					Self::calculate_swap(asset_in, asset_out, amount_out, false).map_err(ExecutorError::Error)?;
+
+				Ok(result.amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				let pool_key = Self::pool_key(asset_a, asset_b);
+				let pool =
+					Pools::<T>::get(pool_key).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+				// Depth of the liquidity active at the current price
+				let (reserve_0, reserve_1) = Self::virtual_reserves(&pool).map_err(ExecutorError::Error)?;
+				if asset_a == pool_key.0 {
+					Ok(reserve_0)
+				} else {
+					Ok(reserve_1)
+				}
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated => {
+				let pool_key = Self::pool_key(asset_a, asset_b);
+				let pool =
+					Pools::<T>::get(pool_key).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+				// Pool price is the price of asset 0 denominated in asset 1
+				let price = price_from_sqrt_price(pool.sqrt_price)
+					.map_err(|_| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
+				// Price of asset_b denominated in asset_a
+				let spot_price = if asset_a == pool_key.0 {
+					FixedU128::one()
+						.checked_div(&price)
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))?
+				} else {
+					price
+				};
+
+				// Fee is paid in asset entering the pool, so the price paid for one unit of asset_b
+				// is increased by the fee.
+				let fee_multiplier = FixedU128::one()
+					.checked_sub(&FixedU128::from(pool.fee))
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
+				let spot_price_with_fee = spot_price
+					.checked_div(&fee_multiplier)
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))?;
+
+				Ok(spot_price_with_fee)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+}
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter_keys()
+			.map(|(asset_0, asset_1)| (PoolType::Concentrated, vec![asset_0, asset_1]))
+			.collect()
+	}
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+use sp_std::prelude::*;
+
+pub use hydra_dx_math::concentrated_liquidity::{FeeGrowth, Liquidity, SqrtPrice, Tick};
+
+pub type Balance = u128;
+
+/// Pool properties and state
+/// `fee`: trade fee taken from the amount in
+/// `tick_spacing`: only ticks which are multiples of tick spacing can be used as position bounds
+/// `sqrt_price`: square root of current price of asset 0 denominated in asset 1
+/// `tick`: current tick - the greatest tick with price lower than or equal to the current price
+/// `liquidity`: liquidity of positions in range of the current price
+/// `fee_growth_global_0` / `fee_growth_global_1`: fees collected per unit of liquidity over the lifetime of the pool
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo {
+	pub fee: Permill,
+	pub tick_spacing: Tick,
+	pub sqrt_price: SqrtPrice,
+	pub tick: Tick,
+	pub liquidity: Liquidity,
+	pub fee_growth_global_0: FeeGrowth,
+	pub fee_growth_global_1: FeeGrowth,
+}
+
+/// State of an initialized tick
+/// `liquidity_gross`: total liquidity of positions using the tick as lower or upper bound
+/// `liquidity_net`: liquidity added to the active liquidity when the tick is crossed from left to right
+/// `fee_growth_outside_0` / `fee_growth_outside_1`: fee growth on the other side of the tick from the current tick
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+	pub liquidity_gross: Liquidity,
+	pub liquidity_net: i128,
+	pub fee_growth_outside_0: FeeGrowth,
+	pub fee_growth_outside_1: FeeGrowth,
+}
+
+/// Liquidity position in a price range
+/// `asset_0` / `asset_1`: pool assets, `asset_0` < `asset_1`
+/// `tick_lower` / `tick_upper`: price range of the position
+/// `liquidity`: liquidity provided in the price range
+/// `fee_growth_inside_0_last` / `fee_growth_inside_1_last`: fee growth inside of the range when fees were last accrued
+/// `fees_owed_0` / `fees_owed_1`: fees accrued by the position and not collected yet
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position<AssetId> {
+	pub asset_0: AssetId,
+	pub asset_1: AssetId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub liquidity: Liquidity,
+	pub fee_growth_inside_0_last: FeeGrowth,
+	pub fee_growth_inside_1_last: FeeGrowth,
+	pub fees_owed_0: Balance,
+	pub fees_owed_1: Balance,
+}
+
+impl<AssetId: Copy> Position<AssetId> {
+	pub(crate) fn new(pool: (AssetId, AssetId), tick_lower: Tick, tick_upper: Tick) -> Self {
+		Self {
+			asset_0: pool.0,
+			asset_1: pool.1,
+			tick_lower,
+			tick_upper,
+			liquidity: 0,
+			fee_growth_inside_0_last: 0,
+			fee_growth_inside_1_last: 0,
+			fees_owed_0: 0,
+			fees_owed_1: 0,
+		}
+	}
+
+	pub fn pool(&self) -> (AssetId, AssetId) {
+		(self.asset_0, self.asset_1)
+	}
+}
+
+/// Result of a swap calculation.
+/// `amount_in`: amount of asset in including the fee
+/// `crossed_ticks`: initialized ticks crossed by the swap with global fee growth of both assets at the time of crossing
+#[derive(Clone, RuntimeDebug, PartialEq, Eq)]
+pub(crate) struct SwapResult {
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub fee_amount: Balance,
+	pub sqrt_price: SqrtPrice,
+	pub tick: Tick,
+	pub liquidity: Liquidity,
+	pub fee_growth_global: FeeGrowth,
+	pub crossed_ticks: Vec<(Tick, FeeGrowth, FeeGrowth)>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult;
+}
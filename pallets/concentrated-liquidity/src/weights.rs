@@ -0,0 +1,181 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_concentrated_liquidity`
+//!
+//! Values are placeholders until the benchmarks in `benchmarking.rs` are run on the reference hardware.
+//!
+//! Trade weights cover a trade which does not cross any tick. `swap_step` is a sell which crosses `s`
+//! initialized ticks - the difference to `swap_step(0)` is the cost of the crossed ticks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_concentrated_liquidity.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn update_pool_fee() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn collect_fees() -> Weight;
+	fn sell() -> Weight;
+	fn buy() -> Weight;
+	fn swap_step(s: u32) -> Weight;
+	fn router_execution_sell(c: u32, e: u32) -> Weight;
+	fn router_execution_buy(c: u32, e: u32) -> Weight;
+	fn calculate_spot_price_with_fee() -> Weight;
+}
+
+impl WeightInfo for () {
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:1)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Storage: `Uniques::ClassAccount` (r:0 w:1)
+	fn create_pool() -> Weight {
+		Weight::from_parts(45_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	fn update_pool_fee() -> Weight {
+		Weight::from_parts(20_000_000, 3_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:2 w:2)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:2 w:2)
+	/// Storage: `ConcentratedLiquidity::NextPositionId` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::Positions` (r:0 w:1)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Storage: `Uniques::CollectionMaxSupply` (r:1 w:0)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	fn add_liquidity() -> Weight {
+		Weight::from_parts(180_000_000, 14_000)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:2 w:2)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:2 w:2)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
+	fn remove_liquidity() -> Weight {
+		Weight::from_parts(190_000_000, 14_000)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:2 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	fn collect_fees() -> Weight {
+		Weight::from_parts(110_000_000, 11_000)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:2 w:1)
+	fn sell() -> Weight {
+		Weight::from_parts(120_000_000, 13_905)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:2 w:1)
+	fn buy() -> Weight {
+		Weight::from_parts(120_000_000, 13_905)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:1 w:0)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:19 w:19)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// The range of component `s` is `[0, 19]`.
+	fn swap_step(s: u32) -> Weight {
+		Weight::from_parts(120_000_000, 13_905)
+			.saturating_add(Weight::from_parts(15_000_000, 2_549).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_sell(c: u32, e: u32) -> Weight {
+		Weight::from_parts(40_000_000, 6_156)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(90_000_000, 7_749).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(e.into())))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// The range of component `c` is `[1, 3]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_buy(c: u32, e: u32) -> Weight {
+		Weight::from_parts(40_000_000, 6_156)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(90_000_000, 7_749).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(e.into())))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	fn calculate_spot_price_with_fee() -> Weight {
+		Weight::from_parts(15_000_000, 3_000).saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+}
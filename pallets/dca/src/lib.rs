@@ -55,6 +55,25 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Price triggers
+//!
+//! A schedule can optionally contain a price trigger, such as "execute only when the price of `asset_out`
+//! in `asset_in` is below X" (buy-the-dip) or "above Y" (take-profit).
+//! The price is taken from the oracle with the oracle period specified in the trigger.
+//!
+//! If the trigger condition is not met or the oracle price is not available, no trade is executed,
+//! but the execution fee is still charged, as the schedule occupies a slot in the block.
+//! The schedule is planned for the next period and the retry counter is not increased.
+//! Once the remaining budget cannot cover the fee, the schedule is terminated.
+//!
+//! ## Pausing, resuming and amending a Schedule
+//!
+//! The owner can pause a schedule, which removes it from the execution planning while keeping its reserved budget.
+//! A paused schedule can be resumed, planning its next execution again.
+//!
+//! The owner can also amend a live or paused schedule without losing its id and history. The period, slippage,
+//! stability threshold and route can be changed, and the budget can be topped up or withdrawn.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -86,7 +105,7 @@ use orml_traits::{arithmetic::CheckedAdd, MultiCurrency, NamedMultiReservableCur
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::{CheckedMul, One};
+use sp_runtime::traits::{CheckedMul, One, Zero};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Saturating},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding,
@@ -98,6 +117,7 @@ use sp_std::{cmp::min, vec};
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -124,7 +144,10 @@ pub mod pallet {
 	use hydradx_traits::{NativePriceOracle, PriceOracle};
 	use orml_traits::NamedMultiReservableCurrency;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -151,6 +174,28 @@ pub mod pallet {
 				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
 				weight.saturating_accrue(weight_for_single_execution);
 
+				if !Self::is_price_trigger_met(&schedule) {
+					Self::deposit_event(Event::PriceTriggerNotMet {
+						id: schedule_id,
+						who: schedule.owner.clone(),
+					});
+
+					let result =
+						Self::take_transaction_fee_from_user(schedule_id, &schedule, weight_for_single_execution)
+							.and_then(|_| {
+								Self::plan_next_period(
+									schedule_id,
+									&schedule,
+									current_blocknumber,
+									&mut randomness_generator,
+								)
+							});
+					if let Err(err) = result {
+						Self::terminate_schedule(schedule_id, &schedule, err);
+					}
+					continue;
+				}
+
 				if let Err(e) = Self::prepare_schedule(
 					current_blocknumber,
 					weight_for_single_execution,
@@ -329,6 +374,18 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The price trigger of the DCA is not met, so the trade is skipped and planned for the next period
+		PriceTriggerNotMet { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and its execution is not planned anymore
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The DCA is amended
+		Amended {
+			id: ScheduleId,
+			who: T::AccountId,
+			amendment: ScheduleAmendment<T::AssetId, BlockNumberFor<T>>,
+		},
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -368,6 +425,14 @@ pub mod pallet {
 		NoParentHashFound,
 		///Error that should not really happen only in case of invalid state of the schedule storage entries
 		InvalidState,
+		///The price of the price trigger must be higher than zero
+		InvalidPriceTrigger,
+		///The schedule is paused
+		SchedulePaused,
+		///The schedule is not paused
+		ScheduleNotPaused,
+		///The route does not match the assets of the order
+		InvalidRoute,
 	}
 
 	/// Id sequencer for schedules
@@ -397,6 +462,11 @@ pub mod pallet {
 	#[pallet::getter(fn retries_on_error)]
 	pub type RetriesOnError<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, u8, ValueQuery>;
 
+	/// Keep tracking of the paused schedules
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	/// Keep tracking of the schedule ids to be executed in the block
 	#[pallet::storage]
 	#[pallet::getter(fn schedule_ids_per_block)]
@@ -422,6 +492,9 @@ pub mod pallet {
 		/// the schedule will be terminated permanently.
 		/// In the case of a successful trade, the retry counter is reset.
 		///
+		/// If the schedule contains a price trigger, trades are executed only when the oracle price meets
+		/// the trigger condition. Otherwise the schedule is planned for the next period.
+		///
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule`: schedule details
@@ -441,6 +514,13 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 			ensure!(who == schedule.owner, Error::<T>::Forbidden);
 
+			if let Some(price_trigger) = &schedule.price_trigger {
+				ensure!(
+					!price_trigger.condition.get_price().is_zero(),
+					Error::<T>::InvalidPriceTrigger
+				);
+			}
+
 			let min_budget = Self::convert_native_amount_to_currency(
 				schedule.order.get_asset_in(),
 				T::MinBudgetInNativeCurrency::get(),
@@ -452,13 +532,7 @@ pub mod pallet {
 
 			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
 
-			let amount_in = match schedule.order {
-				Order::Sell { amount_in, .. } => amount_in,
-				Order::Buy { amount_out, .. } => {
-					let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-					Self::get_amount_in_for_buy(&amount_out, &route)?
-				}
-			};
+			let amount_in = Self::get_amount_in_of_single_trade(&schedule.order)?;
 			let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
 			ensure!(
 				amount_in >= min_trade_amount_in_from_fee,
@@ -548,33 +622,154 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
+			//Paused schedules are not planned in any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+				Self::remove_schedule_id_from_block(next_execution_block, schedule_id)?;
+			}
+
+			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+
+			Self::deposit_event(Event::Terminated {
+				id: schedule_id,
+				who: schedule.owner,
+				error: Error::<T>::ManuallyTerminated.into(),
+			});
+
+			Ok(())
+		}
+
+		/// Pauses a DCA schedule. The schedule is removed from the execution planning,
+		/// but its remaining budget stays reserved.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule is planned.
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::SchedulePaused
+			);
+
 			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+			Self::remove_schedule_id_from_block(next_execution_block, schedule_id)?;
 
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+			PausedSchedules::<T>::insert(schedule_id, ());
 
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
 
-					schedule_ids.remove(index);
+			Ok(())
+		}
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+		/// Resumes a paused DCA schedule and plans the next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: next execution block for the schedule
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				PausedSchedules::<T>::take(schedule_id).is_some(),
+				Error::<T>::ScheduleNotPaused
+			);
 
-			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+			let next_execution_block = Self::get_next_execution_block(start_execution_block)?;
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+			Self::plan_schedule_for_block(&who, next_execution_block, schedule_id, &mut randomness_generator)?;
 
-			Self::deposit_event(Event::Terminated {
+			Self::deposit_event(Event::Resumed { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Amends a live or paused DCA schedule.
+		///
+		/// The period, slippage, stability threshold and route of the schedule can be changed.
+		/// The budget can be topped up, in which case the additional amount is reserved,
+		/// or withdrawn from the remaining budget, in which case the amount is unreserved.
+		/// The changes are applied from the next execution of the schedule.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `amendment`: the changes of the schedule
+		///
+		/// Validations:
+		/// - the route must start with `asset_in` and end with `asset_out` of the order
+		/// - after a withdrawal, the remaining budget must be enough for at least one more trade
+		/// - after a withdrawal, the remaining budget must not be lower than `MinBudgetInNativeCurrency`
+		///
+		/// Emits `Amended` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T>::amend_weight(*schedule_id, amendment))]
+		#[transactional]
+		pub fn amend(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			amendment: ScheduleAmendment<T::AssetId, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				if let Some(period) = amendment.period {
+					schedule.period = period;
+				}
+				if let Some(slippage) = amendment.slippage {
+					schedule.slippage = slippage;
+				}
+				if let Some(stability_threshold) = amendment.stability_threshold {
+					schedule.stability_threshold = stability_threshold;
+				}
+				if let Some(route) = &amendment.route {
+					Self::ensure_route_matches_order(route, &schedule.order)?;
+					schedule.order.set_route(route.clone());
+				}
+				if let Some(budget_change) = amendment.budget {
+					Self::change_budget(schedule_id, schedule, budget_change)?;
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Amended {
 				id: schedule_id,
-				who: schedule.owner,
-				error: Error::<T>::ManuallyTerminated.into(),
+				who,
+				amendment,
 			});
 
 			Ok(())
@@ -770,13 +965,20 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
+		Self::plan_next_period(schedule_id, schedule, current_blocknumber, randomness_generator)
+	}
+
+	fn plan_next_period(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
 		let next_execution_block = current_blocknumber
 			.checked_add(&schedule.period)
 			.ok_or(ArithmeticError::Overflow)?;
 
-		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)?;
-
-		Ok(())
+		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)
 	}
 
 	fn retry_schedule(
@@ -848,6 +1050,122 @@ impl<T: Config> Pallet<T> {
 		diff > max_allowed_difference
 	}
 
+	fn remove_schedule_id_from_block(blocknumber: BlockNumberFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let index = schedule_ids
+				.binary_search(&schedule_id)
+				.map_err(|_| Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
+	}
+
+	fn ensure_route_matches_order(route: &[Trade<T::AssetId>], order: &Order<T::AssetId>) -> DispatchResult {
+		//Empty route means that the default route is used
+		let (Some(first_trade), Some(last_trade)) = (route.first(), route.last()) else {
+			return Ok(());
+		};
+
+		ensure!(
+			first_trade.asset_in == order.get_asset_in() && last_trade.asset_out == order.get_asset_out(),
+			Error::<T>::InvalidRoute
+		);
+		ensure!(
+			route.windows(2).all(|trades| trades[0].asset_out == trades[1].asset_in),
+			Error::<T>::InvalidRoute
+		);
+
+		Ok(())
+	}
+
+	fn change_budget(
+		schedule_id: ScheduleId,
+		schedule: &mut Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		budget_change: BudgetChange,
+	) -> DispatchResult {
+		let sold_currency = schedule.order.get_asset_in();
+		let remaining_amount = RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+
+		let new_remaining_amount = match budget_change {
+			BudgetChange::TopUp(amount) => {
+				T::Currencies::reserve_named(&T::NamedReserveId::get(), sold_currency, &schedule.owner, amount)?;
+				schedule.total_amount = schedule
+					.total_amount
+					.checked_add(amount)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				remaining_amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?
+			}
+			BudgetChange::Withdraw(amount) => {
+				let new_remaining_amount = remaining_amount.checked_sub(amount).ok_or(Error::<T>::BudgetTooLow)?;
+
+				let min_budget =
+					Self::convert_native_amount_to_currency(sold_currency, T::MinBudgetInNativeCurrency::get())?;
+				ensure!(
+					new_remaining_amount >= min_budget,
+					Error::<T>::TotalAmountIsSmallerThanMinBudget
+				);
+
+				let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+				let amount_in_with_transaction_fee = Self::get_amount_in_of_single_trade(&schedule.order)?
+					.checked_add(transaction_fee)
+					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(
+					amount_in_with_transaction_fee <= new_remaining_amount,
+					Error::<T>::BudgetTooLow
+				);
+
+				let remaining_amount_if_insufficient_balance =
+					T::Currencies::unreserve_named(&T::NamedReserveId::get(), sold_currency, &schedule.owner, amount);
+				ensure!(remaining_amount_if_insufficient_balance == 0, Error::<T>::InvalidState);
+				schedule.total_amount = schedule
+					.total_amount
+					.checked_sub(amount)
+					.ok_or(ArithmeticError::Underflow)?;
+
+				new_remaining_amount
+			}
+		};
+
+		RemainingAmounts::<T>::insert(schedule_id, new_remaining_amount);
+
+		Ok(())
+	}
+
+	fn get_amount_in_of_single_trade(order: &Order<T::AssetId>) -> Result<Balance, DispatchError> {
+		match order {
+			Order::Sell { amount_in, .. } => Ok(*amount_in),
+			Order::Buy { amount_out, .. } => {
+				let route = order.get_route_or_default::<T::RouteProvider>();
+				Self::get_amount_in_for_buy(amount_out, &route)
+			}
+		}
+	}
+
+	fn is_price_trigger_met(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> bool {
+		let Some(price_trigger) = &schedule.price_trigger else {
+			return true;
+		};
+
+		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+		let Some(price) = T::OraclePriceProvider::price(route, price_trigger.oracle_period) else {
+			return false;
+		};
+
+		let Some(price) = FixedU128::checked_from_rational(price.n, price.d) else {
+			return false;
+		};
+
+		price_trigger.condition.is_met(price)
+	}
+
 	fn get_amount_in_for_buy(amount_out: &Balance, route: &[Trade<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(route, *amount_out)?;
 
@@ -1033,6 +1351,32 @@ impl<T: Config> Pallet<T> {
 		Ok(fee_amount_in_sold_asset)
 	}
 
+	/// Weight of `amend`.
+	///
+	/// Withdrawing from the budget of a buy order calculates the amount in of a single trade
+	/// on the route the order has after the amendment, so the route calculation is added.
+	pub fn amend_weight(
+		schedule_id: ScheduleId,
+		amendment: &ScheduleAmendment<T::AssetId, BlockNumberFor<T>>,
+	) -> Weight {
+		let weight = <T as Config>::WeightInfo::amend();
+		if !matches!(amendment.budget, Some(BudgetChange::Withdraw(_))) {
+			return weight;
+		}
+		let Some(mut order) = Schedules::<T>::get(schedule_id).map(|schedule| schedule.order) else {
+			return weight;
+		};
+		if let Order::Sell { .. } = order {
+			return weight;
+		}
+		if let Some(route) = &amendment.route {
+			order.set_route(route.clone());
+		}
+		weight.saturating_add(T::AmmTradeWeights::calculate_buy_trade_amounts_weight(
+			&order.get_route_or_default::<T::RouteProvider>(),
+		))
+	}
+
 	// returns DCA overhead weight + router execution weight
 	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
 		let route = &order.get_route_or_default::<T::RouteProvider>();
@@ -1085,6 +1429,7 @@ impl<T: Config> Pallet<T> {
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
 	}
 }
 
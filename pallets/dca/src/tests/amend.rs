@@ -0,0 +1,370 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+
+use crate::assert_scheduled_ids;
+use crate::tests::*;
+use crate::{BudgetChange, Error, Event, Order, ScheduleAmendment};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn no_amendment() -> ScheduleAmendment<AssetId, BlockNumber> {
+	ScheduleAmendment {
+		period: None,
+		slippage: None,
+		stability_threshold: None,
+		route: None,
+		budget: None,
+	}
+}
+
+fn schedule_sell(total_amount: Balance) {
+	let schedule = ScheduleBuilder::new()
+		.with_total_amount(total_amount)
+		.with_order(Order::Sell {
+			asset_in: HDX,
+			asset_out: BTC,
+			amount_in: 10 * ONE,
+			min_amount_out: Balance::MIN,
+			route: create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: BTC,
+			}]),
+		})
+		.build();
+	assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+}
+
+#[test]
+fn amend_should_update_schedule_parameters() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+			let schedule_id = 0;
+
+			let new_route = create_bounded_vec(vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: BTC,
+				},
+			]);
+			let amendment = ScheduleAmendment {
+				period: Some(50),
+				slippage: Some(Some(Permill::from_percent(3))),
+				stability_threshold: Some(Some(Permill::from_percent(4))),
+				route: Some(new_route.clone()),
+				budget: None,
+			};
+
+			//Act
+			assert_ok!(DCA::amend(RuntimeOrigin::signed(ALICE), schedule_id, amendment.clone()));
+
+			//Assert
+			let schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(schedule.period, 50);
+			assert_eq!(schedule.slippage, Some(Permill::from_percent(3)));
+			assert_eq!(schedule.stability_threshold, Some(Permill::from_percent(4)));
+			assert_eq!(
+				schedule.order,
+				Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: 10 * ONE,
+					min_amount_out: Balance::MIN,
+					route: new_route,
+				}
+			);
+			assert_scheduled_ids!(600, vec![schedule_id]);
+
+			expect_events(vec![Event::Amended {
+				id: schedule_id,
+				who: ALICE,
+				amendment,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn amend_should_reset_slippage_to_default() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_slippage(Some(Permill::from_percent(3)))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::amend(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleAmendment {
+					slippage: Some(None),
+					..no_amendment()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(0).unwrap().slippage, None);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_route_does_not_match_order_assets() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleAmendment {
+						route: Some(create_bounded_vec(vec![Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: DAI,
+						}])),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_route_is_not_connected() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleAmendment {
+						route: Some(create_bounded_vec(vec![
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: DAI,
+							},
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							},
+						])),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn amend_should_reserve_top_up() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act
+			assert_ok!(DCA::amend(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleAmendment {
+					budget: Some(BudgetChange::TopUp(50 * ONE)),
+					..no_amendment()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(0).unwrap().total_amount, 150 * ONE);
+			assert_eq!(DCA::remaining_amounts(0), Some(150 * ONE));
+			assert_eq!(
+				150 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn amend_should_unreserve_withdrawn_amount() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act
+			assert_ok!(DCA::amend(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleAmendment {
+					budget: Some(BudgetChange::Withdraw(50 * ONE)),
+					..no_amendment()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(0).unwrap().total_amount, 50 * ONE);
+			assert_eq!(DCA::remaining_amounts(0), Some(50 * ONE));
+			assert_eq!(
+				50 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_remaining_budget_is_not_enough_for_next_trade_after_withdrawal() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleAmendment {
+						budget: Some(BudgetChange::Withdraw(95 * ONE)),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_remaining_budget_is_smaller_than_min_budget_after_withdrawal() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+			MIN_BUDGET.with(|v| *v.borrow_mut() = 50 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleAmendment {
+						budget: Some(BudgetChange::Withdraw(60 * ONE)),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::TotalAmountIsSmallerThanMinBudget
+			);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_withdrawal_is_higher_than_remaining_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleAmendment {
+						budget: Some(BudgetChange::Withdraw(101 * ONE)),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn amend_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			schedule_sell(100 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::amend(
+					RuntimeOrigin::signed(BOB),
+					0,
+					ScheduleAmendment {
+						period: Some(50),
+						..no_amendment()
+					}
+				),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
@@ -27,13 +27,13 @@ use frame_support::PalletId;
 use frame_support::BoundedVec;
 use frame_support::{assert_ok, parameter_types};
 use frame_system as system;
-use frame_system::{ensure_signed, EnsureRoot};
+use frame_system::{ensure_signed, EnsureRoot, EnsureSigned};
 use hydradx_traits::{registry::Inspect as InspectRegistry, AssetKind, NativePriceOracle, OraclePeriod, PriceOracle};
 use orml_traits::{parameter_type_with_key, GetByKey};
 use pallet_currencies::BasicCurrencyAdapter;
 use primitive_types::U128;
 use sp_core::H256;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, ConstU32};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, ConstU32, ConstU64};
 use sp_runtime::Perbill;
 use sp_runtime::Permill;
 use sp_runtime::{
@@ -142,6 +142,8 @@ parameter_types! {
 	pub static MockBlockNumberProvider: u64 = 0;
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = BoundedVec::truncate_from(vec![
 	OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+	pub const MaxExternalPriceDeviation: Permill = Permill::from_percent(10);
+	pub const ExternalPriceReferencePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 }
 
 impl pallet_ema_oracle::Config for Test {
@@ -151,6 +153,12 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type ExternalSourceLocation = AccountId;
+	type ExternalOrigin = EnsureSigned<AccountId>;
+	type ExternalUpdateMinInterval = ConstU64<1>;
+	type MaxExternalPriceDeviation = MaxExternalPriceDeviation;
+	type ExternalPriceReferencePeriod = ExternalPriceReferencePeriod;
+	type ReservedSources = Nothing;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -363,6 +371,7 @@ impl pallet_route_executor::Config for Test {
 	type AMM = Pools;
 	type InspectRegistry = DummyRegistry<Test>;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type Pools = ();
 	type WeightInfo = ();
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type EdToRefundCalculator = MockedEdCalculator;
@@ -1,12 +1,15 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, Order, PriceTrigger, Schedule, ScheduleId};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{BoundedVec, Permill};
 
+pub mod amend;
 pub mod mock;
 pub mod on_initialize;
+pub mod pause;
+pub mod price_trigger;
 pub mod schedule;
 pub mod terminate;
 
@@ -25,6 +28,7 @@ struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub price_trigger: Option<Option<PriceTrigger>>,
 }
 
 impl ScheduleBuilder {
@@ -36,6 +40,7 @@ impl ScheduleBuilder {
 			slippage: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
+			price_trigger: Some(None),
 			order: Some(Order::Buy {
 				asset_in: HDX,
 				asset_out: BTC,
@@ -85,6 +90,11 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_price_trigger(mut self, price_trigger: PriceTrigger) -> ScheduleBuilder {
+		self.price_trigger = Some(Some(price_trigger));
+		self
+	}
+
 	fn build(self) -> Schedule<AccountId, AssetId, BlockNumber> {
 		Schedule {
 			owner: self.owner.unwrap(),
@@ -94,6 +104,7 @@ impl ScheduleBuilder {
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
+			price_trigger: self.price_trigger.unwrap(),
 		}
 	}
 }
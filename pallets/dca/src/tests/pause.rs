@@ -0,0 +1,240 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+
+use crate::tests::*;
+use crate::{assert_scheduled_ids, assert_that_schedule_has_been_removed_from_storages};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pause_should_remove_schedule_from_planned_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), schedule_id, Some(600)));
+
+			//Assert
+			assert!(DCA::schedule_ids_per_block(600).is_empty());
+			assert!(DCA::schedules(schedule_id).is_some());
+			assert_eq!(DCA::paused(schedule_id), Some(()));
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(total_amount));
+			assert_eq!(
+				total_amount,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+
+			expect_events(vec![Event::Paused {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn pause_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::pause(RuntimeOrigin::signed(BOB), 0, Some(600)),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn pause_should_fail_when_schedule_is_already_paused() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), 0, Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::pause(RuntimeOrigin::signed(ALICE), 0, Some(600)),
+				Error::<Test>::SchedulePaused
+			);
+		});
+}
+
+#[test]
+fn pause_should_fail_when_schedule_is_not_planned_in_given_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::pause(RuntimeOrigin::signed(ALICE), 0, Some(700)),
+				Error::<Test>::ScheduleNotFound
+			);
+		});
+}
+
+#[test]
+fn resume_should_plan_paused_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), schedule_id, Some(600)));
+
+			//Act
+			assert_ok!(DCA::resume(RuntimeOrigin::signed(ALICE), schedule_id, Some(700)));
+
+			//Assert
+			assert!(DCA::paused(schedule_id).is_none());
+			assert_scheduled_ids!(700, vec![schedule_id]);
+
+			expect_events(vec![
+				Event::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 700,
+				}
+				.into(),
+				Event::Resumed {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn resume_should_plan_schedule_for_next_block_when_no_block_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), 0, Some(600)));
+
+			//Act
+			assert_ok!(DCA::resume(RuntimeOrigin::signed(ALICE), 0, Option::None));
+
+			//Assert
+			assert_scheduled_ids!(501, vec![0]);
+		});
+}
+
+#[test]
+fn resume_should_fail_when_schedule_is_not_paused() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::resume(RuntimeOrigin::signed(ALICE), 0, Some(700)),
+				Error::<Test>::ScheduleNotPaused
+			);
+		});
+}
+
+#[test]
+fn resume_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), 0, Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::resume(RuntimeOrigin::signed(BOB), 0, Some(700)),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn terminate_should_remove_paused_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), schedule_id, Some(600)));
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), schedule_id, Option::None));
+
+			//Assert
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			assert!(DCA::paused(schedule_id).is_none());
+			assert_eq!(
+				0,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
@@ -0,0 +1,230 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::{create_bounded_vec, ScheduleBuilder};
+use crate::{
+	assert_balance, assert_number_of_executed_sell_trades, assert_scheduled_ids, Error, Event as DcaEvent, Order,
+	PriceCondition, PriceTrigger, Schedule, ScheduleId,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, Trade};
+use hydradx_traits::OraclePeriod;
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+use std::borrow::Borrow;
+
+fn sell_schedule_with_trigger(condition: PriceCondition) -> Schedule<AccountId, AssetId, BlockNumber> {
+	ScheduleBuilder::new()
+		.with_total_amount(5 * ONE)
+		.with_period(ONE_HUNDRED_BLOCKS)
+		.with_order(Order::Sell {
+			asset_in: HDX,
+			asset_out: BTC,
+			amount_in: ONE,
+			min_amount_out: Balance::MIN,
+			route: create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: BTC,
+			}]),
+		})
+		.with_price_trigger(PriceTrigger {
+			condition,
+			// mocked oracle price for this period is 0.88
+			oracle_period: OraclePeriod::TenMinutes,
+		})
+		.build()
+}
+
+#[test]
+fn schedule_should_fail_when_price_of_trigger_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = sell_schedule_with_trigger(PriceCondition::Below(FixedU128::from(0)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidPriceTrigger
+			);
+		});
+}
+
+#[test]
+fn sell_should_be_executed_when_price_is_below_trigger() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = sell_schedule_with_trigger(PriceCondition::Below(FixedU128::from_rational(90, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_scheduled_ids!(601, vec![0]);
+		});
+}
+
+#[test]
+fn sell_should_be_executed_when_price_is_above_trigger() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = sell_schedule_with_trigger(PriceCondition::Above(FixedU128::from_rational(85, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+		});
+}
+
+#[test]
+fn schedule_should_be_planned_for_next_period_when_price_is_not_below_trigger() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = sell_schedule_with_trigger(PriceCondition::Below(FixedU128::from_rational(85, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id: ScheduleId = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_scheduled_ids!(601, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id),
+				Some(total_amount - SELL_DCA_FEE_IN_NATIVE)
+			);
+			assert_eq!(
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE),
+				total_amount - SELL_DCA_FEE_IN_NATIVE
+			);
+			assert_balance!(TreasuryAccount::get(), HDX, SELL_DCA_FEE_IN_NATIVE);
+
+			expect_events(vec![
+				DcaEvent::PriceTriggerNotMet {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn schedule_should_be_planned_for_next_period_when_price_is_not_above_trigger() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = sell_schedule_with_trigger(PriceCondition::Above(FixedU128::from_rational(90, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_scheduled_ids!(601, vec![0]);
+			assert_eq!(DCA::retries_on_error(0), 0);
+		});
+}
+
+#[test]
+fn schedule_should_not_be_terminated_when_price_trigger_is_not_met_more_times_than_max_retries() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = sell_schedule_with_trigger(PriceCondition::Below(FixedU128::from_rational(85, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			for block in [501, 601, 701, 801, 901] {
+				set_to_blocknumber(block);
+			}
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert!(DCA::schedules(0).is_some());
+			assert_scheduled_ids!(1001, vec![0]);
+			assert_eq!(DCA::retries_on_error(0), 0);
+			assert_eq!(DCA::remaining_amounts(0), Some(5 * ONE - 5 * SELL_DCA_FEE_IN_NATIVE));
+		});
+}
+
+#[test]
+fn schedule_should_be_terminated_when_remaining_budget_does_not_cover_fee_and_price_trigger_is_not_met() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = sell_schedule_with_trigger(PriceCondition::Below(FixedU128::from_rational(85, 100)));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			crate::RemainingAmounts::<Test>::insert(0, SELL_DCA_FEE_IN_NATIVE - 1);
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert!(DCA::schedules(0).is_none());
+			assert!(DCA::schedule_ids_per_block(601).is_empty());
+		});
+}
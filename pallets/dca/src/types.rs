@@ -1,8 +1,9 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
+use hydradx_traits::OraclePeriod;
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -32,6 +33,68 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
+	/// Optional price condition which must be met to execute the trade.
+	/// If the condition is not met, the schedule is planned for the next period without counting as a retry.
+	pub price_trigger: Option<PriceTrigger>,
+}
+
+/// Price condition of a DCA schedule.
+///
+/// The price is the oracle price of `asset_out` denominated in `asset_in` of the order,
+/// i.e. the amount of `asset_in` paid for one unit of `asset_out`.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct PriceTrigger {
+	/// The condition the oracle price is compared with.
+	pub condition: PriceCondition,
+	/// The oracle period used to get the price.
+	pub oracle_period: OraclePeriod,
+}
+
+/// Changes of a live DCA schedule. Fields set to `None` are not changed.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct ScheduleAmendment<AssetId, BlockNumber> {
+	/// The new time period (in blocks) between two schedule executions.
+	pub period: Option<BlockNumber>,
+	/// The new slippage limit. `Some(None)` resets it to the default pallet configuration.
+	pub slippage: Option<Option<Permill>>,
+	/// The new price stability threshold. `Some(None)` resets it to the default pallet configuration.
+	pub stability_threshold: Option<Option<Permill>>,
+	/// The new route of the order. Empty route means that the default route is used.
+	pub route: Option<BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>>,
+	/// Change of the budget (`total_amount`) of the schedule.
+	pub budget: Option<BudgetChange>,
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
+pub enum BudgetChange {
+	/// Reserve additional amount and add it to the remaining budget.
+	TopUp(Balance),
+	/// Unreserve amount from the remaining budget.
+	Withdraw(Balance),
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
+pub enum PriceCondition {
+	/// Execute only when the price is below the given price (buy-the-dip).
+	Below(FixedU128),
+	/// Execute only when the price is above the given price (take-profit).
+	Above(FixedU128),
+}
+
+impl PriceCondition {
+	pub fn get_price(&self) -> FixedU128 {
+		match self {
+			PriceCondition::Below(price) => *price,
+			PriceCondition::Above(price) => *price,
+		}
+	}
+
+	pub fn is_met(&self, price: FixedU128) -> bool {
+		match self {
+			PriceCondition::Below(limit) => price < *limit,
+			PriceCondition::Above(limit) => price > *limit,
+		}
+	}
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
@@ -72,6 +135,13 @@ where
 		*asset_out
 	}
 
+	pub fn set_route(&mut self, new_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>) {
+		match self {
+			Order::Sell { route, .. } => *route = new_route,
+			Order::Buy { route, .. } => *route = new_route,
+		}
+	}
+
 	pub fn get_route_or_default<Provider: RouteProvider<AssetId>>(&self) -> Vec<Trade<AssetId>> {
 		let route = match &self {
 			Order::Sell { route, .. } => route,
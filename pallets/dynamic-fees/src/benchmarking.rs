@@ -0,0 +1,57 @@
+// This file is part of pallet-dynamic-fees.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::types::{AssetFeeConfig, LiquidityDepthParams};
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+use sp_runtime::FixedU128;
+
+benchmarks! {
+	where_clause { where T::AssetId: From<u32> }
+
+	set_asset_fee_config {
+		let asset_id: T::AssetId = 1u32.into();
+		let config = AssetFeeConfig {
+			asset_fee_params: FeeParams {
+				min_fee: T::Fee::from_percent(1),
+				max_fee: T::Fee::from_percent(10),
+				decay: FixedU128::from_rational(1, 100_000),
+				amplification: FixedU128::one(),
+			},
+			protocol_fee_params: FeeParams {
+				min_fee: T::Fee::from_percent(1),
+				max_fee: T::Fee::from_percent(10),
+				decay: FixedU128::from_rational(1, 100_000),
+				amplification: FixedU128::one(),
+			},
+			liquidity_depth: Some(LiquidityDepthParams {
+				reference_liquidity: 1_000_000_000_000_000,
+				max_premium: T::Fee::from_percent(1),
+			}),
+		};
+	}: _(RawOrigin::Root, asset_id, Some(config))
+	verify {
+		assert_eq!(AssetFeeConfiguration::<T>::get(asset_id), Some(config));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -40,8 +40,16 @@
 //! The module stores last calculated fees as tuple of `(Fee, Fee, Block number)` where the first item is asset fee,
 //! the second one is protocol fee and the third one is block number indicating when the two fees were updated.
 //!
+//! Fee parameters can be set per asset by `AuthorityOrigin`. Assets without own parameters use the default
+//! `AssetFeeParameters` and `ProtocolFeeParameters`. Per-asset parameters can optionally raise the minimum asset fee
+//! of shallow pools by a premium depending on the asset liquidity.
+//!
 //! ## Interface
 //!
+//! ### Dispatchable functions
+//!
+//! * `set_asset_fee_config` - sets or removes fee parameters of an asset.
+//!
 //! ### Update and retrieve fee
 //!
 //! The module provides implementation of GetByKey trait for `UpdateAndRetrieveFee` struct.
@@ -58,20 +66,24 @@
 use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 use orml_traits::GetByKey;
-use sp_runtime::traits::{BlockNumberProvider, Saturating};
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
 use sp_runtime::{FixedPointOperand, PerThing, SaturatedConversion};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 pub mod traits;
 pub mod types;
+pub mod weights;
 
 pub use pallet::*;
 
 use crate::traits::{Volume, VolumeProvider};
-use crate::types::{FeeEntry, FeeParams};
+use crate::types::{AssetFeeConfig, FeeEntry, FeeParams, LiquidityDepthParams};
 use hydra_dx_math::dynamic_fees::types::OracleEntry;
-use hydra_dx_math::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
+use hydra_dx_math::dynamic_fees::{liquidity_adjusted_min_fee, recalculate_asset_fee, recalculate_protocol_fee};
+pub use weights::WeightInfo;
 
 type Balance = u128;
 
@@ -81,8 +93,8 @@ pub mod pallet {
 	use crate::traits::VolumeProvider;
 	use crate::types::FeeEntry;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::BlockNumberFor;
-	use sp_runtime::traits::{BlockNumberProvider, Zero};
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+	use sp_runtime::traits::BlockNumberProvider;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -94,6 +106,12 @@ pub mod pallet {
 	pub type AssetFee<T: Config> =
 		StorageMap<_, Twox64Concat, T::AssetId, FeeEntry<T::Fee, BlockNumberFor<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn asset_fee_config)]
+	/// Fee parameters of an asset. Default parameters are used for assets without own parameters.
+	pub type AssetFeeConfiguration<T: Config> =
+		StorageMap<_, Twox64Concat, T::AssetId, AssetFeeConfig<T::Fee>, OptionQuery>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -115,16 +133,78 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type ProtocolFeeParameters: Get<FeeParams<Self::Fee>>;
+
+		/// Origin that can set fee parameters of an asset.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee parameters of an asset were set.
+		AssetFeeConfigSet {
+			asset_id: T::AssetId,
+			config: AssetFeeConfig<T::Fee>,
+		},
+		/// Fee parameters of an asset were removed. Default parameters are used.
+		AssetFeeConfigRemoved { asset_id: T::AssetId },
+	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Minimum fee is greater than maximum fee, amplification is zero or reference liquidity is zero.
+		InvalidFeeParameters,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Set fee parameters of an asset.
+		///
+		/// Fees of the asset are calculated using the given parameters instead of the default ones.
+		/// If `config` is `None`, the parameters of the asset are removed and default parameters are used.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`.
+		/// - `asset_id`: Asset id.
+		/// - `config`: Fee parameters of the asset.
+		///
+		/// Emits `AssetFeeConfigSet` or `AssetFeeConfigRemoved` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_fee_config())]
+		pub fn set_asset_fee_config(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			config: Option<AssetFeeConfig<T::Fee>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			match config {
+				Some(config) => {
+					ensure!(
+						Self::are_fee_params_valid(&config.asset_fee_params)
+							&& Self::are_fee_params_valid(&config.protocol_fee_params)
+							&& config
+								.liquidity_depth
+								.map_or(true, |depth| !depth.reference_liquidity.is_zero()),
+						Error::<T>::InvalidFeeParameters
+					);
+
+					AssetFeeConfiguration::<T>::insert(asset_id, config);
+					Self::deposit_event(Event::AssetFeeConfigSet { asset_id, config });
+				}
+				None => {
+					AssetFeeConfiguration::<T>::remove(asset_id);
+					Self::deposit_event(Event::AssetFeeConfigRemoved { asset_id });
+				}
+			}
+
+			Ok(())
+		}
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
@@ -158,8 +238,7 @@ where
 	fn update_fee(asset_id: T::AssetId) -> (T::Fee, T::Fee) {
 		let block_number = T::BlockNumberProvider::current_block_number();
 
-		let asset_fee_params = T::AssetFeeParameters::get();
-		let protocol_fee_params = T::ProtocolFeeParameters::get();
+		let (mut asset_fee_params, protocol_fee_params, liquidity_depth) = Self::fee_params(asset_id);
 
 		let current_fee_entry = Self::current_fees(asset_id).unwrap_or(FeeEntry {
 			asset_fee: asset_fee_params.min_fee,
@@ -183,6 +262,15 @@ where
 			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
 		};
 
+		if let Some(liquidity_depth) = liquidity_depth {
+			asset_fee_params.min_fee = liquidity_adjusted_min_fee(
+				liquidity,
+				asset_fee_params.min_fee,
+				asset_fee_params.max_fee,
+				liquidity_depth.into(),
+			);
+		}
+
 		let asset_fee = recalculate_asset_fee(
 			OracleEntry {
 				amount_in: volume.amount_in(),
@@ -216,6 +304,31 @@ where
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Returns asset fee parameters, protocol fee parameters and liquidity depth parameters of an asset.
+	/// Default parameters are returned if the asset has no own parameters.
+	pub fn fee_params(
+		asset_id: T::AssetId,
+	) -> (
+		FeeParams<T::Fee>,
+		FeeParams<T::Fee>,
+		Option<LiquidityDepthParams<T::Fee>>,
+	) {
+		match Self::asset_fee_config(asset_id) {
+			Some(config) => (
+				config.asset_fee_params,
+				config.protocol_fee_params,
+				config.liquidity_depth,
+			),
+			None => (T::AssetFeeParameters::get(), T::ProtocolFeeParameters::get(), None),
+		}
+	}
+
+	fn are_fee_params_valid(params: &FeeParams<T::Fee>) -> bool {
+		params.min_fee <= params.max_fee && !params.amplification.is_zero()
+	}
+}
+
 pub struct UpdateAndRetrieveFees<T: Config>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> GetByKey<T::AssetId, (T::Fee, T::Fee)> for UpdateAndRetrieveFees<T>
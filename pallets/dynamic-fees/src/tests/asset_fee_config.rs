@@ -0,0 +1,199 @@
+use crate::tests::mock::*;
+use crate::tests::oracle::SingleValueOracle;
+use crate::types::{AssetFeeConfig, FeeParams, LiquidityDepthParams};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::{DispatchError, FixedU128};
+
+const DOT: AssetId = 1;
+
+fn fee_params(min_fee: Fee, max_fee: Fee) -> FeeParams<Fee> {
+	FeeParams {
+		min_fee,
+		max_fee,
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+fn asset_fee_config(min_fee: Fee, max_fee: Fee) -> AssetFeeConfig<Fee> {
+	AssetFeeConfig {
+		asset_fee_params: fee_params(min_fee, max_fee),
+		protocol_fee_params: fee_params(min_fee, max_fee),
+		liquidity_depth: None,
+	}
+}
+
+#[test]
+fn set_asset_fee_config_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let config = asset_fee_config(Fee::from_percent(5), Fee::from_percent(20));
+
+		assert_ok!(DynamicFees::set_asset_fee_config(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(config)
+		));
+
+		assert_eq!(DynamicFees::asset_fee_config(HDX), Some(config));
+		System::assert_last_event(Event::AssetFeeConfigSet { asset_id: HDX, config }.into());
+	});
+}
+
+#[test]
+fn set_asset_fee_config_should_remove_config_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DynamicFees::set_asset_fee_config(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(asset_fee_config(Fee::from_percent(5), Fee::from_percent(20)))
+		));
+
+		assert_ok!(DynamicFees::set_asset_fee_config(RuntimeOrigin::root(), HDX, None));
+
+		assert_eq!(DynamicFees::asset_fee_config(HDX), None);
+		System::assert_last_event(Event::AssetFeeConfigRemoved { asset_id: HDX }.into());
+	});
+}
+
+#[test]
+fn set_asset_fee_config_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::set_asset_fee_config(
+				RuntimeOrigin::signed(1),
+				HDX,
+				Some(asset_fee_config(Fee::from_percent(5), Fee::from_percent(20)))
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_config_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::set_asset_fee_config(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(asset_fee_config(Fee::from_percent(20), Fee::from_percent(5)))
+			),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_config_should_fail_when_amplification_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut config = asset_fee_config(Fee::from_percent(5), Fee::from_percent(20));
+		config.protocol_fee_params.amplification = FixedU128::zero();
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_config(RuntimeOrigin::root(), HDX, Some(config)),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_config_should_fail_when_reference_liquidity_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut config = asset_fee_config(Fee::from_percent(5), Fee::from_percent(20));
+		config.liquidity_depth = Some(LiquidityDepthParams {
+			reference_liquidity: 0,
+			max_premium: Fee::from_percent(1),
+		});
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_config(RuntimeOrigin::root(), HDX, Some(config)),
+			Error::<Test>::InvalidFeeParameters
+		);
+	});
+}
+
+#[test]
+fn fees_should_be_calculated_with_asset_fee_config_when_set() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_protocol_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DynamicFees::set_asset_fee_config(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(asset_fee_config(Fee::from_percent(5), Fee::from_percent(20)))
+			));
+
+			let hdx_fee = retrieve_fee_entry(HDX);
+			let dot_fee = retrieve_fee_entry(DOT);
+
+			assert_eq!(hdx_fee, (Fee::from_percent(5), Fee::from_percent(5)));
+			assert_eq!(dot_fee, (Fee::from_percent(1), Fee::from_percent(1)));
+		});
+}
+
+#[test]
+fn asset_fee_should_include_liquidity_depth_premium_when_set() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let mut config = asset_fee_config(Fee::from_percent(1), Fee::from_percent(40));
+			config.liquidity_depth = Some(LiquidityDepthParams {
+				reference_liquidity: 50 * ONE,
+				max_premium: Fee::from_percent(4),
+			});
+			assert_ok!(DynamicFees::set_asset_fee_config(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(config)
+			));
+
+			let fee = retrieve_fee_entry(HDX);
+
+			// premium is halved as liquidity equals reference liquidity, protocol fee is not affected
+			assert_eq!(fee, (Fee::from_percent(3), Fee::from_percent(1)));
+		});
+}
+
+#[test]
+fn asset_fee_should_not_exceed_max_fee_when_liquidity_depth_premium_is_high() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let mut config = asset_fee_config(Fee::from_percent(1), Fee::from_percent(10));
+			config.liquidity_depth = Some(LiquidityDepthParams {
+				reference_liquidity: ONE,
+				max_premium: Fee::from_percent(50),
+			});
+			assert_ok!(DynamicFees::set_asset_fee_config(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(config)
+			));
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(10));
+		});
+}
@@ -1,7 +1,8 @@
+mod asset_fee_config;
 mod decay;
 mod fees;
 mod limits;
-mod mock;
+pub(crate) mod mock;
 mod oracle;
 mod oracle_fees;
 mod property;
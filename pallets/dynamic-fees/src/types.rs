@@ -3,11 +3,13 @@ use frame_support::pallet_prelude::*;
 use serde::{Deserialize, Serialize};
 use sp_runtime::FixedU128;
 
-use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
+use hydra_dx_math::dynamic_fees::types::{
+	FeeParams as MathFeeParams, LiquidityDepthParams as MathLiquidityDepthParams,
+};
 
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeParams<Fee> {
 	pub min_fee: Fee,
@@ -16,6 +18,25 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+/// Raises the minimum asset fee of shallow pools.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LiquidityDepthParams<Fee> {
+	/// Liquidity at which half of `max_premium` is added to the minimum asset fee.
+	pub reference_liquidity: u128,
+	/// Premium added to the minimum asset fee when liquidity is zero.
+	pub max_premium: Fee,
+}
+
+/// Fee parameters of an asset which override the default parameters.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetFeeConfig<Fee> {
+	pub asset_fee_params: FeeParams<Fee>,
+	pub protocol_fee_params: FeeParams<Fee>,
+	pub liquidity_depth: Option<LiquidityDepthParams<Fee>>,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeEntry<Fee, Block> {
@@ -34,3 +55,12 @@ impl<Fee> From<FeeParams<Fee>> for MathFeeParams<Fee> {
 		}
 	}
 }
+
+impl<Fee> From<LiquidityDepthParams<Fee>> for MathLiquidityDepthParams<Fee> {
+	fn from(value: LiquidityDepthParams<Fee>) -> Self {
+		MathLiquidityDepthParams {
+			reference_liquidity: value.reference_liquidity,
+			max_premium: value.max_premium,
+		}
+	}
+}
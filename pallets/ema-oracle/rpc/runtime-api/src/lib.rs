@@ -0,0 +1,45 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::Trade;
+pub use hydradx_traits::{AggregatedEntry, OraclePeriod, Source};
+pub use pallet_ema_oracle::{OracleError, Price};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query oracle entries and prices aggregated by the EMA oracle.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get oracle entry of `source` for given asset pair and period.
+		/// Price of the entry is `asset_a / asset_b`, updated to the state of the parent block.
+		fn get_entry(source: Source, asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>;
+
+		/// Get all asset pairs tracked by the oracle, together with their source.
+		/// A pair is tracked once it has an oracle entry, whichever whitelist allowed it.
+		fn tracked_pairs() -> Vec<(Source, (AssetId, AssetId))>;
+
+		/// Get price of `route` for given period, composed of oracle prices of each trade in the route.
+		/// Returns `None` if any of the oracles in the route is not available.
+		fn route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<Price>;
+	}
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle pallet.
+//!
+//! All methods accept an optional block hash, so oracle values can be queried at any historical block
+//! available to the node.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+pub use pallet_ema_oracle_rpc_runtime_api::EmaOracleApi as EmaOracleRuntimeApi;
+use pallet_ema_oracle_rpc_runtime_api::{AggregatedEntry, OracleError, OraclePeriod, Price, Source, Trade};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber> {
+	/// Get oracle entry of `source` for given asset pair and period.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+	/// Get all asset pairs tracked by the oracle, together with their source.
+	#[method(name = "emaOracle_trackedPairs")]
+	fn tracked_pairs(&self, at: Option<BlockHash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>>;
+
+	/// Get price of a route for given period.
+	#[method(name = "emaOracle_routePrice")]
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Price>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The oracle entry is not available.
+	OracleNotAvailable,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::OracleNotAvailable => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to query EMA oracle values.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	/// Create new `EmaOracle` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(message: &'static str, e: impl std::fmt::Debug) -> ErrorObject<'static> {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(format!("{:?}", e)))
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber> EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber>
+	for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<Block::Hash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entry(at, source, asset_a, asset_b, period)
+			.map_err(|e| runtime_error("Unable to query oracle entry.", e))?
+			.map_err(|e| {
+				let message = match e {
+					OracleError::NotPresent => "Oracle entry not found.",
+					OracleError::SameAsset => "Oracle is not defined for the same asset.",
+				};
+				ErrorObject::owned(Error::OracleNotAvailable.into(), message, Some(format!("{:?}", e)))
+			})
+	}
+
+	fn tracked_pairs(&self, at: Option<Block::Hash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.tracked_pairs(at)
+			.map_err(|e| runtime_error("Unable to query tracked pairs.", e))
+	}
+
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Price>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.route_price(at, route, period)
+			.map_err(|e| runtime_error("Unable to query route price.", e))
+	}
+}
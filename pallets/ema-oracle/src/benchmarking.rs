@@ -22,8 +22,12 @@ use super::*;
 pub const HDX: AssetId = 1_000;
 pub const DOT: AssetId = 2_000;
 
-use frame_benchmarking::benchmarks;
-use frame_support::{assert_ok, dispatch::RawOrigin, traits::Hooks};
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	dispatch::RawOrigin,
+	traits::{EnsureOrigin, Hooks},
+};
 
 #[cfg(test)]
 use pretty_assertions::assert_eq;
@@ -32,12 +36,64 @@ use crate::Pallet as EmaOracle;
 
 /// Default oracle source.
 const SOURCE: Source = *b"dummysrc";
+/// Source of oracles pushed by the external location.
+const EXTERNAL_SOURCE: Source = *b"external";
 
 fn fill_whitelist_storage<T: Config>(n: u32) {
 	for i in 0..n {
 		assert_ok!(EmaOracle::<T>::add_oracle(RawOrigin::Root.into(), SOURCE, (HDX, i)));
 	}
 }
+
+/// Returns successful external origin together with its location.
+fn external_origin<T: Config>() -> Result<(T::RuntimeOrigin, T::ExternalSourceLocation), BenchmarkError> {
+	let origin = T::ExternalOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let location = T::ExternalOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+	Ok((origin, location))
+}
+
+/// Registers the external location, tracks (HDX, DOT) for its source and records an external
+/// update in block 5. Returns the external origin and the block the next update can be sent in.
+fn init_external_oracle<T: Config>(
+	price: Price,
+	liquidity: Liquidity<Balance>,
+) -> Result<(T::RuntimeOrigin, BlockNumberFor<T>), BenchmarkError> {
+	let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
+	fill_whitelist_storage::<T>(max_entries - 1);
+
+	T::BenchmarkHelper::register_asset(HDX)?;
+	T::BenchmarkHelper::register_asset(DOT)?;
+
+	let (origin, location) = external_origin::<T>()?;
+	assert_ok!(EmaOracle::<T>::register_external_source(
+		RawOrigin::Root.into(),
+		location,
+		EXTERNAL_SOURCE
+	));
+	assert_ok!(EmaOracle::<T>::add_oracle(
+		RawOrigin::Root.into(),
+		EXTERNAL_SOURCE,
+		(HDX, DOT)
+	));
+
+	let initial_data_block: BlockNumberFor<T> = 5u32.into();
+	frame_system::Pallet::<T>::set_block_number(initial_data_block);
+	EmaOracle::<T>::on_initialize(initial_data_block);
+	assert_ok!(EmaOracle::<T>::update_external_price(
+		origin.clone(),
+		(HDX, DOT),
+		price,
+		liquidity
+	));
+	EmaOracle::<T>::on_finalize(initial_data_block);
+
+	// higher update time difference might make exponentiation more expensive
+	let block_num = initial_data_block.saturating_add(1_000_000u32.into());
+	frame_system::Pallet::<T>::set_block_number(block_num);
+	EmaOracle::<T>::on_initialize(block_num);
+
+	Ok((origin, block_num))
+}
 benchmarks! {
 	add_oracle {
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
@@ -379,5 +435,48 @@ benchmarks! {
 		}));
 	}
 
+	register_external_source {
+		let (_, location) = external_origin::<T>()?;
+	}: _(RawOrigin::Root, location.clone(), EXTERNAL_SOURCE)
+	verify {
+		assert_eq!(ExternalSources::<T>::get(location), Some(EXTERNAL_SOURCE));
+	}
+
+	remove_external_source {
+		let (_, location) = external_origin::<T>()?;
+		assert_ok!(EmaOracle::<T>::register_external_source(RawOrigin::Root.into(), location.clone(), EXTERNAL_SOURCE));
+	}: _(RawOrigin::Root, location.clone())
+	verify {
+		assert!(ExternalSources::<T>::get(location).is_none());
+	}
+
+	update_external_price {
+		let price = Price::new(1_000_000_000_000_000, 2_000_000_000_000_000);
+		let liquidity = Liquidity::new(1_000_000_000_000_000, 2_000_000_000_000_000);
+		let (origin, block_num) = init_external_oracle::<T>(price, liquidity)?;
+	}: _<T::RuntimeOrigin>(origin, (HDX, DOT), price, liquidity)
+	verify {
+		assert_eq!(LastExternalUpdate::<T>::get((EXTERNAL_SOURCE, (HDX, DOT))), Some(block_num));
+		assert_eq!(Accumulator::<T>::get().get(&(EXTERNAL_SOURCE, (HDX, DOT))).map(|e| e.price), Some(price));
+	}
+
+	force_update_external_price {
+		let price = Price::new(1_000_000_000_000_000, 2_000_000_000_000_000);
+		let liquidity = Liquidity::new(1_000_000_000_000_000, 2_000_000_000_000_000);
+		let (origin, _) = init_external_oracle::<T>(price, liquidity)?;
+		let location = T::ExternalOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+
+		// pending update of the pair has to be discarded
+		assert_ok!(EmaOracle::<T>::update_external_price(origin, (HDX, DOT), price, liquidity));
+
+		let new_price = Price::new(2_000_000_000_000_000, 1_000_000_000_000_000);
+	}: _(RawOrigin::Root, location, (HDX, DOT), new_price, liquidity)
+	verify {
+		assert!(Accumulator::<T>::get().is_empty());
+		for period in T::SupportedPeriods::get() {
+			assert_eq!(Oracles::<T>::get((EXTERNAL_SOURCE, (HDX, DOT), period)).map(|(e, _)| e.price), Some(new_price));
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
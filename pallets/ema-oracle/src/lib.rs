@@ -28,6 +28,14 @@
 //! Data is ingested by plugging the provided `OnActivityHandler` into callbacks provided by other
 //! pallets (e.g. xyk pallet).
 //!
+//! Data can also be pushed from outside of the chain (e.g. by sibling parachains via XCM) by
+//! `update_external_price`. Each external location has to be registered with its own source
+//! by `AuthorityOrigin` first. External updates are rate-limited per source and asset pair and
+//! rejected if they deviate too much from the `ExternalPriceReferencePeriod` oracle of the same
+//! source and asset pair. Accepted updates are aggregated into the same oracle periods as data from
+//! local sources. If the price moves beyond the allowed deviation, `AuthorityOrigin` can reset the
+//! oracles of the pair to a new price by `force_update_external_price`.
+//!
 //! It is meant to be used by other pallets via the `AggregatedOracle` and `AggregatedPriceOracle`
 //! traits.
 //!
@@ -75,6 +83,9 @@ use hydradx_traits::{
 	OnLiquidityChangedHandler, OnTradeHandler, Volume,
 };
 use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::Permill;
+use sp_core::U512;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -142,6 +153,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
 
+		/// Location of an external price source, e.g. location of a sibling parachain.
+		type ExternalSourceLocation: Parameter + Member + MaxEncodedLen;
+
+		/// Origin of external price updates. Returns location of the sender on success.
+		type ExternalOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::ExternalSourceLocation>;
+
+		/// Minimum number of blocks between two external updates of the same source and asset pair.
+		#[pallet::constant]
+		type ExternalUpdateMinInterval: Get<BlockNumberFor<Self>>;
+
+		/// Maximum allowed deviation of an external price from the reference price of the same oracle.
+		#[pallet::constant]
+		type MaxExternalPriceDeviation: Get<Permill>;
+
+		/// Oracle period whose price is used as the reference for the deviation check of external
+		/// updates. Must be one of `SupportedPeriods`.
+		#[pallet::constant]
+		type ExternalPriceReferencePeriod: Get<OraclePeriod>;
+
+		/// Sources used by local pallets. These can't be assigned to external locations.
+		type ReservedSources: Contains<Source>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
@@ -151,6 +184,20 @@ pub mod pallet {
 		TooManyUniqueEntries,
 		OnTradeValueZero,
 		OracleNotFound,
+		/// External source location is already registered.
+		ExternalSourceAlreadyRegistered,
+		/// Sender of an external update is not a registered external source.
+		ExternalSourceNotFound,
+		/// External update contains same assets, zero price or zero liquidity.
+		InvalidExternalData,
+		/// Oracle for the asset pair is not tracked for the external source.
+		ExternalOracleNotWhitelisted,
+		/// External update was received before `ExternalUpdateMinInterval` elapsed.
+		ExternalUpdateTooFrequent,
+		/// External price deviates from the reference price by more than `MaxExternalPriceDeviation`.
+		ExternalPriceOutlier,
+		/// Source is reserved for a local source of data.
+		ExternalSourceReserved,
 	}
 
 	#[pallet::event]
@@ -160,6 +207,30 @@ pub mod pallet {
 		AddedToWhitelist { source: Source, assets: (AssetId, AssetId) },
 		/// Oracle was removed from the whitelist.
 		RemovedFromWhitelist { source: Source, assets: (AssetId, AssetId) },
+		/// External location was registered as oracle source.
+		ExternalSourceRegistered {
+			location: T::ExternalSourceLocation,
+			source: Source,
+		},
+		/// External location was removed from oracle sources.
+		ExternalSourceRemoved {
+			location: T::ExternalSourceLocation,
+			source: Source,
+		},
+		/// External price update was accepted.
+		ExternalPriceUpdated {
+			source: Source,
+			assets: (AssetId, AssetId),
+			price: Price,
+			liquidity: Liquidity<Balance>,
+		},
+		/// Oracles of an external source were reset to a new price.
+		ExternalPriceForced {
+			source: Source,
+			assets: (AssetId, AssetId),
+			price: Price,
+			liquidity: Liquidity<Balance>,
+		},
 	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
@@ -192,6 +263,16 @@ pub mod pallet {
 	pub type WhitelistedAssets<T: Config> =
 		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
 
+	/// External locations allowed to push oracle updates, mapped to the source of their oracles.
+	#[pallet::storage]
+	pub type ExternalSources<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ExternalSourceLocation, Source, OptionQuery>;
+
+	/// Block number of the last accepted external update of a source and asset pair.
+	#[pallet::storage]
+	pub type LastExternalUpdate<T: Config> =
+		StorageMap<_, Twox64Concat, (Source, (AssetId, AssetId)), BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -241,6 +322,10 @@ pub mod pallet {
 				T::MaxUniqueEntries::get() > 0,
 				"At least one trade should be possible per block."
 			);
+			assert!(
+				T::SupportedPeriods::get().contains(&T::ExternalPriceReferencePeriod::get()),
+				"External price reference period has to be supported."
+			);
 		}
 	}
 
@@ -289,10 +374,208 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Register external location as a source of oracle updates.
+		///
+		/// Oracles of the location are stored under `source`, which should not be used by any
+		/// other source of data. Sources in `ReservedSources` are rejected.
+		///
+		/// Emits `ExternalSourceRegistered` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_external_source())]
+		pub fn register_external_source(
+			origin: OriginFor<T>,
+			location: T::ExternalSourceLocation,
+			source: Source,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!T::ReservedSources::contains(&source),
+				Error::<T>::ExternalSourceReserved
+			);
+			ensure!(
+				!ExternalSources::<T>::contains_key(&location),
+				Error::<T>::ExternalSourceAlreadyRegistered
+			);
+
+			ExternalSources::<T>::insert(&location, source);
+
+			Self::deposit_event(Event::ExternalSourceRegistered { location, source });
+
+			Ok(())
+		}
+
+		/// Remove external location from sources of oracle updates.
+		///
+		/// Existing oracles of the source are kept.
+		///
+		/// Emits `ExternalSourceRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_external_source())]
+		pub fn remove_external_source(origin: OriginFor<T>, location: T::ExternalSourceLocation) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let source = ExternalSources::<T>::take(&location).ok_or(Error::<T>::ExternalSourceNotFound)?;
+
+			Self::deposit_event(Event::ExternalSourceRemoved { location, source });
+
+			Ok(())
+		}
+
+		/// Push price and liquidity of an asset pair from a registered external source.
+		///
+		/// `price` is the price of `assets.1` denominated in `assets.0` and `liquidity` contains
+		/// liquidity of `assets.0` and `assets.1` respectively.
+		///
+		/// The update is recorded in the same way as a liquidity change of a local source and
+		/// integrated into all supported oracle periods at the end of the block.
+		///
+		/// The price has to be within `MaxExternalPriceDeviation` of the current price of the
+		/// `ExternalPriceReferencePeriod` oracle.
+		///
+		/// Emits `ExternalPriceUpdated` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_external_price())]
+		pub fn update_external_price(
+			origin: OriginFor<T>,
+			assets: (AssetId, AssetId),
+			price: Price,
+			liquidity: Liquidity<Balance>,
+		) -> DispatchResult {
+			let location = T::ExternalOrigin::ensure_origin(origin)?;
+			let source = ExternalSources::<T>::get(&location).ok_or(Error::<T>::ExternalSourceNotFound)?;
+
+			let (asset_a, asset_b) = assets;
+			Self::validate_external_data(source, assets, price, liquidity)?;
+
+			let pair = ordered_pair(asset_a, asset_b);
+			let now = T::BlockNumberProvider::current_block_number();
+
+			if let Some(last_update) = LastExternalUpdate::<T>::get((source, pair)) {
+				ensure!(
+					now >= last_update.saturating_add(T::ExternalUpdateMinInterval::get()),
+					Error::<T>::ExternalUpdateTooFrequent
+				);
+			}
+
+			let normalized_price = determine_normalized_price(asset_a, asset_b, price);
+			if let Some((reference, _)) = Self::get_updated_entry(source, pair, T::ExternalPriceReferencePeriod::get())
+			{
+				ensure!(
+					!exceeds_max_deviation(normalized_price, reference.price, T::MaxExternalPriceDeviation::get()),
+					Error::<T>::ExternalPriceOutlier
+				);
+			}
+
+			let entry = OracleEntry {
+				price: normalized_price,
+				volume: Volume::default(),
+				liquidity: determine_normalized_liquidity(asset_a, asset_b, liquidity.a, liquidity.b),
+				updated_at: now,
+			};
+			Self::on_entry(source, pair, entry).map_err(|_| Error::<T>::TooManyUniqueEntries)?;
+
+			LastExternalUpdate::<T>::insert((source, pair), now);
+
+			Self::deposit_event(Event::ExternalPriceUpdated {
+				source,
+				assets,
+				price,
+				liquidity,
+			});
+
+			Ok(())
+		}
+
+		/// Reset oracles of an asset pair of a registered external location to a new price.
+		///
+		/// Can be used by `AuthorityOrigin` when the price moved beyond `MaxExternalPriceDeviation`
+		/// and updates of the external source are rejected. All supported periods are set to the
+		/// new price and liquidity, pending data of the pair in the current block is discarded.
+		///
+		/// Deviation and rate limit checks are not applied.
+		///
+		/// Emits `ExternalPriceForced` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_update_external_price())]
+		pub fn force_update_external_price(
+			origin: OriginFor<T>,
+			location: T::ExternalSourceLocation,
+			assets: (AssetId, AssetId),
+			price: Price,
+			liquidity: Liquidity<Balance>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+			let source = ExternalSources::<T>::get(&location).ok_or(Error::<T>::ExternalSourceNotFound)?;
+
+			let (asset_a, asset_b) = assets;
+			Self::validate_external_data(source, assets, price, liquidity)?;
+
+			let pair = ordered_pair(asset_a, asset_b);
+			let now = T::BlockNumberProvider::current_block_number();
+			let entry = OracleEntry {
+				price: determine_normalized_price(asset_a, asset_b, price),
+				volume: Volume::default(),
+				liquidity: determine_normalized_liquidity(asset_a, asset_b, liquidity.a, liquidity.b),
+				updated_at: now,
+			};
+
+			Accumulator::<T>::mutate(|accumulator| {
+				accumulator.remove(&(source, pair));
+			});
+			for period in T::SupportedPeriods::get().into_iter() {
+				Oracles::<T>::mutate((source, pair, period), |oracle| {
+					let init = oracle.as_ref().map(|(_, init)| *init).unwrap_or(now);
+					*oracle = Some((entry.clone(), init));
+				});
+			}
+
+			LastExternalUpdate::<T>::insert((source, pair), now);
+
+			Self::deposit_event(Event::ExternalPriceForced {
+				source,
+				assets,
+				price,
+				liquidity,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Ensure that external data are valid and the oracle of the asset pair is tracked for `source`.
+	fn validate_external_data(
+		source: Source,
+		(asset_a, asset_b): (AssetId, AssetId),
+		price: Price,
+		liquidity: Liquidity<Balance>,
+	) -> DispatchResult {
+		ensure!(
+			asset_a != asset_b
+				&& !price.n.is_zero()
+				&& !price.d.is_zero()
+				&& !liquidity.a.is_zero()
+				&& !liquidity.b.is_zero(),
+			Error::<T>::InvalidExternalData
+		);
+		ensure!(
+			T::OracleWhitelist::contains(&(source, asset_a, asset_b)),
+			Error::<T>::ExternalOracleNotWhitelisted
+		);
+		Ok(())
+	}
+
+	/// Returns all asset pairs with an oracle entry, together with their source.
+	pub fn tracked_pairs() -> Vec<(Source, (AssetId, AssetId))> {
+		let pairs: BTreeSet<(Source, (AssetId, AssetId))> = Oracles::<T>::iter_keys()
+			.map(|(source, assets, _)| (source, assets))
+			.collect();
+		pairs.into_iter().collect()
+	}
+
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
 	pub(crate) fn on_entry(
@@ -583,6 +866,20 @@ pub fn determine_normalized_liquidity(
 	}
 }
 
+/// Return `true` if `price` differs from `reference` by more than `max_deviation` of `reference`.
+pub(crate) fn exceeds_max_deviation(price: Price, reference: Price, max_deviation: Permill) -> bool {
+	let price_n = U512::from(price.n).saturating_mul(U512::from(reference.d));
+	let reference_n = U512::from(reference.n).saturating_mul(U512::from(price.d));
+	let diff = if price_n > reference_n {
+		price_n - reference_n
+	} else {
+		reference_n - price_n
+	};
+
+	diff.saturating_mul(U512::from(Permill::ACCURACY))
+		> reference_n.saturating_mul(U512::from(max_deviation.deconstruct()))
+}
+
 /// Return ordered asset tuple (A,B) where A < B
 /// Used in storage
 /// The implementation is the same as for AssetPair
@@ -0,0 +1,477 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use mock::{expect_events, EmaOracle, RuntimeOrigin, Test, DOT, HDX};
+
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+/// Account used as the location of the external price feed.
+const PARA: u64 = 2_034;
+const EXTERNAL_SOURCE: Source = *b"external";
+
+fn new_test_ext_with_external_source() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::register_external_source(
+			RuntimeOrigin::root(),
+			PARA,
+			EXTERNAL_SOURCE
+		));
+	});
+	ext
+}
+
+#[test]
+fn register_external_source_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(EmaOracle::register_external_source(
+			RuntimeOrigin::root(),
+			PARA,
+			EXTERNAL_SOURCE
+		));
+
+		assert_eq!(ExternalSources::<Test>::get(PARA), Some(EXTERNAL_SOURCE));
+		expect_events(vec![Event::ExternalSourceRegistered {
+			location: PARA,
+			source: EXTERNAL_SOURCE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn register_external_source_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::register_external_source(RuntimeOrigin::signed(PARA), PARA, EXTERNAL_SOURCE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn register_external_source_should_fail_when_location_already_registered() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_noop!(
+			EmaOracle::register_external_source(RuntimeOrigin::root(), PARA, *b"externa2"),
+			Error::<Test>::ExternalSourceAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_external_source_should_fail_when_source_is_reserved() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::register_external_source(RuntimeOrigin::root(), PARA, SOURCE),
+			Error::<Test>::ExternalSourceReserved
+		);
+	});
+}
+
+#[test]
+fn remove_external_source_should_work() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_ok!(EmaOracle::remove_external_source(RuntimeOrigin::root(), PARA));
+
+		assert_eq!(ExternalSources::<Test>::get(PARA), None);
+		expect_events(vec![Event::ExternalSourceRemoved {
+			location: PARA,
+			source: EXTERNAL_SOURCE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_external_source_should_fail_when_location_not_registered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_external_source(RuntimeOrigin::root(), PARA),
+			Error::<Test>::ExternalSourceNotFound
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_add_entry_to_accumulator() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let price = Price::new(1, 2);
+		let liquidity = Liquidity::new(1_000, 2_000);
+
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			price,
+			liquidity
+		));
+
+		assert_eq!(
+			get_accumulator_entry(EXTERNAL_SOURCE, (HDX, DOT)),
+			Some(OracleEntry {
+				price,
+				volume: Volume::default(),
+				liquidity,
+				updated_at: 1,
+			})
+		);
+		assert_eq!(LastExternalUpdate::<Test>::get((EXTERNAL_SOURCE, (HDX, DOT))), Some(1));
+		expect_events(vec![Event::ExternalPriceUpdated {
+			source: EXTERNAL_SOURCE,
+			assets: (HDX, DOT),
+			price,
+			liquidity,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_external_price_should_normalize_asset_order() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(DOT, HDX),
+			Price::new(1, 2),
+			Liquidity::new(2_000, 1_000)
+		));
+
+		assert_eq!(
+			get_accumulator_entry(EXTERNAL_SOURCE, (HDX, DOT)),
+			Some(OracleEntry {
+				price: Price::new(2, 1),
+				volume: Volume::default(),
+				liquidity: Liquidity::new(1_000, 2_000),
+				updated_at: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_update_oracles_on_finalize() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let price = Price::new(1, 2);
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			price,
+			Liquidity::new(1_000, 2_000)
+		));
+
+		EmaOracle::on_finalize(1);
+
+		for period in supported_periods() {
+			let (entry, _) = Oracles::<Test>::get((EXTERNAL_SOURCE, (HDX, DOT), period))
+				.unwrap_or_else(|| panic!("oracle for {period:?} should exist"));
+			assert_eq!(entry.price, price);
+		}
+	});
+}
+
+#[test]
+fn update_external_price_should_fail_when_source_not_registered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_price(
+				RuntimeOrigin::signed(PARA),
+				(HDX, DOT),
+				Price::new(1, 2),
+				Liquidity::new(1_000, 2_000)
+			),
+			Error::<Test>::ExternalSourceNotFound
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_fail_when_origin_is_not_external() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_price(
+				RuntimeOrigin::root(),
+				(HDX, DOT),
+				Price::new(1, 2),
+				Liquidity::new(1_000, 2_000)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_fail_when_data_is_invalid() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let origin = || RuntimeOrigin::signed(PARA);
+		let liquidity = Liquidity::new(1_000, 2_000);
+
+		assert_noop!(
+			EmaOracle::update_external_price(origin(), (HDX, HDX), Price::new(1, 1), liquidity),
+			Error::<Test>::InvalidExternalData
+		);
+		assert_noop!(
+			EmaOracle::update_external_price(origin(), (HDX, DOT), Price::new(0, 1), liquidity),
+			Error::<Test>::InvalidExternalData
+		);
+		assert_noop!(
+			EmaOracle::update_external_price(origin(), (HDX, DOT), Price::new(1, 0), liquidity),
+			Error::<Test>::InvalidExternalData
+		);
+		assert_noop!(
+			EmaOracle::update_external_price(origin(), (HDX, DOT), Price::new(1, 2), Liquidity::new(0, 2_000)),
+			Error::<Test>::InvalidExternalData
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_fail_when_pair_not_whitelisted() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_price(
+				RuntimeOrigin::signed(PARA),
+				(HDX, INSUFFICIENT_ASSET),
+				Price::new(1, 2),
+				Liquidity::new(1_000, 2_000)
+			),
+			Error::<Test>::ExternalOracleNotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_be_rate_limited() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let price = Price::new(1, 2);
+		let liquidity = Liquidity::new(1_000, 2_000);
+		let interval = <Test as Config>::ExternalUpdateMinInterval::get();
+
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			price,
+			liquidity
+		));
+		EmaOracle::on_finalize(1);
+
+		System::set_block_number(interval);
+		assert_noop!(
+			EmaOracle::update_external_price(RuntimeOrigin::signed(PARA), (DOT, HDX), price, liquidity),
+			Error::<Test>::ExternalUpdateTooFrequent
+		);
+
+		System::set_block_number(1 + interval);
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			price,
+			liquidity
+		));
+		assert_eq!(
+			LastExternalUpdate::<Test>::get((EXTERNAL_SOURCE, (HDX, DOT))),
+			Some(1 + interval)
+		);
+	});
+}
+
+#[test]
+fn update_external_price_should_reject_outliers() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let liquidity = Liquidity::new(1_000, 2_000);
+		let interval = <Test as Config>::ExternalUpdateMinInterval::get();
+
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(100, 100),
+			liquidity
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(1 + interval);
+
+		// max deviation in the mock is 10%
+		assert_noop!(
+			EmaOracle::update_external_price(RuntimeOrigin::signed(PARA), (HDX, DOT), Price::new(111, 100), liquidity),
+			Error::<Test>::ExternalPriceOutlier
+		);
+		assert_noop!(
+			EmaOracle::update_external_price(RuntimeOrigin::signed(PARA), (HDX, DOT), Price::new(89, 100), liquidity),
+			Error::<Test>::ExternalPriceOutlier
+		);
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(105, 100),
+			liquidity
+		));
+	});
+}
+
+#[test]
+fn update_external_price_should_compare_to_reference_period_price() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let liquidity = Liquidity::new(1_000, 2_000);
+		let interval = <Test as Config>::ExternalUpdateMinInterval::get();
+
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(100, 100),
+			liquidity
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(1 + interval);
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(108, 100),
+			liquidity
+		));
+		EmaOracle::on_finalize(1 + interval);
+		System::set_block_number(1 + 2 * interval);
+
+		// within 10% of the last block price, but not of the `TenMinutes` oracle price
+		assert_noop!(
+			EmaOracle::update_external_price(RuntimeOrigin::signed(PARA), (HDX, DOT), Price::new(116, 100), liquidity),
+			Error::<Test>::ExternalPriceOutlier
+		);
+	});
+}
+
+#[test]
+fn force_update_external_price_should_reset_oracles() {
+	new_test_ext_with_external_source().execute_with(|| {
+		let liquidity = Liquidity::new(1_000, 2_000);
+		let interval = <Test as Config>::ExternalUpdateMinInterval::get();
+
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(100, 100),
+			liquidity
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(2);
+		assert_ok!(EmaOracle::on_entry(
+			EXTERNAL_SOURCE,
+			(HDX, DOT),
+			OracleEntry {
+				price: Price::new(100, 100),
+				volume: Volume::default(),
+				liquidity,
+				updated_at: 2,
+			}
+		));
+
+		let price = Price::new(150, 100);
+		assert_ok!(EmaOracle::force_update_external_price(
+			RuntimeOrigin::root(),
+			PARA,
+			(DOT, HDX),
+			Price::new(100, 150),
+			Liquidity::new(2_000, 1_000)
+		));
+
+		assert_eq!(get_accumulator_entry(EXTERNAL_SOURCE, (HDX, DOT)), None);
+		for period in supported_periods() {
+			assert_eq!(
+				Oracles::<Test>::get((EXTERNAL_SOURCE, (HDX, DOT), period)),
+				Some((
+					OracleEntry {
+						price,
+						volume: Volume::default(),
+						liquidity,
+						updated_at: 2,
+					},
+					1
+				))
+			);
+		}
+		assert_eq!(LastExternalUpdate::<Test>::get((EXTERNAL_SOURCE, (HDX, DOT))), Some(2));
+		expect_events(vec![Event::ExternalPriceForced {
+			source: EXTERNAL_SOURCE,
+			assets: (DOT, HDX),
+			price: Price::new(100, 150),
+			liquidity: Liquidity::new(2_000, 1_000),
+		}
+		.into()]);
+
+		// updates close to the new price are accepted again
+		System::set_block_number(2 + interval);
+		assert_ok!(EmaOracle::update_external_price(
+			RuntimeOrigin::signed(PARA),
+			(HDX, DOT),
+			Price::new(155, 100),
+			liquidity
+		));
+	});
+}
+
+#[test]
+fn force_update_external_price_should_fail_when_origin_is_not_authority() {
+	new_test_ext_with_external_source().execute_with(|| {
+		assert_noop!(
+			EmaOracle::force_update_external_price(
+				RuntimeOrigin::signed(PARA),
+				PARA,
+				(HDX, DOT),
+				Price::new(1, 2),
+				Liquidity::new(1_000, 2_000)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_update_external_price_should_fail_when_location_not_registered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::force_update_external_price(
+				RuntimeOrigin::root(),
+				PARA,
+				(HDX, DOT),
+				Price::new(1, 2),
+				Liquidity::new(1_000, 2_000)
+			),
+			Error::<Test>::ExternalSourceNotFound
+		);
+	});
+}
+
+#[test]
+fn exceeds_max_deviation_should_compare_relative_to_reference() {
+	let reference = Price::new(1_000, 2_000);
+	let max = Permill::from_percent(10);
+
+	assert!(!exceeds_max_deviation(Price::new(1_000, 2_000), reference, max));
+	assert!(!exceeds_max_deviation(Price::new(1_100, 2_000), reference, max));
+	assert!(!exceeds_max_deviation(Price::new(900, 2_000), reference, max));
+	assert!(exceeds_max_deviation(Price::new(1_101, 2_000), reference, max));
+	assert!(exceeds_max_deviation(Price::new(899, 2_000), reference, max));
+	assert!(!exceeds_max_deviation(
+		Price::new(u128::MAX, u128::MAX),
+		Price::new(1, 1),
+		max
+	));
+}
@@ -23,11 +23,11 @@ use frame_support::parameter_types;
 use frame_support::sp_runtime::{
 	bounded_vec,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 use frame_support::traits::{Contains, Everything};
 use frame_support::BoundedVec;
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::OraclePeriod::{self, *};
 use hydradx_traits::Source;
 use hydradx_traits::{AssetPairAccountIdFor, Liquidity, Volume};
@@ -125,6 +125,16 @@ impl AssetPairAccountIdFor<AssetId, u64> for AssetPairAccountIdTest {
 
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = bounded_vec![LastBlock, TenMinutes, Day, Week];
+	pub const ExternalUpdateMinInterval: BlockNumber = 5;
+	pub const MaxExternalPriceDeviation: Permill = Permill::from_percent(10);
+	pub const ExternalPriceReferencePeriod: OraclePeriod = TenMinutes;
+}
+
+pub struct ReservedSources;
+impl Contains<Source> for ReservedSources {
+	fn contains(source: &Source) -> bool {
+		source == &crate::tests::SOURCE
+	}
 }
 
 pub struct OracleWhitelist;
@@ -141,6 +151,12 @@ impl Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = OracleWhitelist;
 	type MaxUniqueEntries = ConstU32<45>;
+	type ExternalSourceLocation = AccountId;
+	type ExternalOrigin = EnsureSigned<AccountId>;
+	type ExternalUpdateMinInterval = ExternalUpdateMinInterval;
+	type MaxExternalPriceDeviation = MaxExternalPriceDeviation;
+	type ExternalPriceReferencePeriod = ExternalPriceReferencePeriod;
+	type ReservedSources = ReservedSources;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -16,6 +16,7 @@
 // limitations under the License.
 
 mod add_and_remove_oracle;
+mod external_source;
 mod invariants;
 mod mock;
 
@@ -118,6 +119,44 @@ fn genesis_config_works() {
 		});
 }
 
+#[test]
+fn tracked_pairs_should_list_each_pair_with_oracle_entry_once() {
+	ExtBuilder::default()
+		.with_initial_data(vec![
+			(
+				SOURCE,
+				(HDX, DOT),
+				(1_000_000, 1).into(),
+				Liquidity::new(2_000_000, 2_000_000_000),
+			),
+			(
+				SOURCE,
+				(HDX, ACA),
+				(3_000_000, 1).into(),
+				Liquidity::new(4_000_000, 4_000_000_000),
+			),
+		])
+		.build()
+		.execute_with(|| {
+			let mut expected = vec![(SOURCE, ordered_pair(HDX, DOT)), (SOURCE, ordered_pair(HDX, ACA))];
+			expected.sort();
+			assert_eq!(EmaOracle::tracked_pairs(), expected);
+		});
+}
+
+#[test]
+fn tracked_pairs_should_include_pair_once_oracle_is_updated() {
+	new_test_ext().execute_with(|| {
+		assert!(EmaOracle::tracked_pairs().is_empty());
+
+		System::set_block_number(5);
+		assert_ok!(EmaOracle::on_trade(SOURCE, ordered_pair(HDX, DOT), ORACLE_ENTRY_1));
+		EmaOracle::on_finalize(5);
+
+		assert_eq!(EmaOracle::tracked_pairs(), vec![(SOURCE, ordered_pair(HDX, DOT))]);
+	});
+}
+
 #[test]
 fn on_trade_should_work() {
 	new_test_ext().execute_with(|| {
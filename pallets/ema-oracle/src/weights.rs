@@ -15,32 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_ema_oracle`
+//! Weights for `pallet_ema_oracle`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-05-23, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/hydradx
-// benchmark
-// pallet
-// --wasm-execution=compiled
-// --pallet
-// *
-// --extrinsic
-// *
-// --heap-pages
-// 4096
-// --steps
-// 50
-// --repeat
-// 20
-// --template=scripts/pallet-weight-template.hbs
-// --output
-// weights*
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-05-23
+//! (STEPS: `50`, REPEAT: `20`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -61,6 +40,10 @@ pub trait WeightInfo {
 	fn on_trade_multiple_tokens(b: u32) -> Weight;
 	fn on_liquidity_changed_multiple_tokens(b: u32) -> Weight;
 	fn get_entry() -> Weight;
+	fn register_external_source() -> Weight;
+	fn remove_external_source() -> Weight;
+	fn update_external_price() -> Weight;
+	fn force_update_external_price() -> Weight;
 }
 
 /// Weights for `pallet_ema_oracle` using the HydraDX node and recommended hardware.
@@ -160,4 +143,72 @@ impl WeightInfo for () {
 		// Minimum execution time: 17_768_000 picoseconds.
 		Weight::from_parts(18_090_000, 6294).saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `EmaOracle::ExternalSources` (r:1 w:1)
+	/// Proof: `EmaOracle::ExternalSources` (`max_values`: None, `max_size`: Some(686), added: 3161, mode: `MaxEncodedLen`)
+	fn register_external_source() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4151`
+		// Minimum execution time: 12_904_000 picoseconds.
+		Weight::from_parts(13_231_000, 4151)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::ExternalSources` (r:1 w:1)
+	/// Proof: `EmaOracle::ExternalSources` (`max_values`: None, `max_size`: Some(686), added: 3161, mode: `MaxEncodedLen`)
+	fn remove_external_source() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `227`
+		//  Estimated: `4151`
+		// Minimum execution time: 14_126_000 picoseconds.
+		Weight::from_parts(14_502_000, 4151)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::ExternalSources` (r:1 w:0)
+	/// Proof: `EmaOracle::ExternalSources` (`max_values`: None, `max_size`: Some(686), added: 3161, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
+	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::LastExternalUpdate` (r:1 w:1)
+	/// Proof: `EmaOracle::LastExternalUpdate` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	fn update_external_price() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `7012`
+		//  Estimated: `8911`
+		// Minimum execution time: 58_417_000 picoseconds.
+		Weight::from_parts(59_306_000, 8911)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EmaOracle::ExternalSources` (r:1 w:0)
+	/// Proof: `EmaOracle::ExternalSources` (`max_values`: None, `max_size`: Some(686), added: 3161, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
+	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:3 w:3)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::LastExternalUpdate` (r:0 w:1)
+	/// Proof: `EmaOracle::LastExternalUpdate` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn force_update_external_price() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `7124`
+		//  Estimated: `9446`
+		// Minimum execution time: 49_872_000 picoseconds.
+		Weight::from_parts(50_715_000, 9446)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
@@ -22,6 +22,7 @@ use crate::Pallet as LBP;
 use hydradx_traits::router::{PoolType, TradeExecution};
 
 use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 
 const SEED: u32 = 1;
@@ -103,6 +104,41 @@ benchmarks! {
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &caller), 1000000000000000);
 	}
 
+	set_post_sale_action {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let action = Some(PostSaleAction::XykPool);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), action)
+	verify {
+		assert_eq!(LBP::<T>::pool_data(&pool_id).unwrap().post_sale_action, action);
+	}
+
+	approve_post_sale_action {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let action = PostSaleAction::Omnipool { weight_cap: Permill::from_percent(10) };
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		let start = BlockNumberFor::<T>::from(10u32);
+		let end = BlockNumberFor::<T>::from(20u32);
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::set_post_sale_action(RawOrigin::Signed(caller).into(), pool_id.clone(), Some(action))?;
+
+		// worst case is a full list of other pending pools
+		let pending: Vec<PoolId<T>> = (1..MAX_PENDING_POST_SALE_ACTIONS).map(|i| account("pool", i, SEED)).collect();
+		PendingPostSaleActions::<T>::put(BoundedVec::truncate_from(pending));
+
+	}: _(RawOrigin::Root, pool_id.clone(), action)
+	verify {
+		assert!(LBP::<T>::pending_post_sale_actions().contains(&pool_id));
+	}
+
 	sell {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
@@ -296,6 +332,8 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_update_pool_data());
 			assert_ok!(Pallet::<Test>::test_benchmark_add_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_post_sale_action());
+			assert_ok!(Pallet::<Test>::test_benchmark_approve_post_sale_action());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
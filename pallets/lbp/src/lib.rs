@@ -24,17 +24,20 @@
 pub use crate::types::{Amount, AssetId, AssetPair, Balance};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, CheckedMul, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, FixedPointNumber, FixedU128, Permill, RuntimeDebug, TransactionOutcome,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
+	storage::with_transaction,
 	traits::{EnsureOrigin, Get, LockIdentifier},
 	transactional,
+	weights::Weight,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::types::LBPWeight;
 use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
@@ -60,6 +63,7 @@ pub mod weights;
 #[cfg(test)]
 mod invariants;
 
+pub mod migration;
 mod provider;
 mod trade_execution;
 pub mod types;
@@ -76,6 +80,29 @@ type PoolId<T> = <T as frame_system::Config>::AccountId;
 pub enum WeightCurveType {
 	#[default]
 	Linear,
+	/// Weights follow `(1 - e^(-steepness * t)) / (1 - e^(-steepness))` of the total change, where `t` is
+	/// the elapsed fraction of the sale. Most of the change happens early and the weights then approach
+	/// the final weight exponentially.
+	/// `steepness` has to be within `(0, MAX_CURVE_STEEPNESS]`.
+	Exponential { steepness: FixedU128 },
+	/// Weights follow `ln(1 + steepness * t) / ln(1 + steepness)` of the total change, where `t` is
+	/// the elapsed fraction of the sale. The change slows down more gradually than with the exponential curve
+	/// of the same steepness, so more of it is left for the end of the sale.
+	/// `steepness` has to be within `(0, MAX_CURVE_STEEPNESS]`.
+	Logarithmic { steepness: FixedU128 },
+	/// Weights change in `steps` equal jumps distributed evenly over the sale.
+	Step { steps: u32 },
+}
+
+/// Action executed automatically when the sale ends.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum PostSaleAction {
+	/// Remaining liquidity is used to create a new XYK pool. Shares are minted to the pool owner.
+	XykPool,
+	/// Remaining liquidity is added to the Omnipool. Sold asset is added as a new token with `weight_cap`
+	/// if it is not in the Omnipool yet. Position NFTs are minted to the pool owner.
+	Omnipool { weight_cap: Permill },
 }
 
 /// Max weight corresponds to 100%
@@ -84,6 +111,15 @@ pub const MAX_WEIGHT: LBPWeight = 100_000_000;
 /// Max sale duration is 14 days, assuming 6 sec blocks
 pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 
+/// Max steepness of non-linear weight curves. Higher values can overflow the exponentiation used in the math.
+pub const MAX_CURVE_STEEPNESS: u32 = 20;
+
+/// Max number of pools waiting for execution of their post-sale action
+pub const MAX_PENDING_POST_SALE_ACTIONS: u32 = 10;
+
+/// Smoothing period of the sale price EMA used to price the post-sale action, 10 minutes assuming 6 sec blocks
+pub const SALE_PRICE_EMA_PERIOD: u64 = 100;
+
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
@@ -119,6 +155,9 @@ pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
 
 	/// repayment target of the accumulated asset in fee collectors account, when this target is reached fee drops from 20% to fee
 	pub repay_target: Balance,
+
+	/// action executed when the sale ends
+	pub post_sale_action: Option<PostSaleAction>,
 }
 
 impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockNumber> {
@@ -144,10 +183,23 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 			fee,
 			fee_collector,
 			repay_target,
+			post_sale_action: None,
 		}
 	}
 }
 
+/// Exponential moving average of the price of the sold asset denominated in the accumulated asset.
+/// Updated on the first trade of every block with the price left by the last trade of the previous block.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SalePrice<BlockNumber> {
+	/// price averaged over blocks before `updated_at`
+	pub ema: EmaPrice,
+	/// spot price after the last trade in `updated_at` block
+	pub last: EmaPrice,
+	/// block of the last trade
+	pub updated_at: BlockNumber,
+}
+
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
 		weight_curve: WeightCurveType,
@@ -159,17 +211,72 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	) -> Option<LBPWeight>;
 }
 
+/// Moves liquidity of an ended sale into another pool.
+pub trait PostSaleHandler<AccountId> {
+	/// Moves `accumulated` and `sold` assets held by `owner` into a pool specified by `action`.
+	/// `price` is the EMA of the sale price of the sold asset denominated in the accumulated asset.
+	fn migrate_liquidity(
+		action: PostSaleAction,
+		owner: &AccountId,
+		accumulated: (AssetId, Balance),
+		sold: (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult;
+
+	fn migrate_liquidity_weight(action: PostSaleAction) -> Weight;
+}
+
+impl<AccountId> PostSaleHandler<AccountId> for () {
+	fn migrate_liquidity(
+		_action: PostSaleAction,
+		_owner: &AccountId,
+		_accumulated: (AssetId, Balance),
+		_sold: (AssetId, Balance),
+		_price: FixedU128,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Post-sale action is not supported"))
+	}
+
+	fn migrate_liquidity_weight(_action: PostSaleAction) -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Exponential { steepness } => hydra_dx_math::lbp::calculate_exponential_weights(
+				start,
+				end,
+				initial_weight,
+				final_weight,
+				at,
+				steepness,
+			),
+			WeightCurveType::Logarithmic { steepness } => hydra_dx_math::lbp::calculate_logarithmic_weights(
+				start,
+				end,
+				initial_weight,
+				final_weight,
+				at,
+				steepness,
+			),
+			WeightCurveType::Step { steps } => {
+				hydra_dx_math::lbp::calculate_step_weights(start, end, initial_weight, final_weight, at, steps)
+			}
+		}
+		.ok()
 	}
 }
 
@@ -179,7 +286,10 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::OriginFor;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -196,6 +306,9 @@ pub mod pallet {
 		/// The origin which can create a new pool
 		type CreatePoolOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can approve post-sale actions
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Function for calculation of LBP weights
 		type LBPWeightFunction: LBPWeightCalculation<BlockNumberFor<Self>>;
 
@@ -223,10 +336,17 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Handler moving liquidity of ended sales according to their post-sale action
+		type PostSaleHandler: PostSaleHandler<Self::AccountId>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::execute_post_sale_actions()
+		}
+
 		fn integrity_test() {
 			// The exponentiation used in the math can overflow for values smaller than 3
 			assert!(T::MaxInRatio::get() >= 3, "LBP: MaxInRatio is set to invalid value.");
@@ -305,6 +425,18 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Weight curve parameters are out of range
+		InvalidWeightCurve,
+
+		/// Too many pools are waiting for execution of their post-sale action
+		TooManyPostSaleActions,
+
+		/// Approved post-sale action does not match the action set by the pool owner
+		PostSaleActionMismatch,
+
+		/// Sale has no start and end block set
+		SaleNotScheduled,
 	}
 
 	#[pallet::event]
@@ -361,6 +493,29 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Post-sale action of the pool was set or removed.
+		PostSaleActionSet {
+			pool: PoolId<T>,
+			action: Option<PostSaleAction>,
+		},
+
+		/// Post-sale action of the pool was approved and will be executed when the sale ends.
+		PostSaleActionApproved { pool: PoolId<T>, action: PostSaleAction },
+
+		/// Liquidity of the ended sale was moved according to the post-sale action.
+		PostSaleActionExecuted {
+			pool: PoolId<T>,
+			owner: T::AccountId,
+			action: PostSaleAction,
+		},
+
+		/// Post-sale action failed. Liquidity stays in the pool and has to be removed by the pool owner.
+		PostSaleActionFailed {
+			pool: PoolId<T>,
+			action: PostSaleAction,
+			error: DispatchError,
+		},
 	}
 
 	/// Details of a pool.
@@ -369,6 +524,18 @@ pub mod pallet {
 	pub type PoolData<T: Config> =
 		StorageMap<_, Blake2_128Concat, PoolId<T>, Pool<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
+	/// Pools with an approved post-sale action which has not been executed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_post_sale_actions)]
+	pub type PendingPostSaleActions<T: Config> =
+		StorageValue<_, BoundedVec<PoolId<T>, ConstU32<MAX_PENDING_POST_SALE_ACTIONS>>, ValueQuery>;
+
+	/// EMA of the sale price of pools. Used to price the post-sale action.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_price)]
+	pub type SalePrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, SalePrice<BlockNumberFor<T>>, OptionQuery>;
+
 	/// Storage used for tracking existing fee collectors
 	/// Not more than one fee collector per asset possible
 	#[pallet::storage]
@@ -404,8 +571,8 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. Linear, exponential,
+		/// logarithmic and step functions are implemented. See `WeightCurveType`.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
@@ -678,28 +845,9 @@ pub mod pallet {
 
 			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
 
-			let (asset_a, asset_b) = pool_data.assets;
+			Self::do_remove_liquidity(&pool_id, pool_data)?;
 
-			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
-			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
-
-			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a)?;
-			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b)?;
-
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
-
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a,
-				amount_b,
-			});
+			<PendingPostSaleActions<T>>::mutate(|pending| pending.retain(|id| *id != pool_id));
 
 			Ok(())
 		}
@@ -763,10 +911,286 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set an action which is executed automatically when the sale ends.
+		///
+		/// Remaining liquidity of the pool is moved to the pool owner and then to a new pool
+		/// specified by the action. If the action fails, liquidity stays in the pool.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The action can be set only if the sale has not already started.
+		/// The action is not executed until it is approved by `T::AuthorityOrigin`. Changing the action
+		/// revokes previous approval.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `action`: The post-sale action. `None` removes previously set action.
+		///
+		/// Emits `PostSaleActionSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_post_sale_action())]
+		pub fn set_post_sale_action(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			action: Option<PostSaleAction>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<PoolData<T>>::try_mutate(&pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(who == pool.owner, Error::<T>::NotOwner);
+
+				ensure!(!Self::has_pool_started(pool), Error::<T>::SaleStarted);
+
+				pool.post_sale_action = action;
+
+				Ok(())
+			})?;
+
+			<PendingPostSaleActions<T>>::mutate(|pending| pending.retain(|id| *id != pool_id));
+
+			Self::deposit_event(Event::PostSaleActionSet { pool: pool_id, action });
+
+			Ok(())
+		}
+
+		/// Approve the post-sale action set by the pool owner.
+		///
+		/// Omnipool action lists the sold asset in the Omnipool, so the action and its weight cap
+		/// have to be approved by governance.
+		///
+		/// The dispatch origin for this call must be `T::AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `action`: The approved post-sale action. Must be equal to the action set by the pool owner.
+		///
+		/// Emits `PostSaleActionApproved` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_post_sale_action())]
+		pub fn approve_post_sale_action(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			action: PostSaleAction,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(
+				pool_data.post_sale_action == Some(action),
+				Error::<T>::PostSaleActionMismatch
+			);
+
+			ensure!(pool_data.end.is_some(), Error::<T>::SaleNotScheduled);
+
+			<PendingPostSaleActions<T>>::try_mutate(|pending| -> DispatchResult {
+				if !pending.contains(&pool_id) {
+					pending
+						.try_push(pool_id.clone())
+						.map_err(|_| Error::<T>::TooManyPostSaleActions)?;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PostSaleActionApproved { pool: pool_id, action });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Transfers all liquidity of the pool to the pool owner and destroys the pool.
+	fn do_remove_liquidity(
+		pool_id: &PoolId<T>,
+		pool_data: Pool<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, pool_id);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, pool_id);
+
+		T::MultiCurrency::transfer(asset_a, pool_id, &pool_data.owner, amount_a)?;
+		T::MultiCurrency::transfer(asset_b, pool_id, &pool_data.owner, amount_b)?;
+
+		if Self::collected_fees(&pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
+		<PoolData<T>>::remove(pool_id);
+		<SalePrices<T>>::remove(pool_id);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: pool_id.clone(),
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+		});
+
+		Ok((amount_a, amount_b))
+	}
+
+	/// Executes post-sale actions of pools whose sale has ended.
+	/// Pools are removed from the pending list whether the action succeeds or not.
+	/// Pools which are not scheduled anymore are removed from the pending list without executing the action.
+	fn execute_post_sale_actions() -> Weight {
+		let pending = <PendingPostSaleActions<T>>::get();
+		let mut weight = T::DbWeight::get().reads(1);
+
+		if pending.is_empty() {
+			return weight;
+		}
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut remaining = pending.clone();
+
+		for pool_id in pending.iter() {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			let Some(pool_data) = <PoolData<T>>::get(pool_id) else {
+				remaining.retain(|id| id != pool_id);
+				continue;
+			};
+			let (Some(action), Some(end)) = (pool_data.post_sale_action, pool_data.end) else {
+				remaining.retain(|id| id != pool_id);
+				continue;
+			};
+			if end >= now {
+				continue;
+			}
+
+			weight.saturating_accrue(
+				T::WeightInfo::remove_liquidity().saturating_add(T::PostSaleHandler::migrate_liquidity_weight(action)),
+			);
+
+			let owner = pool_data.owner.clone();
+			let result = with_transaction(|| {
+				let result = Self::do_execute_post_sale_action(pool_id, pool_data, action);
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+
+			match result {
+				Ok(()) => Self::deposit_event(Event::PostSaleActionExecuted {
+					pool: pool_id.clone(),
+					owner,
+					action,
+				}),
+				Err(error) => Self::deposit_event(Event::PostSaleActionFailed {
+					pool: pool_id.clone(),
+					action,
+					error,
+				}),
+			}
+
+			remaining.retain(|id| id != pool_id);
+		}
+
+		if remaining.len() != pending.len() {
+			<PendingPostSaleActions<T>>::put(remaining);
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+		}
+
+		weight
+	}
+
+	fn do_execute_post_sale_action(
+		pool_id: &PoolId<T>,
+		pool_data: Pool<T::AccountId, BlockNumberFor<T>>,
+		action: PostSaleAction,
+	) -> DispatchResult {
+		let end = pool_data.end.ok_or(Error::<T>::SaleNotEnded)?;
+		let (asset_a, asset_b) = pool_data.assets;
+		let owner = pool_data.owner.clone();
+
+		// Spot price can be moved by a single trade at the end of the sale, so the EMA of the sale price is used.
+		// The final spot price is used only if there were no trades.
+		let price = match <SalePrices<T>>::get(pool_id) {
+			Some(sale_price) => {
+				let sale_price = Self::updated_sale_price(sale_price, end.saturating_add(One::one()));
+				FixedU128::checked_from_rational(sale_price.ema.n, sale_price.ema.d)
+			}
+			None => Self::calculate_sale_price(pool_id, &pool_data, end),
+		}
+		.ok_or(Error::<T>::Overflow)?;
+
+		let (amount_a, amount_b) = Self::do_remove_liquidity(pool_id, pool_data)?;
+
+		T::PostSaleHandler::migrate_liquidity(action, &owner, (asset_a, amount_a), (asset_b, amount_b), price)
+	}
+
+	/// Spot price of the sold asset denominated in the accumulated asset at block `at`.
+	fn calculate_sale_price(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		at: BlockNumberFor<T>,
+	) -> Option<FixedU128> {
+		let (weight_a, weight_b) = Self::calculate_weights(pool_data, at).ok()?;
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, pool_id);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, pool_id);
+
+		FixedU128::checked_from_rational(amount_a, amount_b)
+			.and_then(|price| price.checked_mul(&FixedU128::checked_from_rational(weight_b, weight_a)?))
+	}
+
+	/// Integrates the last price of `sale_price` into the EMA for every block between `updated_at` and `now`.
+	fn updated_sale_price(
+		sale_price: SalePrice<BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) -> SalePrice<BlockNumberFor<T>> {
+		if now <= sale_price.updated_at {
+			return sale_price;
+		}
+
+		let iterations = now.saturating_sub(sale_price.updated_at).saturated_into::<u32>();
+		let smoothing = hydra_dx_math::ema::smoothing_from_period(SALE_PRICE_EMA_PERIOD);
+
+		SalePrice {
+			ema: hydra_dx_math::ema::iterated_price_ema(iterations, sale_price.ema, sale_price.last, smoothing),
+			last: sale_price.last,
+			updated_at: now,
+		}
+	}
+
+	/// Updates the sale price EMA of the pool with the spot prices before and after a trade.
+	fn update_sale_price(
+		pool_id: &PoolId<T>,
+		price_before: Option<FixedU128>,
+		price_after: Option<FixedU128>,
+		now: BlockNumberFor<T>,
+	) {
+		let to_ema_price = |price: FixedU128| EmaPrice::new(price.into_inner(), FixedU128::DIV);
+
+		let Some(price_after) = price_after.map(to_ema_price) else {
+			return;
+		};
+
+		<SalePrices<T>>::mutate(pool_id, |maybe_sale_price| {
+			*maybe_sale_price = match maybe_sale_price.take() {
+				Some(sale_price) => Some(SalePrice {
+					last: price_after,
+					..Self::updated_sale_price(sale_price, now)
+				}),
+				// The first trade starts the EMA with the price set by the pool owner
+				None => price_before.map(to_ema_price).map(|ema| SalePrice {
+					ema,
+					last: price_after,
+					updated_at: now,
+				}),
+			};
+		});
+	}
+
 	fn calculate_weights(
 		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
 		at: BlockNumberFor<T>,
@@ -825,6 +1249,16 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
+		ensure!(
+			match pool_data.weight_curve {
+				WeightCurveType::Linear => true,
+				WeightCurveType::Exponential { steepness } | WeightCurveType::Logarithmic { steepness } =>
+					!steepness.is_zero() && steepness <= FixedU128::from(MAX_CURVE_STEEPNESS as u128),
+				WeightCurveType::Step { steps } => !steps.is_zero(),
+			},
+			Error::<T>::InvalidWeightCurve
+		);
+
 		Ok(())
 	}
 
@@ -880,6 +1314,9 @@ impl<T: Config> Pallet<T> {
 		let pool_account = Self::get_pair_id(transfer.assets);
 		let pool = <PoolData<T>>::try_get(&pool_account).map_err(|_| Error::<T>::PoolNotFound)?;
 
+		let now = T::BlockNumberProvider::current_block_number();
+		let price_before = Self::calculate_sale_price(&pool_account, &pool, now);
+
 		// Transfer assets between pool and user
 		T::MultiCurrency::transfer(
 			transfer.assets.asset_in,
@@ -908,6 +1345,9 @@ impl<T: Config> Pallet<T> {
 		let collected_fee_total = Self::collected_fees(&pool) + fee_amount;
 		T::MultiCurrency::set_lock(COLLECTOR_LOCK_ID, fee_asset, &pool.fee_collector, collected_fee_total)?;
 
+		let price_after = Self::calculate_sale_price(&pool_account, &pool, now);
+		Self::update_sale_price(&pool_account, price_before, price_after, now);
+
 		Ok(())
 	}
 
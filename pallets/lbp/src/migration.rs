@@ -0,0 +1,116 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+/// The log target.
+const TARGET: &str = "runtime::lbp::migration::v1";
+
+pub mod v0 {
+	use super::*;
+
+	/// Pool without post-sale action.
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
+		pub owner: AccountId,
+		pub start: Option<BlockNumber>,
+		pub end: Option<BlockNumber>,
+		pub assets: (AssetId, AssetId),
+		pub initial_weight: LBPWeight,
+		pub final_weight: LBPWeight,
+		pub weight_curve: WeightCurveType,
+		pub fee: (u32, u32),
+		pub fee_collector: AccountId,
+		pub repay_target: Balance,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Adds empty post-sale action to all existing pools.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let pools_count = PoolData::<T>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "LBP migration: PRE checks successful!");
+
+			Ok(pools_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::warn!(
+					target: TARGET,
+					"LBP migration to v1 skipped because storage version is not 0"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for LBP");
+
+			let mut translated: u64 = 0;
+			PoolData::<T>::translate::<v0::Pool<T::AccountId, BlockNumberFor<T>>, _>(|_, pool| {
+				translated.saturating_inc();
+				Some(Pool {
+					owner: pool.owner,
+					start: pool.start,
+					end: pool.end,
+					assets: pool.assets,
+					initial_weight: pool.initial_weight,
+					final_weight: pool.final_weight,
+					weight_curve: pool.weight_curve,
+					fee: pool.fee,
+					fee_collector: pool.fee_collector,
+					repay_target: pool.repay_target,
+					post_sale_action: None,
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: TARGET, "LBP migration to v1 migrated {:?} pools", translated);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let previous_count = <u64 as Decode>::decode(&mut state.as_slice()).unwrap();
+			let pools = PoolData::<T>::iter_values().collect::<Vec<_>>();
+
+			assert_eq!(
+				pools.len() as u64,
+				previous_count,
+				"Migrated pools don't match the pools prior migration!"
+			);
+			assert!(pools.iter().all(|pool| pool.post_sale_action.is_none()));
+
+			log::info!(target: TARGET, "LBP migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
@@ -15,6 +15,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
@@ -54,6 +55,7 @@ pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	fee: DEFAULT_FEE,
 	fee_collector: CHARLIE,
 	repay_target: 0,
+	post_sale_action: None,
 };
 
 pub const SAMPLE_AMM_TRANSFER: AMMTransfer<AccountId, AssetId, AssetPair, Balance> = AMMTransfer {
@@ -175,6 +177,7 @@ impl Config for Test {
 	type MultiCurrency = Currency;
 	type LockedBalance = MultiLockedBalance;
 	type CreatePoolOrigin = frame_system::EnsureRoot<u64>;
+	type AuthorityOrigin = frame_system::EnsureRoot<u64>;
 	type LBPWeightFunction = lbp::LBPWeightFunction;
 	type AssetPairAccountId = AssetPairAccountIdTest;
 	type WeightInfo = ();
@@ -183,6 +186,50 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type PostSaleHandler = MockPostSaleHandler;
+}
+
+pub type PostSaleMigration = (
+	PostSaleAction,
+	AccountId,
+	(AssetId, Balance),
+	(AssetId, Balance),
+	FixedU128,
+);
+
+thread_local! {
+	pub static POST_SALE_MIGRATIONS: RefCell<Vec<PostSaleMigration>> = RefCell::new(Vec::new());
+	pub static POST_SALE_MIGRATION_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+pub struct MockPostSaleHandler;
+
+impl PostSaleHandler<AccountId> for MockPostSaleHandler {
+	fn migrate_liquidity(
+		action: PostSaleAction,
+		owner: &AccountId,
+		accumulated: (AssetId, Balance),
+		sold: (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult {
+		if POST_SALE_MIGRATION_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("migration failed"));
+		}
+		POST_SALE_MIGRATIONS.with(|v| v.borrow_mut().push((action, *owner, accumulated, sold, price)));
+		Ok(())
+	}
+
+	fn migrate_liquidity_weight(_action: PostSaleAction) -> Weight {
+		Weight::zero()
+	}
+}
+
+pub fn post_sale_migrations() -> Vec<PostSaleMigration> {
+	POST_SALE_MIGRATIONS.with(|v| v.borrow().clone())
+}
+
+pub fn set_post_sale_migration_fails(fails: bool) {
+	POST_SALE_MIGRATION_FAILS.with(|v| *v.borrow_mut() = fails);
 }
 
 pub struct ExtBuilder {
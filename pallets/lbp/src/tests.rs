@@ -20,14 +20,16 @@
 
 use super::*;
 use crate::mock::{
-	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, AccountId, RuntimeCall as Call, DEFAULT_FEE,
-	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START,
-	SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
+	expect_events, generate_trades, post_sale_migrations, run_to_sale_end, run_to_sale_start,
+	set_post_sale_migration_fails, AccountId, RuntimeCall as Call, DEFAULT_FEE, EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID,
+	INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START, SAMPLE_AMM_TRANSFER,
+	SAMPLE_POOL_DATA,
 };
 pub use crate::mock::{
 	set_block_number, Currency, ExtBuilder, LBPPallet, RuntimeOrigin as Origin, Test, ALICE, BOB, BSX, CHARLIE, ETH,
 	HDX, KUSD,
 };
+use frame_support::traits::Hooks;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use hydradx_traits::{AMMTransfer, LockedBalance};
 use sp_runtime::traits::{BadOrigin, Dispatchable};
@@ -81,6 +83,7 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 
 		assert_eq!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).unwrap(), pool_data2);
@@ -212,6 +215,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -227,6 +231,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -241,6 +246,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -258,6 +264,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -280,6 +287,7 @@ fn max_sale_duration_ckeck() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		}));
 		assert_noop!(
 			LBPPallet::validate_pool_data(&Pool {
@@ -293,6 +301,7 @@ fn max_sale_duration_ckeck() {
 				fee: DEFAULT_FEE,
 				fee_collector: CHARLIE,
 				repay_target: 0,
+				post_sale_action: None,
 			}),
 			Error::<Test>::MaxSaleDurationExceeded
 		);
@@ -313,6 +322,7 @@ fn calculate_weights_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			post_sale_action: None,
 		};
 		assert_eq!(LBPPallet::calculate_weights(&pool_data, 170), Ok((38333333, 61666667)));
 
@@ -358,6 +368,167 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_work_with_non_linear_curves() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 20_000_000,
+			final_weight: 80_000_000,
+			weight_curve: WeightCurveType::Exponential {
+				steepness: FixedU128::from(1),
+			},
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+			post_sale_action: None,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((20_000_000, 80_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((57_347_559, 42_652_441))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((80_000_000, 20_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Logarithmic {
+			steepness: FixedU128::from(1),
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 170),
+			Ok((65_932_084, 34_067_916))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((80_000_000, 20_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Step { steps: 4 };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 174),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 175),
+			Ok((65_000_000, 35_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((80_000_000, 20_000_000))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
+#[test]
+fn validate_pool_data_should_fail_when_weight_curve_parameters_are_invalid() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: SALE_START,
+			end: SALE_END,
+			assets: (KUSD, BSX),
+			initial_weight: 20_000_000,
+			final_weight: 90_000_000,
+			weight_curve: WeightCurveType::Exponential {
+				steepness: FixedU128::from(MAX_CURVE_STEEPNESS as u128),
+			},
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+			post_sale_action: None,
+		};
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
+
+		pool_data.weight_curve = WeightCurveType::Exponential {
+			steepness: FixedU128::from(0),
+		};
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		pool_data.weight_curve = WeightCurveType::Logarithmic {
+			steepness: FixedU128::from(MAX_CURVE_STEEPNESS as u128 + 1),
+		};
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		pool_data.weight_curve = WeightCurveType::Step { steps: 0 };
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_work_with_non_linear_weight_curve() {
+	new_test_ext().execute_with(|| {
+		let weight_curve = WeightCurveType::Logarithmic {
+			steepness: FixedU128::from(5),
+		};
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			weight_curve,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		assert_eq!(
+			<PoolData<Test>>::get(KUSD_BSX_POOL_ID).unwrap().weight_curve,
+			weight_curve
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_weight_curve_parameters_are_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Step { steps: 0 },
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InvalidWeightCurve
+		);
+	});
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1928,6 +2099,7 @@ fn zero_weight_should_not_work() {
 			fee: Some((5, 100)),
 			fee_collector: Some(BOB),
 			repay_target: Some(0),
+			post_sale_action: None,
 		});
 
 		assert_noop!(call.dispatch(Origin::signed(ALICE)), Error::<Test>::InvalidWeight);
@@ -3616,6 +3788,7 @@ fn repay_fee_not_applied_when_set_to_zero() {
 	new_test_ext().execute_with(|| {
 		let pool = Pool {
 			repay_target: 0,
+			post_sale_action: None,
 			..SAMPLE_POOL_DATA
 		};
 		assert_eq!(LBPPallet::is_repay_fee_applied(&pool), false);
@@ -3627,6 +3800,7 @@ fn repay_fee_applied_when_set() {
 	new_test_ext().execute_with(|| {
 		let pool = Pool {
 			repay_target: 10_000_000,
+			post_sale_action: None,
 			..SAMPLE_POOL_DATA
 		};
 		assert_eq!(LBPPallet::is_repay_fee_applied(&pool), true);
@@ -3639,6 +3813,7 @@ fn repay_fee_not_applied_when_target_reached() {
 		let pool = Pool {
 			fee_collector: ALICE,
 			repay_target: INITIAL_BALANCE,
+			post_sale_action: None,
 			..SAMPLE_POOL_DATA
 		};
 		assert_ok!(Currency::set_lock(
@@ -4082,3 +4257,383 @@ mod spot_price_calculation {
 		})
 	}
 }
+
+fn set_and_approve_post_sale_action(action: PostSaleAction) {
+	assert_ok!(LBPPallet::set_post_sale_action(
+		Origin::signed(ALICE),
+		KUSD_BSX_POOL_ID,
+		Some(action),
+	));
+	assert_ok!(LBPPallet::approve_post_sale_action(
+		Origin::root(),
+		KUSD_BSX_POOL_ID,
+		action,
+	));
+}
+
+#[test]
+fn set_post_sale_action_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(PostSaleAction::XykPool),
+		));
+
+		assert_eq!(
+			LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap().post_sale_action,
+			Some(PostSaleAction::XykPool)
+		);
+		// action is not executed until it is approved
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+
+		expect_events(vec![Event::PostSaleActionSet {
+			pool: KUSD_BSX_POOL_ID,
+			action: Some(PostSaleAction::XykPool),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_post_sale_action_should_replace_previous_action_and_revoke_approval() {
+	predefined_test_ext().execute_with(|| {
+		let omnipool_action = PostSaleAction::Omnipool {
+			weight_cap: Permill::from_percent(10),
+		};
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(omnipool_action),
+		));
+
+		assert_eq!(
+			LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap().post_sale_action,
+			Some(omnipool_action)
+		);
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+	});
+}
+
+#[test]
+fn set_post_sale_action_should_remove_action_when_none_is_provided() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+		));
+
+		assert_eq!(LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap().post_sale_action, None);
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+	});
+}
+
+#[test]
+fn set_post_sale_action_should_fail_when_called_by_non_owner() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_post_sale_action(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(PostSaleAction::XykPool)),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn set_post_sale_action_should_fail_when_pool_does_not_exist() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_post_sale_action(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(PostSaleAction::XykPool)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_post_sale_action_should_fail_when_sale_started() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_post_sale_action(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(PostSaleAction::XykPool)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn post_sale_action_should_not_be_executed_before_sale_ends() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		set_block_number(SALE_END.unwrap());
+		LBPPallet::on_initialize(SALE_END.unwrap());
+
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(post_sale_migrations().is_empty());
+		assert_eq!(
+			LBPPallet::pending_post_sale_actions().into_inner(),
+			vec![KUSD_BSX_POOL_ID]
+		);
+	});
+}
+
+#[test]
+fn post_sale_action_should_migrate_liquidity_when_sale_ends() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		run_to_sale_end();
+		LBPPallet::on_initialize(SALE_END.unwrap() + 1);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<FeeCollectorWithAsset<Test>>::contains_key(CHARLIE, KUSD));
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 0);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 0);
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), INITIAL_BALANCE);
+
+		// no trades, final spot price is used
+		// final weights are 80% / 20%, price = (1_000_000_000 / 2_000_000_000) * (20 / 80)
+		assert_eq!(
+			post_sale_migrations(),
+			vec![(
+				PostSaleAction::XykPool,
+				ALICE,
+				(KUSD, 1_000_000_000),
+				(BSX, 2_000_000_000),
+				FixedU128::from_rational(1, 8)
+			)]
+		);
+
+		expect_events(vec![Event::PostSaleActionExecuted {
+			pool: KUSD_BSX_POOL_ID,
+			owner: ALICE,
+			action: PostSaleAction::XykPool,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn post_sale_action_should_keep_liquidity_in_pool_when_migration_fails() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+		set_post_sale_migration_fails(true);
+
+		run_to_sale_end();
+		LBPPallet::on_initialize(SALE_END.unwrap() + 1);
+
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+
+		expect_events(vec![Event::PostSaleActionFailed {
+			pool: KUSD_BSX_POOL_ID,
+			action: PostSaleAction::XykPool,
+			error: DispatchError::Other("migration failed"),
+		}
+		.into()]);
+
+		// liquidity can still be removed by the owner
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_pending_post_sale_action() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		// sale has not started yet
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert!(LBPPallet::pending_post_sale_actions().is_empty());
+	});
+}
+
+#[test]
+fn approve_post_sale_action_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let action = PostSaleAction::Omnipool {
+			weight_cap: Permill::from_percent(10),
+		};
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(action),
+		));
+
+		assert_ok!(LBPPallet::approve_post_sale_action(
+			Origin::root(),
+			KUSD_BSX_POOL_ID,
+			action,
+		));
+
+		assert_eq!(
+			LBPPallet::pending_post_sale_actions().into_inner(),
+			vec![KUSD_BSX_POOL_ID]
+		);
+
+		expect_events(vec![Event::PostSaleActionApproved {
+			pool: KUSD_BSX_POOL_ID,
+			action,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn approve_post_sale_action_should_fail_when_called_by_non_authority() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(PostSaleAction::XykPool),
+		));
+
+		assert_noop!(
+			LBPPallet::approve_post_sale_action(Origin::signed(ALICE), KUSD_BSX_POOL_ID, PostSaleAction::XykPool),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn approve_post_sale_action_should_fail_when_weight_cap_does_not_match() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(PostSaleAction::Omnipool {
+				weight_cap: Permill::from_percent(50),
+			}),
+		));
+
+		assert_noop!(
+			LBPPallet::approve_post_sale_action(
+				Origin::root(),
+				KUSD_BSX_POOL_ID,
+				PostSaleAction::Omnipool {
+					weight_cap: Permill::from_percent(10),
+				}
+			),
+			Error::<Test>::PostSaleActionMismatch
+		);
+	});
+}
+
+#[test]
+fn approve_post_sale_action_should_fail_when_action_is_not_set() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::approve_post_sale_action(Origin::root(), KUSD_BSX_POOL_ID, PostSaleAction::XykPool),
+			Error::<Test>::PostSaleActionMismatch
+		);
+	});
+}
+
+#[test]
+fn approve_post_sale_action_should_fail_when_sale_is_not_scheduled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(PostSaleAction::XykPool),
+		));
+
+		assert_noop!(
+			LBPPallet::approve_post_sale_action(Origin::root(), KUSD_BSX_POOL_ID, PostSaleAction::XykPool),
+			Error::<Test>::SaleNotScheduled
+		);
+	});
+}
+
+#[test]
+fn post_sale_action_should_not_be_executed_when_not_approved() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_post_sale_action(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(PostSaleAction::XykPool),
+		));
+
+		run_to_sale_end();
+		LBPPallet::on_initialize(SALE_END.unwrap() + 1);
+
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(post_sale_migrations().is_empty());
+	});
+}
+
+#[test]
+fn post_sale_action_should_use_sale_price_ema_when_pool_was_traded() {
+	predefined_test_ext().execute_with(|| {
+		set_and_approve_post_sale_action(PostSaleAction::XykPool);
+
+		let end = SALE_END.unwrap();
+		set_block_number(end);
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		let price_before = LBPPallet::calculate_sale_price(&KUSD_BSX_POOL_ID, &pool_data, end).unwrap();
+		assert_eq!(price_before, FixedU128::from_rational(1, 8));
+
+		// large buy in the last block of the sale moves the spot price up
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			500_000_000,
+			2_000_000_000
+		));
+		let price_after = LBPPallet::calculate_sale_price(&KUSD_BSX_POOL_ID, &pool_data, end).unwrap();
+		assert!(price_after > price_before);
+
+		let amount_a = Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID);
+		let amount_b = Currency::free_balance(BSX, &KUSD_BSX_POOL_ID);
+
+		run_to_sale_end();
+		LBPPallet::on_initialize(end + 1);
+
+		// price after the trade is integrated into the EMA for a single block only
+		let expected_price = hydra_dx_math::ema::iterated_price_ema(
+			1,
+			EmaPrice::new(price_before.into_inner(), FixedU128::DIV),
+			EmaPrice::new(price_after.into_inner(), FixedU128::DIV),
+			hydra_dx_math::ema::smoothing_from_period(SALE_PRICE_EMA_PERIOD),
+		);
+		let expected_price = FixedU128::checked_from_rational(expected_price.n, expected_price.d).unwrap();
+		assert!(expected_price > price_before && expected_price < price_after);
+
+		assert_eq!(
+			post_sale_migrations(),
+			vec![(
+				PostSaleAction::XykPool,
+				ALICE,
+				(KUSD, amount_a),
+				(BSX, amount_b),
+				expected_price
+			)]
+		);
+		assert!(LBPPallet::sale_price(KUSD_BSX_POOL_ID).is_none());
+	});
+}
@@ -15,27 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_lbp`
+//! Weights for `pallet_lbp`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-06-20, STEPS: `10`, REPEAT: `30`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// target/release/hydradx
-// benchmark
-// pallet
-// --chain=dev
-// --steps=10
-// --repeat=30
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=scripts/pallet-weight-template.hbs
-// --pallet=pallet-lbp
-// --output=weights/lbp.rs
-// --extrinsic=**
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-06-20
+//! (STEPS: `10`, REPEAT: `30`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -59,6 +43,8 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_buy() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn set_post_sale_action() -> Weight;
+	fn approve_post_sale_action() -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -142,14 +128,17 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SalePrices` (r:0 w:1)
+	/// Proof: `LBP::SalePrices` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `1687`
 		//  Estimated: `11322`
 		// Minimum execution time: 131_548_000 picoseconds.
 		Weight::from_parts(132_825_000, 11322)
 			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -165,14 +154,17 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SalePrices` (r:1 w:1)
+	/// Proof: `LBP::SalePrices` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `1977`
 		//  Estimated: `13905`
 		// Minimum execution time: 233_172_000 picoseconds.
 		Weight::from_parts(234_278_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
@@ -188,14 +180,17 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SalePrices` (r:1 w:1)
+	/// Proof: `LBP::SalePrices` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `1977`
 		//  Estimated: `13905`
 		// Minimum execution time: 232_521_000 picoseconds.
 		Weight::from_parts(233_949_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
@@ -211,9 +206,12 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SalePrices` (r:1 w:1)
+	/// Proof: `LBP::SalePrices` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `822 + e * (1155 ±0)`
 		//  Estimated: `6156 + e * (7749 ±251_795_645_551_580_832)`
@@ -224,8 +222,8 @@ impl WeightInfo for () {
 			// Standard Error: 1_985_547
 			.saturating_add(Weight::from_parts(154_967_721, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().reads((14_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 7749).saturating_mul(e.into()))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
@@ -242,9 +240,12 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SalePrices` (r:1 w:1)
+	/// Proof: `LBP::SalePrices` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 3]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
 		//  Measured:  `822 + e * (1155 ±0)`
 		//  Estimated: `6156 + e * (7749 ±0)`
@@ -255,8 +256,8 @@ impl WeightInfo for () {
 			// Standard Error: 3_706_588
 			.saturating_add(Weight::from_parts(112_896_249, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().reads((14_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(e.into())))
 			.saturating_add(Weight::from_parts(0, 7749).saturating_mul(e.into()))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
@@ -283,4 +284,32 @@ impl WeightInfo for () {
 		// Minimum execution time: 24_307_000 picoseconds.
 		Weight::from_parts(24_702_000, 6156).saturating_add(RocksDbWeight::get().reads(4_u64))
 	}
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PendingPostSaleActions` (r:1 w:1)
+	/// Proof: `LBP::PendingPostSaleActions` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	fn set_post_sale_action() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `3631`
+		// Minimum execution time: 21_452_000 picoseconds.
+		Weight::from_parts(21_890_000, 3631)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PendingPostSaleActions` (r:1 w:1)
+	/// Proof: `LBP::PendingPostSaleActions` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	fn approve_post_sale_action() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `3631`
+		// Minimum execution time: 18_964_000 picoseconds.
+		Weight::from_parts(19_385_000, 3631)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
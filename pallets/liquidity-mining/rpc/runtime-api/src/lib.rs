@@ -0,0 +1,46 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Liquidity mining pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+pub use pallet_liquidity_mining::{DepositId, FarmEntryRewards, GlobalFarmId, GlobalFarmRewardsInfo};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Liquidity mining program(instance of the liquidity mining pallet) to query.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum LiquidityMiningProgram {
+	Omnipool,
+	XYK,
+	Stableswap,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query liquidity mining rewards.
+	pub trait LiquidityMiningApi<AssetId> where
+		AssetId: Codec,
+	{
+		/// Get claimable and unclaimable(locked by the loyalty curve) rewards of all deposit's farm
+		/// entries as if they were claimed in the current block.
+		fn deposit_rewards(program: LiquidityMiningProgram, deposit_id: DepositId) -> Result<Vec<FarmEntryRewards<AssetId>>, DispatchError>;
+
+		/// Get current APR and rewards not distributed yet by the global farm.
+		fn global_farm_rewards_info(program: LiquidityMiningProgram, global_farm_id: GlobalFarmId) -> Result<GlobalFarmRewardsInfo<AssetId>, DispatchError>;
+	}
+}
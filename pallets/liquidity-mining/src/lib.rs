@@ -92,11 +92,13 @@
 mod tests;
 mod types;
 
+pub mod migration;
+
 pub use pallet::*;
 
 pub use crate::types::{
-	Balance, DefaultPriceAdjustment, DepositData, DepositId, FarmId, FarmMultiplier, FarmState, GlobalFarmData,
-	GlobalFarmId, LoyaltyCurve, YieldFarmData, YieldFarmEntry, YieldFarmId,
+	Balance, DefaultPriceAdjustment, DepositData, DepositId, FarmEntryRewards, FarmId, FarmMultiplier, FarmState,
+	GlobalFarmData, GlobalFarmId, GlobalFarmRewardsInfo, LoyaltyCurve, YieldFarmData, YieldFarmEntry, YieldFarmId,
 };
 use codec::{Decode, Encode, FullCodec};
 use frame_support::{
@@ -107,12 +109,13 @@ use frame_support::{
 		traits::{AccountIdConversion, BlockNumberProvider, MaybeSerializeDeserialize, One, Zero},
 		RuntimeDebug,
 	},
+	storage::with_transaction,
 	traits::{Defensive, DefensiveOption},
 	PalletId,
 };
 
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::ArithmeticError;
+use sp_runtime::{ArithmeticError, TransactionOutcome};
 
 use hydra_dx_math::liquidity_mining as math;
 use hydradx_traits::{liquidity_mining::PriceAdjustment, pools::DustRemovalAccountWhitelist, registry::Inspect};
@@ -142,7 +145,10 @@ pub(crate) const MIN_YIELD_FARM_MULTIPLIER: FixedU128 = FixedU128::from_inner(1_
 pub mod pallet {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
@@ -318,6 +324,13 @@ pub mod pallet {
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
+
+		/// Loyalty curve's periods parameters are not valid. Vesting and step periods can't be 0
+		/// and step increase can't be 0.
+		InvalidLoyaltyCurve,
+
+		/// Deposit does not exist.
+		DepositNotFound,
 	}
 
 	//NOTE: these errors should never happen.
@@ -656,9 +669,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref curve) = loyalty_curve {
 			ensure!(
-				curve.initial_reward_percentage.lt(&FixedU128::one()),
+				curve.initial_reward_percentage().lt(&FixedU128::one()),
 				Error::<T, I>::InvalidInitialRewardPercentage
 			);
+
+			let is_valid = match curve {
+				LoyaltyCurve::Hyperbolic { .. } => true,
+				LoyaltyCurve::Linear { vesting_periods, .. } => !vesting_periods.is_zero(),
+				LoyaltyCurve::CliffLinear { vesting_periods, .. } => !vesting_periods.is_zero(),
+				LoyaltyCurve::Step {
+					step_periods,
+					step_increase,
+					..
+				} => !step_periods.is_zero() && !step_increase.is_zero(),
+			};
+			ensure!(is_valid, Error::<T, I>::InvalidLoyaltyCurve);
 		}
 
 		<GlobalFarm<T, I>>::try_mutate(
@@ -1144,34 +1169,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Self::sync_global_farm(global_farm, current_period)?;
 						Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
 
-						//NOTE: this should never fail yield-farm's stopped must be >= entry's
-						//stopped
-						let delta_stopped =
-							yield_farm
-								.total_stopped
-								.checked_sub(&farm_entry.stopped_at_creation)
-								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						//NOTE: yield-farm's `updated_at` is updated to current period if it's
-						//possible so this should be ok.
-						let periods = yield_farm
-							.updated_at
-							.checked_sub(&farm_entry.entered_at)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
-							.checked_sub(&delta_stopped)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						let loyalty_multiplier =
-							Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())?;
-
-						let (rewards, unclaimable_rewards) = math::calculate_user_reward(
-							farm_entry.accumulated_rpvs,
-							farm_entry.valued_shares,
-							farm_entry.accumulated_claimed_rewards,
-							yield_farm.accumulated_rpvs,
-							loyalty_multiplier,
-						)
-						.map_err(|_| ArithmeticError::Overflow)?;
+						let (rewards, unclaimable_rewards) =
+							Self::calculate_farm_entry_rewards(farm_entry, yield_farm)?;
 
 						//In case of low rewards and insufficient balance, we send rewards to treasury to prevent ED error
 						let ed = T::AssetRegistry::existential_deposit(global_farm.reward_currency).ok_or(
@@ -1473,6 +1472,133 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Returns rewards of all deposit's farm entries as if they were claimed in the current period.
+	/// Farm entries in terminated yield farms are skipped because they can't be claimed.
+	///
+	/// NOTE: Rewards are calculated on synced copies of the farms and all storage changes are
+	/// reverted so this function can be used to query rewards e.g. from runtime API.
+	/// Rewards lower than existential deposit may be sent to treasury by `claim_rewards()`.
+	pub fn deposit_rewards(deposit_id: DepositId) -> Result<Vec<FarmEntryRewards<T::AssetId>>, DispatchError> {
+		let deposit = Self::deposit(deposit_id).ok_or(Error::<T, I>::DepositNotFound)?;
+
+		let mut rewards = Vec::with_capacity(deposit.yield_farm_entries.len());
+		for farm_entry in deposit.yield_farm_entries.iter() {
+			let yield_farm_key = (
+				deposit.amm_pool_id.clone(),
+				farm_entry.global_farm_id,
+				farm_entry.yield_farm_id,
+			);
+
+			let maybe_rewards = with_transaction(|| {
+				TransactionOutcome::Rollback(Self::simulate_claim_rewards(yield_farm_key, farm_entry))
+			})?;
+
+			if let Some(entry_rewards) = maybe_rewards {
+				rewards.push(entry_rewards);
+			}
+		}
+
+		Ok(rewards)
+	}
+
+	/// Returns current APR and not distributed rewards of the global farm.
+	///
+	/// Parameters:
+	/// - `global_farm_id`: global farm id.
+	/// - `blocks_per_year`: number of blocks produced per year used to calculate APR.
+	///
+	/// NOTE: Global farm is synced to the current period and all storage changes are reverted.
+	pub fn global_farm_rewards_info(
+		global_farm_id: GlobalFarmId,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Result<GlobalFarmRewardsInfo<T::AssetId>, DispatchError> {
+		with_transaction(|| {
+			TransactionOutcome::Rollback(Self::simulate_global_farm_sync(global_farm_id, blocks_per_year))
+		})
+	}
+
+	/// This function syncs farms and calculates farm entry's rewards same way as `claim_rewards()`.
+	/// Returns `None` if yield farm is terminated.
+	///
+	/// WARN: Caller is responsible for reverting of the storage changes.
+	#[require_transactional]
+	fn simulate_claim_rewards(
+		yield_farm_key: (T::AmmPoolId, GlobalFarmId, YieldFarmId),
+		farm_entry: &YieldFarmEntry<T, I>,
+	) -> Result<Option<FarmEntryRewards<T::AssetId>>, DispatchError> {
+		//NOTE: yield-farm must exist if yield-farm-entry exists.
+		let mut yield_farm = <YieldFarm<T, I>>::get(yield_farm_key)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::YieldFarmNotFound.into())?;
+
+		if yield_farm.state.is_terminated() {
+			return Ok(None);
+		}
+
+		//NOTE: global-farm must exist if yield-farm exists.
+		let mut global_farm = <GlobalFarm<T, I>>::get(farm_entry.global_farm_id)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+		let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+
+		Self::sync_global_farm(&mut global_farm, current_period)?;
+		Self::sync_yield_farm(&mut yield_farm, &mut global_farm, current_period)?;
+
+		let (claimable_rewards, unclaimable_rewards) = Self::calculate_farm_entry_rewards(farm_entry, &yield_farm)?;
+
+		Ok(Some(FarmEntryRewards {
+			global_farm_id: global_farm.id,
+			yield_farm_id: yield_farm.id,
+			reward_currency: global_farm.reward_currency,
+			claimable_rewards,
+			unclaimable_rewards,
+		}))
+	}
+
+	/// This function syncs global farm and calculates its rewards info.
+	///
+	/// WARN: Caller is responsible for reverting of the storage changes.
+	#[require_transactional]
+	fn simulate_global_farm_sync(
+		global_farm_id: GlobalFarmId,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Result<GlobalFarmRewardsInfo<T::AssetId>, DispatchError> {
+		let mut global_farm = <GlobalFarm<T, I>>::get(global_farm_id).ok_or(Error::<T, I>::GlobalFarmNotFound)?;
+
+		let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+		Self::sync_global_farm(&mut global_farm, current_period)?;
+
+		let global_farm_account = Self::farm_account_id(global_farm.id)?;
+		let remaining_rewards = T::MultiCurrency::free_balance(global_farm.reward_currency, &global_farm_account)
+			.saturating_sub(T::AssetRegistry::get(&global_farm.reward_currency));
+
+		//NOTE: inactive farm is not rewarded.
+		let apr = if global_farm.state.is_active() {
+			let periods_per_year = blocks_per_year
+				.checked_div(&global_farm.blocks_per_period)
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+			math::calculate_global_farm_apr(
+				global_farm.total_shares_z,
+				global_farm.price_adjustment,
+				global_farm.yield_per_period.into(),
+				global_farm.max_reward_per_period,
+				periods_per_year,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?
+		} else {
+			FixedU128::zero()
+		};
+
+		Ok(GlobalFarmRewardsInfo {
+			global_farm_id: global_farm.id,
+			incentivized_asset: global_farm.incentivized_asset,
+			reward_currency: global_farm.reward_currency,
+			apr,
+			remaining_rewards,
+			pending_rewards: global_farm.pending_rewards,
+		})
+	}
+
 	/// Account id holding rewards allocated from all global farms for all yield farms.
 	pub fn pot_account_id() -> Option<T::AccountId> {
 		T::PalletId::get().try_into_account()
@@ -1505,6 +1631,43 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())
 	}
 
+	/// This function calculates farm entry's claimable and unclaimable rewards from synced
+	/// `yield_farm`.
+	///
+	/// Returns: `(claimable rewards, unclaimable rewards)`
+	fn calculate_farm_entry_rewards(
+		farm_entry: &YieldFarmEntry<T, I>,
+		yield_farm: &YieldFarmData<T, I>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		//NOTE: this should never fail yield-farm's stopped must be >= entry's stopped
+		let delta_stopped = yield_farm
+			.total_stopped
+			.checked_sub(&farm_entry.stopped_at_creation)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		//NOTE: yield-farm's `updated_at` is updated to current period if it's possible so this
+		//should be ok.
+		let periods = yield_farm
+			.updated_at
+			.checked_sub(&farm_entry.entered_at)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
+			.checked_sub(&delta_stopped)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		let loyalty_multiplier = Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())?;
+
+		let rewards = math::calculate_user_reward(
+			farm_entry.accumulated_rpvs,
+			farm_entry.valued_shares,
+			farm_entry.accumulated_claimed_rewards,
+			yield_farm.accumulated_rpvs,
+			loyalty_multiplier,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		Ok(rewards)
+	}
+
 	/// This function returns loyalty multiplier or error.
 	fn get_loyalty_multiplier(periods: PeriodOf<T>, curve: Option<LoyaltyCurve>) -> Result<FixedU128, DispatchError> {
 		let curve = match curve {
@@ -1512,8 +1675,34 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			None => return Ok(FixedU128::one()), //no loyalty curve mean no loyalty multiplier
 		};
 
-		let m = math::calculate_loyalty_multiplier(periods, curve.initial_reward_percentage, curve.scale_coef)
-			.map_err(|_| ArithmeticError::Overflow)?;
+		let m = match curve {
+			LoyaltyCurve::Hyperbolic {
+				initial_reward_percentage,
+				scale_coef,
+			} => math::calculate_loyalty_multiplier(periods, initial_reward_percentage, scale_coef),
+			LoyaltyCurve::Linear {
+				initial_reward_percentage,
+				vesting_periods,
+			} => math::calculate_linear_loyalty_multiplier(periods, initial_reward_percentage, vesting_periods),
+			LoyaltyCurve::CliffLinear {
+				initial_reward_percentage,
+				cliff_periods,
+				vesting_periods,
+			} => math::calculate_cliff_linear_loyalty_multiplier(
+				periods,
+				initial_reward_percentage,
+				cliff_periods,
+				vesting_periods,
+			),
+			LoyaltyCurve::Step {
+				initial_reward_percentage,
+				step_periods,
+				step_increase,
+			} => {
+				math::calculate_step_loyalty_multiplier(periods, initial_reward_percentage, step_periods, step_increase)
+			}
+		}
+		.map_err(|_| ArithmeticError::Overflow)?;
 
 		ensure!(
 			m.le(&FixedU128::one()),
@@ -1939,6 +2128,14 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 	fn get_global_farm_id(deposit_id: DepositId, yield_farm_id: YieldFarmId) -> Option<u32> {
 		Self::get_global_farm_id(deposit_id, yield_farm_id)
 	}
+
+	fn get_yield_farm_ids(deposit_id: DepositId) -> Option<Vec<YieldFarmId>> {
+		Self::deposit(deposit_id).map(|d| d.yield_farm_entries.iter().map(|e| e.yield_farm_id).collect())
+	}
+
+	fn get_amm_pool_id(deposit_id: DepositId) -> Option<Self::AmmPoolId> {
+		Self::deposit(deposit_id).map(|d| d.amm_pool_id)
+	}
 }
 
 impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Inspect<T::AccountId> for Pallet<T, I> {
@@ -0,0 +1,139 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::Saturating;
+
+/// The log target.
+const TARGET: &str = "runtime::liquidity-mining::migration::v1";
+
+pub mod v0 {
+	use super::*;
+
+	/// Hyperbolic loyalty curve which was the only supported loyalty curve.
+	#[derive(Encode, Decode)]
+	pub struct LoyaltyCurve {
+		pub initial_reward_percentage: FixedU128,
+		pub scale_coef: u32,
+	}
+
+	/// Yield farm data with hyperbolic loyalty curve.
+	#[derive(Encode, Decode)]
+	pub struct YieldFarmData<T: Config<I>, I: 'static = ()> {
+		pub id: FarmId,
+		pub updated_at: PeriodOf<T>,
+		pub total_shares: Balance,
+		pub total_valued_shares: Balance,
+		pub accumulated_rpvs: FixedU128,
+		pub accumulated_rpz: FixedU128,
+		pub loyalty_curve: Option<LoyaltyCurve>,
+		pub multiplier: FarmMultiplier,
+		pub state: FarmState,
+		pub entries_count: u64,
+		pub left_to_distribute: Balance,
+		pub total_stopped: PeriodOf<T>,
+		pub _phantom: PhantomData<I>,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Converts loyalty curves of all existing yield farms to `LoyaltyCurve::Hyperbolic`.
+	pub struct Migration<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for Migration<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T, I>>(), 0, "Storage version too high.");
+
+			let yield_farms_count = YieldFarm::<T, I>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "Liquidity mining migration: PRE checks successful!");
+
+			Ok(yield_farms_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T, I>>() != 0 {
+				log::warn!(
+					target: TARGET,
+					"Liquidity mining migration to v1 skipped because storage version is not 0"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for Liquidity mining");
+
+			let mut translated: u64 = 0;
+			YieldFarm::<T, I>::translate_values::<v0::YieldFarmData<T, I>, _>(|yield_farm| {
+				translated.saturating_inc();
+				Some(YieldFarmData {
+					id: yield_farm.id,
+					updated_at: yield_farm.updated_at,
+					total_shares: yield_farm.total_shares,
+					total_valued_shares: yield_farm.total_valued_shares,
+					accumulated_rpvs: yield_farm.accumulated_rpvs,
+					accumulated_rpz: yield_farm.accumulated_rpz,
+					loyalty_curve: yield_farm.loyalty_curve.map(|curve| LoyaltyCurve::Hyperbolic {
+						initial_reward_percentage: curve.initial_reward_percentage,
+						scale_coef: curve.scale_coef,
+					}),
+					multiplier: yield_farm.multiplier,
+					state: yield_farm.state,
+					entries_count: yield_farm.entries_count,
+					left_to_distribute: yield_farm.left_to_distribute,
+					total_stopped: yield_farm.total_stopped,
+					_phantom: PhantomData,
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			log::info!(
+				target: TARGET,
+				"Liquidity mining migration to v1 migrated {:?} yield farms",
+				translated
+			);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T, I>>(), 1, "Unexpected storage version.");
+
+			let previous_count = <u64 as Decode>::decode(&mut state.as_slice()).unwrap();
+			let yield_farms = YieldFarm::<T, I>::iter_values().collect::<Vec<_>>();
+
+			assert_eq!(
+				yield_farms.len() as u64,
+				previous_count,
+				"Migrated yield farms don't match the yield farms prior migration!"
+			);
+			assert!(yield_farms
+				.iter()
+				.all(|yield_farm| matches!(yield_farm.loyalty_curve, None | Some(LoyaltyCurve::Hyperbolic { .. }))));
+
+			log::info!(target: TARGET, "Liquidity mining migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
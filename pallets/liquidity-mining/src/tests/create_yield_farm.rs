@@ -98,7 +98,7 @@ fn create_yield_farm_should_work() {
 				accumulated_rpvs: Zero::zero(),
 				accumulated_rpz: Zero::zero(),
 				multiplier: FixedU128::from(10_000_u128),
-				loyalty_curve: Some(LoyaltyCurve {
+				loyalty_curve: Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from_inner(100_000_000_000_000_000),
 					scale_coef: 50,
 				}),
@@ -132,7 +132,7 @@ fn create_yield_farm_should_work() {
 				accumulated_rpvs: Zero::zero(),
 				accumulated_rpz: Zero::zero(),
 				multiplier: FixedU128::from(50_000_128),
-				loyalty_curve: Some(LoyaltyCurve {
+				loyalty_curve: Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from_inner(1),
 					scale_coef: 0,
 				}),
@@ -251,27 +251,27 @@ fn add_yield_farm_invalid_loyalty_curve_should_not_work() {
 	predefined_test_ext().execute_with(|| {
 		let _ = with_transaction(|| {
 			let curves = vec![
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::one(),
 					scale_coef: 0,
 				}),
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from_float(1.0),
 					scale_coef: 1_000_000,
 				}),
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from_float(1.000_000_000_000_000_001),
 					scale_coef: 25_996_000,
 				}),
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from(1_u128),
 					scale_coef: 25_996_000,
 				}),
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from(5_u128),
 					scale_coef: 25_996_000,
 				}),
-				Some(LoyaltyCurve {
+				Some(LoyaltyCurve::Hyperbolic {
 					initial_reward_percentage: FixedU128::from(16_874_354_654_u128),
 					scale_coef: 25_996_000,
 				}),
@@ -296,6 +296,123 @@ fn add_yield_farm_invalid_loyalty_curve_should_not_work() {
 	});
 }
 
+#[test]
+fn add_yield_farm_invalid_loyalty_curve_params_should_not_work() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			let curves = vec![
+				Some(LoyaltyCurve::Linear {
+					initial_reward_percentage: FixedU128::from_float(0.5),
+					vesting_periods: 0,
+				}),
+				Some(LoyaltyCurve::CliffLinear {
+					initial_reward_percentage: FixedU128::from_float(0.5),
+					cliff_periods: 10,
+					vesting_periods: 0,
+				}),
+				Some(LoyaltyCurve::Step {
+					initial_reward_percentage: FixedU128::from_float(0.5),
+					step_periods: 0,
+					step_increase: FixedU128::from_float(0.1),
+				}),
+				Some(LoyaltyCurve::Step {
+					initial_reward_percentage: FixedU128::from_float(0.5),
+					step_periods: 10,
+					step_increase: FixedU128::zero(),
+				}),
+			];
+
+			for c in curves {
+				assert_noop!(
+					LiquidityMining::create_yield_farm(
+						ALICE,
+						ALICE_FARM,
+						FixedU128::from(10_000_u128),
+						c,
+						BSX_HDX_AMM,
+						vec![BSX, HDX],
+					),
+					Error::<Test, Instance1>::InvalidLoyaltyCurve
+				);
+			}
+
+			assert_noop!(
+				LiquidityMining::create_yield_farm(
+					ALICE,
+					ALICE_FARM,
+					FixedU128::from(10_000_u128),
+					Some(LoyaltyCurve::Linear {
+						initial_reward_percentage: FixedU128::one(),
+						vesting_periods: 100,
+					}),
+					BSX_HDX_AMM,
+					vec![BSX, HDX],
+				),
+				Error::<Test, Instance1>::InvalidInitialRewardPercentage
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn create_yield_farm_with_non_hyperbolic_loyalty_curve_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			let curves = vec![
+				(
+					BSX_HDX_AMM,
+					vec![BSX, HDX],
+					LoyaltyCurve::Linear {
+						initial_reward_percentage: FixedU128::from_float(0.25),
+						vesting_periods: 1_000,
+					},
+				),
+				(
+					BSX_DOT_AMM,
+					vec![BSX, DOT],
+					LoyaltyCurve::CliffLinear {
+						initial_reward_percentage: FixedU128::zero(),
+						cliff_periods: 100,
+						vesting_periods: 500,
+					},
+				),
+				(
+					BSX_ETH_AMM,
+					vec![BSX, ETH],
+					LoyaltyCurve::Step {
+						initial_reward_percentage: FixedU128::from_float(0.2),
+						step_periods: 50,
+						step_increase: FixedU128::from_float(0.2),
+					},
+				),
+			];
+
+			for (amm_pool_id, assets, curve) in curves {
+				let yield_farm_id = LiquidityMining::create_yield_farm(
+					ALICE,
+					ALICE_FARM,
+					FixedU128::from(10_000_u128),
+					Some(curve.clone()),
+					amm_pool_id,
+					assets,
+				)
+				.unwrap();
+
+				assert_eq!(
+					LiquidityMining::yield_farm((amm_pool_id, ALICE_FARM, yield_farm_id))
+						.unwrap()
+						.loyalty_curve,
+					Some(curve)
+				);
+			}
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
 #[test]
 fn add_yield_farm_invalid_multiplier_should_not_work() {
 	predefined_test_ext().execute_with(|| {
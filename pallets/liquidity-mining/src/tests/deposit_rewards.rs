@@ -0,0 +1,236 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+//12s blocks
+const BLOCKS_PER_YEAR: BlockNumber = 2_628_000;
+
+#[test]
+fn deposit_rewards_should_return_same_rewards_as_claim_rewards() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			const FAIL_ON_DOUBLECLAIM: bool = true;
+			set_block_number(50_000);
+			assert_ok!(LiquidityMining::redeposit_lp_shares(
+				EVE_FARM,
+				EVE_BSX_TKN1_YIELD_FARM_ID,
+				PREDEFINED_DEPOSIT_IDS[0],
+				|_, _, _| { Ok(4_000 * ONE) }
+			));
+
+			set_block_number(800_000);
+			assert_ok!(LiquidityMining::redeposit_lp_shares(
+				DAVE_FARM,
+				DAVE_BSX_TKN1_YIELD_FARM_ID,
+				PREDEFINED_DEPOSIT_IDS[0],
+				|_, _, _| { Ok(5_000 * ONE) }
+			));
+
+			set_block_number(1_000_000);
+
+			let pot = LiquidityMining::pot_account_id().unwrap();
+			let deposit = LiquidityMining::deposit(PREDEFINED_DEPOSIT_IDS[0]).unwrap();
+			let gc_farm = LiquidityMining::global_farm(GC_FARM).unwrap();
+			let gc_yield_farm =
+				LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap();
+			let pot_bsx_balance = Tokens::free_balance(BSX, &pot);
+
+			//Act
+			let rewards = LiquidityMining::deposit_rewards(PREDEFINED_DEPOSIT_IDS[0]).unwrap();
+
+			//Assert
+			//NOTE: storage must not be changed.
+			assert_eq!(LiquidityMining::deposit(PREDEFINED_DEPOSIT_IDS[0]).unwrap(), deposit);
+			assert_eq!(LiquidityMining::global_farm(GC_FARM).unwrap(), gc_farm);
+			assert_eq!(
+				LiquidityMining::yield_farm((BSX_TKN1_AMM, GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID)).unwrap(),
+				gc_yield_farm
+			);
+			assert_eq!(Tokens::free_balance(BSX, &pot), pot_bsx_balance);
+
+			let mut expected_rewards = Vec::new();
+			for (global_farm_id, yield_farm_id) in [
+				(GC_FARM, GC_BSX_TKN1_YIELD_FARM_ID),
+				(EVE_FARM, EVE_BSX_TKN1_YIELD_FARM_ID),
+				(DAVE_FARM, DAVE_BSX_TKN1_YIELD_FARM_ID),
+			] {
+				let (_, reward_currency, claimed, unclaimable) = LiquidityMining::claim_rewards(
+					ALICE,
+					PREDEFINED_DEPOSIT_IDS[0],
+					yield_farm_id,
+					FAIL_ON_DOUBLECLAIM,
+				)
+				.unwrap();
+
+				expected_rewards.push(FarmEntryRewards {
+					global_farm_id,
+					yield_farm_id,
+					reward_currency,
+					claimable_rewards: claimed,
+					unclaimable_rewards: unclaimable,
+				});
+			}
+
+			assert_eq!(rewards, expected_rewards);
+			assert_eq!(
+				rewards[1],
+				FarmEntryRewards {
+					global_farm_id: EVE_FARM,
+					yield_farm_id: EVE_BSX_TKN1_YIELD_FARM_ID,
+					reward_currency: KSM,
+					claimable_rewards: 7_238_095_238_095_238_088,
+					unclaimable_rewards: 361_904_761_904_761_912,
+				}
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_rewards_should_skip_entries_in_terminated_yield_farms() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			set_block_number(50_000);
+			assert_ok!(LiquidityMining::redeposit_lp_shares(
+				EVE_FARM,
+				EVE_BSX_TKN1_YIELD_FARM_ID,
+				PREDEFINED_DEPOSIT_IDS[0],
+				|_, _, _| { Ok(4_000 * ONE) }
+			));
+
+			set_block_number(100_000);
+			assert_ok!(LiquidityMining::stop_yield_farm(GC, GC_FARM, BSX_TKN1_AMM));
+			assert_ok!(LiquidityMining::terminate_yield_farm(
+				GC,
+				GC_FARM,
+				GC_BSX_TKN1_YIELD_FARM_ID,
+				BSX_TKN1_AMM
+			));
+
+			set_block_number(1_000_000);
+
+			//Act & assert
+			let rewards = LiquidityMining::deposit_rewards(PREDEFINED_DEPOSIT_IDS[0]).unwrap();
+
+			assert_eq!(rewards.len(), 1);
+			assert_eq!(rewards[0].global_farm_id, EVE_FARM);
+			assert_eq!(rewards[0].yield_farm_id, EVE_BSX_TKN1_YIELD_FARM_ID);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_rewards_should_not_work_when_deposit_does_not_exist() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::deposit_rewards(999_999),
+				Error::<Test, Instance1>::DepositNotFound
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn global_farm_rewards_info_should_work() {
+	predefined_test_ext_with_deposits().execute_with(|| {
+		let _ = with_transaction(|| {
+			set_block_number(1_000_000);
+
+			let global_farm = LiquidityMining::global_farm(GC_FARM).unwrap();
+
+			//Act
+			let info = LiquidityMining::global_farm_rewards_info(GC_FARM, BLOCKS_PER_YEAR).unwrap();
+
+			//Assert
+			//NOTE: storage must not be changed.
+			assert_eq!(LiquidityMining::global_farm(GC_FARM).unwrap(), global_farm);
+
+			let mut synced_global_farm = global_farm.clone();
+			let current_period = LiquidityMining::get_current_period(global_farm.blocks_per_period).unwrap();
+			let reward = LiquidityMining::sync_global_farm(&mut synced_global_farm, current_period).unwrap();
+			assert!(!reward.is_zero());
+
+			let global_farm_account = LiquidityMining::farm_account_id(GC_FARM).unwrap();
+			let periods_per_year = BLOCKS_PER_YEAR / global_farm.blocks_per_period;
+
+			assert_eq!(
+				info,
+				GlobalFarmRewardsInfo {
+					global_farm_id: GC_FARM,
+					incentivized_asset: BSX,
+					reward_currency: BSX,
+					apr: hydra_dx_math::liquidity_mining::calculate_global_farm_apr(
+						synced_global_farm.total_shares_z,
+						synced_global_farm.price_adjustment,
+						synced_global_farm.yield_per_period.into(),
+						synced_global_farm.max_reward_per_period,
+						periods_per_year,
+					)
+					.unwrap(),
+					remaining_rewards: Tokens::free_balance(BSX, &global_farm_account) - 1_000,
+					pending_rewards: synced_global_farm.pending_rewards,
+				}
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn global_farm_rewards_info_should_return_max_apr_when_farm_is_empty() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			set_block_number(100_000);
+
+			//Act & assert
+			//yield_per_period: 20%, blocks_per_period: 1_000
+			assert_eq!(
+				LiquidityMining::global_farm_rewards_info(ALICE_FARM, BLOCKS_PER_YEAR)
+					.unwrap()
+					.apr,
+				FixedU128::from_rational(5_256, 10) //0.2 * 2_628
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn global_farm_rewards_info_should_not_work_when_global_farm_does_not_exist() {
+	predefined_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			assert_noop!(
+				LiquidityMining::global_farm_rewards_info(999_999, BLOCKS_PER_YEAR),
+				Error::<Test, Instance1>::GlobalFarmNotFound
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
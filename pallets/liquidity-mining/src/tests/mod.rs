@@ -251,6 +251,7 @@ pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
 pub mod deposit_lp_shares;
+pub mod deposit_rewards;
 pub mod full_run;
 pub mod invariants;
 pub mod mock;
@@ -234,15 +234,15 @@ fn get_period_number_should_not_work_when_block_per_period_is_zero() {
 #[test]
 fn get_loyalty_multiplier_should_work() {
 	let loyalty_curve_1 = LoyaltyCurve::default();
-	let loyalty_curve_2 = LoyaltyCurve {
+	let loyalty_curve_2 = LoyaltyCurve::Hyperbolic {
 		initial_reward_percentage: FixedU128::from(1),
 		scale_coef: 50,
 	};
-	let loyalty_curve_3 = LoyaltyCurve {
+	let loyalty_curve_3 = LoyaltyCurve::Hyperbolic {
 		initial_reward_percentage: FixedU128::from_inner(123_580_000_000_000_000), // 0.12358
 		scale_coef: 23,
 	};
-	let loyalty_curve_4 = LoyaltyCurve {
+	let loyalty_curve_4 = LoyaltyCurve::Hyperbolic {
 		initial_reward_percentage: FixedU128::from_inner(0), // 0.12358
 		scale_coef: 15,
 	};
@@ -395,6 +395,63 @@ fn get_loyalty_multiplier_should_work() {
 	}
 }
 
+#[test]
+fn get_loyalty_multiplier_should_work_for_non_hyperbolic_curves() {
+	let linear = LoyaltyCurve::Linear {
+		initial_reward_percentage: FixedU128::from_float(0.5),
+		vesting_periods: 100,
+	};
+
+	let cliff_linear = LoyaltyCurve::CliffLinear {
+		initial_reward_percentage: FixedU128::from_float(0.2),
+		cliff_periods: 50,
+		vesting_periods: 200,
+	};
+
+	let step = LoyaltyCurve::Step {
+		initial_reward_percentage: FixedU128::from_float(0.4),
+		step_periods: 30,
+		step_increase: FixedU128::from_float(0.25),
+	};
+
+	//(periods, linear, cliff_linear, step)
+	let testing_values = vec![
+		(0, 0.5_f64, 0.2_f64, 0.4_f64),
+		(10, 0.55_f64, 0.2_f64, 0.4_f64),
+		(29, 0.645_f64, 0.2_f64, 0.4_f64),
+		(30, 0.65_f64, 0.2_f64, 0.65_f64),
+		(49, 0.745_f64, 0.2_f64, 0.65_f64),
+		(50, 0.75_f64, 0.2_f64, 0.65_f64),
+		(60, 0.8_f64, 0.24_f64, 0.9_f64),
+		(90, 0.95_f64, 0.36_f64, 1_f64),
+		(100, 1_f64, 0.4_f64, 1_f64),
+		(150, 1_f64, 0.6_f64, 1_f64),
+		(250, 1_f64, 1_f64, 1_f64),
+		(4_000, 1_f64, 1_f64, 1_f64),
+	];
+
+	let precission_delta = FixedU128::from_inner(100_000_000); //0.000_000_000_1
+	for (periods, expected_linear, expected_cliff_linear, expected_step) in testing_values.iter() {
+		assert!(is_approx_eq_fixedu128(
+			LiquidityMining::get_loyalty_multiplier(*periods, Some(linear.clone())).unwrap(),
+			FixedU128::from_float(*expected_linear),
+			precission_delta
+		));
+
+		assert!(is_approx_eq_fixedu128(
+			LiquidityMining::get_loyalty_multiplier(*periods, Some(cliff_linear.clone())).unwrap(),
+			FixedU128::from_float(*expected_cliff_linear),
+			precission_delta
+		));
+
+		assert!(is_approx_eq_fixedu128(
+			LiquidityMining::get_loyalty_multiplier(*periods, Some(step.clone())).unwrap(),
+			FixedU128::from_float(*expected_step),
+			precission_delta
+		));
+	}
+}
+
 #[test]
 fn sync_global_farm_should_work() {
 	let testing_values = vec![
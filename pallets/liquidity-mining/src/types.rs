@@ -267,20 +267,70 @@ impl<T: Config<I>, I: 'static> YieldFarmData<T, I> {
 /// Loyalty curve to calculate loyalty multiplier.
 ///
 /// `t = t_now - t_added`
-/// `num = t + initial_reward_percentage * scale_coef`
-/// `denom = t + scale_coef`
-///
-/// `loyalty_multiplier = num/denom`
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-#[scale_info(skip_type_params(T, I))]
-pub struct LoyaltyCurve {
-	pub initial_reward_percentage: FixedU128,
-	pub scale_coef: u32,
+pub enum LoyaltyCurve {
+	/// `num = t + initial_reward_percentage * scale_coef`
+	/// `denom = t + scale_coef`
+	///
+	/// `loyalty_multiplier = num/denom`
+	Hyperbolic {
+		initial_reward_percentage: FixedU128,
+		scale_coef: u32,
+	},
+
+	/// Loyalty multiplier grows linearly from `initial_reward_percentage` to `1` in `vesting_periods`.
+	///
+	/// `loyalty_multiplier = initial_reward_percentage + (1 - initial_reward_percentage) * t / vesting_periods`
+	Linear {
+		initial_reward_percentage: FixedU128,
+		vesting_periods: u32,
+	},
+
+	/// Loyalty multiplier is `initial_reward_percentage` for `cliff_periods` and after the cliff
+	/// it grows linearly to `1` in `vesting_periods`.
+	CliffLinear {
+		initial_reward_percentage: FixedU128,
+		cliff_periods: u32,
+		vesting_periods: u32,
+	},
+
+	/// Loyalty multiplier grows by `step_increase` every `step_periods` and it's capped at `1`.
+	///
+	/// `loyalty_multiplier = min(1, initial_reward_percentage + step_increase * floor(t / step_periods))`
+	Step {
+		initial_reward_percentage: FixedU128,
+		step_periods: u32,
+		step_increase: FixedU128,
+	},
+}
+
+impl LoyaltyCurve {
+	/// Returns loyalty multiplier at `t = 0`.
+	pub fn initial_reward_percentage(&self) -> FixedU128 {
+		match self {
+			LoyaltyCurve::Hyperbolic {
+				initial_reward_percentage,
+				..
+			}
+			| LoyaltyCurve::Linear {
+				initial_reward_percentage,
+				..
+			}
+			| LoyaltyCurve::CliffLinear {
+				initial_reward_percentage,
+				..
+			}
+			| LoyaltyCurve::Step {
+				initial_reward_percentage,
+				..
+			} => *initial_reward_percentage,
+		}
+	}
 }
 
 impl Default for LoyaltyCurve {
 	fn default() -> Self {
-		Self {
+		Self::Hyperbolic {
 			initial_reward_percentage: FixedU128::from_inner(500_000_000_000_000_000), // 0.5
 			scale_coef: 100,
 		}
@@ -427,3 +477,30 @@ impl FarmState {
 		*self == FarmState::Terminated
 	}
 }
+
+/// Rewards of the deposit's farm entry as if they were claimed in the current period.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FarmEntryRewards<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	pub yield_farm_id: YieldFarmId,
+	pub reward_currency: AssetId,
+	/// Rewards user would receive by claiming.
+	pub claimable_rewards: Balance,
+	/// Rewards locked by the loyalty curve. These rewards are returned to the global farm if user
+	/// withdraws LP shares.
+	pub unclaimable_rewards: Balance,
+}
+
+/// Current state of the global farm's rewards.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct GlobalFarmRewardsInfo<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	pub incentivized_asset: AssetId,
+	pub reward_currency: AssetId,
+	/// Annual percentage rate of the rewards relative to the farm's stake valued in reward currency.
+	pub apr: FixedU128,
+	/// Rewards left in the global farm's account which were not distributed yet.
+	pub remaining_rewards: Balance,
+	/// Rewards transferred to the pot which were not distributed to yield farms yet.
+	pub pending_rewards: Balance,
+}
@@ -44,12 +44,14 @@ pub mod migration;
 pub mod weights;
 
 use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
 	ensure,
-	pallet_prelude::{DispatchError, DispatchResult},
+	pallet_prelude::{DispatchError, DispatchResult, Weight},
 	sp_runtime::traits::{AccountIdConversion, Zero},
+	storage::with_storage_layer,
 	traits::DefensiveOption,
 	traits::{
-		tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+		tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
 		Get,
 	},
 	PalletId,
@@ -57,6 +59,7 @@ use frame_support::{
 use frame_system::{
 	ensure_signed,
 	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
 };
 use hydra_dx_math::ema::EmaPrice as Price;
 use hydradx_traits::{
@@ -66,11 +69,15 @@ use hydradx_traits::{
 use orml_traits::MultiCurrency;
 use pallet_ema_oracle::OracleError;
 use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
-use pallet_omnipool::{types::Position as OmniPosition, NFTCollectionIdOf};
+use pallet_omnipool::{
+	traits::{ExternalPriceProvider, OmnipoolHooks},
+	types::Position as OmniPosition,
+	NFTCollectionIdOf, WeightInfo as OmnipoolWeightInfo,
+};
 use primitive_types::U256;
 use primitives::{Balance, ItemId as DepositId};
 use sp_runtime::{ArithmeticError, FixedU128, Perquintill};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -130,6 +137,7 @@ pub mod pallet {
 		type NFTHandler: Mutate<Self::AccountId>
 			+ Create<Self::AccountId>
 			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>
+			+ InspectEnumerable<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>
 			+ Transfer<Self::AccountId>;
 
 		/// Liquidity mining handler for managing liquidity mining functionalities
@@ -275,6 +283,13 @@ pub mod pallet {
 
 		/// All LP shares were unlocked and NFT representing deposit was destroyed.
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards from all deposits of the account were claimed.
+		AllRewardsClaimed {
+			who: T::AccountId,
+			claims: Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Balance)>,
+			compounded: Vec<(T::AssetId, Balance)>,
+		},
 	}
 
 	#[pallet::error]
@@ -878,6 +893,108 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Claim rewards from all yield farm entries of all deposits owned by `origin`.
+		///
+		/// Deposits are processed until `max_entries` yield farm entries were visited. Entries which
+		/// can't be claimed (e.g. rewards were already claimed in the current period) are skipped.
+		///
+		/// If `compound` is `true`, claimed rewards are added back to the omnipool as new LP
+		/// positions owned by `origin`. Rewards in asset which can't be added to the omnipool stay
+		/// in `origin`'s account.
+		///
+		/// Every deposit has at least one yield farm entry so at most `max_entries` deposits are
+		/// visited.
+		///
+		/// Parameters:
+		/// - `origin`: owner of deposits.
+		/// - `max_entries`: max number of yield farm entries to claim rewards from.
+		/// - `compound`: if `true` claimed rewards are added back to the omnipool.
+		///
+		/// Emits `AllRewardsClaimed` event when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::claim_all_rewards_weight(*max_entries, *max_entries, *compound))]
+		pub fn claim_all_rewards(origin: OriginFor<T>, max_entries: u32, compound: bool) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut claims: Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Balance)> = Vec::new();
+			let mut visited_deposits: u32 = 0;
+			let mut processed_entries: u32 = 0;
+
+			let deposits = <T as pallet::Config>::NFTHandler::owned_in_collection(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&who,
+			);
+
+			'deposits: for deposit_id in deposits.take(max_entries as usize) {
+				visited_deposits = visited_deposits.saturating_add(1);
+
+				let yield_farm_ids = T::LiquidityMiningHandler::get_yield_farm_ids(deposit_id).unwrap_or_default();
+
+				for yield_farm_id in yield_farm_ids {
+					if processed_entries >= max_entries {
+						break 'deposits;
+					}
+					processed_entries = processed_entries.saturating_add(1);
+
+					let claim_result = with_storage_layer(|| -> Result<_, DispatchError> {
+						let (global_farm_id, reward_currency, claimed, _) =
+							T::LiquidityMiningHandler::claim_rewards(who.clone(), deposit_id, yield_farm_id)?;
+
+						ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+						Ok((global_farm_id, reward_currency, claimed))
+					});
+
+					if let Ok((global_farm_id, reward_currency, claimed)) = claim_result {
+						match claims
+							.iter_mut()
+							.find(|(g, y, c, _)| *g == global_farm_id && *y == yield_farm_id && *c == reward_currency)
+						{
+							Some((_, _, _, total)) => *total = total.saturating_add(claimed),
+							None => claims.push((global_farm_id, yield_farm_id, reward_currency, claimed)),
+						}
+					}
+				}
+			}
+
+			ensure!(!claims.is_empty(), Error::<T>::ZeroClaimedRewards);
+
+			let mut compounded: Vec<(T::AssetId, Balance)> = Vec::new();
+			if compound {
+				for (_, _, reward_currency, claimed) in claims.iter() {
+					match compounded.iter_mut().find(|(c, _)| c == reward_currency) {
+						Some((_, total)) => *total = total.saturating_add(*claimed),
+						None => compounded.push((*reward_currency, *claimed)),
+					}
+				}
+
+				compounded.retain(|(reward_currency, amount)| {
+					with_storage_layer(|| {
+						OmnipoolPallet::<T>::add_liquidity(
+							RawOrigin::Signed(who.clone()).into(),
+							*reward_currency,
+							*amount,
+						)
+					})
+					.is_ok()
+				});
+			}
+
+			Self::deposit_event(Event::AllRewardsClaimed {
+				who,
+				claims,
+				compounded,
+			});
+
+			Ok(Some(Self::claim_all_rewards_weight(
+				visited_deposits,
+				processed_entries,
+				compound,
+			))
+			.into())
+		}
 	}
 }
 
@@ -951,6 +1068,29 @@ impl<T: Config> Pallet<T> {
 		Ok(position_value)
 	}
 
+	/// Weight of the `claim_all_rewards` for `deposits` visited deposits and `entries` yield farm
+	/// entries. Every visited deposit is read from the account's deposits enumeration and its
+	/// yield farm entries are read. Every entry can yield rewards in different currency so
+	/// compounding is accounted for each entry.
+	fn claim_all_rewards_weight(deposits: u32, entries: u32, compound: bool) -> Weight {
+		// Enumeration reads one more key to find out there are no more deposits.
+		let enumeration_weight = T::DbWeight::get().reads(1);
+		let deposit_weight = T::DbWeight::get().reads(2);
+
+		let mut entry_weight = <T as pallet::Config>::WeightInfo::claim_rewards();
+		if compound {
+			entry_weight = entry_weight.saturating_add(
+				<T as pallet_omnipool::Config>::WeightInfo::add_liquidity()
+					.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+					.saturating_add(T::ExternalPriceOracle::get_price_weight()),
+			);
+		}
+
+		enumeration_weight
+			.saturating_add(deposit_weight.saturating_mul(deposits.into()))
+			.saturating_add(entry_weight.saturating_mul(entries.into()))
+	}
+
 	/// This function check if origin is signed and returns account if account is owner of the
 	/// deposit.
 	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
@@ -0,0 +1,204 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use orml_traits::MultiCurrency;
+
+const GC_G_FARM_ID: GlobalFarmId = 1;
+const CHARLIE_G_FARM_ID: GlobalFarmId = 2;
+const GC_Y_FARM_ID: YieldFarmId = 3;
+const CHARLIE_Y_FARM_ID: YieldFarmId = 4;
+const DEPOSIT_ID: DepositId = 1;
+
+fn ext_with_deposit_in_two_farms() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(CHARLIE, HDX, 100_000_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			//id: 2
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			CHARLIE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, GC_G_FARM_ID, KSM, FixedU128::one(), None) //id: 3
+		.with_yield_farm(CHARLIE, CHARLIE_G_FARM_ID, KSM, FixedU128::one(), None) //id: 4
+		.build();
+
+	ext.execute_with(|| {
+		let omnipool_position_id = 2;
+
+		assert_ok!(OmnipoolMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			GC_G_FARM_ID,
+			GC_Y_FARM_ID,
+			omnipool_position_id
+		));
+
+		assert_ok!(OmnipoolMining::redeposit_shares(
+			RuntimeOrigin::signed(LP1),
+			CHARLIE_G_FARM_ID,
+			CHARLIE_Y_FARM_ID,
+			DEPOSIT_ID
+		));
+
+		set_block_number(1_000);
+	});
+
+	ext
+}
+
+fn last_claims() -> (
+	Vec<(GlobalFarmId, YieldFarmId, AssetId, mock::Balance)>,
+	Vec<(AssetId, mock::Balance)>,
+) {
+	match System::events().last().expect("events expected").event.clone() {
+		RuntimeEvent::OmnipoolMining(crate::Event::AllRewardsClaimed {
+			who,
+			claims,
+			compounded,
+		}) => {
+			assert_eq!(who, LP1);
+			(claims, compounded)
+		}
+		e => panic!("unexpected last event: {:?}", e),
+	}
+}
+
+#[test]
+fn claim_all_rewards_should_claim_rewards_from_all_farm_entries() {
+	ext_with_deposit_in_two_farms().execute_with(|| {
+		let lp1_hdx_balance = Tokens::free_balance(HDX, &LP1);
+
+		//Act
+		assert_ok!(OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 10, false));
+
+		//Assert
+		let (claims, compounded) = last_claims();
+		assert_eq!(claims.len(), 2);
+		assert!(compounded.is_empty());
+
+		assert_eq!(
+			claims
+				.iter()
+				.map(|(g, y, c, _)| (*g, *y, *c))
+				.collect::<Vec<(GlobalFarmId, YieldFarmId, AssetId)>>(),
+			vec![
+				(GC_G_FARM_ID, GC_Y_FARM_ID, HDX),
+				(CHARLIE_G_FARM_ID, CHARLIE_Y_FARM_ID, HDX)
+			]
+		);
+		assert!(claims.iter().all(|(_, _, _, claimed)| !claimed.is_zero()));
+
+		let total_claimed: mock::Balance = claims.iter().map(|(_, _, _, claimed)| claimed).sum();
+		assert_eq!(Tokens::free_balance(HDX, &LP1), lp1_hdx_balance + total_claimed);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_not_claim_more_than_max_entries() {
+	ext_with_deposit_in_two_farms().execute_with(|| {
+		//Act
+		assert_ok!(OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 1, false));
+
+		//Assert
+		let (claims, _) = last_claims();
+		assert_eq!(claims.len(), 1);
+		assert_eq!((claims[0].0, claims[0].1), (GC_G_FARM_ID, GC_Y_FARM_ID));
+
+		//Already claimed entry is skipped and remaining entry is claimed.
+		assert_ok!(OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 2, false));
+
+		let (claims, _) = last_claims();
+		assert_eq!(claims.len(), 1);
+		assert_eq!((claims[0].0, claims[0].1), (CHARLIE_G_FARM_ID, CHARLIE_Y_FARM_ID));
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_fail_when_nothing_was_claimed() {
+	ext_with_deposit_in_two_farms().execute_with(|| {
+		assert_ok!(OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 10, false));
+
+		//Rewards were already claimed in this period.
+		assert_noop!(
+			OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 10, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Account without deposits.
+		assert_noop!(
+			OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP2), 10, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Zero `max_entries`
+		set_block_number(2_000);
+		assert_noop!(
+			OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 0, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_add_claimed_rewards_to_omnipool_when_compound_is_true() {
+	ext_with_deposit_in_two_farms().execute_with(|| {
+		let lp1_hdx_balance = Tokens::free_balance(HDX, &LP1);
+		let lp1_positions: Vec<ItemId> = DummyNFT::owned_in_collection(&OMNIPOOL_COLLECTION_ID, &LP1).collect();
+
+		//Act
+		assert_ok!(OmnipoolMining::claim_all_rewards(RuntimeOrigin::signed(LP1), 10, true));
+
+		//Assert
+		let (claims, compounded) = last_claims();
+		let total_claimed: mock::Balance = claims.iter().map(|(_, _, _, claimed)| claimed).sum();
+		assert_eq!(compounded, vec![(HDX, total_claimed)]);
+
+		//Claimed rewards were added to the omnipool as new position.
+		assert_eq!(Tokens::free_balance(HDX, &LP1), lp1_hdx_balance);
+		let new_positions: Vec<ItemId> = DummyNFT::owned_in_collection(&OMNIPOOL_COLLECTION_ID, &LP1)
+			.filter(|id| !lp1_positions.contains(id))
+			.collect();
+		assert_eq!(new_positions.len(), 1);
+
+		let position = Omnipool::load_position(new_positions[0], LP1).unwrap();
+		assert_eq!(position.asset_id, HDX);
+		assert_eq!(position.amount, total_claimed);
+	});
+}
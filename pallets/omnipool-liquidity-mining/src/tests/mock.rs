@@ -26,13 +26,13 @@ use frame_support::BoundedVec;
 use hydradx_traits::liquidity_mining::PriceAdjustment;
 use pallet_omnipool;
 
-use frame_support::traits::{ConstU128, Contains, Everything};
+use frame_support::traits::{ConstU128, Contains, Everything, Nothing};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 	weights::RuntimeDbWeight,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use orml_traits::parameter_type_with_key;
 use orml_traits::GetByKey;
 use pallet_liquidity_mining as warehouse_liquidity_mining;
@@ -247,6 +247,8 @@ use pallet_ema_oracle::MAX_PERIODS;
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = BoundedVec::truncate_from(vec![
 		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+	pub const MaxExternalPriceDeviation: Permill = Permill::from_percent(10);
+	pub const ExternalPriceReferencePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 }
 
 impl pallet_ema_oracle::Config for Test {
@@ -256,6 +258,12 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type ExternalSourceLocation = AccountId;
+	type ExternalOrigin = EnsureSigned<AccountId>;
+	type ExternalUpdateMinInterval = ConstU64<1>;
+	type MaxExternalPriceDeviation = MaxExternalPriceDeviation;
+	type ExternalPriceReferencePeriod = ExternalPriceReferencePeriod;
+	type ReservedSources = Nothing;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -575,7 +583,7 @@ impl ExtBuilder {
 	}
 }
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
 use hydra_dx_math::ema::EmaPrice;
 
 pub struct DummyNFT;
@@ -641,6 +649,56 @@ impl Transfer<AccountId> for DummyNFT {
 	}
 }
 
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		let mut collections: Vec<CollectionId> = NFTS.with(|v| v.borrow().keys().map(|(c, _)| *c).collect());
+		collections.sort();
+		collections.dedup();
+		Box::new(collections.into_iter())
+	}
+
+	fn items(collection: &Self::CollectionId) -> Self::ItemsIterator {
+		let mut items: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.keys()
+				.filter(|(c, _)| c == collection)
+				.map(|(_, i)| *i)
+				.collect()
+		});
+		items.sort();
+		Box::new(items.into_iter())
+	}
+
+	fn owned(who: &AccountId) -> Self::OwnedIterator {
+		let mut owned: Vec<(CollectionId, ItemId)> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| *owner == who)
+				.map(|(key, _)| *key)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let mut owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((c, _), owner)| c == collection && *owner == who)
+				.map(|((_, i), _)| *i)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+}
+
 use hydradx_traits::Inspect as InspectRegistry;
 
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
@@ -0,0 +1,518 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! ## Overview
+//!
+//! Subpool is a Stableswap pool whose share token is an Omnipool asset.
+//!
+//! Correlated assets ( eg. stablecoins ) can be migrated from the Omnipool into a subpool. Reserve of a migrated asset
+//! is moved to the subpool and converted into subpool share tokens, which become part of the share asset reserve in the Omnipool.
+//! Hub asset of the migrated asset is moved to the share asset, so the assets share liquidity instead of each having its own Omnipool slot.
+//!
+//! Omnipool positions of a migrated asset are not converted automatically. Position owner converts it to a position
+//! of the subpool share asset by calling `migrate_position`.
+//!
+//! Trades between Omnipool assets and assets migrated into subpools are executed in one step by `sell` and `buy`.
+//! Trade is executed as a sequence of Stableswap liquidity operations and Omnipool trades on behalf of the trader.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_subpool` - Creates a subpool from two Omnipool assets and adds the share asset to the Omnipool.
+//! * `migrate_asset_to_subpool` - Migrates an Omnipool asset into an existing subpool.
+//! * `migrate_position` - Converts Omnipool position of a migrated asset into a position of the subpool share asset.
+//! * `sell` - Sells given amount of asset in for asset out. Any of the assets can be an Omnipool or a subpool asset.
+//! * `buy` - Buys given amount of asset out for asset in. Any of the assets can be an Omnipool or a subpool asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::DispatchResult;
+use frame_support::{ensure, transactional};
+use frame_system::pallet_prelude::OriginFor;
+use hydra_dx_math::omnipool_subpools::{
+	calculate_asset_migration_details, convert_position, create_subpool_initial_state,
+};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::types::{AssetState, Position, Tradability};
+use pallet_stableswap::types::AssetAmount;
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, Permill};
+use sp_std::num::NonZeroU16;
+use sp_std::vec;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+use crate::types::{AssetDetail, Balance};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+type CurrencyOf<T> = <T as pallet_omnipool::Config>::Currency;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool::Config
+		+ pallet_stableswap::Config<AssetId = <Self as pallet_omnipool::Config>::AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can create subpools and migrate assets into subpools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Assets migrated from the Omnipool into a subpool.
+	/// Maps migrated asset to the subpool id and asset's state at the time of migration.
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_assets)]
+	pub type MigratedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (AssetIdOf<T>, AssetDetail), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Subpool was created from two Omnipool assets.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: (AssetIdOf<T>, AssetIdOf<T>),
+		},
+		/// Omnipool asset was migrated into a subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+		},
+		/// Position of a migrated asset was converted into a position of the subpool share asset.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+		},
+		/// Sell trade executed.
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// Buy trade executed.
+		BuyExecuted {
+			who: T::AccountId,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Subpool does not exist or its share asset is not in the Omnipool.
+		SubpoolNotFound,
+		/// Asset of the position has not been migrated into a subpool.
+		AssetNotMigrated,
+		/// Trading same assets is not allowed.
+		SameAssetTradeNotAllowed,
+		/// Invalid amplification.
+		InvalidAmplification,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a subpool from two Omnipool assets.
+		///
+		/// Reserves of both assets are moved from the Omnipool to a new Stableswap pool.
+		/// Share tokens of the pool are minted to the Omnipool account and the share asset is added to the Omnipool
+		/// with hub reserve of both assets.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `asset_a`: Omnipool asset to migrate
+		/// - `asset_b`: Omnipool asset to migrate
+		/// - `share_asset_weight_cap`: weight cap of the share asset in the Omnipool
+		/// - `amplification`: subpool amplification
+		/// - `fee`: subpool fee
+		///
+		/// Emits `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool())]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			asset_a: AssetIdOf<T>,
+			asset_b: AssetIdOf<T>,
+			share_asset_weight_cap: Permill,
+			amplification: u16,
+			fee: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			let amplification = NonZeroU16::new(amplification).ok_or(Error::<T>::InvalidAmplification)?;
+
+			let asset_state_a = OmnipoolPallet::<T>::load_asset_state(asset_a)?;
+			let asset_state_b = OmnipoolPallet::<T>::load_asset_state(asset_b)?;
+
+			let pool_id = StableswapPallet::<T>::do_create_pool(share_asset, &[asset_a, asset_b], amplification, fee)?;
+
+			let subpool_state = create_subpool_initial_state(&(&asset_state_a).into(), &(&asset_state_b).into())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Self::move_asset_reserve(asset_a, pool_id, asset_state_a.reserve)?;
+			Self::move_asset_reserve(asset_b, pool_id, asset_state_b.reserve)?;
+			CurrencyOf::<T>::deposit(pool_id, &OmnipoolPallet::<T>::protocol_account(), subpool_state.reserve)?;
+
+			OmnipoolPallet::<T>::remove_asset(asset_a)?;
+			OmnipoolPallet::<T>::remove_asset(asset_b)?;
+			OmnipoolPallet::<T>::add_asset(
+				pool_id,
+				AssetState::from((subpool_state, share_asset_weight_cap, Tradability::default())),
+			)?;
+
+			for (asset_id, asset_state) in [(asset_a, asset_state_a), (asset_b, asset_state_b)] {
+				let (details, _) = calculate_asset_migration_details(&(&asset_state).into(), None, Balance::zero())
+					.ok_or(ArithmeticError::Overflow)?;
+				MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+			}
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id,
+				assets: (asset_a, asset_b),
+			});
+
+			Ok(())
+		}
+
+		/// Migrate an Omnipool asset into an existing subpool.
+		///
+		/// Reserve of the asset is moved to the subpool and converted into subpool share tokens.
+		/// Hub reserve, shares and protocol shares of the asset are added to the share asset state in the Omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: subpool id
+		/// - `asset_id`: Omnipool asset to migrate
+		///
+		/// Emits `AssetMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_asset_to_subpool())]
+		#[transactional]
+		pub fn migrate_asset_to_subpool(
+			origin: OriginFor<T>,
+			pool_id: AssetIdOf<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::is_subpool(pool_id), Error::<T>::SubpoolNotFound);
+
+			let subpool_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			let asset_state = OmnipoolPallet::<T>::load_asset_state(asset_id)?;
+			let share_issuance = CurrencyOf::<T>::total_issuance(pool_id);
+
+			let (details, delta_changes) = calculate_asset_migration_details(
+				&(&asset_state).into(),
+				Some(&(&subpool_state).into()),
+				share_issuance,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			let delta_changes = delta_changes.ok_or(ArithmeticError::Overflow)?;
+
+			StableswapPallet::<T>::add_asset_to_pool(pool_id, asset_id)?;
+
+			Self::move_asset_reserve(asset_id, pool_id, asset_state.reserve)?;
+
+			OmnipoolPallet::<T>::update_asset_state(pool_id, delta_changes)?;
+			CurrencyOf::<T>::deposit(pool_id, &OmnipoolPallet::<T>::protocol_account(), details.share_tokens)?;
+			OmnipoolPallet::<T>::remove_asset(asset_id)?;
+
+			MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+
+			Self::deposit_event(Event::AssetMigrated { asset_id, pool_id });
+
+			Ok(())
+		}
+
+		/// Convert Omnipool position of a migrated asset into a position of the subpool share asset.
+		///
+		/// Position NFT is kept, only position's asset, amount, shares and price are updated.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: Omnipool position id
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		#[transactional]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+			let (pool_id, detail) = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted = convert_position((&position).into(), detail.into()).ok_or(ArithmeticError::Overflow)?;
+
+			OmnipoolPallet::<T>::set_position(
+				position_id,
+				&Position {
+					asset_id: pool_id,
+					amount: converted.amount,
+					shares: converted.shares,
+					price: converted.price,
+				},
+			)?;
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				owner: who,
+				asset_id: position.asset_id,
+				pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by selling given `amount` of `asset_in`.
+		///
+		/// Any of the assets can be an Omnipool asset or an asset migrated into a subpool.
+		/// Subpool asset is converted to subpool shares ( or the other way round ) and shares are traded in the Omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_in`: ID of asset sold
+		/// - `asset_out`: ID of asset bought
+		/// - `amount`: Amount of asset sold
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+
+			let initial_balance = CurrencyOf::<T>::free_balance(asset_out, &who);
+
+			match (Self::subpool_of(asset_in), Self::subpool_of(asset_out)) {
+				(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+					StableswapPallet::<T>::sell(origin, pool_in, asset_in, asset_out, amount, min_buy_amount)?;
+				}
+				(Some(pool_in), Some(pool_out)) => {
+					let shares_in = Self::add_subpool_liquidity(origin.clone(), &who, pool_in, asset_in, amount)?;
+					let shares_out = Self::omnipool_sell(origin.clone(), &who, pool_in, pool_out, shares_in)?;
+					StableswapPallet::<T>::remove_liquidity_one_asset(
+						origin,
+						pool_out,
+						asset_out,
+						shares_out,
+						min_buy_amount,
+					)?;
+				}
+				(Some(pool_in), None) => {
+					let shares_in = Self::add_subpool_liquidity(origin.clone(), &who, pool_in, asset_in, amount)?;
+					OmnipoolPallet::<T>::sell(origin, pool_in, asset_out, shares_in, min_buy_amount)?;
+				}
+				(None, Some(pool_out)) => {
+					let shares_out = Self::omnipool_sell(origin.clone(), &who, asset_in, pool_out, amount)?;
+					StableswapPallet::<T>::remove_liquidity_one_asset(
+						origin,
+						pool_out,
+						asset_out,
+						shares_out,
+						min_buy_amount,
+					)?;
+				}
+				(None, None) => {
+					OmnipoolPallet::<T>::sell(origin, asset_in, asset_out, amount, min_buy_amount)?;
+				}
+			}
+
+			let amount_out = CurrencyOf::<T>::free_balance(asset_out, &who).saturating_sub(initial_balance);
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: amount,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by buying given `amount` of `asset_out`.
+		///
+		/// Any of the assets can be an Omnipool asset or an asset migrated into a subpool.
+		/// Subpool asset is converted to subpool shares ( or the other way round ) and shares are traded in the Omnipool.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_out`: ID of asset bought
+		/// - `asset_in`: ID of asset sold
+		/// - `amount`: Amount of asset bought
+		/// - `max_sell_amount`: Maximum amount allowed to be sold
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetIdOf<T>,
+			asset_in: AssetIdOf<T>,
+			amount: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+
+			let initial_balance = CurrencyOf::<T>::free_balance(asset_in, &who);
+
+			match (Self::subpool_of(asset_in), Self::subpool_of(asset_out)) {
+				(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+					StableswapPallet::<T>::buy(origin, pool_in, asset_out, asset_in, amount, max_sell_amount)?;
+				}
+				(Some(pool_in), Some(pool_out)) => {
+					let shares_out = StableswapPallet::<T>::calculate_shares_for_amount(pool_out, asset_out, amount)?;
+					let shares_in = OmnipoolPallet::<T>::quote_buy(pool_in, pool_out, shares_out)?.amount_in;
+					StableswapPallet::<T>::add_liquidity_shares(
+						origin.clone(),
+						pool_in,
+						shares_in,
+						asset_in,
+						max_sell_amount,
+					)?;
+					OmnipoolPallet::<T>::buy(origin.clone(), pool_out, pool_in, shares_out, shares_in)?;
+					StableswapPallet::<T>::withdraw_asset_amount(origin, pool_out, asset_out, amount, shares_out)?;
+				}
+				(Some(pool_in), None) => {
+					let shares_in = OmnipoolPallet::<T>::quote_buy(pool_in, asset_out, amount)?.amount_in;
+					StableswapPallet::<T>::add_liquidity_shares(
+						origin.clone(),
+						pool_in,
+						shares_in,
+						asset_in,
+						max_sell_amount,
+					)?;
+					OmnipoolPallet::<T>::buy(origin, asset_out, pool_in, amount, shares_in)?;
+				}
+				(None, Some(pool_out)) => {
+					let shares_out = StableswapPallet::<T>::calculate_shares_for_amount(pool_out, asset_out, amount)?;
+					OmnipoolPallet::<T>::buy(origin.clone(), pool_out, asset_in, shares_out, max_sell_amount)?;
+					StableswapPallet::<T>::withdraw_asset_amount(origin, pool_out, asset_out, amount, shares_out)?;
+				}
+				(None, None) => {
+					OmnipoolPallet::<T>::buy(origin, asset_out, asset_in, amount, max_sell_amount)?;
+				}
+			}
+
+			let amount_in = initial_balance.saturating_sub(CurrencyOf::<T>::free_balance(asset_in, &who));
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns subpool id if given asset has been migrated into a subpool.
+	pub fn subpool_of(asset_id: AssetIdOf<T>) -> Option<AssetIdOf<T>> {
+		MigratedAssets::<T>::get(asset_id).map(|(pool_id, _)| pool_id)
+	}
+
+	/// Returns `true` if given pool is a Stableswap pool whose share asset is in the Omnipool.
+	pub fn is_subpool(pool_id: AssetIdOf<T>) -> bool {
+		pallet_stableswap::Pools::<T>::contains_key(pool_id) && OmnipoolPallet::<T>::exists(pool_id)
+	}
+
+	/// Move reserve of a migrated asset from Omnipool account to subpool account.
+	fn move_asset_reserve(asset_id: AssetIdOf<T>, pool_id: AssetIdOf<T>, amount: Balance) -> DispatchResult {
+		CurrencyOf::<T>::transfer(
+			asset_id,
+			&OmnipoolPallet::<T>::protocol_account(),
+			&StableswapPallet::<T>::pool_account(pool_id),
+			amount,
+		)
+	}
+
+	/// Add liquidity of single asset to a subpool. Returns amount of received shares.
+	fn add_subpool_liquidity(
+		origin: OriginFor<T>,
+		who: &T::AccountId,
+		pool_id: AssetIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let initial_shares = CurrencyOf::<T>::free_balance(pool_id, who);
+		StableswapPallet::<T>::add_liquidity(origin, pool_id, vec![AssetAmount::new(asset_id, amount)])?;
+		Ok(CurrencyOf::<T>::free_balance(pool_id, who).saturating_sub(initial_shares))
+	}
+
+	/// Sell asset in the Omnipool without a limit. Returns received amount of asset out.
+	fn omnipool_sell(
+		origin: OriginFor<T>,
+		who: &T::AccountId,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let initial_balance = CurrencyOf::<T>::free_balance(asset_out, who);
+		OmnipoolPallet::<T>::sell(origin, asset_in, asset_out, amount, Balance::zero())?;
+		Ok(CurrencyOf::<T>::free_balance(asset_out, who).saturating_sub(initial_balance))
+	}
+}
@@ -0,0 +1,139 @@
+use crate::tests::mock::*;
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+fn builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 100 * ONE), (BOB, HDX, 100 * ONE)])
+		.with_subpool(DAI, USDC)
+}
+
+#[test]
+fn buy_should_trade_in_subpool_when_both_assets_are_in_same_subpool() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(BOB),
+			USDC,
+			DAI,
+			10 * ONE,
+			11 * ONE
+		));
+
+		let spent = 100 * ONE - Tokens::free_balance(DAI, &BOB);
+		assert!(spent > 10 * ONE && spent < 11 * ONE);
+		assert_balance!(BOB, USDC, 10 * ONE);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: USDC,
+			amount_in: spent,
+			amount_out: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_trade_shares_in_omnipool_when_selling_subpool_asset() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			20 * ONE,
+			15 * ONE
+		));
+
+		let spent = 100 * ONE - Tokens::free_balance(DAI, &BOB);
+		assert!(spent > 10 * ONE && spent < 15 * ONE);
+		assert_balance!(BOB, HDX, 120 * ONE);
+		assert_balance!(BOB, SHARE, 0);
+		assert_balance!(subpool_account(), DAI, 1_000 * ONE + spent);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: spent,
+			amount_out: 20 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_withdraw_from_subpool_when_buying_subpool_asset() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			25 * ONE
+		));
+
+		let spent = 100 * ONE - Tokens::free_balance(HDX, &BOB);
+		assert!(spent > 20 * ONE && spent < 25 * ONE);
+		assert_balance!(BOB, DAI, 110 * ONE);
+		assert_balance!(BOB, SHARE, 0);
+		assert_balance!(subpool_account(), DAI, 990 * ONE);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: spent,
+			amount_out: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_trade_in_omnipool_when_both_assets_are_in_omnipool() {
+	builder().build().execute_with(|| {
+		let expected = Omnipool::quote_buy(HDX, DOT, ONE).unwrap().amount_in;
+
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			ONE,
+			100 * ONE
+		));
+
+		assert_balance!(BOB, DOT, ONE);
+		assert_balance!(BOB, HDX, 100 * ONE - expected);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_assets_are_same() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::buy(RuntimeOrigin::signed(BOB), DAI, DAI, 10 * ONE, 100 * ONE),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded_in_subpool() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::buy(RuntimeOrigin::signed(BOB), HDX, DAI, 20 * ONE, ONE),
+			pallet_stableswap::Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded_in_omnipool() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 10 * ONE, ONE),
+			pallet_omnipool::Error::<Test>::SellLimitExceeded
+		);
+	});
+}
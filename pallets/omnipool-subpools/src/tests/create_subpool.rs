@@ -0,0 +1,129 @@
+use crate::tests::mock::*;
+use crate::types::AssetDetail;
+use crate::{assert_balance, Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+#[test]
+fn create_subpool_should_work_when_assets_are_in_omnipool() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE,
+			DAI,
+			USDC,
+			Permill::from_percent(100),
+			100,
+			Permill::zero(),
+		));
+
+		assert!(!Omnipool::exists(DAI));
+		assert!(!Omnipool::exists(USDC));
+
+		let share_state = Omnipool::load_asset_state(SHARE).unwrap();
+		assert_eq!(share_state.reserve, 2_000 * ONE);
+		assert_eq!(share_state.hub_reserve, 2_000 * ONE);
+		assert_eq!(share_state.shares, 2_000 * ONE);
+		assert_eq!(share_state.protocol_shares, 0);
+
+		assert_eq!(Stableswap::pools(SHARE).unwrap().assets.into_inner(), vec![DAI, USDC]);
+		assert_balance!(subpool_account(), DAI, 1_000 * ONE);
+		assert_balance!(subpool_account(), USDC, 1_000 * ONE);
+		assert_balance!(Omnipool::protocol_account(), DAI, 0);
+		assert_balance!(Omnipool::protocol_account(), USDC, 0);
+		assert_eq!(Tokens::total_issuance(SHARE), 2_000 * ONE);
+
+		assert_eq!(
+			MigratedAssets::<Test>::get(DAI),
+			Some((
+				SHARE,
+				AssetDetail {
+					price: (1_000 * ONE, 1_000 * ONE),
+					shares: 1_000 * ONE,
+					hub_reserve: 1_000 * ONE,
+					share_tokens: 1_000 * ONE,
+				}
+			))
+		);
+		assert_eq!(OmnipoolSubpools::subpool_of(USDC), Some(SHARE));
+
+		expect_events(vec![Event::SubpoolCreated {
+			pool_id: SHARE,
+			assets: (DAI, USDC),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_subpool_should_keep_hub_asset_reserve_unchanged() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hub_balance = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE,
+			DAI,
+			USDC,
+			Permill::from_percent(100),
+			100,
+			Permill::zero(),
+		));
+
+		assert_balance!(Omnipool::protocol_account(), LRNA, hub_balance);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::signed(ALICE),
+				SHARE,
+				DAI,
+				USDC,
+				Permill::from_percent(100),
+				100,
+				Permill::zero(),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				DAI,
+				USDT,
+				Permill::from_percent(100),
+				100,
+				Permill::zero(),
+			),
+			pallet_omnipool::Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_amplification_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				DAI,
+				USDC,
+				Permill::from_percent(100),
+				0,
+				Permill::zero(),
+			),
+			Error::<Test>::InvalidAmplification
+		);
+	});
+}
@@ -0,0 +1,81 @@
+use crate::tests::mock::*;
+use crate::types::AssetDetail;
+use crate::{assert_balance, Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn migrate_asset_to_subpool_should_work_when_subpool_exists() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE,
+			USDT
+		));
+
+		assert!(!Omnipool::exists(USDT));
+
+		let share_state = Omnipool::load_asset_state(SHARE).unwrap();
+		assert_eq!(share_state.reserve, 2_500 * ONE);
+		assert_eq!(share_state.hub_reserve, 2_500 * ONE);
+		assert_eq!(share_state.shares, 2_500 * ONE);
+		assert_eq!(share_state.protocol_shares, 0);
+
+		assert_eq!(
+			Stableswap::pools(SHARE).unwrap().assets.into_inner(),
+			vec![DAI, USDC, USDT]
+		);
+		assert_balance!(subpool_account(), USDT, 500 * ONE);
+		assert_balance!(Omnipool::protocol_account(), USDT, 0);
+		assert_eq!(Tokens::total_issuance(SHARE), 2_500 * ONE);
+
+		assert_eq!(
+			MigratedAssets::<Test>::get(USDT),
+			Some((
+				SHARE,
+				AssetDetail {
+					price: (1_000_000 * ONE * ONE, 1_000_000 * ONE * ONE),
+					shares: 500 * ONE,
+					hub_reserve: 500 * ONE,
+					share_tokens: 500 * ONE,
+				}
+			))
+		);
+
+		expect_events(vec![Event::AssetMigrated {
+			asset_id: USDT,
+			pool_id: SHARE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::signed(ALICE), SHARE, USDT),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_subpool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE, USDT),
+			Error::<Test>::SubpoolNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE, DAI),
+			pallet_omnipool::Error::<Test>::AssetNotFound
+		);
+	});
+}
@@ -0,0 +1,87 @@
+use crate::tests::mock::*;
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn migrate_position_should_convert_position_to_share_asset_position() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			DAI_POSITION_ID
+		));
+
+		let position = Omnipool::positions(DAI_POSITION_ID).unwrap();
+		assert_eq!(position.asset_id, SHARE);
+		assert_eq!(position.amount, 1_000 * ONE);
+		assert_eq!(position.shares, 1_000 * ONE);
+		assert_eq!(position.price.0, position.price.1);
+
+		expect_events(vec![Event::PositionMigrated {
+			position_id: DAI_POSITION_ID,
+			owner: LP1,
+			asset_id: DAI,
+			pool_id: SHARE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_position_should_work_when_asset_is_migrated_into_existing_subpool() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE,
+			USDT
+		));
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION_ID
+		));
+
+		let position = Omnipool::positions(USDT_POSITION_ID).unwrap();
+		assert_eq!(position.asset_id, SHARE);
+		assert_eq!(position.amount, 500 * ONE);
+		assert_eq!(position.shares, 500 * ONE);
+	});
+}
+
+#[test]
+fn migrated_position_should_be_withdrawn_in_share_asset() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			DAI_POSITION_ID
+		));
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			DAI_POSITION_ID,
+			1_000 * ONE
+		));
+
+		assert_balance!(LP1, SHARE, 1_000 * ONE);
+		assert!(Omnipool::positions(DAI_POSITION_ID).is_none());
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_origin_is_not_position_owner() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(ALICE), DAI_POSITION_ID),
+			pallet_omnipool::Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_is_not_migrated() {
+	ExtBuilder::default().with_subpool(DAI, USDC).build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), DOT_POSITION_ID),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
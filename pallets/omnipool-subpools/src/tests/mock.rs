@@ -0,0 +1,462 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Omnipool subpools pallet.
+#![allow(clippy::type_complexity)]
+
+use crate as pallet_omnipool_subpools;
+
+use core::ops::RangeInclusive;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::{
+	pools::DustRemovalAccountWhitelist, registry::Inspect as InspectRegistry, AccountIdFor, AssetKind,
+};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_omnipool::traits::ExternalPriceProvider;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, FixedU128, Permill,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+pub use orml_traits::MultiCurrency;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AccountId = u64;
+pub type AssetId = u32;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const USDC: AssetId = 3;
+pub const USDT: AssetId = 4;
+pub const DOT: AssetId = 5;
+pub const SHARE: AssetId = 100;
+
+pub const LP1: AccountId = 1;
+pub const ALICE: AccountId = 2;
+pub const BOB: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+thread_local! {
+	pub static POSITIONS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Omnipool: pallet_omnipool,
+		Stableswap: pallet_stableswap,
+		OmnipoolSubpools: pallet_omnipool_subpools,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const PositionCollectionId: u32 = 1000;
+	pub const MinTradeAmount: Balance = 1000;
+	pub const MinAddedLiquidity: Balance = 1000;
+	pub const MaxInRatio: Balance = 1;
+	pub const MaxOutRatio: Balance = 1;
+	pub MinWithdrawFee: Permill = Permill::zero();
+	pub const MinimumLiquidity: Balance = 1_000_000;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u32;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = MinTradeAmount;
+	type MinimumPoolLiquidity = MinAddedLiquidity;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CollectionId = u32;
+	type OmnipoolHooks = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = SpotPriceOracle;
+	type Fee = FeeProvider;
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type RateProvider = ();
+	type VolumeProvider = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+impl pallet_omnipool_subpools::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	omnipool_tokens: Vec<(AssetId, FixedU128, Balance)>,
+	subpool: Option<(AssetId, AssetId)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![],
+			omnipool_tokens: vec![
+				(HDX, FixedU128::from_float(0.5), 10_000 * ONE),
+				(DAI, FixedU128::from(1), 1_000 * ONE),
+				(USDC, FixedU128::from(1), 1_000 * ONE),
+				(USDT, FixedU128::from(1), 500 * ONE),
+				(DOT, FixedU128::from(5), 200 * ONE),
+			],
+			subpool: None,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_subpool(mut self, asset_a: AssetId, asset_b: AssetId) -> Self {
+		self.subpool = Some((asset_a, asset_b));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			for asset in [HDX, LRNA, DAI, USDC, USDT, DOT, SHARE] {
+				v.borrow_mut().insert(asset, 12);
+			}
+		});
+
+		let mut balances = self.endowed_accounts.clone();
+		for (asset_id, _, amount) in self.omnipool_tokens.iter() {
+			balances.push((Omnipool::protocol_account(), *asset_id, *amount));
+		}
+
+		orml_tokens::GenesisConfig::<Test> { balances }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for (asset_id, price, _) in self.omnipool_tokens {
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					asset_id,
+					price,
+					Permill::from_percent(100),
+					LP1,
+				));
+			}
+
+			if let Some((asset_a, asset_b)) = self.subpool {
+				assert_ok!(OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE,
+					asset_a,
+					asset_b,
+					Permill::from_percent(100),
+					100,
+					Permill::zero(),
+				));
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(id: Self::AssetId) -> Option<u128> {
+		Some(ExistentialDeposits::get(&id))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+		Ok(())
+	}
+
+	fn prepare_rate_source(
+		_asset_id: AssetId,
+		_underlying: AssetId,
+	) -> Result<pallet_stableswap::types::RateSource<AssetId>, DispatchError> {
+		Err(DispatchError::Other("Rates are not supported"))
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|a| a != account));
+		Ok(())
+	}
+}
+
+pub struct SpotPriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for SpotPriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(_asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		Ok(EmaPrice::new(asset_state.hub_reserve, asset_state.reserve))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(Permill::zero(), Permill::zero())
+	}
+}
+
+pub(crate) fn subpool_account() -> AccountId {
+	Stableswap::pool_account(SHARE)
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+// Positions created by the builder, in order of added tokens.
+pub const DAI_POSITION_ID: u32 = 1;
+pub const USDT_POSITION_ID: u32 = 3;
+pub const DOT_POSITION_ID: u32 = 4;
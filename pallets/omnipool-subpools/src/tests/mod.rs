@@ -0,0 +1,13 @@
+mod buy;
+mod create_subpool;
+mod migrate_asset;
+mod migrate_position;
+pub(crate) mod mock;
+mod sell;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
@@ -0,0 +1,142 @@
+use crate::tests::mock::*;
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+fn builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 100 * ONE), (BOB, HDX, 100 * ONE)])
+		.with_subpool(DAI, USDC)
+}
+
+#[test]
+fn sell_should_trade_in_subpool_when_both_assets_are_in_same_subpool() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			USDC,
+			10 * ONE,
+			9 * ONE
+		));
+
+		let received = Tokens::free_balance(USDC, &BOB);
+		assert!(received > 9 * ONE && received < 10 * ONE);
+		assert_balance!(BOB, DAI, 90 * ONE);
+		assert_balance!(subpool_account(), DAI, 1_010 * ONE);
+		assert_eq!(Omnipool::load_asset_state(SHARE).unwrap().reserve, 2_000 * ONE);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: USDC,
+			amount_in: 10 * ONE,
+			amount_out: received,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_trade_shares_in_omnipool_when_selling_subpool_asset() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			15 * ONE
+		));
+
+		let received = Tokens::free_balance(HDX, &BOB) - 100 * ONE;
+		assert!(received > 15 * ONE && received < 20 * ONE);
+		assert_balance!(BOB, DAI, 90 * ONE);
+		assert_balance!(BOB, SHARE, 0);
+		assert_balance!(subpool_account(), DAI, 1_010 * ONE);
+		assert!(Omnipool::load_asset_state(SHARE).unwrap().reserve > 2_000 * ONE);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 10 * ONE,
+			amount_out: received,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_withdraw_from_subpool_when_buying_subpool_asset() {
+	builder().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			20 * ONE,
+			9 * ONE
+		));
+
+		let received = Tokens::free_balance(DAI, &BOB) - 100 * ONE;
+		assert!(received > 9 * ONE && received < 10 * ONE);
+		assert_balance!(BOB, HDX, 80 * ONE);
+		assert_balance!(BOB, SHARE, 0);
+		assert_balance!(subpool_account(), DAI, 1_000 * ONE - received);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 20 * ONE,
+			amount_out: received,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_trade_in_omnipool_when_both_assets_are_in_omnipool() {
+	builder().build().execute_with(|| {
+		let expected = Omnipool::quote_sell(HDX, DOT, 20 * ONE).unwrap().amount_out;
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			20 * ONE,
+			0
+		));
+
+		assert_balance!(BOB, DOT, expected);
+		assert_balance!(BOB, HDX, 80 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_assets_are_same() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::sell(RuntimeOrigin::signed(BOB), DAI, DAI, 10 * ONE, 0),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached_in_omnipool() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::sell(RuntimeOrigin::signed(BOB), DAI, HDX, 10 * ONE, 20 * ONE),
+			pallet_omnipool::Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached_in_subpool() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 20 * ONE, 10 * ONE),
+			pallet_stableswap::Error::<Test>::SlippageLimit
+		);
+	});
+}
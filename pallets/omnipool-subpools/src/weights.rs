@@ -0,0 +1,89 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_omnipool_subpools`
+//!
+//! Values are placeholders until the pallet benchmarks are run with the benchmark CLI
+//! on the reference hardware. Storage access is listed as the benchmarks are expected to record it.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_omnipool_subpools.
+pub trait WeightInfo {
+	fn create_subpool() -> Weight;
+	fn migrate_asset_to_subpool() -> Weight;
+	fn migrate_position() -> Weight;
+	fn sell() -> Weight;
+	fn buy() -> Weight;
+}
+
+/// Weights for pallet_omnipool_subpools using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	fn create_subpool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3258`
+		//  Estimated: `16590`
+		// Minimum execution time: 187_310_000 picoseconds.
+		Weight::from_parts(189_062_000, 16590)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	fn migrate_asset_to_subpool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3671`
+		//  Estimated: `11322`
+		// Minimum execution time: 141_872_000 picoseconds.
+		Weight::from_parts(143_405_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	fn migrate_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2138`
+		//  Estimated: `3593`
+		// Minimum execution time: 47_915_000 picoseconds.
+		Weight::from_parts(48_622_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sell() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7941`
+		//  Estimated: `29403`
+		// Minimum execution time: 2_598_410_000 picoseconds.
+		Weight::from_parts(2_611_248_000, 29403)
+			.saturating_add(RocksDbWeight::get().reads(98_u64))
+			.saturating_add(RocksDbWeight::get().writes(44_u64))
+	}
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7941`
+		//  Estimated: `29403`
+		// Minimum execution time: 2_667_035_000 picoseconds.
+		Weight::from_parts(2_680_517_000, 29403)
+			.saturating_add(RocksDbWeight::get().reads(95_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
+	}
+}
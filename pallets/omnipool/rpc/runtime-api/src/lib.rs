@@ -0,0 +1,49 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_omnipool::types::{AssetReserveState, SimpleImbalance, TradeQuote};
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query Omnipool state and trade quotes.
+	pub trait OmnipoolApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Get state of all assets in the Omnipool, including asset reserves.
+		fn asset_states() -> Vec<(AssetId, AssetReserveState<Balance>)>;
+
+		/// Get state of an asset in the Omnipool. Returns `None` if asset is not in the pool.
+		fn asset_state(asset_id: AssetId) -> Option<AssetReserveState<Balance>>;
+
+		/// Get current Hub Asset imbalance.
+		fn hub_asset_imbalance() -> SimpleImbalance<Balance>;
+
+		/// Get spot price of `asset_b` denominated in `asset_a` ( `asset_a / asset_b` ), excluding fees.
+		fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128>;
+
+		/// Calculate result of selling `amount_in` of `asset_in` for `asset_out` without executing the trade.
+		fn quote_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Calculate result of buying `amount_out` of `asset_out` for `asset_in` without executing the trade.
+		fn quote_buy(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+	}
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the Omnipool pallet.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+pub use pallet_omnipool_rpc_runtime_api::OmnipoolApi as OmnipoolRuntimeApi;
+use pallet_omnipool_rpc_runtime_api::{AssetReserveState, SimpleImbalance, TradeQuote};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, FixedU128};
+
+#[rpc(client, server)]
+pub trait OmnipoolApi<BlockHash, AssetId, Balance> {
+	/// Get state of all assets in the Omnipool.
+	#[method(name = "omnipool_assetStates")]
+	fn asset_states(&self, at: Option<BlockHash>) -> RpcResult<Vec<(AssetId, AssetReserveState<Balance>)>>;
+
+	/// Get state of given asset in the Omnipool.
+	#[method(name = "omnipool_assetState")]
+	fn asset_state(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<AssetReserveState<Balance>>>;
+
+	/// Get current Hub Asset imbalance.
+	#[method(name = "omnipool_hubAssetImbalance")]
+	fn hub_asset_imbalance(&self, at: Option<BlockHash>) -> RpcResult<SimpleImbalance<Balance>>;
+
+	/// Get spot price of `asset_b` denominated in `asset_a`.
+	#[method(name = "omnipool_spotPrice")]
+	fn spot_price(&self, asset_a: AssetId, asset_b: AssetId, at: Option<BlockHash>) -> RpcResult<Option<FixedU128>>;
+
+	/// Calculate result of selling `amount_in` of `asset_in` for `asset_out`.
+	#[method(name = "omnipool_quoteSell")]
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+
+	/// Calculate result of buying `amount_out` of `asset_out` for `asset_in`.
+	#[method(name = "omnipool_quoteBuy")]
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The trade cannot be executed in current state of the pool.
+	InvalidTrade,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidTrade => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to query Omnipool state.
+pub struct Omnipool<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Omnipool<C, Block> {
+	/// Create new `Omnipool` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(message: &'static str, e: impl std::fmt::Debug) -> ErrorObject<'static> {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(format!("{:?}", e)))
+}
+
+impl<C, Block, AssetId, Balance> OmnipoolApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Omnipool<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: OmnipoolRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec,
+	Balance: Codec,
+{
+	fn asset_states(&self, at: Option<Block::Hash>) -> RpcResult<Vec<(AssetId, AssetReserveState<Balance>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.asset_states(at)
+			.map_err(|e| runtime_error("Unable to query asset states.", e))
+	}
+
+	fn asset_state(&self, asset_id: AssetId, at: Option<Block::Hash>) -> RpcResult<Option<AssetReserveState<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.asset_state(at, asset_id)
+			.map_err(|e| runtime_error("Unable to query asset state.", e))
+	}
+
+	fn hub_asset_imbalance(&self, at: Option<Block::Hash>) -> RpcResult<SimpleImbalance<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.hub_asset_imbalance(at)
+			.map_err(|e| runtime_error("Unable to query hub asset imbalance.", e))
+	}
+
+	fn spot_price(&self, asset_a: AssetId, asset_b: AssetId, at: Option<Block::Hash>) -> RpcResult<Option<FixedU128>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.spot_price(at, asset_a, asset_b)
+			.map_err(|e| runtime_error("Unable to query spot price.", e))
+	}
+
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<Block::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_sell(at, asset_in, asset_out, amount_in)
+			.map_err(|e| runtime_error("Unable to calculate sell quote.", e))?
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::InvalidTrade.into(),
+					"Sell cannot be executed.",
+					Some(format!("{:?}", e)),
+				)
+			})
+	}
+
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<Block::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_buy(at, asset_in, asset_out, amount_out)
+			.map_err(|e| runtime_error("Unable to calculate buy quote.", e))?
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::InvalidTrade.into(),
+					"Buy cannot be executed.",
+					Some(format!("{:?}", e)),
+				)
+			})
+	}
+}
@@ -112,7 +112,7 @@ pub mod types;
 pub mod weights;
 
 use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability, TradeQuote};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -2130,6 +2130,241 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
+	/// Returns state of all assets in the Omnipool, including current asset reserves.
+	pub fn asset_states() -> Vec<(T::AssetId, AssetReserveState<Balance>)> {
+		let account = Self::protocol_account();
+		Assets::<T>::iter()
+			.map(|(asset_id, state)| {
+				let reserve = T::Currency::free_balance(asset_id, &account);
+				(asset_id, (state, reserve).into())
+			})
+			.collect()
+	}
+
+	/// Calculate result of selling `amount` of `asset_in` for `asset_out` without executing the trade.
+	///
+	/// Performs the same checks as `sell`, except the ones related to trader's balance.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		// Selling for hub asset is not supported
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+
+		if asset_in == T::HubAssetId::get() {
+			ensure!(
+				Self::is_hub_asset_allowed(Tradability::SELL) && asset_out_state.tradable.contains(Tradability::BUY),
+				Error::<T>::NotAllowed
+			);
+			ensure!(
+				amount
+					<= asset_out_state
+						.hub_reserve
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
+				&(&asset_out_state).into(),
+				amount,
+				asset_fee,
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				Self::get_hub_asset_balance_of_protocol_account(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				*state_changes.asset.delta_reserve
+					<= asset_out_state
+						.reserve
+						.checked_div(T::MaxOutRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				Error::<T>::MaxOutRatioExceeded
+			);
+
+			return Ok(TradeQuote {
+				amount_in: *state_changes.asset.delta_hub_reserve,
+				amount_out: *state_changes.asset.delta_reserve,
+				hub_amount_in: Balance::zero(),
+				hub_amount_out: Balance::zero(),
+				asset_fee: state_changes.fee.asset_fee,
+				protocol_fee: state_changes.fee.protocol_fee,
+				delta_imbalance: state_changes.delta_imbalance.into(),
+			});
+		}
+
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			Error::<T>::NotAllowed
+		);
+		ensure!(
+			amount
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset_out.delta_reserve > Balance::zero(),
+			Error::<T>::ZeroAmountOut
+		);
+		ensure!(
+			*state_changes.asset_out.delta_reserve
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset_in.delta_reserve,
+			amount_out: *state_changes.asset_out.delta_reserve,
+			hub_amount_in: *state_changes.asset_in.delta_hub_reserve,
+			hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
+			asset_fee: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+			delta_imbalance: state_changes.delta_imbalance.into(),
+		})
+	}
+
+	/// Calculate result of buying `amount` of `asset_out` for `asset_in` without executing the trade.
+	///
+	/// Performs the same checks as `buy`, except the ones related to trader's balance.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		// Buying hub asset is not supported
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+
+		ensure!(
+			amount
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		if asset_in == T::HubAssetId::get() {
+			ensure!(
+				Self::is_hub_asset_allowed(Tradability::SELL) && asset_out_state.tradable.contains(Tradability::BUY),
+				Error::<T>::NotAllowed
+			);
+
+			let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
+				&(&asset_out_state).into(),
+				amount,
+				asset_fee,
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				Self::get_hub_asset_balance_of_protocol_account(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				*state_changes.asset.delta_hub_reserve
+					<= asset_out_state
+						.hub_reserve
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			return Ok(TradeQuote {
+				amount_in: *state_changes.asset.delta_hub_reserve,
+				amount_out: *state_changes.asset.delta_reserve,
+				hub_amount_in: Balance::zero(),
+				hub_amount_out: Balance::zero(),
+				asset_fee: state_changes.fee.asset_fee,
+				protocol_fee: state_changes.fee.protocol_fee,
+				delta_imbalance: state_changes.delta_imbalance.into(),
+			});
+		}
+
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			Error::<T>::NotAllowed
+		);
+		ensure!(asset_out_state.reserve >= amount, Error::<T>::InsufficientLiquidity);
+
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset_in.delta_reserve
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset_in.delta_reserve,
+			amount_out: *state_changes.asset_out.delta_reserve,
+			hub_amount_in: *state_changes.asset_in.delta_hub_reserve,
+			hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
+			asset_fee: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+			delta_imbalance: state_changes.delta_imbalance.into(),
+		})
+	}
+
 	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
 	fn process_trade_fee(trader: &T::AccountId, asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let account = Self::protocol_account();
@@ -0,0 +1,217 @@
+use super::*;
+use crate::types::TradeQuote;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn quote_sell_should_match_executed_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(10))
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let sell_amount = 50 * ONE;
+
+			let quote = Omnipool::quote_sell(100, 200, sell_amount).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, sell_amount, 0));
+
+			expect_events(vec![Event::SellExecuted {
+				who: LP1,
+				asset_in: 100,
+				asset_out: 200,
+				amount_in: quote.amount_in,
+				amount_out: quote.amount_out,
+				hub_amount_in: quote.hub_amount_in,
+				hub_amount_out: quote.hub_amount_out,
+				asset_fee_amount: quote.asset_fee,
+				protocol_fee_amount: quote.protocol_fee,
+			}
+			.into()]);
+
+			assert_eq!(quote.amount_in, sell_amount);
+			assert_eq!(Tokens::free_balance(200, &LP1), quote.amount_out);
+		});
+}
+
+#[test]
+fn quote_buy_should_match_executed_buy() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(10))
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let buy_amount = 50 * ONE;
+
+			let quote = Omnipool::quote_buy(100, 200, buy_amount).unwrap();
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP1),
+				200,
+				100,
+				buy_amount,
+				Balance::MAX
+			));
+
+			expect_events(vec![Event::BuyExecuted {
+				who: LP1,
+				asset_in: 100,
+				asset_out: 200,
+				amount_in: quote.amount_in,
+				amount_out: quote.amount_out,
+				hub_amount_in: quote.hub_amount_in,
+				hub_amount_out: quote.hub_amount_out,
+				asset_fee_amount: quote.asset_fee,
+				protocol_fee_amount: quote.protocol_fee,
+			}
+			.into()]);
+
+			assert_eq!(quote.amount_out, buy_amount);
+			assert_eq!(Tokens::free_balance(100, &LP1), 1000 * ONE - quote.amount_in);
+		});
+}
+
+#[test]
+fn quote_sell_of_hub_asset_should_report_imbalance_change() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, LRNA, 100 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let quote = Omnipool::quote_sell(LRNA, 100, 10 * ONE).unwrap();
+
+			let imbalance_before = HubAssetImbalance::<Test>::get();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, 100, 10 * ONE, 0));
+
+			assert_eq!(Tokens::free_balance(100, &LP3), quote.amount_out);
+			assert!(quote.delta_imbalance.negative);
+			assert_eq!(
+				HubAssetImbalance::<Test>::get(),
+				(imbalance_before - quote.delta_imbalance.value).unwrap()
+			);
+		});
+}
+
+#[test]
+fn quote_should_not_change_state() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let states_before = Omnipool::asset_states();
+
+			let quote = Omnipool::quote_sell(100, 200, 50 * ONE).unwrap();
+			assert!(quote.amount_out > 0);
+			let quote = Omnipool::quote_buy(100, 200, 50 * ONE).unwrap();
+			assert!(quote.amount_in > 0);
+
+			assert_eq!(Omnipool::asset_states(), states_before);
+		});
+}
+
+#[test]
+fn asset_states_should_include_all_assets_with_reserves() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let mut states = Omnipool::asset_states();
+			states.sort_by_key(|(asset_id, _)| *asset_id);
+
+			assert_eq!(
+				states.iter().map(|(asset_id, _)| *asset_id).collect::<Vec<_>>(),
+				vec![HDX, DAI, 100]
+			);
+
+			for (asset_id, state) in states {
+				assert_eq!(state, Omnipool::load_asset_state(asset_id).unwrap());
+			}
+		});
+}
+
+#[test]
+fn quote_should_fail_when_trade_is_not_allowed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::quote_sell(100, 100, 10 * ONE),
+				Error::<Test>::SameAssetTradeNotAllowed
+			);
+			assert_noop!(Omnipool::quote_sell(100, LRNA, 10 * ONE), Error::<Test>::NotAllowed);
+			assert_noop!(Omnipool::quote_buy(100, LRNA, 10 * ONE), Error::<Test>::NotAllowed);
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				200,
+				Tradability::SELL
+			));
+
+			assert_noop!(Omnipool::quote_sell(100, 200, 10 * ONE), Error::<Test>::NotAllowed);
+			assert_eq!(
+				Omnipool::quote_sell(200, 100, 10 * ONE).map(|q: TradeQuote<Balance>| q.amount_in),
+				Ok(10 * ONE)
+			);
+		});
+}
@@ -4,6 +4,8 @@ use super::*;
 use codec::MaxEncodedLen;
 use frame_support::pallet_prelude::*;
 use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{FixedPointNumber, FixedU128};
 use sp_std::ops::{Add, Sub};
 
@@ -16,6 +18,7 @@ pub type Price = FixedU128;
 bitflags::bitflags! {
 	/// Indicates whether asset can be bought or sold to/from Omnipool and/or liquidity added/removed.
 	#[derive(Encode,Decode, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub struct Tradability: u8 {
 		/// Asset is frozen. No operations are allowed.
 		const FROZEN = 0b0000_0000;
@@ -140,6 +143,7 @@ where
 /// Simple type to represent imbalance which can be positive or negative.
 // Note: Simple prefix is used not to confuse with Imbalance trait from frame_support.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SimpleImbalance<Balance> {
 	pub value: Balance,
 	pub negative: bool,
@@ -154,6 +158,15 @@ impl<Balance: Default> Default for SimpleImbalance<Balance> {
 	}
 }
 
+impl<Balance> From<BalanceUpdate<Balance>> for SimpleImbalance<Balance> {
+	fn from(delta: BalanceUpdate<Balance>) -> Self {
+		match delta {
+			BalanceUpdate::Increase(value) => Self { value, negative: false },
+			BalanceUpdate::Decrease(value) => Self { value, negative: true },
+		}
+	}
+}
+
 /// The addition operator + for SimpleImbalance.
 ///
 /// Adds amount to imbalance.
@@ -214,7 +227,8 @@ impl<Balance: CheckedAdd + CheckedSub + PartialOrd + Copy> Sub<Balance> for Simp
 }
 
 /// Asset state representation including asset pool reserve.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetReserveState<Balance> {
 	/// Quantity of asset in omnipool
 	pub reserve: Balance,
@@ -319,3 +333,24 @@ where
 		})
 	}
 }
+
+/// Result of a trade calculated against current Omnipool state without executing it.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TradeQuote<Balance> {
+	/// Amount of asset in paid by the trader
+	pub amount_in: Balance,
+	/// Amount of asset out received by the trader
+	pub amount_out: Balance,
+	/// Quantity of Hub Asset removed from asset in subpool
+	pub hub_amount_in: Balance,
+	/// Quantity of Hub Asset added to asset out subpool
+	pub hub_amount_out: Balance,
+	/// Asset fee amount, in asset out
+	pub asset_fee: Balance,
+	/// Protocol fee amount, in Hub Asset
+	pub protocol_fee: Balance,
+	/// Change of Hub Asset imbalance caused by the trade.
+	/// `negative` is set when the trade decreases the imbalance.
+	pub delta_imbalance: SimpleImbalance<Balance>,
+}
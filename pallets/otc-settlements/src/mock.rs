@@ -107,9 +107,20 @@ impl pallet_otc::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = ExistentialDeposits;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type Router = Router;
+	type PalletId = OtcPalletId;
+	type BlockAuthor = BlockAuthor;
+	type LimitOrderFee = LimitOrderFee;
+	type RouterWeightInfo = ();
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub BlockAuthor: Option<AccountId> = None;
+	pub LimitOrderFee: Permill = Permill::from_rational(1u32, 1000u32);
+	pub const OtcPalletId: PalletId = PalletId(*b"otcorder");
+}
+
 parameter_types! {
 	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 }
@@ -131,6 +142,7 @@ impl pallet_route_executor::Config for Test {
 	type AMM = Omnipool;
 	type InspectRegistry = AssetRegistry;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type Pools = ();
 	type WeightInfo = ();
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type EdToRefundCalculator = MockedEdCalculator;
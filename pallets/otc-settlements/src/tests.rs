@@ -173,7 +173,8 @@ fn otcs_list_storage_should_be_sorted_on_new_block() {
 			DAI,
 			1_000_000_000,
 			2_000_000_000,
-			true,
+			true,,
+			None
 		));
 
 		OtcSettlements::sort_otcs(block_num);
@@ -197,7 +198,8 @@ fn profit_should_be_transferred_to_treasury_when_zero_initial_pallet_balance() {
 			DAI,
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		let pallet_acc = OtcSettlements::account_id();
@@ -227,7 +229,8 @@ fn profit_should_be_transferred_to_treasury_when_nonzero_initial_pallet_balance(
 			DAI,
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		let pallet_acc = OtcSettlements::account_id();
@@ -259,7 +262,8 @@ fn existing_arb_opportunity_should_trigger_trade() {
 			DAI, // otc asset_out
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		// get otc price
@@ -312,7 +316,8 @@ fn existing_arb_opportunity_should_trigger_trade_when_otc_is_not_partially_filla
 			// existing_arb_opportunity_should_trigger_trade test
 			828_170_776_368_178,
 			1_664_623_260_500_037,
-			false, // not partially fillable
+			false, // not partially fillable,
+			None
 		));
 
 		// get otc price
@@ -366,7 +371,8 @@ fn existing_arb_opportunity_of_insufficient_asset_should_trigger_trade() {
 			HDX, // otc asset_out
 			200_000 * ONE,
 			101_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		// get otc price
@@ -417,7 +423,8 @@ fn multiple_arb_opportunities_should_trigger_trades() {
 			DAI, // otc asset_out
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 		assert_ok!(OTC::place_order(
 			RuntimeOrigin::signed(ALICE),
@@ -425,7 +432,8 @@ fn multiple_arb_opportunities_should_trigger_trades() {
 			KSM, // otc asset_out
 			100_000 * ONE,
 			101_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
@@ -455,7 +463,8 @@ fn trade_should_be_triggered_when_arb_opportunity_appears() {
 			DAI, // otc asset_out
 			100_001 * ONE,
 			200_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		// get otc price
@@ -526,7 +535,8 @@ fn trade_should_not_be_triggered_when_there_is_no_arb_opportunity() {
 			DAI, // otc asset_out
 			100_001 * ONE,
 			200_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		// get otc price
@@ -573,7 +583,8 @@ fn trade_should_not_be_triggered_when_optimal_amount_not_found() {
 			DAI, // otc asset_out
 			1_000_000 * ONE,
 			8_000_000_001 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		// get otc price
@@ -620,7 +631,8 @@ fn test_offchain_worker_unsigned_transaction_submission() {
 			DAI, // otc asset_out
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		let otc_id = 0;
@@ -658,7 +670,8 @@ fn test_offchain_worker_signed_transaction_submission() {
 			DAI, // otc asset_out
 			100_000 * ONE,
 			201_000 * ONE,
-			true,
+			true,,
+			None
 		));
 
 		let otc_id = 0;
@@ -684,7 +697,8 @@ fn place_orders() {
 		DAI,
 		1_000_000_000,
 		2_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -693,7 +707,8 @@ fn place_orders() {
 		LRNA,
 		1_000_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -702,7 +717,8 @@ fn place_orders() {
 		HDX,
 		4_000_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -711,7 +727,8 @@ fn place_orders() {
 		LRNA,
 		7_000_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -720,7 +737,8 @@ fn place_orders() {
 		DAI,
 		2_000_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -729,7 +747,8 @@ fn place_orders() {
 		DAI,
 		2_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -738,7 +757,8 @@ fn place_orders() {
 		DAI,
 		2_000_000_000,
 		3_000_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -747,7 +767,8 @@ fn place_orders() {
 		DOT,
 		2_000_000_000,
 		3_000_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -756,7 +777,8 @@ fn place_orders() {
 		DOT,
 		9_000_000_000,
 		3_000_000_000,
-		true,
+		true,,
+		None
 	));
 
 	assert_ok!(OTC::place_order(
@@ -765,6 +787,7 @@ fn place_orders() {
 		DOT,
 		2_000_000_000,
 		13_000_000_000,
-		true,
+		true,,
+		None
 	));
 }
@@ -35,7 +35,7 @@ benchmarks! {
 		let (dot, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
-  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()))
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
 	}
@@ -47,7 +47,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, None)
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
 	verify {
@@ -61,7 +61,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, None)
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32)
 	verify {
@@ -73,7 +73,7 @@ benchmarks! {
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, None)
 		);
   }:  _(RawOrigin::Signed(owner.clone()), 0u32)
 	verify {
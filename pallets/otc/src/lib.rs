@@ -19,6 +19,16 @@
 // It allows anyone to `place_order` by specifying a pair of assets (in and out), their respective amounts, and
 // whether the order is partially fillable. The order price is static and calculated as `amount_out / amount_in`.
 //
+// An order can optionally be valid only until a given block (`valid_until`). Expired orders cannot be filled and
+// are removed in `on_idle`, where their remaining reserved amount is unreserved and an `Expired` event is emitted.
+//
+// An order can also be placed as a limit order with `place_limit_order`. Limit orders can be filled by anyone like
+// regular orders, but they are also filled automatically in `on_idle` by trading through the router, once the
+// route price (including fees) reaches the order price. The trade is executed by the pallet account, which
+// transfers `LimitOrderFee` of the order `amount_in` to the block author, who executes `on_idle`, and everything else
+// to the order owner. No fee is charged if the block author is not known.
+// Limit orders are processed round-robin: the next `on_idle` continues after the last processed limit order.
+//
 // ## Notes
 // The pallet implements a minimum order size as an alternative to storage fees. The amounts of an open order cannot
 // be lower than the existential deposit for the respective asset, multiplied by `ExistentialDepositMultiplier`.
@@ -30,16 +40,26 @@
 // * `partial_fill_order` - fill an OTC order (partially).
 // * `fill_order` - fill an OTC order (completely).
 // * `cancel_order` - cancel an open OTC order.
+// * `place_limit_order` - create a new OTC order which is filled automatically through the router.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, require_transactional};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use frame_support::{pallet_prelude::*, require_transactional, transactional, PalletId};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
+use hydradx_traits::router::{
+	AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, RouterT, Trade,
+};
 use hydradx_traits::Inspect;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
-use sp_runtime::traits::{One, Zero};
+use sp_runtime::traits::{AccountIdConversion, One, Saturating, Zero};
+use sp_runtime::{ArithmeticError, FixedPointNumber, FixedU128, Permill};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -101,10 +121,41 @@ pub mod pallet {
 		#[pallet::constant]
 		type ExistentialDepositMultiplier: Get<u8>;
 
+		/// Router used to fill limit orders.
+		type Router: RouteProvider<Self::AssetId>
+			+ RouterT<Self::RuntimeOrigin, Self::AssetId, Balance, Trade<Self::AssetId>, AmountInAndOut<Balance>>
+			+ RouteSpotPriceProvider<Self::AssetId>;
+
+		/// Pallet id. The pallet account executes the router trades of limit orders.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Author of the current block. Limit orders are filled in `on_idle` on behalf of the block author,
+		/// who receives their fee.
+		type BlockAuthor: Get<Option<Self::AccountId>>;
+
+		/// Fee charged from `amount_in` of a limit order filled through the router and paid to the block author.
+		#[pallet::constant]
+		type LimitOrderFee: Get<Permill>;
+
+		/// Router weight information.
+		type RouterWeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used_weight = Self::expire_orders(now, remaining_weight);
+			used_weight.saturating_add(Self::fill_limit_orders(
+				now,
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -132,7 +183,19 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			valid_until: Option<BlockNumberFor<T>>,
+		},
+		/// An Order has been placed as a limit order
+		LimitOrderPlaced { order_id: OrderId },
+		/// A limit order has been filled through the router
+		LimitOrderFilled {
+			order_id: OrderId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
 		},
+		/// An Order has expired and its reserved amount has been unreserved
+		Expired { order_id: OrderId },
 	}
 
 	#[pallet::error]
@@ -155,6 +218,14 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// Last valid block of an order must not be in the past
+		InvalidExpiry,
+		/// Order is not valid anymore
+		OrderExpired,
+		/// Price for the route is not available
+		PriceNotAvailable,
+		/// Route price has not reached the price of the limit order
+		LimitPriceNotReached,
 	}
 
 	/// ID sequencer for Orders
@@ -166,6 +237,31 @@ pub mod pallet {
 	#[pallet::getter(fn orders)]
 	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
 
+	/// Orders which are filled automatically through the router
+	#[pallet::storage]
+	#[pallet::getter(fn limit_orders)]
+	pub type LimitOrders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, (), OptionQuery>;
+
+	/// Last block in which an order can be filled. Orders without an entry do not expire.
+	#[pallet::storage]
+	#[pallet::getter(fn valid_until)]
+	pub type ValidUntil<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Orders indexed by the last block in which they can be filled.
+	#[pallet::storage]
+	pub type ExpiringOrders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, OrderId, (), OptionQuery>;
+
+	/// Next block whose `ExpiringOrders` have not been expired yet. Set when the first order with a validity is placed.
+	#[pallet::storage]
+	#[pallet::getter(fn next_expiry_block)]
+	pub type NextExpiryBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Last limit order processed in `on_idle`. The next `on_idle` continues with the following limit order.
+	#[pallet::storage]
+	#[pallet::getter(fn limit_order_cursor)]
+	pub type LimitOrderCursor<T: Config> = StorageValue<_, OrderId, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new OTC order
@@ -176,6 +272,7 @@ pub mod pallet {
 		/// - `amount_in`: Amount that the order is seeking to buy
 		/// - `amount_out`: Amount that the order is selling
 		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `valid_until`: Optional last block in which the order can be filled
 		///
 		/// Validations:
 		/// - asset_in must be registered
@@ -183,11 +280,14 @@ pub mod pallet {
 		///   ExistentialDepositMultiplier
 		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
 		///   ExistentialDepositMultiplier
+		/// - valid_until must not be in the past
+		///
+		/// Expired orders are removed in `on_idle` and their remaining reserved amount is unreserved.
 		///
 		/// Events:
 		/// - `Placed` event when successful.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order().saturating_add(T::DbWeight::get().writes(1)))]
 		pub fn place_order(
 			origin: OriginFor<T>,
 			asset_in: T::AssetId,
@@ -195,6 +295,7 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			valid_until: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let order = Order {
@@ -206,28 +307,9 @@ pub mod pallet {
 				partially_fillable,
 			};
 
-			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
-			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
-			Self::ensure_min_order_amount(order.asset_out, amount_out)?;
-
-			<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
-				let order_id = *next_id;
-
-				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
-				<Orders<T>>::insert(order_id, &order);
-
-				Self::deposit_event(Event::Placed {
-					order_id,
-					asset_in: order.asset_in,
-					asset_out: order.asset_out,
-					amount_in: order.amount_in,
-					amount_out,
-					partially_fillable: order.partially_fillable,
-				});
+			Self::do_place_order(order, valid_until)?;
 
-				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
-				Ok(())
-			})
+			Ok(())
 		}
 
 		/// Fill an OTC order (partially)
@@ -252,6 +334,7 @@ pub mod pallet {
 			<Orders<T>>::try_mutate(order_id, |maybe_order| -> DispatchResult {
 				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
 
+				Self::ensure_not_expired(order_id)?;
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
 
 				let amount_out_calculation = U256::from(order.amount_out)
@@ -290,9 +373,10 @@ pub mod pallet {
 		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			Self::ensure_not_expired(order_id)?;
 
 			Self::execute_order(&order, &who, order.amount_in, order.amount_out)?;
-			<Orders<T>>::remove(order_id);
+			Self::remove_order(order_id);
 
 			Self::deposit_event(Event::Filled {
 				order_id,
@@ -327,15 +411,329 @@ pub mod pallet {
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
 				*maybe_order = None;
+				<LimitOrders<T>>::remove(order_id);
+				Self::remove_validity(order_id);
 
 				Self::deposit_event(Event::Cancelled { order_id });
 				Ok(())
 			})
 		}
+
+		/// Create a new OTC limit order
+		///
+		/// The order is a regular OTC order which can be filled by anyone, but it is also filled
+		/// automatically through the router in `on_idle` once the route price reaches the order price.
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being bought
+		/// - `asset_out`: Asset which is being sold
+		/// - `amount_in`: Amount that the order is seeking to buy
+		/// - `amount_out`: Amount that the order is selling
+		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `valid_until`: Optional last block in which the order can be filled
+		///
+		/// Validations:
+		/// - same as in `place_order`
+		///
+		/// Events:
+		/// - `Placed` and `LimitOrderPlaced` events when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order().saturating_add(T::DbWeight::get().writes(2)))]
+		pub fn place_limit_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			partially_fillable: bool,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let order = Order {
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				partially_fillable,
+			};
+
+			let order_id = Self::do_place_order(order, valid_until)?;
+			<LimitOrders<T>>::insert(order_id, ());
+
+			Self::deposit_event(Event::LimitOrderPlaced { order_id });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_place_order(
+		order: Order<T::AccountId, T::AssetId>,
+		valid_until: Option<BlockNumberFor<T>>,
+	) -> Result<OrderId, DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(valid_until) = valid_until {
+			ensure!(valid_until >= now, Error::<T>::InvalidExpiry);
+		}
+		ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
+		Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
+		Self::ensure_min_order_amount(order.asset_out, order.amount_out)?;
+
+		<NextOrderId<T>>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+			let order_id = *next_id;
+
+			T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
+			<Orders<T>>::insert(order_id, &order);
+			if let Some(valid_until) = valid_until {
+				<ValidUntil<T>>::insert(order_id, valid_until);
+				<ExpiringOrders<T>>::insert(valid_until, order_id, ());
+				// Orders cannot be placed with a validity in the past, so no earlier block needs to be processed.
+				<NextExpiryBlock<T>>::mutate(|next_expiry_block| {
+					next_expiry_block.get_or_insert(now);
+				});
+			}
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				asset_in: order.asset_in,
+				asset_out: order.asset_out,
+				amount_in: order.amount_in,
+				amount_out: order.amount_out,
+				partially_fillable: order.partially_fillable,
+				valid_until,
+			});
+
+			*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
+			Ok(order_id)
+		})
+	}
+
+	/// Account which executes the router trades of limit orders.
+	pub fn pallet_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Remove an order together with its limit order and validity entries.
+	fn remove_order(order_id: OrderId) {
+		<Orders<T>>::remove(order_id);
+		<LimitOrders<T>>::remove(order_id);
+		Self::remove_validity(order_id);
+	}
+
+	fn remove_validity(order_id: OrderId) {
+		if let Some(valid_until) = <ValidUntil<T>>::take(order_id) {
+			<ExpiringOrders<T>>::remove(valid_until, order_id);
+		}
+	}
+
+	fn is_expired(order_id: OrderId, now: BlockNumberFor<T>) -> bool {
+		<ValidUntil<T>>::get(order_id).map_or(false, |valid_until| now > valid_until)
+	}
+
+	fn ensure_not_expired(order_id: OrderId) -> DispatchResult {
+		ensure!(
+			!Self::is_expired(order_id, frame_system::Pallet::<T>::block_number()),
+			Error::<T>::OrderExpired
+		);
+		Ok(())
+	}
+
+	/// Remove expired orders and unreserve their remaining `amount_out`, as long as there is enough weight left.
+	/// Only orders indexed in `ExpiringOrders` under the blocks from `NextExpiryBlock` until the previous block are
+	/// visited. Returns the consumed weight.
+	fn expire_orders(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+		if cursor_weight.any_gt(remaining_weight) {
+			return Weight::zero();
+		}
+		let mut used_weight = cursor_weight;
+
+		let Some(mut block) = <NextExpiryBlock<T>>::get() else {
+			return used_weight;
+		};
+
+		let read_weight = T::DbWeight::get().reads(1);
+		let expire_weight = <T as Config>::WeightInfo::cancel_order().saturating_add(T::DbWeight::get().writes(3));
+
+		'blocks: while block < now {
+			if used_weight.saturating_add(read_weight).any_gt(remaining_weight) {
+				break;
+			}
+			used_weight.saturating_accrue(read_weight);
+
+			for order_id in <ExpiringOrders<T>>::iter_key_prefix(block) {
+				if used_weight.saturating_add(expire_weight).any_gt(remaining_weight) {
+					break 'blocks;
+				}
+				used_weight.saturating_accrue(expire_weight);
+
+				if let Some(order) = <Orders<T>>::get(order_id) {
+					// The order amounts are reduced by partial fills, so only the remaining amount_out is still reserved.
+					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+					Self::deposit_event(Event::Expired { order_id });
+				}
+				Self::remove_order(order_id);
+				// Stale index entries are removed as well
+				<ExpiringOrders<T>>::remove(block, order_id);
+			}
+
+			block.saturating_inc();
+		}
+
+		<NextExpiryBlock<T>>::put(block);
+
+		used_weight
+	}
+
+	/// Route used to fill a limit order. The order sells `asset_out` for `asset_in`.
+	fn limit_order_route(order: &Order<T::AccountId, T::AssetId>) -> Vec<Trade<T::AssetId>> {
+		T::Router::get_route(AssetPair::new(order.asset_out, order.asset_in))
+	}
+
+	/// Weight of one attempt to fill a limit order through the router.
+	fn fill_limit_order_weight(route: &[Trade<T::AssetId>]) -> Weight {
+		<T as Config>::WeightInfo::fill_order()
+			.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+			.saturating_add(T::RouterWeightInfo::calculate_spot_price_with_fee_weight(route))
+			.saturating_add(T::RouterWeightInfo::sell_weight(route))
+	}
+
+	/// Fill limit orders whose price has been reached, as long as there is enough weight left.
+	/// Processing continues after the limit order stored in `LimitOrderCursor` and wraps around to the first
+	/// limit order in the next block once the end is reached, so that every limit order is eventually processed.
+	/// Returns the consumed weight.
+	fn fill_limit_orders(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+		if cursor_weight.any_gt(remaining_weight) {
+			return Weight::zero();
+		}
+		let mut used_weight = cursor_weight;
+
+		let order_ids = match <LimitOrderCursor<T>>::get() {
+			Some(last_order_id) => <LimitOrders<T>>::iter_keys_from(<LimitOrders<T>>::hashed_key_for(last_order_id)),
+			None => <LimitOrders<T>>::iter_keys(),
+		};
+
+		let mut last_processed = None;
+		let mut reached_end = true;
+
+		for order_id in order_ids {
+			let read_weight = T::DbWeight::get()
+				.reads(3)
+				.saturating_add(T::RouterWeightInfo::get_route_weight());
+			if used_weight.saturating_add(read_weight).any_gt(remaining_weight) {
+				reached_end = false;
+				break;
+			}
+
+			if Self::is_expired(order_id, now) {
+				used_weight.saturating_accrue(read_weight);
+				last_processed = Some(order_id);
+				continue;
+			}
+
+			let Some(order) = <Orders<T>>::get(order_id) else {
+				// Stale entry without an order
+				used_weight.saturating_accrue(read_weight.saturating_add(T::DbWeight::get().writes(1)));
+				<LimitOrders<T>>::remove(order_id);
+				last_processed = Some(order_id);
+				continue;
+			};
+
+			let route = Self::limit_order_route(&order);
+			let fill_weight = Self::fill_limit_order_weight(&route);
+			if used_weight
+				.saturating_add(read_weight)
+				.saturating_add(fill_weight)
+				.any_gt(remaining_weight)
+			{
+				// The order is processed again in the next block, but its reads have been done already.
+				used_weight.saturating_accrue(read_weight);
+				reached_end = false;
+				break;
+			}
+			used_weight.saturating_accrue(read_weight.saturating_add(fill_weight));
+			last_processed = Some(order_id);
+
+			// Orders whose price has not been reached yet are expected to fail and are kept for the next blocks.
+			let _ = Self::fill_limit_order(order_id, order, route);
+		}
+
+		if reached_end {
+			<LimitOrderCursor<T>>::kill();
+		} else if let Some(order_id) = last_processed {
+			<LimitOrderCursor<T>>::put(order_id);
+		}
+
+		used_weight
+	}
+
+	/// Fill the whole remaining amount of a limit order by selling `amount_out` through the router.
+	///
+	/// The router trade is executed by the pallet account and the received amount is the change of its `asset_in`
+	/// balance. The fee is transferred to the block author and the owner receives the rest, which must be at least
+	/// the order `amount_in`. No fee is charged if the block author is not known.
+	#[transactional]
+	fn fill_limit_order(
+		order_id: OrderId,
+		order: Order<T::AccountId, T::AssetId>,
+		route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		let order_price =
+			FixedU128::checked_from_rational(order.amount_out, order.amount_in).ok_or(ArithmeticError::Overflow)?;
+		let route_price = T::Router::spot_price_with_fee(&route).ok_or(Error::<T>::PriceNotAvailable)?;
+		ensure!(route_price <= order_price, Error::<T>::LimitPriceNotReached);
+
+		let executor = Self::pallet_account();
+		let block_author = T::BlockAuthor::get();
+		let fee = if block_author.is_some() {
+			T::LimitOrderFee::get().mul_floor(order.amount_in)
+		} else {
+			Balance::zero()
+		};
+		let min_amount_out = order.amount_in.checked_add(fee).ok_or(ArithmeticError::Overflow)?;
+
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+		T::Currency::transfer(order.asset_out, &order.owner, &executor, order.amount_out)?;
+
+		let balance_before = T::Currency::free_balance(order.asset_in, &executor);
+		T::Router::sell(
+			RawOrigin::Signed(executor.clone()).into(),
+			order.asset_out,
+			order.asset_in,
+			order.amount_out,
+			min_amount_out,
+			route,
+		)?;
+		let received = T::Currency::free_balance(order.asset_in, &executor)
+			.checked_sub(balance_before)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		let amount_in = received.checked_sub(fee).ok_or(ArithmeticError::Underflow)?;
+		ensure!(amount_in >= order.amount_in, Error::<T>::LimitPriceNotReached);
+		T::Currency::transfer(order.asset_in, &executor, &order.owner, amount_in)?;
+		if let Some(block_author) = block_author {
+			if !fee.is_zero() {
+				T::Currency::transfer(order.asset_in, &executor, &block_author, fee)?;
+			}
+		}
+
+		Self::remove_order(order_id);
+
+		Self::deposit_event(Event::LimitOrderFilled {
+			order_id,
+			amount_in,
+			amount_out: order.amount_out,
+			fee,
+		});
+
+		Ok(())
+	}
+
 	fn ensure_min_order_amount(asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let min_amount = T::ExistentialDeposits::get(&asset)
 			.checked_mul(T::ExistentialDepositMultiplier::get().into())
@@ -0,0 +1,304 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn place_order_should_store_valid_until() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+
+		// Assert
+		assert_eq!(OTC::valid_until(0), Some(10));
+		assert_eq!(otc::ExpiringOrders::<Test>::get(10, 0), Some(()));
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 20 * ONE,
+			amount_out: 100 * ONE,
+			partially_fillable: true,
+			valid_until: Some(10),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_valid_until_is_in_the_past() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+
+		// Act
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(9)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_in_last_valid_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+		System::set_block_number(10);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::valid_until(0).is_none());
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+		System::set_block_number(11);
+
+		// Act
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+		System::set_block_number(11);
+
+		// Act
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+		let alice_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+		// Act
+		OTC::on_idle(11, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::valid_until(0).is_none());
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance_before + 100 * ONE);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_order_in_last_valid_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+
+		// Act
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_unreserve_remaining_amount_when_partially_filled_order_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE));
+
+		let alice_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+		let bob_hdx_balance_before = Tokens::free_balance(HDX, &BOB);
+
+		// Act
+		OTC::on_idle(11, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance_before + 75 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_hdx_balance_before);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_expired_order_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+
+		// Act
+		let used_weight = OTC::on_idle(11, Weight::zero());
+
+		// Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::valid_until(0).is_some());
+	});
+}
+
+#[test]
+fn cancel_order_should_remove_valid_until() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert!(OTC::valid_until(0).is_none());
+		assert!(otc::ExpiringOrders::<Test>::get(10, 0).is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_expire_orders_of_all_blocks_since_last_processed_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for valid_until in [5, 10, 30] {
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(valid_until)
+			));
+		}
+
+		// Act
+		OTC::on_idle(20, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_none());
+		assert!(OTC::orders(2).is_some());
+		assert_eq!(otc::ExpiringOrders::<Test>::iter_prefix(5).count(), 0);
+		assert_eq!(otc::ExpiringOrders::<Test>::iter_prefix(10).count(), 0);
+		assert_eq!(otc::ExpiringOrders::<Test>::get(30, 2), Some(()));
+		assert_eq!(OTC::next_expiry_block(), Some(20));
+	});
+}
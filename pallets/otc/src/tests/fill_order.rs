@@ -30,7 +30,8 @@ fn complete_fill_order_should_work() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -82,7 +83,8 @@ fn complete_fill_order_should_work_when_order_is_not_partially_fillable() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			false
+			false,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -134,7 +136,8 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		assert_ok!(OTC::place_order(
@@ -143,7 +146,8 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			10 * ONE,
 			50 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -198,7 +202,8 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 			HDX,
 			200 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
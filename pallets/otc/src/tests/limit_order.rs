@@ -0,0 +1,414 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn place_limit_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10)
+		));
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(OTC::limit_orders(0), Some(()));
+		assert_eq!(OTC::valid_until(0), Some(10));
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+
+		expect_events(vec![
+			Event::Placed {
+				order_id: 0,
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+				partially_fillable: true,
+				valid_until: Some(10),
+			}
+			.into(),
+			Event::LimitOrderPlaced { order_id: 0 }.into(),
+		]);
+	});
+}
+
+#[test]
+fn place_limit_order_should_throw_error_when_expiry_is_in_the_past() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+
+		// Act
+		assert_noop!(
+			OTC::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(9)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_fill_limit_order_when_price_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		// 4 HDX per DAI, so 100 HDX are sold for 25 DAI
+		set_router_price(4, 1);
+
+		let alice_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+		let block_author_dai_balance_before = Tokens::free_balance(DAI, &BLOCK_AUTHOR);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::limit_orders(0).is_none());
+
+		let fee = 20 * ONE / 100;
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_hdx_balance_before);
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			alice_dai_balance_before + 25 * ONE - fee
+		);
+		assert_eq!(
+			Tokens::free_balance(DAI, &BLOCK_AUTHOR),
+			block_author_dai_balance_before + fee
+		);
+		assert_eq!(Tokens::free_balance(DAI, &OTC::pallet_account()), 0);
+		assert_eq!(Tokens::free_balance(HDX, &OTC::pallet_account()), 0);
+
+		expect_events(vec![Event::LimitOrderFilled {
+			order_id: 0,
+			amount_in: 25 * ONE - fee,
+			amount_out: 100 * ONE,
+			fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_fill_limit_order_when_price_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		set_router_price(6, 1);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::limit_orders(0).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_not_fill_limit_order_when_fee_is_not_covered() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		// exactly the order price, nothing is left for the fee
+		set_router_price(5, 1);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::limit_orders(0).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_fill_limit_order_without_fee_when_block_author_is_not_known() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		// exactly the order price, which is enough when no fee is charged
+		set_router_price(5, 1);
+		set_block_author(None);
+
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+		let block_author_dai_balance_before = Tokens::free_balance(DAI, &BLOCK_AUTHOR);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance_before + 20 * ONE);
+		assert_eq!(
+			Tokens::free_balance(DAI, &BLOCK_AUTHOR),
+			block_author_dai_balance_before
+		);
+
+		expect_events(vec![Event::LimitOrderFilled {
+			order_id: 0,
+			amount_in: 20 * ONE,
+			amount_out: 100 * ONE,
+			fee: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_transfer_pallet_account_balance_to_order_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		set_router_price(4, 1);
+		assert_ok!(Tokens::deposit(DAI, &OTC::pallet_account(), 10 * ONE));
+
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		let fee = 20 * ONE / 100;
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(
+			Tokens::free_balance(DAI, &ALICE),
+			alice_dai_balance_before + 25 * ONE - fee
+		);
+		assert_eq!(Tokens::free_balance(DAI, &OTC::pallet_account()), 10 * ONE);
+	});
+}
+
+#[test]
+fn on_idle_should_expire_limit_order_instead_of_filling_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(5)
+		));
+		set_router_price(4, 1);
+
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+
+		// Act
+		OTC::on_idle(6, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::limit_orders(0).is_none());
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance_before);
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_fill_limit_order_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+		set_router_price(4, 1);
+
+		// Act
+		let used_weight = OTC::on_idle(1, Weight::zero());
+
+		// Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::limit_orders(0).is_some());
+	});
+}
+
+#[test]
+fn on_idle_should_continue_filling_limit_orders_after_last_processed_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for _ in 0..2 {
+			assert_ok!(OTC::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None
+			));
+		}
+		set_router_price(4, 1);
+
+		let order_ids = otc::LimitOrders::<Test>::iter_keys().collect::<Vec<_>>();
+		otc::LimitOrderCursor::<Test>::put(order_ids[0]);
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::limit_orders(order_ids[0]).is_some());
+		assert!(OTC::limit_orders(order_ids[1]).is_none());
+		assert_eq!(OTC::limit_order_cursor(), None);
+
+		// Act
+		OTC::on_idle(2, Weight::MAX);
+
+		// Assert
+		assert!(OTC::limit_orders(order_ids[0]).is_none());
+		assert!(OTC::orders(order_ids[0]).is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_remove_limit_order_entry_without_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		otc::LimitOrders::<Test>::insert(5, ());
+
+		// Act
+		OTC::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(OTC::limit_orders(5).is_none());
+	});
+}
+
+#[test]
+fn limit_order_should_be_removed_when_cancelled() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert!(OTC::limit_orders(0).is_none());
+	});
+}
+
+#[test]
+fn limit_order_should_be_removed_when_filled_by_user() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None
+		));
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::limit_orders(0).is_none());
+	});
+}
@@ -16,17 +16,20 @@
 use crate as otc;
 use crate::Config;
 use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
 	parameter_types,
-	traits::{Everything, Nothing},
+	traits::{Everything, Get, Nothing},
+	PalletId,
 };
 use frame_system as system;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, RouterT, Trade};
 use hydradx_traits::{registry::Inspect, AssetKind};
 use orml_tokens::AccountData;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult, FixedPointNumber, FixedU128, Permill,
 };
 use std::{cell::RefCell, collections::HashMap};
 
@@ -47,6 +50,8 @@ pub const ONE: Balance = 1_000_000_000_000;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const BLOCK_AUTHOR: AccountId = 3;
+pub const POOL: AccountId = 4;
 
 frame_support::construct_runtime!(
 	pub enum Test
@@ -61,11 +66,16 @@ thread_local! {
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
 	pub static EXISTENTIAL_DEPOSIT: RefCell<HashMap<AssetId, u128>>= RefCell::new(HashMap::default());
 	pub static PRECISIONS: RefCell<HashMap<AssetId, u32>>= RefCell::new(HashMap::default());
+	/// Price of the mocked router as (amount of route asset_in, amount of route asset_out)
+	pub static ROUTER_PRICE: RefCell<(Balance, Balance)> = RefCell::new((1, 1));
+	pub static CURRENT_BLOCK_AUTHOR: RefCell<Option<AccountId>> = RefCell::new(Some(BLOCK_AUTHOR));
 }
 
 parameter_types! {
 	pub NativeCurrencyId: AssetId = HDX;
 	pub ExistentialDepositMultiplier: u8 = 5;
+	pub const OtcPalletId: PalletId = PalletId(*b"otcorder");
+	pub LimitOrderFee: Permill = Permill::from_percent(1);
 }
 
 parameter_type_with_key! {
@@ -81,9 +91,108 @@ impl otc::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = ExistentialDeposits;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type Router = MockRouter;
+	type PalletId = OtcPalletId;
+	type BlockAuthor = MockBlockAuthor;
+	type LimitOrderFee = LimitOrderFee;
+	type RouterWeightInfo = ();
 	type WeightInfo = ();
 }
 
+pub fn set_block_author(author: Option<AccountId>) {
+	CURRENT_BLOCK_AUTHOR.with(|v| {
+		*v.borrow_mut() = author;
+	});
+}
+
+pub struct MockBlockAuthor;
+
+impl Get<Option<AccountId>> for MockBlockAuthor {
+	fn get() -> Option<AccountId> {
+		CURRENT_BLOCK_AUTHOR.with(|v| *v.borrow())
+	}
+}
+
+pub fn set_router_price(amount_in: Balance, amount_out: Balance) {
+	ROUTER_PRICE.with(|v| {
+		*v.borrow_mut() = (amount_in, amount_out);
+	});
+}
+
+/// Router which trades with `POOL` account at `ROUTER_PRICE` without any fee.
+pub struct MockRouter;
+
+impl RouteProvider<AssetId> for MockRouter {}
+
+impl RouteSpotPriceProvider<AssetId> for MockRouter {
+	fn spot_price_with_fee(_route: &[Trade<AssetId>]) -> Option<FixedU128> {
+		let (amount_in, amount_out) = ROUTER_PRICE.with(|v| *v.borrow());
+		FixedU128::checked_from_rational(amount_in, amount_out)
+	}
+}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = frame_system::ensure_signed(origin)?;
+		let (price_in, price_out) = ROUTER_PRICE.with(|v| *v.borrow());
+		let amount_out = amount_in * price_out / price_in;
+		if amount_out < min_amount_out {
+			return Err(DispatchError::Other("TradingLimitReached"));
+		}
+
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_in, &who, &POOL, amount_in)?;
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_out, &POOL, &who, amount_out)
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 63;
@@ -238,6 +347,12 @@ impl Default for ExtBuilder {
 		EXISTENTIAL_DEPOSIT.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ROUTER_PRICE.with(|v| {
+			*v.borrow_mut() = (1, 1);
+		});
+		CURRENT_BLOCK_AUTHOR.with(|v| {
+			*v.borrow_mut() = Some(BLOCK_AUTHOR);
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -245,6 +360,9 @@ impl Default for ExtBuilder {
 				(BOB, HDX, 10_000),
 				(ALICE, DAI, 100),
 				(BOB, DAI, 100),
+				(BLOCK_AUTHOR, DAI, 10),
+				(POOL, HDX, 10_000),
+				(POOL, DAI, 1_000),
 			],
 			registered_assets: vec![HDX, DAI],
 		}
@@ -16,7 +16,9 @@
 pub mod mock;
 
 pub mod cancel_order;
+pub mod expiry;
 pub mod fill_order;
 pub mod invariants;
+pub mod limit_order;
 pub mod partial_fill_order;
 pub mod place_order;
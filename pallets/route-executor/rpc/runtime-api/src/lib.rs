@@ -0,0 +1,46 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::{AmountInAndOut, AssetPair, PoolType, Trade};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to discover routes and calculate trade amounts of routes.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Find the route with the highest amount out for selling `amount_in` of `asset_pair.asset_in`.
+		/// Returns the route and the calculated amounts of each trade.
+		fn find_best_sell_route(asset_pair: AssetPair<AssetId>, amount_in: Balance) -> Option<(Vec<Trade<AssetId>>, Vec<AmountInAndOut<Balance>>)>;
+
+		/// Find the route with the lowest amount in for buying `amount_out` of `asset_pair.asset_out`.
+		/// Returns the route and the calculated amounts of each trade in the order of the route.
+		fn find_best_buy_route(asset_pair: AssetPair<AssetId>, amount_out: Balance) -> Option<(Vec<Trade<AssetId>>, Vec<AmountInAndOut<Balance>>)>;
+
+		/// Calculate amounts of each trade of the route when selling `amount_in`.
+		fn calculate_sell_trade_amounts(route: Vec<Trade<AssetId>>, amount_in: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError>;
+
+		/// Calculate amounts of each trade of the route when buying `amount_out`.
+		/// The amounts are returned in the order of the route.
+		fn calculate_buy_trade_amounts(route: Vec<Trade<AssetId>>, amount_out: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError>;
+	}
+}
@@ -33,14 +33,18 @@ use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::registry::Inspect as RegistryInspect;
-use hydradx_traits::router::{inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider};
+use hydradx_traits::router::{
+	inverse_route, AssetPair, PoolsProvider, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider,
+};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution, WeightedRoute,
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use sp_core::U512;
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating, TransactionOutcome};
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Zero};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Saturating, TransactionOutcome,
+};
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -54,6 +58,12 @@ pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
 
+/// Max number of routes an amount can be split between in a split trade
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 4;
+
+/// Max number of trades in a route found by the route discovery
+pub const MAX_NUMBER_OF_DISCOVERED_TRADES: u32 = 3;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -85,7 +95,8 @@ pub mod pallet {
 			+ CheckedAdd
 			+ CheckedDiv
 			+ Saturating
-			+ Zero;
+			+ Zero
+			+ AtLeast32BitUnsigned;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -111,6 +122,9 @@ pub mod pallet {
 		/// Pool type used in the default route
 		type DefaultRoutePoolType: Get<PoolType<Self::AssetId>>;
 
+		/// Pools considered when discovering the best route for an asset pair
+		type Pools: PoolsProvider<Self::AssetId>;
+
 		/// Origin able to set route without validation
 		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -154,6 +168,10 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		///The max number of routes in a split trade is reached
+		MaxSplitRoutesExceeded,
+		///The weights of the split routes are zero or do not sum up to 100%, or a route would trade zero amount
+		InvalidSplit,
 	}
 
 	/// Storing routes for asset pairs
@@ -196,59 +214,13 @@ pub mod pallet {
 
 			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
 
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_out_before_trade =
-				T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
-
-			let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(
-				last_trade_amount.amount_out >= min_amount_out,
-				Error::<T>::TradingLimitReached
-			);
-
-			for (trade_amount, trade) in trade_amounts.iter().zip(route) {
-				let user_balance_of_asset_in_before_trade =
-					T::Currency::reducible_balance(trade.asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-				let execution_result = T::AMM::execute_sell(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_in,
-					trade_amount.amount_out,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_spent_asset_in_at_least(
-					who.clone(),
-					trade.asset_in,
-					user_balance_of_asset_in_before_trade,
-					trade_amount.amount_in,
-				)?;
-			}
-
-			Self::ensure_that_user_received_asset_out_at_most(
-				who,
-				asset_in,
-				asset_out,
-				user_balance_of_asset_out_before_trade,
-				last_trade_amount.amount_out,
-			)?;
+			let amount_out = Self::do_sell(origin, who, asset_in, asset_out, amount_in, min_amount_out, route)?;
 
 			Self::deposit_event(Event::Executed {
 				asset_in,
 				asset_out,
 				amount_in,
-				amount_out: last_trade_amount.amount_out,
+				amount_out,
 			});
 
 			Ok(())
@@ -281,54 +253,13 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 
 			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
 
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
+			let amount_in = Self::do_buy(origin, who, asset_in, asset_out, amount_out, max_amount_in, route)?;
 
 			Self::deposit_event(Event::Executed {
 				asset_in,
 				asset_out,
-				amount_in: first_trade.amount_in,
+				amount_in,
 				amount_out,
 			});
 
@@ -453,6 +384,116 @@ pub mod pallet {
 
 			Self::insert_route(asset_pair, new_route)
 		}
+
+		/// Executes a sell split between several routes.
+		/// `amount_in` is divided between the routes according to their weights and the routes are executed one after another.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive from all routes.
+		/// - `routes`: Routes with the weights of the split. Weights must be non-zero and sum up to 100%.
+		///   Max number of routes is `MAX_NUMBER_OF_SPLIT_ROUTES`.
+		///
+		/// Emits one `Executed` event with the total amounts when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T>::sell_split_weight(routes))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<WeightedRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+			let mut amount_out = T::Balance::zero();
+			for (route_amount_in, route) in Self::split_amount(amount_in, routes)? {
+				let route_amount_out = Self::do_sell(
+					origin.clone(),
+					who.clone(),
+					asset_in,
+					asset_out,
+					route_amount_in,
+					T::Balance::zero(),
+					route,
+				)?;
+				amount_out = amount_out
+					.checked_add(&route_amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Executes a buy split between several routes.
+		/// `amount_out` is divided between the routes according to their weights and the routes are executed one after another.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on all routes.
+		/// - `routes`: Routes with the weights of the split. Weights must be non-zero and sum up to 100%.
+		///   Max number of routes is `MAX_NUMBER_OF_SPLIT_ROUTES`.
+		///
+		/// Emits one `Executed` event with the total amounts when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::buy_split_weight(routes))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<WeightedRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+			let mut amount_in = T::Balance::zero();
+			for (route_amount_out, route) in Self::split_amount(amount_out, routes)? {
+				let route_amount_in = Self::do_buy(
+					origin.clone(),
+					who.clone(),
+					asset_in,
+					asset_out,
+					route_amount_out,
+					max_amount_in,
+					route,
+				)?;
+				amount_in = amount_in
+					.checked_add(&route_amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -462,6 +503,175 @@ impl<T: Config> Pallet<T> {
 		PalletId(*b"routerex").into_account_truncating()
 	}
 
+	#[allow(clippy::too_many_arguments)]
+	fn do_sell(
+		origin: OriginFor<T>,
+		who: T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let user_balance_of_asset_out_before_trade =
+			T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+
+		let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(
+			last_trade_amount.amount_out >= min_amount_out,
+			Error::<T>::TradingLimitReached
+		);
+
+		for (trade_amount, trade) in trade_amounts.iter().zip(route) {
+			let user_balance_of_asset_in_before_trade =
+				T::Currency::reducible_balance(trade.asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+			let execution_result = T::AMM::execute_sell(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_in,
+				trade_amount.amount_out,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_spent_asset_in_at_least(
+				who.clone(),
+				trade.asset_in,
+				user_balance_of_asset_in_before_trade,
+				trade_amount.amount_in,
+			)?;
+		}
+
+		Self::ensure_that_user_received_asset_out_at_most(
+			who,
+			asset_in,
+			asset_out,
+			user_balance_of_asset_out_before_trade,
+			last_trade_amount.amount_out,
+		)?;
+
+		Ok(last_trade_amount.amount_out)
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn do_buy(
+		origin: OriginFor<T>,
+		who: T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+		for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)?;
+
+		Ok(first_trade.amount_in)
+	}
+
+	/// Validates the weighted routes of a split trade and splits `amount` between them according to their weights.
+	/// Any rounding remainder is added to the last route, so the split amounts always sum up to `amount`.
+	fn split_amount(
+		amount: T::Balance,
+		routes: Vec<WeightedRoute<T::AssetId>>,
+	) -> Result<Vec<(T::Balance, Vec<Trade<T::AssetId>>)>, DispatchError> {
+		ensure!(!routes.is_empty(), Error::<T>::InvalidSplit);
+		ensure!(
+			(routes.len() as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::MaxSplitRoutesExceeded
+		);
+
+		let mut total_weight: u32 = 0;
+		for weighted_route in routes.iter() {
+			ensure!(!weighted_route.weight.is_zero(), Error::<T>::InvalidSplit);
+			ensure!(!weighted_route.route.is_empty(), Error::<T>::InvalidRoute);
+			total_weight = total_weight.saturating_add(weighted_route.weight.deconstruct());
+		}
+		ensure!(total_weight == Permill::one().deconstruct(), Error::<T>::InvalidSplit);
+
+		let mut remaining = amount;
+		let last_idx = routes.len().saturating_sub(1);
+
+		let mut split = Vec::with_capacity(routes.len());
+		for (idx, weighted_route) in routes.into_iter().enumerate() {
+			let route_amount = if idx == last_idx {
+				remaining
+			} else {
+				weighted_route.weight.mul_floor(amount)
+			};
+			ensure!(!route_amount.is_zero(), Error::<T>::InvalidSplit);
+			remaining = remaining.checked_sub(&route_amount).ok_or(ArithmeticError::Underflow)?;
+
+			split.push((route_amount, weighted_route.route));
+		}
+
+		Ok(split)
+	}
+
+	fn sell_split_weight(routes: &[WeightedRoute<T::AssetId>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, weighted_route| {
+			weight.saturating_add(T::WeightInfo::sell_weight(&weighted_route.route))
+		})
+	}
+
+	fn buy_split_weight(routes: &[WeightedRoute<T::AssetId>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, weighted_route| {
+			weight.saturating_add(T::WeightInfo::buy_weight(&weighted_route.route))
+		})
+	}
+
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
 		ensure!(
 			(route_length as u32) <= MAX_NUMBER_OF_TRADES,
@@ -602,6 +812,8 @@ impl<T: Config> Pallet<T> {
 			PoolType::Stableswap(pool_id) => pool_id,
 			PoolType::XYK => first_route.asset_out,
 			PoolType::LBP => first_route.asset_out,
+			PoolType::Weighted(pool_id) => pool_id,
+			PoolType::Concentrated => first_route.asset_out,
 		};
 
 		let asset_in_liquidity = T::AMM::get_liquidity_depth(first_route.pool, first_route.asset_in, asset_b);
@@ -696,6 +908,141 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Finds the route which gives the highest amount of `asset_out` for selling `amount_in` of `asset_in`.
+	///
+	/// Candidates are the on-chain (or default) route and every route of at most `MAX_NUMBER_OF_DISCOVERED_TRADES`
+	/// trades through the pools of `T::Pools`. Routes which can not be calculated by the AMMs are skipped.
+	///
+	/// Returns the route together with the calculated amounts of its trades, or `None` if no route is found.
+	pub fn find_best_sell_route(
+		asset_pair: AssetPair<T::AssetId>,
+		amount_in: T::Balance,
+	) -> Option<(Vec<Trade<T::AssetId>>, Vec<AmountInAndOut<T::Balance>>)> {
+		if asset_pair.asset_in == asset_pair.asset_out {
+			return None;
+		}
+
+		let mut best: Option<(Vec<Trade<T::AssetId>>, Vec<AmountInAndOut<T::Balance>>)> = None;
+		for route in Self::discover_routes(asset_pair) {
+			let Ok(amounts) = Self::calculate_sell_trade_amounts(&route, amount_in) else {
+				continue;
+			};
+			let Some(amount_out) = amounts.last().map(|amounts| amounts.amount_out) else {
+				continue;
+			};
+			let is_better = match &best {
+				Some((_, best_amounts)) => best_amounts.last().map_or(true, |best| amount_out > best.amount_out),
+				None => true,
+			};
+			if is_better {
+				best = Some((route, amounts));
+			}
+		}
+
+		best
+	}
+
+	/// Finds the route which requires the lowest amount of `asset_in` for buying `amount_out` of `asset_out`.
+	///
+	/// Candidates are the on-chain (or default) route and every route of at most `MAX_NUMBER_OF_DISCOVERED_TRADES`
+	/// trades through the pools of `T::Pools`. Routes which can not be calculated by the AMMs are skipped.
+	///
+	/// Returns the route together with the calculated amounts of its trades in the order of the route,
+	/// or `None` if no route is found.
+	pub fn find_best_buy_route(
+		asset_pair: AssetPair<T::AssetId>,
+		amount_out: T::Balance,
+	) -> Option<(Vec<Trade<T::AssetId>>, Vec<AmountInAndOut<T::Balance>>)> {
+		if asset_pair.asset_in == asset_pair.asset_out {
+			return None;
+		}
+
+		let mut best: Option<(Vec<Trade<T::AssetId>>, Vec<AmountInAndOut<T::Balance>>)> = None;
+		for route in Self::discover_routes(asset_pair) {
+			let Ok(mut amounts) = Self::calculate_buy_trade_amounts(&route, amount_out) else {
+				continue;
+			};
+			amounts.reverse();
+			let Some(amount_in) = amounts.first().map(|amounts| amounts.amount_in) else {
+				continue;
+			};
+			let is_better = match &best {
+				Some((_, best_amounts)) => best_amounts.first().map_or(true, |best| amount_in < best.amount_in),
+				None => true,
+			};
+			if is_better {
+				best = Some((route, amounts));
+			}
+		}
+
+		best
+	}
+
+	/// Collects candidate routes for the asset pair. The on-chain (or default) route is always the first candidate.
+	fn discover_routes(asset_pair: AssetPair<T::AssetId>) -> Vec<Vec<Trade<T::AssetId>>> {
+		let pools = T::Pools::pools();
+		let mut routes = vec![<Pallet<T> as RouteProvider<T::AssetId>>::get_route(asset_pair)];
+		let mut path = Vec::with_capacity(MAX_NUMBER_OF_DISCOVERED_TRADES as usize);
+
+		Self::collect_routes(
+			&pools,
+			asset_pair.asset_in,
+			asset_pair.asset_out,
+			None,
+			&mut path,
+			&mut routes,
+		);
+
+		routes
+	}
+
+	/// Depth-first search of routes from `asset` to `asset_out`.
+	/// An asset is never visited twice in a route and two consecutive trades are never executed in the same pool.
+	fn collect_routes(
+		pools: &[(PoolType<T::AssetId>, Vec<T::AssetId>)],
+		asset: T::AssetId,
+		asset_out: T::AssetId,
+		previous_pool_idx: Option<usize>,
+		path: &mut Vec<Trade<T::AssetId>>,
+		routes: &mut Vec<Vec<Trade<T::AssetId>>>,
+	) {
+		if path.len() >= MAX_NUMBER_OF_DISCOVERED_TRADES as usize {
+			return;
+		}
+
+		for (pool_idx, (pool, assets)) in pools.iter().enumerate() {
+			if previous_pool_idx == Some(pool_idx) || !assets.contains(&asset) {
+				continue;
+			}
+
+			for next_asset in assets.iter() {
+				let visited = *next_asset == asset
+					|| path
+						.iter()
+						.any(|trade| trade.asset_in == *next_asset || trade.asset_out == *next_asset);
+				if visited {
+					continue;
+				}
+
+				path.push(Trade {
+					pool: *pool,
+					asset_in: asset,
+					asset_out: *next_asset,
+				});
+
+				if *next_asset == asset_out {
+					if !routes.contains(path) {
+						routes.push(path.clone());
+					}
+				} else {
+					Self::collect_routes(pools, *next_asset, asset_out, Some(pool_idx), path, routes);
+				}
+
+				path.pop();
+			}
+		}
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
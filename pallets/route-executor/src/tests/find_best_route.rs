@@ -0,0 +1,233 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AmountInAndOut, Trade};
+use frame_support::assert_ok;
+use hydradx_traits::router::{AssetPair, PoolType};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn find_best_sell_route_should_return_default_route_when_no_pools_are_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100);
+
+		//Assert
+		assert_eq!(
+			best,
+			Some((
+				default_omnipool_route(),
+				vec![AmountInAndOut {
+					amount_in: 100,
+					amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+				}]
+			))
+		);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_return_none_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Router::find_best_sell_route(AssetPair::new(HDX, HDX), 100), None);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_return_none_when_no_route_can_be_calculated() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, DOT, AUSD]),
+			(PoolType::XYK, vec![DOT, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				Router::find_best_sell_route(AssetPair::new(HDX, AUSD), INVALID_CALCULATION_AMOUNT),
+				None
+			);
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_return_route_with_highest_amount_out() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, vec![HDX, DOT, AUSD]),
+			(PoolType::XYK, vec![DOT, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100);
+
+			//Assert
+			assert_eq!(
+				best,
+				Some((
+					vec![
+						Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: DOT,
+						},
+						Trade {
+							pool: PoolType::XYK,
+							asset_in: DOT,
+							asset_out: AUSD,
+						}
+					],
+					vec![
+						AmountInAndOut {
+							amount_in: 100,
+							amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+						},
+						AmountInAndOut {
+							amount_in: OMNIPOOL_SELL_CALCULATION_RESULT,
+							amount_out: XYK_SELL_CALCULATION_RESULT,
+						}
+					]
+				))
+			);
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_consider_onchain_route() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::Omnipool, vec![HDX, AUSD])])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let onchain_route = vec![HDX_AUSD_TRADE_IN_XYK];
+			assert_ok!(Router::force_insert_route(
+				RuntimeOrigin::root(),
+				AssetPair::new(HDX, AUSD),
+				onchain_route.clone()
+			));
+
+			//Act
+			let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100);
+
+			//Assert
+			assert_eq!(best.map(|(route, _)| route), Some(onchain_route));
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_not_discover_routes_longer_than_max_number_of_discovered_trades() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::XYK, vec![HDX, DOT]),
+			(PoolType::XYK, vec![DOT, KSM]),
+			(PoolType::XYK, vec![KSM, MOVR]),
+			(PoolType::XYK, vec![MOVR, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100);
+
+			//Assert
+			assert_eq!(best.map(|(route, _)| route), Some(default_omnipool_route()));
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_allow_consecutive_trades_in_different_pools_of_same_type() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::XYK, vec![HDX, DOT]), (PoolType::XYK, vec![DOT, AUSD])])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100);
+
+			//Assert
+			assert_eq!(
+				best.map(|(route, _)| route),
+				Some(vec![
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: HDX,
+						asset_out: DOT,
+					},
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: DOT,
+						asset_out: AUSD,
+					}
+				])
+			);
+		});
+}
+
+#[test]
+fn find_best_buy_route_should_return_route_with_lowest_amount_in() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::XYK, vec![HDX, DOT]),
+			(PoolType::Omnipool, vec![HDX, DOT, AUSD]),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best = Router::find_best_buy_route(AssetPair::new(HDX, AUSD), 100);
+
+			//Assert
+			assert_eq!(
+				best,
+				Some((
+					vec![
+						Trade {
+							pool: PoolType::XYK,
+							asset_in: HDX,
+							asset_out: DOT,
+						},
+						Trade {
+							pool: PoolType::Omnipool,
+							asset_in: DOT,
+							asset_out: AUSD,
+						}
+					],
+					vec![
+						AmountInAndOut {
+							amount_in: XYK_BUY_CALCULATION_RESULT,
+							amount_out: OMNIPOOL_BUY_CALCULATION_RESULT,
+						},
+						AmountInAndOut {
+							amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+							amount_out: 100,
+						}
+					]
+				))
+			);
+		});
+}
+
+#[test]
+fn find_best_buy_route_should_return_none_when_no_route_can_be_calculated() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::Omnipool, vec![HDX, AUSD])])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				Router::find_best_buy_route(AssetPair::new(HDX, AUSD), INVALID_CALCULATION_AMOUNT),
+				None
+			);
+		});
+}
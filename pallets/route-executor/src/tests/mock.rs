@@ -23,7 +23,7 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use hydradx_traits::router::{ExecutorError, PoolType, RefundEdCalculator, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, RefundEdCalculator, TradeExecution};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{fungibles::FungibleCurrencies, BasicCurrencyAdapter};
 use pretty_assertions::assert_eq;
@@ -152,10 +152,24 @@ impl Config for Test {
 	type AMM = Pools;
 	type EdToRefundCalculator = MockedEdCalculator;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type Pools = MockedPools;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 }
 
+type PoolWithAssets = (PoolType<AssetId>, Vec<AssetId>);
+thread_local! {
+	pub static POOLS: RefCell<Vec<PoolWithAssets>> = RefCell::new(Vec::default());
+}
+
+pub struct MockedPools;
+
+impl PoolsProvider<AssetId> for MockedPools {
+	fn pools() -> Vec<PoolWithAssets> {
+		POOLS.with(|v| v.borrow().clone())
+	}
+}
+
 pub struct MockedEdCalculator;
 
 impl RefundEdCalculator<Balance> for MockedEdCalculator {
@@ -249,6 +263,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<PoolWithAssets>,
 }
 
 // Returns default values for genesis config
@@ -256,6 +271,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			pools: vec![],
 		}
 	}
 }
@@ -266,6 +282,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<PoolWithAssets>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -297,6 +318,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		POOLS.with(|v| {
+			*v.borrow_mut() = self.pools;
+		});
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
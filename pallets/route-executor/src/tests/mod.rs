@@ -1,6 +1,8 @@
 pub mod buy;
+pub mod find_best_route;
 pub mod force_insert_route;
 pub mod mock;
 pub mod sell;
 pub mod set_route;
+pub mod split;
 pub mod spot_price;
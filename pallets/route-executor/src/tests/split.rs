@@ -0,0 +1,283 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade, WeightedRoute};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use sp_runtime::Permill;
+
+fn weighted(percent: u32, pool: PoolType<AssetId>) -> WeightedRoute<AssetId> {
+	WeightedRoute {
+		weight: Permill::from_percent(percent),
+		route: vec![Trade {
+			pool,
+			asset_in: HDX,
+			asset_out: AUSD,
+		}],
+	}
+}
+
+#[test]
+fn sell_split_should_execute_all_routes_with_weighted_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(40, PoolType::Omnipool)];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			100,
+			XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 60, HDX, AUSD),
+			(PoolType::Omnipool, 40, HDX, AUSD),
+		]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: 100,
+			amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_split_should_add_rounding_remainder_to_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			WeightedRoute {
+				weight: Permill::from_parts(333_333),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			WeightedRoute {
+				weight: Permill::from_parts(333_333),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			WeightedRoute {
+				weight: Permill::from_parts(333_334),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			100,
+			0,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 33, HDX, AUSD),
+			(PoolType::XYK, 33, HDX, AUSD),
+			(PoolType::XYK, 34, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(40, PoolType::Omnipool)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT + 1,
+				routes
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_weights_do_not_sum_up_to_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(30, PoolType::Omnipool)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_has_zero_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(100, PoolType::XYK), weighted(0, PoolType::Omnipool)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_split_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(10, PoolType::XYK), weighted(90, PoolType::Omnipool)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 5, 0, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_max_number_of_routes_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(20, PoolType::XYK); 5];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::MaxSplitRoutesExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted(50, PoolType::XYK),
+			WeightedRoute {
+				weight: Permill::from_percent(50),
+				route: vec![Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: DOT,
+				}],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				HDX,
+				100,
+				0,
+				vec![weighted(100, PoolType::XYK)]
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_execute_all_routes_with_weighted_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(40, PoolType::LBP)];
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			100,
+			XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			routes
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 60, HDX, AUSD), (PoolType::LBP, 40, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			amount_out: 100,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(40, PoolType::LBP)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT - 1,
+				routes
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_weights_do_not_sum_up_to_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted(60, PoolType::XYK), weighted(50, PoolType::LBP)];
+
+		//Act and Assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 100, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
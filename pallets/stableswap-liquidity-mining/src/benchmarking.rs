@@ -0,0 +1,414 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::{Pallet as System, RawOrigin};
+use pallet_stableswap::types::AssetAmount;
+use sp_runtime::{traits::One, Permill};
+
+const SEED: u32 = 0;
+const ONE: Balance = 1_000_000_000_000;
+const INITIAL_BALANCE: Balance = 100_000_000 * ONE;
+const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * ONE;
+
+const HDX: u32 = 0;
+const ASSET_A: u32 = 2_000;
+const ASSET_B: u32 = 2_001;
+const ASSET_C: u32 = 2_002;
+
+// Pool id is also pool's share asset.
+const POOL_1: u32 = 2_100;
+const POOL_2: u32 = 2_101;
+
+//NOTE: incentivized asset must be in all pools.
+const INCENTIVIZED_ASSET: u32 = ASSET_A;
+const REWARD_CURRENCY: u32 = HDX;
+
+fn funded_account<T: Config>(name: &'static str, index: u32, assets: &[u32]) -> T::AccountId
+where
+	T::AssetId: From<u32>,
+{
+	let who: T::AccountId = account(name, index, SEED);
+
+	for asset in assets {
+		T::Currencies::deposit((*asset).into(), &who, INITIAL_BALANCE).unwrap();
+	}
+
+	who
+}
+
+/// Creates `POOL_1` with `ASSET_A` and `ASSET_B` and `POOL_2` with `ASSET_A` and `ASSET_C`, adds
+/// initial liquidity to both pools and makes oracle prices of pools' shares available.
+fn init_stableswap_pools<T: Config>() -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	for asset in [ASSET_A, ASSET_B, ASSET_C] {
+		<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(asset.into(), 12)?;
+	}
+
+	let provider = funded_account::<T>("provider", 0, &[ASSET_A, ASSET_B, ASSET_C]);
+
+	for (pool_id, assets) in [(POOL_1, [ASSET_A, ASSET_B]), (POOL_2, [ASSET_A, ASSET_C])] {
+		<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(pool_id.into(), 18)?;
+
+		let successful_origin = <T as pallet_stableswap::Config>::AuthorityOrigin::try_successful_origin().unwrap();
+		StableswapPallet::<T>::create_pool(
+			successful_origin,
+			pool_id.into(),
+			assets.iter().map(|a| (*a).into()).collect(),
+			100,
+			Permill::from_percent(1),
+		)?;
+
+		stableswap_add_liquidity::<T>(provider.clone(), pool_id, &assets, 1_000_000 * ONE)?;
+
+		for asset in assets {
+			<T as Config>::BenchmarkHelper::set_share_price(asset.into(), pool_id.into())?;
+		}
+	}
+
+	Ok(())
+}
+
+fn stableswap_add_liquidity<T: Config>(
+	who: T::AccountId,
+	pool_id: u32,
+	assets: &[u32],
+	amount: Balance,
+) -> Result<Balance, DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	let liquidity = assets.iter().map(|a| AssetAmount::new((*a).into(), amount)).collect();
+
+	StableswapPallet::<T>::add_liquidity(RawOrigin::Signed(who.clone()).into(), pool_id.into(), liquidity)?;
+
+	Ok(T::Currencies::free_balance(pool_id.into(), &who))
+}
+
+fn create_gfarm<T: Config>(owner: T::AccountId) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	let successful_origin = <T as Config>::CreateOrigin::try_successful_origin().unwrap();
+
+	Pallet::<T>::create_global_farm(
+		successful_origin,
+		G_FARM_TOTAL_REWARDS,
+		BlockNumberFor::<T>::from(100_000_u32),
+		BlockNumberFor::<T>::from(1_u32),
+		INCENTIVIZED_ASSET.into(),
+		REWARD_CURRENCY.into(),
+		owner,
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)
+}
+
+fn create_yfarm<T: Config>(owner: T::AccountId, global_farm_id: GlobalFarmId, pool_id: u32) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	Pallet::<T>::create_yield_farm(
+		RawOrigin::Signed(owner).into(),
+		global_farm_id,
+		pool_id.into(),
+		FixedU128::one(),
+		Some(LoyaltyCurve::default()),
+	)
+}
+
+/// Adds liquidity to `POOL_1` and deposits all received shares to the yield farm.
+fn lm_deposit_shares<T: Config>(
+	who: T::AccountId,
+	global_farm_id: GlobalFarmId,
+	yield_farm_id: YieldFarmId,
+) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	let shares = stableswap_add_liquidity::<T>(who.clone(), POOL_1, &[ASSET_A, ASSET_B], 1_000 * ONE)?;
+
+	Pallet::<T>::deposit_shares(
+		RawOrigin::Signed(who).into(),
+		global_farm_id,
+		yield_farm_id,
+		POOL_1.into(),
+		shares,
+	)
+}
+
+/// Creates 5 global farms, each with a yield farm for `POOL_1`, and deposits shares of `lp1` to
+/// all but the last yield farm. `lp2` deposits shares to the last yield farm so it is updated.
+///
+/// Ids of created farms are: gId: 1, yId: 2; gId: 3, yId: 4; gId: 5, yId: 6; gId: 7, yId: 8;
+/// gId: 9, yId: 10
+fn init_farms_with_max_entries<T: Config>(lp1: T::AccountId, lp2: T::AccountId) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	for i in 0..5_u32 {
+		let owner = funded_account::<T>("fowner", i, &[REWARD_CURRENCY]);
+		let global_farm_id = 2 * i + 1;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner, global_farm_id, POOL_1)?;
+	}
+
+	set_period::<T>(200);
+
+	let lp1_deposit_id = 1;
+	lm_deposit_shares::<T>(lp1.clone(), 1, 2)?;
+	for (global_farm_id, yield_farm_id) in [(3, 4), (5, 6), (7, 8)] {
+		Pallet::<T>::redeposit_shares(
+			RawOrigin::Signed(lp1.clone()).into(),
+			global_farm_id,
+			yield_farm_id,
+			POOL_1.into(),
+			lp1_deposit_id,
+		)?;
+	}
+
+	lm_deposit_shares::<T>(lp2, 9, 10)
+}
+
+fn set_period<T: Config>(to: u32) {
+	//NOTE: global farms are created with period size = 1 block.
+	System::<T>::set_block_number(to.into());
+}
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+	}
+
+	create_global_farm {
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let successful_origin = <T as Config>::CreateOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, G_FARM_TOTAL_REWARDS, BlockNumberFor::<T>::from(100_000_u32), BlockNumberFor::<T>::from(100_u32), INCENTIVIZED_ASSET.into(), REWARD_CURRENCY.into(), owner, Perquintill::from_percent(20), 1_000, FixedU128::one())
+
+	update_global_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, FixedU128::from_inner(234_456_677_000_000_000_u128))
+
+	terminate_global_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_1.into())?;
+		Pallet::<T>::terminate_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, yield_farm_id, POOL_1.into())?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id)
+
+	create_yield_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_2.into(), FixedU128::one(), Some(LoyaltyCurve::default()))
+
+	update_yield_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_1.into(), FixedU128::from(2_u128))
+
+	stop_yield_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_1.into())
+
+	resume_yield_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let pool_2_farm_id = 2;
+		let pool_1_farm_id = 3;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_2)?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_2.into())?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, pool_1_farm_id)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, pool_2_farm_id, POOL_2.into(), FixedU128::from(2_u128))
+
+	terminate_yield_farm {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner.clone(), global_farm_id, POOL_1)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id)?;
+
+		Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_1.into())?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, yield_farm_id, POOL_1.into())
+
+	deposit_shares {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp1 = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let lp2 = funded_account::<T>("lp", 2, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner, global_farm_id, POOL_1)?;
+
+		set_period::<T>(200);
+		lm_deposit_shares::<T>(lp1, global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(300);
+		let shares = stableswap_add_liquidity::<T>(lp2.clone(), POOL_1, &[ASSET_A, ASSET_B], 1_000 * ONE)?;
+
+		assert!(DepositPoolId::<T>::get(2).is_none());
+	}: _(RawOrigin::Signed(lp2), global_farm_id, yield_farm_id, POOL_1.into(), shares)
+	verify {
+		assert!(DepositPoolId::<T>::get(2).is_some());
+	}
+
+	redeposit_shares {
+		init_stableswap_pools::<T>()?;
+
+		let lp1 = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let lp2 = funded_account::<T>("lp", 2, &[ASSET_A, ASSET_B]);
+		let lp1_deposit_id = 1;
+
+		init_farms_with_max_entries::<T>(lp1.clone(), lp2)?;
+
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(lp1), 9, 10, POOL_1.into(), lp1_deposit_id)
+
+	claim_rewards {
+		init_stableswap_pools::<T>()?;
+
+		let lp1 = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let lp2 = funded_account::<T>("lp", 2, &[ASSET_A, ASSET_B]);
+		let lp1_deposit_id = 1;
+
+		init_farms_with_max_entries::<T>(lp1.clone(), lp2)?;
+
+		set_period::<T>(400);
+		let lp1_rew_curr_balance = T::Currencies::free_balance(REWARD_CURRENCY.into(), &lp1);
+	}: _(RawOrigin::Signed(lp1.clone()), lp1_deposit_id, 2)
+	verify {
+		assert!(T::Currencies::free_balance(REWARD_CURRENCY.into(), &lp1) > lp1_rew_curr_balance);
+	}
+
+	withdraw_shares {
+		init_stableswap_pools::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[REWARD_CURRENCY]);
+		let lp = funded_account::<T>("lp", 1, &[ASSET_A, ASSET_B]);
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let lp_deposit_id = 1;
+
+		create_gfarm::<T>(owner.clone())?;
+		create_yfarm::<T>(owner, global_farm_id, POOL_1)?;
+
+		set_period::<T>(200);
+		lm_deposit_shares::<T>(lp.clone(), global_farm_id, yield_farm_id)?;
+
+		set_period::<T>(400);
+		let lp_rew_curr_balance = T::Currencies::free_balance(REWARD_CURRENCY.into(), &lp);
+	}: _(RawOrigin::Signed(lp.clone()), lp_deposit_id, yield_farm_id, POOL_1.into())
+	verify {
+		assert!(T::Currencies::free_balance(REWARD_CURRENCY.into(), &lp) > lp_rew_curr_balance);
+		assert!(DepositPoolId::<T>::get(lp_deposit_id).is_none());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1133 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Stableswap liquidity mining pallet
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality for liquidity mining programs with time incentive(loyalty
+//! factor) and multiple incentives scheme for Stableswap pools.
+//!
+//! This pallet is build on top of the [pallet-liquidity-mining]. Users deposit share tokens of a
+//! stableswap pool and are rewarded for each period they stay in the liquidity mining program.
+//! Deposit is represented by an NFT minted to the depositor.
+//!
+//! Value of deposited shares(`valued_shares`) is expressed in global farm's `incentivized_asset`
+//! which must be one of the pool assets. Shares are valued by the price of the share in
+//! `incentivized_asset` provided by the oracle.
+//!
+//! Multiple Incentives
+//!
+//! This feature allow users to redeposit already deposited shares to multiple yield farms and
+//! receive incentives from this farms.
+//! Shares can be redeposited only to different yield farms running liquidity mining for the same
+//! stableswap pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+pub mod migration;
+pub mod weights;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+use frame_support::{ensure, sp_runtime::traits::Zero, storage::with_storage_layer, PalletId};
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::ema::EmaPrice as Price;
+use hydradx_traits::liquidity_mining::{
+	GlobalFarmId, Inspect as LiquidityMiningInspect, Mutate as LiquidityMiningMutate, YieldFarmId,
+};
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
+use pallet_ema_oracle::OracleError;
+use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
+use pallet_stableswap::types::{AssetAmount, StableswapHooks};
+use pallet_stableswap::{WeightInfo as StableswapWeightInfo, MAX_ASSETS_IN_POOL};
+
+use frame_support::{pallet_prelude::*, sp_runtime::traits::AccountIdConversion};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use hydradx_traits::registry::Inspect as RegistryInspect;
+use orml_traits::MultiCurrency;
+use primitive_types::U256;
+use primitives::{Balance, CollectionId, ItemId as DepositId};
+use sp_runtime::{ArithmeticError, FixedU128, Perquintill};
+use sp_std::{vec, vec::Vec};
+
+type PeriodOf<T> = BlockNumberFor<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Make the oracle price of `pool_id`'s share in `asset_id` available.
+	fn set_share_price(asset_id: AssetId, pool_id: AssetId) -> DispatchResult;
+}
+
+#[frame_support::pallet]
+#[allow(clippy::too_many_arguments)]
+pub mod pallet {
+	use super::*;
+	use hydradx_traits::pools::DustRemovalAccountWhitelist;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		pub _marker: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let pallet_account = <Pallet<T>>::account_id();
+
+			T::NonDustableWhitelistHandler::add_account(&pallet_account).unwrap();
+
+			<T as pallet::Config>::NFTHandler::create_collection(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&pallet_account,
+				&pallet_account,
+			)
+			.unwrap()
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_stableswap::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency for transfers.
+		type Currencies: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// The origin account that can create new liquidity mining program.
+		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet id.
+		type PalletId: Get<PalletId>;
+
+		/// NFT collection id for liquidity mining's deposit nfts.
+		#[pallet::constant]
+		type NFTCollectionId: Get<CollectionId>;
+
+		/// Non fungible handling
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = DepositId, CollectionId = CollectionId>
+			+ InspectEnumerable<Self::AccountId, ItemId = DepositId, CollectionId = CollectionId>
+			+ Transfer<Self::AccountId>;
+
+		/// Liquidity mining handler for managing liquidity mining functionalities
+		type LiquidityMiningHandler: LiquidityMiningMutate<
+				Self::AccountId,
+				Self::AssetId,
+				BlockNumberFor<Self>,
+				Error = DispatchError,
+				AmmPoolId = Self::AssetId,
+				Balance = Balance,
+				LoyaltyCurve = LoyaltyCurve,
+				Period = PeriodOf<Self>,
+			> + LiquidityMiningInspect<Self::AccountId>;
+
+		/// Account whitelist manager to exclude pallet account from dusting mechanism.
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// AssetRegistry used to retrieve information about asset.
+		type AssetRegistry: RegistryInspect<AssetId = Self::AssetId>;
+
+		/// Identifier of oracle data source
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Oracle's price aggregation period.
+		#[pallet::constant]
+		type OraclePeriod: Get<OraclePeriod>;
+
+		/// Oracle providing price of pool's share in pool assets used to calculate `valued_shares`.
+		type PriceOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, Price, Error = OracleError>;
+
+		/// Weight information for extrinsic in this module.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	#[pallet::storage]
+	/// Map of LM's deposit ids to stableswap pools whose shares are locked in the deposit.
+	pub(super) type DepositPoolId<T: Config> = StorageMap<_, Blake2_128Concat, DepositId, T::AssetId, OptionQuery>;
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Nft pallet didn't return an owner.
+		CantFindDepositOwner,
+
+		/// Account balance of stableswap pool shares is not sufficient.
+		InsufficientStableswapSharesBalance,
+
+		/// Stableswap pool does not exist
+		StableswapPoolDoesntExist,
+
+		/// Account is not deposit owner.
+		NotDepositOwner,
+
+		/// Deposit data not found
+		DepositDataNotFound,
+
+		/// Calculated reward to claim is 0.
+		ZeroClaimedRewards,
+
+		/// Asset is not in the stableswap pool.
+		AssetNotInStableswapPool,
+
+		/// Provided stableswap pool is not used by the deposit.
+		InvalidStableswapPool,
+
+		/// Asset is not registered in asset registry.
+		AssetNotRegistered,
+
+		/// Failed to calculate `pot`'s account.
+		FailToGetPotId,
+
+		/// Oracle could not be found for requested assets.
+		OracleNotAvailable,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New global farm was created.
+		GlobalFarmCreated {
+			id: GlobalFarmId,
+			owner: T::AccountId,
+			total_rewards: Balance,
+			reward_currency: T::AssetId,
+			yield_per_period: Perquintill,
+			planned_yielding_periods: PeriodOf<T>,
+			blocks_per_period: BlockNumberFor<T>,
+			incentivized_asset: T::AssetId,
+			max_reward_per_period: Balance,
+			min_deposit: Balance,
+			price_adjustment: FixedU128,
+		},
+
+		/// Global farm's `price_adjustment` was updated.
+		GlobalFarmUpdated {
+			id: GlobalFarmId,
+			price_adjustment: FixedU128,
+		},
+
+		/// New yield farm was added into the farm.
+		YieldFarmCreated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			multiplier: FarmMultiplier,
+			pool_id: T::AssetId,
+			loyalty_curve: Option<LoyaltyCurve>,
+		},
+
+		/// Global farm was terminated.
+		GlobalFarmTerminated {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			reward_currency: T::AssetId,
+			undistributed_rewards: Balance,
+		},
+
+		/// New shares were deposited.
+		SharesDeposited {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			pool_id: T::AssetId,
+			deposit_id: DepositId,
+		},
+
+		/// Shares were redeposited for a new yield farm entry
+		SharesRedeposited {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			pool_id: T::AssetId,
+			deposit_id: DepositId,
+		},
+
+		/// Rewards were claimed.
+		RewardClaimed {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			claimed: Balance,
+			reward_currency: T::AssetId,
+			deposit_id: DepositId,
+		},
+
+		/// Shares were withdrawn.
+		SharesWithdrawn {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			amount: Balance,
+			deposit_id: DepositId,
+		},
+
+		/// Yield farm for stableswap pool was stopped.
+		YieldFarmStopped {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			pool_id: T::AssetId,
+		},
+
+		/// Yield farm for stableswap pool was resumed.
+		YieldFarmResumed {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		},
+
+		/// Yield farm was terminated from global farm.
+		YieldFarmTerminated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			pool_id: T::AssetId,
+		},
+
+		/// Yield farm multiplier was updated.
+		YieldFarmUpdated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		},
+
+		/// NFT representing deposit has been destroyed
+		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards from all deposits of the account were claimed.
+		AllRewardsClaimed {
+			who: T::AccountId,
+			claims: Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Balance)>,
+			compounded: Vec<(T::AssetId, Balance)>,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new liquidity mining program with provided parameters.
+		///
+		/// `owner` account has to have at least `total_rewards` balance. This fund will be
+		/// transferred from `owner` to farm account.
+		/// In case of `reward_currency` is insufficient asset, farm's `owner` has to pay existential
+		/// deposit for global farm account and for liquidity mining `pot` account.
+		///
+		/// The dispatch origin for this call must be `T::CreateOrigin`.
+		/// !!!WARN: `T::CreateOrigin` has power over funds of `owner`'s account and it should be
+		/// configured to trusted origin e.g Sudo or Governance.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `total_rewards`: total rewards planned to distribute. This rewards will be
+		/// distributed between all yield farms in the global farm.
+		/// - `planned_yielding_periods`: planned number of periods to distribute `total_rewards`.
+		/// WARN: THIS IS NOT HARD DEADLINE. Not all rewards have to be distributed in
+		/// `planned_yielding_periods`. Rewards are distributed based on the situation in the yield
+		/// farms and can be distributed in a longer time frame but never in the shorter time frame.
+		/// - `blocks_per_period`:  number of blocks in a single period. Min. number of blocks per
+		/// period is 1.
+		/// - `incentivized_asset`: asset to be incentivized in stableswap pools. All yield farms
+		/// added into liq. mining program have to have `incentivized_asset` in their pool.
+		/// - `reward_currency`: payoff currency of rewards.
+		/// - `owner`: liq. mining program owner.
+		/// - `yield_per_period`: percentage return on `reward_currency` of all farms p.a.
+		/// - `min_deposit`: minimum amount which can be deposited to the farm
+		/// - `price_adjustment`: price adjustment between `incentivized_asset` and `reward_currency`.
+		/// Value is replaced by the oracle price when it's available.
+		///
+		/// Emits `GlobalFarmCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_global_farm())]
+		pub fn create_global_farm(
+			origin: OriginFor<T>,
+			total_rewards: Balance,
+			planned_yielding_periods: PeriodOf<T>,
+			blocks_per_period: BlockNumberFor<T>,
+			incentivized_asset: T::AssetId,
+			reward_currency: T::AssetId,
+			owner: T::AccountId,
+			yield_per_period: Perquintill,
+			min_deposit: Balance,
+			price_adjustment: FixedU128,
+		) -> DispatchResult {
+			<T as pallet::Config>::CreateOrigin::ensure_origin(origin)?;
+
+			if !<T as pallet::Config>::AssetRegistry::is_sufficient(reward_currency) {
+				let ed = <T as pallet::Config>::AssetRegistry::existential_deposit(reward_currency)
+					.ok_or(Error::<T>::AssetNotRegistered)?;
+
+				let pot = T::LiquidityMiningHandler::pot_account().ok_or(Error::<T>::FailToGetPotId)?;
+				T::Currencies::transfer(reward_currency, &owner, &pot, ed)?;
+			}
+
+			let (id, max_reward_per_period) = T::LiquidityMiningHandler::create_global_farm(
+				total_rewards,
+				planned_yielding_periods,
+				blocks_per_period,
+				incentivized_asset,
+				reward_currency,
+				owner.clone(),
+				yield_per_period,
+				min_deposit,
+				price_adjustment,
+			)?;
+
+			Self::deposit_event(Event::GlobalFarmCreated {
+				id,
+				owner,
+				total_rewards,
+				reward_currency,
+				yield_per_period,
+				planned_yielding_periods,
+				blocks_per_period,
+				incentivized_asset,
+				max_reward_per_period,
+				min_deposit,
+				price_adjustment,
+			});
+
+			Ok(())
+		}
+
+		/// Update global farm's prices adjustment.
+		///
+		/// Only farm's owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to update
+		/// - `price_adjustment`: new value for price adjustment
+		///
+		/// Emits `GlobalFarmUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_global_farm())]
+		pub fn update_global_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			price_adjustment: FixedU128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::LiquidityMiningHandler::update_global_farm_price_adjustment(who, global_farm_id, price_adjustment)?;
+
+			Self::deposit_event(Event::GlobalFarmUpdated {
+				id: global_farm_id,
+				price_adjustment,
+			});
+
+			Ok(())
+		}
+
+		/// Terminate existing liq. mining program.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// WARN: To successfully terminate a farm, farm have to be empty(all yield farms in the global farm must be terminated).
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of global farm to be terminated.
+		///
+		/// Emits `GlobalFarmTerminated` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_global_farm())]
+		pub fn terminate_global_farm(origin: OriginFor<T>, global_farm_id: GlobalFarmId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (reward_currency, undistributed_rewards, who) =
+				T::LiquidityMiningHandler::terminate_global_farm(who, global_farm_id)?;
+
+			Self::deposit_event(Event::GlobalFarmTerminated {
+				global_farm_id,
+				who,
+				reward_currency,
+				undistributed_rewards,
+			});
+			Ok(())
+		}
+
+		/// Add yield farm for given stableswap pool.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Only stableswap pools with `incentivized_asset` in their assets can be added into the farm.
+		/// Stableswap pool has to exist to successfully create yield farm.
+		/// Yield farm for same pool can exist only once in the global farm.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id to which a yield farm will be added.
+		/// - `pool_id`: stableswap pool identifying yield farm. Liq. mining will be allowed for
+		/// shares of this pool.
+		/// - `multiplier`: yield farm multiplier.
+		/// - `loyalty_curve`: curve to calculate loyalty multiplier to distribute rewards to users
+		/// with time incentive. `None` means no loyalty multiplier.
+		///
+		/// Emits `YieldFarmCreated` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm())]
+		pub fn create_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+			loyalty_curve: Option<LoyaltyCurve>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool_assets = Self::ensure_stableswap_pool(pool_id)?;
+
+			let yield_farm_id = T::LiquidityMiningHandler::create_yield_farm(
+				who,
+				global_farm_id,
+				multiplier,
+				loyalty_curve.clone(),
+				pool_id,
+				pool_assets,
+			)?;
+
+			Self::deposit_event(Event::YieldFarmCreated {
+				global_farm_id,
+				yield_farm_id,
+				multiplier,
+				pool_id,
+				loyalty_curve,
+			});
+
+			Ok(())
+		}
+
+		/// Update yield farm multiplier.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be updated.
+		/// - `pool_id`: stableswap pool identifying yield farm in global farm.
+		/// - `multiplier`: new yield farm multiplier.
+		///
+		/// Emits `YieldFarmUpdated` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_yield_farm())]
+		pub fn update_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_stableswap_pool(pool_id)?;
+
+			let yield_farm_id = T::LiquidityMiningHandler::update_yield_farm_multiplier(
+				who.clone(),
+				global_farm_id,
+				pool_id,
+				multiplier,
+			)?;
+
+			Self::deposit_event(Event::YieldFarmUpdated {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				pool_id,
+				multiplier,
+			});
+
+			Ok(())
+		}
+
+		/// Stop liq. miming for specific yield farm.
+		///
+		/// This function claims rewards from `GlobalFarm` last time and stops yield farm
+		/// incentivization from a `GlobalFarm`. Users will be able to only withdraw
+		/// shares(with claiming) after calling this function.
+		/// `deposit_shares()` and `claim_rewards()` are not allowed on canceled yield farm.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: farm id in which yield farm will be canceled.
+		/// - `pool_id`: stableswap pool identifying yield farm in the farm.
+		///
+		/// Emits `YieldFarmStopped` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::stop_yield_farm())]
+		pub fn stop_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//NOTE: don't check pool existence, owner must be able to stop yield farm.
+			let yield_farm_id = T::LiquidityMiningHandler::stop_yield_farm(who.clone(), global_farm_id, pool_id)?;
+
+			Self::deposit_event(Event::YieldFarmStopped {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Resume yield farm for stopped yield farm.
+		///
+		/// This function resume incentivization from `GlobalFarm` and restore full functionality
+		/// for yield farm. Users will be able to deposit, claim and withdraw again.
+		///
+		/// WARN: Yield farm is NOT rewarded for time it was stopped.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be resumed.
+		/// - `yield_farm_id`: id of yield farm to be resumed.
+		/// - `pool_id`: stableswap pool identifying yield farm in global farm.
+		/// - `multiplier`: yield farm multiplier in the farm.
+		///
+		/// Emits `YieldFarmResumed` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_yield_farm())]
+		pub fn resume_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_stableswap_pool(pool_id)?;
+
+			T::LiquidityMiningHandler::resume_yield_farm(
+				who.clone(),
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				multiplier,
+			)?;
+
+			Self::deposit_event(Event::<T>::YieldFarmResumed {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				pool_id,
+				multiplier,
+			});
+
+			Ok(())
+		}
+
+		/// Remove yield farm
+		///
+		/// This function marks a yield farm as ready to be removed from storage when it's empty. Users will
+		/// be able to only withdraw shares(without claiming rewards from yield farm). Unpaid rewards
+		/// will be transferred back to global farm and will be used to distribute to other yield farms.
+		///
+		/// Yield farm must be stopped before calling this function.
+		///
+		/// Only global farm's owner can perform this action. Yield farm stays in the storage until it's
+		/// empty(all farm entries are withdrawn). Last withdrawn from yield farm trigger removing from
+		/// the storage.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: farm id from which yield farm should be terminated.
+		/// - `yield_farm_id`: id of yield farm to be terminated.
+		/// - `pool_id`: stableswap pool identifying yield farm in the global farm.
+		///
+		/// Emits `YieldFarmTerminated` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_yield_farm())]
+		pub fn terminate_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//NOTE: don't check pool existence, owner must be able to terminate yield farm.
+			T::LiquidityMiningHandler::terminate_yield_farm(who.clone(), global_farm_id, yield_farm_id, pool_id)?;
+
+			Self::deposit_event(Event::YieldFarmTerminated {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Deposit stableswap shares to a liq. mining.
+		///
+		/// This function transfers shares from `origin` to pallet's account and mint nft for
+		/// `origin` account. Minted nft represents deposit in the liq. mining.
+		///
+		/// Parameters:
+		/// - `origin`: account depositing shares. This account has to have at least
+		/// `shares_amount` of pool's shares.
+		/// - `global_farm_id`: id of global farm to which user wants to deposit shares.
+		/// - `yield_farm_id`: id of yield farm to deposit to.
+		/// - `pool_id`: stableswap pool identifying shares user wants to deposit.
+		/// - `shares_amount`: amount of shares user wants to deposit.
+		///
+		/// Emits `SharesDeposited` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit_shares())]
+		pub fn deposit_shares(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			shares_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_stableswap_pool(pool_id)?;
+
+			ensure!(
+				T::Currencies::ensure_can_withdraw(pool_id, &who, shares_amount).is_ok(),
+				Error::<T>::InsufficientStableswapSharesBalance
+			);
+
+			let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				shares_amount,
+				Self::get_token_value_of_lp_shares,
+			)?;
+
+			Self::lock_shares(pool_id, &who, shares_amount)?;
+			DepositPoolId::<T>::insert(deposit_id, pool_id);
+			<T as pallet::Config>::NFTHandler::mint_into(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&deposit_id,
+				&who,
+			)?;
+
+			Self::deposit_event(Event::SharesDeposited {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				amount: shares_amount,
+				pool_id,
+				deposit_id,
+			});
+
+			Ok(())
+		}
+
+		/// Redeposit already locked shares to another yield farm.
+		///
+		/// This function create yield farm entry for existing deposit. Shares are not transferred
+		/// and amount of shares is based on existing deposit.
+		///
+		/// This function DOESN'T create new deposit.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `global_farm_id`: global farm identifier.
+		/// - `yield_farm_id`: yield farm identifier redepositing to.
+		/// - `pool_id`: stableswap pool identifying shares user want to redeposit.
+		/// - `deposit_id`: identifier of the deposit.
+		///
+		/// Emits `SharesRedeposited` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeposit_shares())]
+		pub fn redeposit_shares(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			deposit_id: DepositId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			Self::ensure_stableswap_pool(pool_id)?;
+
+			let (shares_amount, deposit_pool_id) = T::LiquidityMiningHandler::redeposit_lp_shares(
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				Self::get_token_value_of_lp_shares,
+			)?;
+
+			ensure!(pool_id == deposit_pool_id, Error::<T>::InvalidStableswapPool);
+
+			Self::deposit_event(Event::SharesRedeposited {
+				global_farm_id,
+				yield_farm_id,
+				who: owner,
+				amount: shares_amount,
+				pool_id,
+				deposit_id,
+			});
+
+			Ok(())
+		}
+
+		/// Claim rewards from liq. mining for deposit represented by `nft_id`.
+		///
+		/// This function calculate user rewards from liq. mining and transfer rewards to `origin`
+		/// account. Claiming in the same period is allowed only once.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farm.
+		/// - `yield_farm_id`: yield farm identifier to claim rewards from.
+		///
+		/// Emits `RewardClaimed` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_id: YieldFarmId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			let (global_farm_id, reward_currency, claimed, _) =
+				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
+
+			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: owner,
+				claimed,
+				reward_currency,
+				deposit_id,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw shares from liq. mining with reward claiming if possible.
+		///
+		/// List of possible cases of transfers of shares and claimed rewards:
+		///
+		/// * yield farm is active(yield farm is not stopped) - claim and transfer rewards(if it
+		/// wasn't claimed in this period) and transfer shares.
+		/// * liq. mining is stopped - claim and transfer rewards(if it
+		/// wasn't claimed in this period) and transfer shares.
+		/// * yield farm was terminated - only shares will be transferred.
+		/// * farm was terminated - only shares will be transferred.
+		///
+		/// User's unclaimable rewards will be transferred back to global farm's account.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farm.
+		/// - `yield_farm_id`: yield farm identifier to withdraw shares from.
+		/// - `pool_id`: stableswap pool identifying yield farm in global farm.
+		///
+		/// Emits:
+		/// * `RewardClaimed` if claim happen
+		/// * `SharesWithdrawn` event when successful
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_shares())]
+		pub fn withdraw_shares(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			let deposit_pool_id = DepositPoolId::<T>::get(deposit_id).ok_or(Error::<T>::DepositDataNotFound)?;
+			ensure!(pool_id == deposit_pool_id, Error::<T>::InvalidStableswapPool);
+
+			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
+				.ok_or(Error::<T>::DepositDataNotFound)?;
+
+			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
+				owner.clone(),
+				deposit_id,
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+			)?;
+
+			if let Some((reward_currency, claimed, _)) = claim_data {
+				if !claimed.is_zero() {
+					Self::deposit_event(Event::RewardClaimed {
+						global_farm_id,
+						yield_farm_id,
+						who: owner.clone(),
+						claimed,
+						reward_currency,
+						deposit_id,
+					});
+				}
+			}
+
+			if !withdrawn_amount.is_zero() {
+				Self::deposit_event(Event::SharesWithdrawn {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					pool_id,
+					amount: withdrawn_amount,
+					deposit_id,
+				});
+			}
+
+			if is_destroyed {
+				Self::unlock_shares(pool_id, &owner, withdrawn_amount)?;
+				DepositPoolId::<T>::remove(deposit_id);
+				<T as pallet::Config>::NFTHandler::burn(
+					&<T as pallet::Config>::NFTCollectionId::get(),
+					&deposit_id,
+					Some(&owner),
+				)?;
+
+				Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+			}
+
+			Ok(())
+		}
+
+		/// Claim rewards from all yield farm entries of all deposits owned by `origin`.
+		///
+		/// Deposits are processed until `max_entries` yield farm entries were visited. Entries which
+		/// can't be claimed (e.g. rewards were already claimed in the current period) are skipped.
+		///
+		/// If `compound` is `true`, claimed rewards are added back as liquidity to the stableswap
+		/// pool of the deposit and the pool's shares are minted to `origin`. Rewards in asset which
+		/// is not in the deposit's pool or which can't be added to the pool stay in `origin`'s
+		/// account.
+		///
+		/// Every deposit has at least one yield farm entry so at most `max_entries` deposits are
+		/// visited.
+		///
+		/// Parameters:
+		/// - `origin`: owner of deposits.
+		/// - `max_entries`: max number of yield farm entries to claim rewards from.
+		/// - `compound`: if `true` claimed rewards are added back to the stableswap pools.
+		///
+		/// Emits `AllRewardsClaimed` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::claim_all_rewards_weight(*max_entries, *max_entries, *compound))]
+		pub fn claim_all_rewards(origin: OriginFor<T>, max_entries: u32, compound: bool) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut claims: Vec<(GlobalFarmId, YieldFarmId, T::AssetId, Balance)> = Vec::new();
+			let mut claims_per_pool: Vec<(T::AssetId, T::AssetId, Balance)> = Vec::new();
+			let mut visited_deposits: u32 = 0;
+			let mut processed_entries: u32 = 0;
+
+			let deposits = <T as pallet::Config>::NFTHandler::owned_in_collection(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&who,
+			);
+			'deposits: for deposit_id in deposits.take(max_entries as usize) {
+				visited_deposits = visited_deposits.saturating_add(1);
+
+				let pool_id = match DepositPoolId::<T>::get(deposit_id) {
+					Some(pool_id) => pool_id,
+					None => continue,
+				};
+				let yield_farm_ids = T::LiquidityMiningHandler::get_yield_farm_ids(deposit_id).unwrap_or_default();
+
+				for yield_farm_id in yield_farm_ids {
+					if processed_entries >= max_entries {
+						break 'deposits;
+					}
+					processed_entries = processed_entries.saturating_add(1);
+
+					let claim_result = with_storage_layer(|| -> Result<_, DispatchError> {
+						let (global_farm_id, reward_currency, claimed, _) =
+							T::LiquidityMiningHandler::claim_rewards(who.clone(), deposit_id, yield_farm_id)?;
+
+						ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+						Ok((global_farm_id, reward_currency, claimed))
+					});
+
+					if let Ok((global_farm_id, reward_currency, claimed)) = claim_result {
+						match claims
+							.iter_mut()
+							.find(|(g, y, c, _)| *g == global_farm_id && *y == yield_farm_id && *c == reward_currency)
+						{
+							Some((_, _, _, total)) => *total = total.saturating_add(claimed),
+							None => claims.push((global_farm_id, yield_farm_id, reward_currency, claimed)),
+						}
+
+						match claims_per_pool
+							.iter_mut()
+							.find(|(p, c, _)| *p == pool_id && *c == reward_currency)
+						{
+							Some((_, _, total)) => *total = total.saturating_add(claimed),
+							None => claims_per_pool.push((pool_id, reward_currency, claimed)),
+						}
+					}
+				}
+			}
+
+			ensure!(!claims.is_empty(), Error::<T>::ZeroClaimedRewards);
+
+			let mut compounded: Vec<(T::AssetId, Balance)> = Vec::new();
+			if compound {
+				for (pool_id, reward_currency, amount) in claims_per_pool {
+					let compound_result = with_storage_layer(|| {
+						StableswapPallet::<T>::add_liquidity(
+							RawOrigin::Signed(who.clone()).into(),
+							pool_id,
+							vec![AssetAmount::new(reward_currency, amount)],
+						)
+					});
+
+					if compound_result.is_ok() {
+						match compounded.iter_mut().find(|(c, _)| *c == reward_currency) {
+							Some((_, total)) => *total = total.saturating_add(amount),
+							None => compounded.push((reward_currency, amount)),
+						}
+					}
+				}
+			}
+
+			Self::deposit_event(Event::AllRewardsClaimed {
+				who,
+				claims,
+				compounded,
+			});
+
+			Ok(Some(Self::claim_all_rewards_weight(
+				visited_deposits,
+				processed_entries,
+				compound,
+			))
+			.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account ID of the pot holding locked shares. This account is also owner of NFT class
+	/// for all the NFTs minted by this pallet.
+	pub fn account_id() -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_account_truncating()
+	}
+
+	fn lock_shares(pool_id: T::AssetId, who: &T::AccountId, amount: Balance) -> Result<(), DispatchError> {
+		T::Currencies::transfer(pool_id, who, &Self::account_id(), amount)
+	}
+
+	fn unlock_shares(pool_id: T::AssetId, who: &T::AccountId, amount: Balance) -> Result<(), DispatchError> {
+		T::Currencies::transfer(pool_id, &Self::account_id(), who, amount)
+	}
+
+	/// This function returns value of stableswap shares in the `asset` currency.
+	///
+	/// Shares are valued by the oracle price of the pool's share in `asset`.
+	fn get_token_value_of_lp_shares(
+		asset: T::AssetId,
+		pool_id: T::AssetId,
+		shares_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let pool_assets = Self::ensure_stableswap_pool(pool_id)?;
+
+		ensure!(pool_assets.contains(&asset), Error::<T>::AssetNotInStableswapPool);
+
+		let (price, _) = T::PriceOracle::get_price(asset, pool_id, T::OraclePeriod::get(), T::OracleSource::get())
+			.map_err(|_| Error::<T>::OracleNotAvailable)?;
+
+		let shares_value: u128 = U256::from(shares_amount)
+			.checked_mul(price.n.into())
+			.ok_or(ArithmeticError::Overflow)?
+			.checked_div(price.d.into())
+			.ok_or(ArithmeticError::DivisionByZero)?
+			.try_into()
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+		Ok(shares_value)
+	}
+
+	/// This function returns assets of the stableswap pool or error if pool doesn't exist.
+	fn ensure_stableswap_pool(pool_id: T::AssetId) -> Result<Vec<T::AssetId>, Error<T>> {
+		let pool = StableswapPallet::<T>::pools(pool_id).ok_or(Error::<T>::StableswapPoolDoesntExist)?;
+
+		Ok(pool.assets.into_inner())
+	}
+
+	/// Weight of the `claim_all_rewards` for `deposits` visited deposits and `entries` yield farm
+	/// entries. Every visited deposit is read from the account's deposits enumeration and its pool
+	/// and yield farm entries are read. Every entry can be compounded into different pool so
+	/// compounding is accounted for each entry.
+	fn claim_all_rewards_weight(deposits: u32, entries: u32, compound: bool) -> Weight {
+		// Enumeration reads one more key to find out there are no more deposits.
+		let enumeration_weight = T::DbWeight::get().reads(1);
+		let deposit_weight = T::DbWeight::get().reads(3);
+
+		let mut entry_weight = <T as pallet::Config>::WeightInfo::claim_rewards();
+		if compound {
+			entry_weight = entry_weight.saturating_add(
+				<T as pallet_stableswap::Config>::WeightInfo::add_liquidity()
+					.saturating_add(<T as pallet_stableswap::Config>::Hooks::on_liquidity_changed_weight(
+						MAX_ASSETS_IN_POOL as usize,
+					))
+					.saturating_add(StableswapPallet::<T>::dynamic_fee_weight()),
+			);
+		}
+
+		enumeration_weight
+			.saturating_add(deposit_weight.saturating_mul(deposits.into()))
+			.saturating_add(entry_weight.saturating_mul(entries.into()))
+	}
+
+	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
+		let who = ensure_signed(origin)?;
+
+		let nft_owner =
+			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &deposit_id)
+				.ok_or(Error::<T>::CantFindDepositOwner)?;
+
+		ensure!(nft_owner == who, Error::<T>::NotDepositOwner);
+
+		Ok(who)
+	}
+}
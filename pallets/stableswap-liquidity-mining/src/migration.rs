@@ -0,0 +1,90 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+
+/// The log target.
+const TARGET: &str = "runtime::stableswap-liquidity-mining::migration::v1";
+
+pub mod v1 {
+	use super::*;
+
+	/// Sets up the pallet on a live chain the same way genesis does: creates the NFT collection
+	/// for deposits and excludes pallet's account from dusting.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			log::info!(target: TARGET, "Stableswap liquidity mining migration: PRE checks successful!");
+
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::warn!(
+					target: TARGET,
+					"Stableswap liquidity mining migration to v1 skipped because storage version is not 0"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for stableswap liquidity mining");
+
+			let pallet_account = Pallet::<T>::account_id();
+
+			if let Err(e) = T::NonDustableWhitelistHandler::add_account(&pallet_account) {
+				log::error!(target: TARGET, "Failed to whitelist pallet account: {:?}", e);
+			}
+
+			if let Err(e) = <T as pallet::Config>::NFTHandler::create_collection(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&pallet_account,
+				&pallet_account,
+			) {
+				log::error!(target: TARGET, "Failed to create deposit NFT collection: {:?}", e);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: TARGET, "Stableswap liquidity mining migration to v1 finished");
+
+			T::DbWeight::get().reads_writes(3, 5)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let pallet_account = Pallet::<T>::account_id();
+			assert_eq!(
+				<T as pallet::Config>::NFTHandler::collection_owner(&<T as pallet::Config>::NFTCollectionId::get()),
+				Some(pallet_account),
+				"Deposit NFT collection was not created!"
+			);
+
+			log::info!(target: TARGET, "Stableswap liquidity mining migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,238 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pallet_stableswap::types::AssetAmount;
+
+fn last_claims() -> (
+	Vec<(GlobalFarmId, YieldFarmId, AssetId, Balance)>,
+	Vec<(AssetId, Balance)>,
+) {
+	match System::events().last().expect("events expected").event.clone() {
+		RuntimeEvent::StableswapMining(crate::Event::AllRewardsClaimed {
+			who,
+			claims,
+			compounded,
+		}) => {
+			assert_eq!(who, ALICE);
+			(claims, compounded)
+		}
+		e => panic!("unexpected last event: {:?}", e),
+	}
+}
+
+fn deposit_twice() {
+	assert_ok!(StableswapMining::deposit_shares(
+		RuntimeOrigin::signed(ALICE),
+		GC_FARM,
+		DAI_USDT_YIELD_FARM,
+		DAI_USDT_POOL,
+		100 * ONE
+	));
+	assert_ok!(StableswapMining::deposit_shares(
+		RuntimeOrigin::signed(ALICE),
+		GC_FARM,
+		DAI_USDT_YIELD_FARM,
+		DAI_USDT_POOL,
+		100 * ONE
+	));
+}
+
+#[test]
+fn claim_all_rewards_should_claim_rewards_from_all_deposits() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		deposit_twice();
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(StableswapMining::claim_all_rewards(
+			RuntimeOrigin::signed(ALICE),
+			10,
+			false
+		));
+
+		//Assert
+		//NOTE: 2 deposits * 100 valued shares * 1% yield per period * 10 periods
+		let expected_claimed = 20 * ONE;
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: ALICE,
+			claims: vec![(GC_FARM, DAI_USDT_YIELD_FARM, HDX, expected_claimed)],
+			compounded: vec![],
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), expected_claimed);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_not_claim_more_than_max_entries() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		deposit_twice();
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(StableswapMining::claim_all_rewards(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			false
+		));
+
+		//Assert
+		let (claims, _) = last_claims();
+		assert_eq!(claims, vec![(GC_FARM, DAI_USDT_YIELD_FARM, HDX, 10 * ONE)]);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10 * ONE);
+
+		//Already claimed entry is skipped and remaining entry is claimed.
+		assert_ok!(StableswapMining::claim_all_rewards(
+			RuntimeOrigin::signed(ALICE),
+			2,
+			false
+		));
+
+		let (claims, _) = last_claims();
+		assert_eq!(claims, vec![(GC_FARM, DAI_USDT_YIELD_FARM, HDX, 10 * ONE)]);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 20 * ONE);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_fail_when_nothing_was_claimed() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		deposit_twice();
+
+		set_block_number(11);
+
+		assert_ok!(StableswapMining::claim_all_rewards(
+			RuntimeOrigin::signed(ALICE),
+			10,
+			false
+		));
+
+		//Rewards were already claimed in this period.
+		assert_noop!(
+			StableswapMining::claim_all_rewards(RuntimeOrigin::signed(ALICE), 10, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Account without deposits.
+		assert_noop!(
+			StableswapMining::claim_all_rewards(RuntimeOrigin::signed(BOB), 10, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Zero `max_entries`
+		set_block_number(21);
+		assert_noop!(
+			StableswapMining::claim_all_rewards(RuntimeOrigin::signed(ALICE), 0, false),
+			crate::Error::<Test>::ZeroClaimedRewards
+		);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::claim_all_rewards(RuntimeOrigin::none(), 10, false),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_add_rewards_to_deposit_pool_when_compound_is_true() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(GC, DAI, 1_000_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(ALICE, USDT, 1_000 * ONE),
+		])
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			DAI,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, GC_FARM, DAI_USDT_POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DAI_USDT_POOL,
+				vec![AssetAmount::new(DAI, 100 * ONE), AssetAmount::new(USDT, 100 * ONE)]
+			));
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				100 * ONE
+			));
+
+			let alice_dai_balance = Tokens::free_balance(DAI, &ALICE);
+			let alice_shares_balance = Tokens::free_balance(DAI_USDT_POOL, &ALICE);
+
+			set_block_number(11);
+
+			//Act
+			assert_ok!(StableswapMining::claim_all_rewards(
+				RuntimeOrigin::signed(ALICE),
+				10,
+				true
+			));
+
+			//Assert
+			let expected_claimed = 10 * ONE;
+			let (claims, compounded) = last_claims();
+			assert_eq!(claims, vec![(GC_FARM, DAI_USDT_YIELD_FARM, DAI, expected_claimed)]);
+			assert_eq!(compounded, vec![(DAI, expected_claimed)]);
+
+			//Claimed rewards were added to the pool for new shares.
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance);
+			assert!(Tokens::free_balance(DAI_USDT_POOL, &ALICE) > alice_shares_balance);
+		});
+}
+
+#[test]
+fn claim_all_rewards_should_not_compound_rewards_in_asset_not_in_deposit_pool() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		deposit_twice();
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(StableswapMining::claim_all_rewards(
+			RuntimeOrigin::signed(ALICE),
+			10,
+			true
+		));
+
+		//Assert
+		let (claims, compounded) = last_claims();
+		assert_eq!(claims, vec![(GC_FARM, DAI_USDT_YIELD_FARM, HDX, 20 * ONE)]);
+		assert!(compounded.is_empty());
+
+		//Rewards in HDX can't be added to DAI/USDT pool so they stay in the account.
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 20 * ONE);
+	});
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn claim_rewards_should_work() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(StableswapMining::claim_rewards(
+			RuntimeOrigin::signed(ALICE),
+			deposit_id,
+			DAI_USDT_YIELD_FARM
+		));
+
+		//Assert
+		//NOTE: 100 valued shares * 1% yield per period * 10 periods
+		let expected_claimed = 10 * ONE;
+		assert_last_event!(crate::Event::RewardClaimed {
+			global_farm_id: GC_FARM,
+			yield_farm_id: DAI_USDT_YIELD_FARM,
+			who: ALICE,
+			claimed: expected_claimed,
+			reward_currency: HDX,
+			deposit_id,
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), expected_claimed);
+	});
+}
+
+#[test]
+fn claim_rewards_should_use_oracle_valued_shares() {
+	ExtBuilder::default()
+		.with_share_price(DAI, DAI_USDT_POOL, (2, 1))
+		.with_default_farms()
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				100 * ONE
+			));
+
+			set_block_number(11);
+
+			assert_ok!(StableswapMining::claim_rewards(
+				RuntimeOrigin::signed(ALICE),
+				deposit_id,
+				DAI_USDT_YIELD_FARM
+			));
+
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), 20 * ONE);
+		});
+}
+
+#[test]
+fn claim_rewards_should_fail_when_claiming_twice_in_same_period() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		set_block_number(11);
+
+		assert_ok!(StableswapMining::claim_rewards(
+			RuntimeOrigin::signed(ALICE),
+			deposit_id,
+			DAI_USDT_YIELD_FARM
+		));
+
+		assert_noop!(
+			StableswapMining::claim_rewards(RuntimeOrigin::signed(ALICE), deposit_id, DAI_USDT_YIELD_FARM),
+			WarehouseError::DoubleClaimInPeriod
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		set_block_number(11);
+
+		assert_noop!(
+			StableswapMining::claim_rewards(RuntimeOrigin::signed(BOB), deposit_id, DAI_USDT_YIELD_FARM),
+			Error::<Test>::NotDepositOwner
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_fail_when_deposit_does_not_exist() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::claim_rewards(RuntimeOrigin::signed(ALICE), 1, DAI_USDT_YIELD_FARM),
+			Error::<Test>::CantFindDepositOwner
+		);
+	});
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_global_farm_should_work_when_origin_is_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let total_rewards = 1_000_000 * ONE;
+		let planned_yielding_periods = 1_000;
+		let blocks_per_period = 1;
+		let yield_per_period = Perquintill::from_percent(1);
+		let min_deposit = 1_000;
+		let price_adjustment = FixedU128::one();
+
+		assert_ok!(StableswapMining::create_global_farm(
+			RuntimeOrigin::root(),
+			total_rewards,
+			planned_yielding_periods,
+			blocks_per_period,
+			DAI,
+			HDX,
+			GC,
+			yield_per_period,
+			min_deposit,
+			price_adjustment,
+		));
+
+		assert_last_event!(crate::Event::GlobalFarmCreated {
+			id: GC_FARM,
+			owner: GC,
+			total_rewards,
+			reward_currency: HDX,
+			yield_per_period,
+			planned_yielding_periods,
+			blocks_per_period,
+			incentivized_asset: DAI,
+			max_reward_per_period: 1_000 * ONE,
+			min_deposit,
+			price_adjustment,
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(HDX, &GC), 0);
+	});
+}
+
+#[test]
+fn create_global_farm_should_fail_when_origin_is_not_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::create_global_farm(
+				RuntimeOrigin::signed(ALICE),
+				1_000_000 * ONE,
+				1_000,
+				1,
+				DAI,
+				HDX,
+				GC,
+				Perquintill::from_percent(1),
+				1_000,
+				FixedU128::one(),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_global_farm_should_fail_when_owner_has_not_enough_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::create_global_farm(
+				RuntimeOrigin::root(),
+				2_000_000 * ONE,
+				1_000,
+				1,
+				DAI,
+				HDX,
+				GC,
+				Perquintill::from_percent(1),
+				1_000,
+				FixedU128::one(),
+			),
+			WarehouseError::InsufficientRewardCurrencyBalance
+		);
+	});
+}
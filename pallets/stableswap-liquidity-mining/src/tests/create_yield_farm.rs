@@ -0,0 +1,141 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_yield_farm_should_work_when_stableswap_pool_exists() {
+	ExtBuilder::default()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			let multiplier = FixedU128::from(2);
+			let loyalty_curve = Some(LoyaltyCurve::default());
+
+			assert_ok!(StableswapMining::create_yield_farm(
+				RuntimeOrigin::signed(GC),
+				GC_FARM,
+				DAI_USDT_POOL,
+				multiplier,
+				loyalty_curve.clone()
+			));
+
+			assert_last_event!(crate::Event::YieldFarmCreated {
+				global_farm_id: GC_FARM,
+				yield_farm_id: DAI_USDT_YIELD_FARM,
+				multiplier,
+				pool_id: DAI_USDT_POOL,
+				loyalty_curve,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_stableswap_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::create_yield_farm(
+					RuntimeOrigin::signed(GC),
+					GC_FARM,
+					NOT_A_POOL,
+					FixedU128::one(),
+					None
+				),
+				Error::<Test>::StableswapPoolDoesntExist
+			);
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_incentivized_asset_is_not_in_the_pool() {
+	ExtBuilder::default()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::create_yield_farm(
+					RuntimeOrigin::signed(GC),
+					GC_FARM,
+					USDT_USDC_POOL,
+					FixedU128::one(),
+					None
+				),
+				WarehouseError::MissingIncentivizedAsset
+			);
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_origin_is_not_farm_owner() {
+	ExtBuilder::default()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::create_yield_farm(
+					RuntimeOrigin::signed(ALICE),
+					GC_FARM,
+					DAI_USDT_POOL,
+					FixedU128::one(),
+					None
+				),
+				WarehouseError::Forbidden
+			);
+		});
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use pallet_liquidity_mining::{DepositData, YieldFarmEntry};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn deposit_shares_should_work() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+		let shares_amount = 100 * ONE;
+
+		//Act
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			shares_amount
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::SharesDeposited {
+			global_farm_id: GC_FARM,
+			yield_farm_id: DAI_USDT_YIELD_FARM,
+			who: ALICE,
+			amount: shares_amount,
+			pool_id: DAI_USDT_POOL,
+			deposit_id,
+		}
+		.into());
+
+		//Storage check
+		assert_eq!(crate::DepositPoolId::<Test>::get(deposit_id), Some(DAI_USDT_POOL));
+
+		let deposit =
+			pallet_liquidity_mining::Deposit::<Test, pallet_liquidity_mining::Instance1>::get(deposit_id).unwrap();
+		let mut expected_deposit = DepositData::new(shares_amount, DAI_USDT_POOL);
+		expected_deposit
+			.add_yield_farm_entry(YieldFarmEntry::new(
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				shares_amount,
+				FixedU128::zero(),
+				1,
+				0,
+			))
+			.unwrap();
+
+		assert_eq!(deposit, expected_deposit);
+
+		//Balances check: shares are locked in the pallet's account.
+		assert_eq!(Tokens::free_balance(DAI_USDT_POOL, &ALICE), 900 * ONE);
+		assert_eq!(
+			Tokens::free_balance(DAI_USDT_POOL, &StableswapMining::account_id()),
+			shares_amount
+		);
+
+		//NFT check: lm deposit should be minted for user.
+		let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
+		assert_eq!(owner, ALICE);
+	});
+}
+
+#[test]
+fn deposit_shares_should_value_shares_with_oracle_price() {
+	ExtBuilder::default()
+		.with_share_price(DAI, DAI_USDT_POOL, (2, 1))
+		.with_default_farms()
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+			let shares_amount = 100 * ONE;
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				shares_amount
+			));
+
+			let deposit =
+				pallet_liquidity_mining::Deposit::<Test, pallet_liquidity_mining::Instance1>::get(deposit_id).unwrap();
+			let mut expected_deposit = DepositData::new(shares_amount, DAI_USDT_POOL);
+			expected_deposit
+				.add_yield_farm_entry(YieldFarmEntry::new(
+					GC_FARM,
+					DAI_USDT_YIELD_FARM,
+					200 * ONE,
+					FixedU128::zero(),
+					1,
+					0,
+				))
+				.unwrap();
+
+			assert_eq!(deposit, expected_deposit);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_account_has_not_enough_shares() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				1_001 * ONE
+			),
+			Error::<Test>::InsufficientStableswapSharesBalance
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_stableswap_pool_does_not_exist() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				NOT_A_POOL,
+				100 * ONE
+			),
+			Error::<Test>::StableswapPoolDoesntExist
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_oracle_price_is_not_available() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		SHARE_PRICES.with(|v| v.borrow_mut().clear());
+
+		assert_noop!(
+			StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				100 * ONE
+			),
+			Error::<Test>::OracleNotAvailable
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_shares_are_deposited_to_yield_farm_of_different_pool() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(CHARLIE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				USDT_USDC_POOL,
+				100 * ONE
+			),
+			WarehouseError::YieldFarmNotFound
+		);
+	});
+}
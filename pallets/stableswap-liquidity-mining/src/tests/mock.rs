@@ -0,0 +1,707 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::type_complexity)]
+
+use crate::*;
+use core::ops::RangeInclusive;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use crate as stableswap_liquidity_mining;
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::liquidity_mining::PriceAdjustment;
+use hydradx_traits::{
+	oracle::{AggregatedPriceOracle, OraclePeriod, Source},
+	pools::DustRemovalAccountWhitelist,
+	AccountIdFor, AssetKind, Inspect as InspectRegistry,
+};
+use orml_traits::parameter_type_with_key;
+use orml_traits::GetByKey;
+use pallet_liquidity_mining as warehouse_liquidity_mining;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup, One},
+	BuildStorage, DispatchResult, Permill,
+};
+
+use warehouse_liquidity_mining::{GlobalFarmData, Instance1};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type WarehouseError = warehouse_liquidity_mining::Error<Test, Instance1>;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type AssetId = u32;
+//NTF types
+pub type ItemId = u128;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const USDC: AssetId = 4;
+
+// Stableswap pools, pool id is also pool's share asset.
+pub const DAI_USDT_POOL: AssetId = 100;
+pub const USDT_USDC_POOL: AssetId = 101;
+pub const NOT_A_POOL: AssetId = 102;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const GC: AccountId = 4;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const LM_COLLECTION_ID: u128 = 1;
+
+pub const GC_FARM: GlobalFarmId = 1;
+pub const DAI_USDT_YIELD_FARM: YieldFarmId = 2;
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<(CollectionId, ItemId), AccountId>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static SHARE_PRICES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Stableswap: pallet_stableswap,
+		WarehouseLM: warehouse_liquidity_mining::<Instance1>,
+		StableswapMining: stableswap_liquidity_mining,
+	}
+);
+
+parameter_types! {
+	pub static MockBlockNumberProvider: u64 = 0;
+}
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		System::block_number()
+	}
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const LMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const LMCollectionId: CollectionId = LM_COLLECTION_ID;
+	pub const PeriodOracle: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const OracleSource: Source = *b"stablesw";
+}
+
+impl stableswap_liquidity_mining::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currencies = Tokens;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type PalletId = LMPalletId;
+	type NFTCollectionId = LMCollectionId;
+	type NFTHandler = DummyNFT;
+	type LiquidityMiningHandler = WarehouseLM;
+	type NonDustableWhitelistHandler = Whitelist;
+	type AssetRegistry = DummyRegistry;
+	type OracleSource = OracleSource;
+	type OraclePeriod = PeriodOracle;
+	type PriceOracle = DummyOracle;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyOracle;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+	pub const WarehouseLMPalletId: PalletId = PalletId(*b"WhouseLm");
+	pub const MinTotalFarmRewards: Balance = 1_000_000 * ONE;
+	pub const MinPlannedYieldingPeriods: BlockNumber = 100;
+	#[derive(PartialEq, Eq)]
+	pub const MaxEntriesPerDeposit: u32 = 5;
+	pub const MaxYieldFarmsPerGlobalFarm: u32 = 10;
+}
+
+impl warehouse_liquidity_mining::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Tokens;
+	type PalletId = WarehouseLMPalletId;
+	type TreasuryAccountId = TreasuryAccount;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = MockBlockNumberProvider;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = DummyRegistry;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PriceAdjustment = DummyOracle;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1_000_000;
+	pub const MinimumTradingLimit: Balance = 1_000;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type RateProvider = ();
+	type VolumeProvider = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AssetId, Vec<AssetId>)>,
+	share_prices: Vec<(AssetId, AssetId, (Balance, Balance))>,
+	lm_global_farms: Vec<(
+		Balance,
+		PeriodOf<Test>,
+		BlockNumber,
+		AssetId,
+		AssetId,
+		AccountId,
+		Perquintill,
+		Balance,
+		FixedU128,
+	)>,
+	lm_yield_farms: Vec<(AccountId, GlobalFarmId, AssetId, FarmMultiplier, Option<LoyaltyCurve>)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		SHARE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(GC, HDX, 1_000_000 * ONE),
+				(ALICE, DAI_USDT_POOL, 1_000 * ONE),
+				(BOB, DAI_USDT_POOL, 1_000 * ONE),
+				(CHARLIE, USDT_USDC_POOL, 1_000 * ONE),
+			],
+			pools: vec![(DAI_USDT_POOL, vec![DAI, USDT]), (USDT_USDC_POOL, vec![USDT, USDC])],
+			share_prices: vec![
+				(DAI, DAI_USDT_POOL, (1, 1)),
+				(USDT, DAI_USDT_POOL, (1, 1)),
+				(USDT, USDT_USDC_POOL, (1, 1)),
+				(USDC, USDT_USDC_POOL, (1, 1)),
+			],
+			lm_global_farms: vec![],
+			lm_yield_farms: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_share_price(mut self, asset: AssetId, pool_id: AssetId, price: (Balance, Balance)) -> Self {
+		self.share_prices.push((asset, pool_id, price));
+		self
+	}
+
+	pub fn with_global_farm(
+		mut self,
+		total_rewards: Balance,
+		planned_yielding_periods: PeriodOf<Test>,
+		blocks_per_period: BlockNumber,
+		incentivized_asset: AssetId,
+		reward_currency: AssetId,
+		owner: AccountId,
+		yield_per_period: Perquintill,
+		min_deposit: Balance,
+		price_adjustment: FixedU128,
+	) -> Self {
+		self.lm_global_farms.push((
+			total_rewards,
+			planned_yielding_periods,
+			blocks_per_period,
+			incentivized_asset,
+			reward_currency,
+			owner,
+			yield_per_period,
+			min_deposit,
+			price_adjustment,
+		));
+		self
+	}
+
+	pub fn with_yield_farm(
+		mut self,
+		owner: AccountId,
+		id: GlobalFarmId,
+		pool_id: AssetId,
+		multiplier: FarmMultiplier,
+		loyalty_curve: Option<LoyaltyCurve>,
+	) -> Self {
+		self.lm_yield_farms
+			.push((owner, id, pool_id, multiplier, loyalty_curve));
+		self
+	}
+
+	/// Creates the default global farm owned by `GC` with `DAI` as incentivized asset and `HDX`
+	/// as reward currency together with yield farm for `DAI_USDT_POOL`.
+	pub fn with_default_farms(self) -> Self {
+		self.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, GC_FARM, DAI_USDT_POOL, FixedU128::one(), None)
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			for asset in [HDX, DAI, USDT, USDC, DAI_USDT_POOL, USDT_USDC_POOL, NOT_A_POOL] {
+				v.borrow_mut().insert(asset, 12);
+			}
+		});
+
+		SHARE_PRICES.with(|v| {
+			for (asset, pool_id, price) in self.share_prices.iter() {
+				v.borrow_mut().insert((*asset, *pool_id), *price);
+			}
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			set_block_number(1);
+
+			for (pool_id, assets) in self.pools {
+				assert_ok!(Stableswap::create_pool(
+					RuntimeOrigin::root(),
+					pool_id,
+					assets,
+					100,
+					Permill::zero(),
+				));
+			}
+
+			for gf in self.lm_global_farms {
+				assert_ok!(StableswapMining::create_global_farm(
+					RuntimeOrigin::root(),
+					gf.0,
+					gf.1,
+					gf.2,
+					gf.3,
+					gf.4,
+					gf.5,
+					gf.6,
+					gf.7,
+					gf.8,
+				));
+			}
+
+			for yf in self.lm_yield_farms {
+				assert_ok!(StableswapMining::create_yield_farm(
+					RuntimeOrigin::signed(yf.0),
+					yf.1,
+					yf.2,
+					yf.3,
+					yf.4
+				));
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		NFTS.with(|v| {
+			if let Some(o) = v.borrow().get(&(*collection, *item)) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
+	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u128> + Into<u128> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert((*collection, *item), (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(&(*collection, *item));
+		});
+		Ok(())
+	}
+}
+
+impl Transfer<AccountId> for DummyNFT {
+	fn transfer(collection: &Self::CollectionId, item: &Self::ItemId, destination: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			let key = (*collection, *item);
+
+			if !m.contains_key(&key) {
+				return Err(sp_runtime::DispatchError::Other("NFT not found"));
+			}
+
+			m.insert(key, *destination);
+
+			Ok(())
+		})
+	}
+}
+
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		let mut collections: Vec<CollectionId> = NFTS.with(|v| v.borrow().keys().map(|(c, _)| *c).collect());
+		collections.sort();
+		collections.dedup();
+		Box::new(collections.into_iter())
+	}
+
+	fn items(collection: &Self::CollectionId) -> Self::ItemsIterator {
+		let mut items: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.keys()
+				.filter(|(c, _)| c == collection)
+				.map(|(_, i)| *i)
+				.collect()
+		});
+		items.sort();
+		Box::new(items.into_iter())
+	}
+
+	fn owned(who: &AccountId) -> Self::OwnedIterator {
+		let mut owned: Vec<(CollectionId, ItemId)> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| *owner == who)
+				.map(|(key, _)| *key)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let mut owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((c, _), owner)| c == collection && *owner == who)
+				.map(|((_, i), _)| *i)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		true
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn decimals(asset_id: Self::AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn exists(asset_id: Self::AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		Some(1u128)
+	}
+}
+
+impl GetByKey<AssetId, Balance> for DummyRegistry {
+	fn get(_key: &AssetId) -> Balance {
+		1_000_u128
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+
+	fn prepare_rate_source(
+		_asset_id: AssetId,
+		_underlying: AssetId,
+	) -> Result<pallet_stableswap::types::RateSource<AssetId>, DispatchError> {
+		unimplemented!()
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<AssetId> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(*asset as u128) * 1_000
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct DummyOracle;
+
+impl AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice> for DummyOracle {
+	type Error = OracleError;
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumber), Self::Error> {
+		SHARE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.map(|(n, d)| (EmaPrice::new(n, d), 0))
+			.ok_or(OracleError::NotPresent)
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl PriceAdjustment<GlobalFarmData<Test, Instance1>> for DummyOracle {
+	type Error = DispatchError;
+
+	type PriceAdjustment = FixedU128;
+
+	fn get(_global_farm: &GlobalFarmData<Test, Instance1>) -> Result<Self::PriceAdjustment, Self::Error> {
+		Ok(FixedU128::one())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl stableswap_liquidity_mining::BenchmarkHelper<AssetId> for DummyOracle {
+	fn set_share_price(asset_id: AssetId, pool_id: AssetId) -> DispatchResult {
+		set_share_price(asset_id, pool_id, (1, 1));
+
+		Ok(())
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		if Whitelist::contains(account) {
+			return Err(sp_runtime::DispatchError::Other("Account is already in the whitelist"));
+		}
+
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
+
+pub fn set_share_price(asset: AssetId, pool_id: AssetId, price: (Balance, Balance)) {
+	SHARE_PRICES.with(|v| {
+		v.borrow_mut().insert((asset, pool_id), price);
+	});
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const BOB_FARM: GlobalFarmId = 3;
+const BOB_DAI_USDT_YIELD_FARM: YieldFarmId = 4;
+
+fn with_two_farms() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(GC, HDX, 1_000_000 * ONE),
+			(BOB, HDX, 1_000_000 * ONE),
+			(ALICE, DAI_USDT_POOL, 1_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 1_000 * ONE),
+		])
+		.with_default_farms()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			BOB,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(BOB, BOB_FARM, DAI_USDT_POOL, FixedU128::one(), None)
+}
+
+#[test]
+fn redeposit_shares_should_work() {
+	with_two_farms().build().execute_with(|| {
+		let deposit_id = 1;
+		let shares_amount = 100 * ONE;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			shares_amount
+		));
+
+		//Act
+		assert_ok!(StableswapMining::redeposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			BOB_FARM,
+			BOB_DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			deposit_id
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::SharesRedeposited {
+			global_farm_id: BOB_FARM,
+			yield_farm_id: BOB_DAI_USDT_YIELD_FARM,
+			who: ALICE,
+			amount: shares_amount,
+			pool_id: DAI_USDT_POOL,
+			deposit_id,
+		}
+		.into());
+
+		//Shares are locked only once.
+		assert_eq!(Tokens::free_balance(DAI_USDT_POOL, &ALICE), 900 * ONE);
+		assert_eq!(
+			Tokens::free_balance(DAI_USDT_POOL, &StableswapMining::account_id()),
+			shares_amount
+		);
+	});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_origin_is_not_deposit_owner() {
+	with_two_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		assert_noop!(
+			StableswapMining::redeposit_shares(
+				RuntimeOrigin::signed(BOB),
+				BOB_FARM,
+				BOB_DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				deposit_id
+			),
+			Error::<Test>::NotDepositOwner
+		);
+	});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_pool_is_not_deposit_pool() {
+	with_two_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		assert_noop!(
+			StableswapMining::redeposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				BOB_FARM,
+				BOB_DAI_USDT_YIELD_FARM,
+				USDT_USDC_POOL,
+				deposit_id
+			),
+			Error::<Test>::InvalidStableswapPool
+		);
+	});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_shares_are_already_in_yield_farm() {
+	with_two_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		assert_noop!(
+			StableswapMining::redeposit_shares(
+				RuntimeOrigin::signed(ALICE),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL,
+				deposit_id
+			),
+			WarehouseError::DoubleLock
+		);
+	});
+}
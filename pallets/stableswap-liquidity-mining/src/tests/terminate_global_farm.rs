@@ -0,0 +1,59 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn terminate_global_farm_should_work_when_farm_has_no_yield_farms() {
+	ExtBuilder::default()
+		.with_global_farm(
+			1_000_000 * ONE,
+			1_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_ok!(StableswapMining::terminate_global_farm(
+				RuntimeOrigin::signed(GC),
+				GC_FARM
+			));
+
+			assert_last_event!(crate::Event::GlobalFarmTerminated {
+				global_farm_id: GC_FARM,
+				who: GC,
+				reward_currency: HDX,
+				undistributed_rewards: 1_000_000 * ONE,
+			}
+			.into());
+
+			assert_eq!(Tokens::free_balance(HDX, &GC), 1_000_000 * ONE);
+		});
+}
+
+#[test]
+fn terminate_global_farm_should_fail_when_farm_has_live_yield_farms() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::terminate_global_farm(RuntimeOrigin::signed(GC), GC_FARM),
+			WarehouseError::GlobalFarmIsNotEmpty
+		);
+	});
+}
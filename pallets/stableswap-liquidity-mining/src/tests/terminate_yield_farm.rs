@@ -0,0 +1,57 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn terminate_yield_farm_should_work_when_yield_farm_is_stopped() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_ok!(StableswapMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			DAI_USDT_POOL
+		));
+
+		assert_ok!(StableswapMining::terminate_yield_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL
+		));
+
+		assert_last_event!(crate::Event::YieldFarmTerminated {
+			global_farm_id: GC_FARM,
+			yield_farm_id: DAI_USDT_YIELD_FARM,
+			who: GC,
+			pool_id: DAI_USDT_POOL,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn terminate_yield_farm_should_fail_when_yield_farm_is_not_stopped() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::terminate_yield_farm(
+				RuntimeOrigin::signed(GC),
+				GC_FARM,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL
+			),
+			WarehouseError::LiquidityMiningIsActive
+		);
+	});
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn update_global_farm_should_work() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let price_adjustment = FixedU128::from_rational(1, 2);
+
+		assert_ok!(StableswapMining::update_global_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			price_adjustment
+		));
+
+		assert_last_event!(crate::Event::GlobalFarmUpdated {
+			id: GC_FARM,
+			price_adjustment,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn update_global_farm_should_fail_when_origin_is_not_farm_owner() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::update_global_farm(RuntimeOrigin::signed(ALICE), GC_FARM, FixedU128::one()),
+			WarehouseError::Forbidden
+		);
+	});
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn update_yield_farm_should_work() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let multiplier = FixedU128::from(3);
+
+		assert_ok!(StableswapMining::update_yield_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			DAI_USDT_POOL,
+			multiplier
+		));
+
+		assert_last_event!(crate::Event::YieldFarmUpdated {
+			global_farm_id: GC_FARM,
+			yield_farm_id: DAI_USDT_YIELD_FARM,
+			who: GC,
+			pool_id: DAI_USDT_POOL,
+			multiplier,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn update_yield_farm_should_fail_when_stableswap_pool_does_not_exist() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::update_yield_farm(RuntimeOrigin::signed(GC), GC_FARM, NOT_A_POOL, FixedU128::one()),
+			Error::<Test>::StableswapPoolDoesntExist
+		);
+	});
+}
@@ -0,0 +1,169 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn withdraw_shares_should_work() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+		let shares_amount = 100 * ONE;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			shares_amount
+		));
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(StableswapMining::withdraw_shares(
+			RuntimeOrigin::signed(ALICE),
+			deposit_id,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL
+		));
+
+		//Assert
+		assert!(has_event(
+			crate::Event::RewardClaimed {
+				global_farm_id: GC_FARM,
+				yield_farm_id: DAI_USDT_YIELD_FARM,
+				who: ALICE,
+				claimed: 10 * ONE,
+				reward_currency: HDX,
+				deposit_id,
+			}
+			.into()
+		));
+
+		assert!(has_event(
+			crate::Event::SharesWithdrawn {
+				global_farm_id: GC_FARM,
+				yield_farm_id: DAI_USDT_YIELD_FARM,
+				who: ALICE,
+				pool_id: DAI_USDT_POOL,
+				amount: shares_amount,
+				deposit_id,
+			}
+			.into()
+		));
+
+		assert_last_event!(crate::Event::DepositDestroyed { who: ALICE, deposit_id }.into());
+
+		assert_eq!(Tokens::free_balance(DAI_USDT_POOL, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(DAI_USDT_POOL, &StableswapMining::account_id()), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10 * ONE);
+
+		assert_eq!(crate::DepositPoolId::<Test>::get(deposit_id), None);
+		assert_eq!(DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id), None::<AccountId>);
+	});
+}
+
+#[test]
+fn withdraw_shares_should_only_unlock_shares_when_yield_farm_is_terminated() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		set_block_number(11);
+
+		assert_ok!(StableswapMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			DAI_USDT_POOL
+		));
+		assert_ok!(StableswapMining::terminate_yield_farm(
+			RuntimeOrigin::signed(GC),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL
+		));
+
+		//Act
+		assert_ok!(StableswapMining::withdraw_shares(
+			RuntimeOrigin::signed(ALICE),
+			deposit_id,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::DepositDestroyed { who: ALICE, deposit_id }.into());
+
+		assert_eq!(Tokens::free_balance(DAI_USDT_POOL, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 0);
+	});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_pool_is_not_deposit_pool() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		assert_noop!(
+			StableswapMining::withdraw_shares(
+				RuntimeOrigin::signed(ALICE),
+				deposit_id,
+				DAI_USDT_YIELD_FARM,
+				USDT_USDC_POOL
+			),
+			Error::<Test>::InvalidStableswapPool
+		);
+	});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default().with_default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(ALICE),
+			GC_FARM,
+			DAI_USDT_YIELD_FARM,
+			DAI_USDT_POOL,
+			100 * ONE
+		));
+
+		assert_noop!(
+			StableswapMining::withdraw_shares(
+				RuntimeOrigin::signed(BOB),
+				deposit_id,
+				DAI_USDT_YIELD_FARM,
+				DAI_USDT_POOL
+			),
+			Error::<Test>::NotDepositOwner
+		);
+	});
+}
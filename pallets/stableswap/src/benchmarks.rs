@@ -27,7 +27,7 @@ use frame_system::{Pallet as System, RawOrigin};
 use hydradx_traits::router::{PoolType, TradeExecution};
 use orml_traits::MultiCurrency;
 use orml_traits::MultiCurrencyExtended;
-use sp_runtime::Permill;
+use sp_runtime::{FixedU128, Permill};
 
 const ASSET_ID_OFFSET: u32 = 2_000;
 
@@ -431,6 +431,80 @@ benchmarks! {
 		assert_eq!(pool.fee, new_fee);
 	}
 
+	update_rate_sources{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+		}
+		let underlying: T::AssetId = (MAX_ASSETS_IN_POOL + ASSET_ID_OFFSET).into();
+		T::BenchmarkHelper::register_asset(underlying, 12)?;
+
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids.clone(),
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		// Worst case: every pool asset has a rate source which has to be retrieved
+		let mut rate_sources = vec![];
+		for asset_id in asset_ids {
+			rate_sources.push(Some(T::BenchmarkHelper::prepare_rate_source(asset_id, underlying)?));
+		}
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, rate_sources.clone())
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.rate_sources.to_vec(), rate_sources);
+	}
+
+	set_dynamic_fee_params{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+		let liquidity_added = 300_000_000_000_000u128;
+
+		let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut added_liquidity: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+			T::Currency::update_balance(asset_id, &lp_provider, liquidity_added as i128)?;
+			initial.push(AssetAmount::new(asset_id, initial_liquidity));
+			added_liquidity.push(AssetAmount::new(asset_id, liquidity_added));
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		let params = DynamicFeeParams {
+			min_fee: Permill::from_rational(1u32, 10_000u32),
+			max_fee: Permill::from_percent(1),
+			decay: FixedU128::from_rational(5, 10_000),
+			amplification: FixedU128::from(2),
+		};
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Some(params))
+	verify {
+		assert_eq!(crate::Pallet::<T>::dynamic_fee_params(pool_id), Some(params));
+	}
+
 	update_amplification{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -29,6 +29,19 @@
 //!
 //! This is currently used to update on-chain oracle.
 //!
+//! ### Rate providers
+//!
+//! Pool asset can be a yield-bearing asset whose value grows against its underlying asset (eg. vDOT and DOT).
+//! Authority can set a rate source for each pool asset - either the EMA oracle price of the asset in its underlying asset
+//! or a custom rate provided by `RateProvider`. Reserves are scaled by the rates before the invariant, trade amounts and shares are calculated.
+//!
+//! ### Dynamic fees
+//!
+//! Authority can enable dynamic fees for a pool by setting `DynamicFeeParams`. The fee is then recalculated once per block,
+//! on the first pool operation, from the pool imbalance and the oracle volume of pool assets provided by `VolumeProvider`.
+//! The fee grows with the net outflow of pool assets and decays otherwise, but not below the fee given by the current
+//! pool imbalance. The fee moves between `min_fee` and `max_fee`.
+//!
 //! ### Terminology
 //!
 //! * **LP** - liquidity provider
@@ -54,21 +67,26 @@
 extern crate core;
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::weights::Weight;
 use frame_support::{ensure, require_transactional, transactional, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{registry::Inspect, AccountIdFor};
 pub use pallet::*;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Zero};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Saturating, Zero};
 use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
 
+pub mod migration;
 mod trade_execution;
 pub mod types;
 pub mod weights;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
+use crate::types::{
+	AssetAmount, Balance, DynamicFeeEntry, DynamicFeeParams, PoolInfo, PoolState, RateProvider, RateSource,
+	StableswapHooks, Tradability, VolumeProvider,
+};
 use hydra_dx_math::stableswap::types::AssetReserve;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use orml_traits::MultiCurrency;
@@ -106,7 +124,10 @@ pub mod pallet {
 	use sp_runtime::Permill;
 	use sp_std::num::NonZeroU16;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -146,6 +167,12 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Provider of rates of yield-bearing pool assets.
+		type RateProvider: RateProvider<Self::AssetId>;
+
+		/// Provider of traded volume of pool assets used to calculate dynamic fees.
+		type VolumeProvider: VolumeProvider<Self::AssetId>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -176,6 +203,16 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Dynamic fee parameters of pools. Pools without dynamic fee parameters use the static pool fee.
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_fee_params)]
+	pub type DynamicFeeParameters<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, DynamicFeeParams>;
+
+	/// Last calculated dynamic fee of pools.
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_fee)]
+	pub type DynamicFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, DynamicFeeEntry<BlockNumberFor<T>>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -239,6 +276,18 @@ pub mod pallet {
 			start_block: BlockNumberFor<T>,
 			end_block: BlockNumberFor<T>,
 		},
+
+		/// Rate sources of pool assets have been updated.
+		RateSourcesUpdated {
+			pool_id: T::AssetId,
+			rate_sources: Vec<Option<RateSource<T::AssetId>>>,
+		},
+
+		/// Dynamic fee parameters of a pool have been set or removed.
+		DynamicFeeParamsUpdated {
+			pool_id: T::AssetId,
+			params: Option<DynamicFeeParams>,
+		},
 	}
 
 	#[pallet::error]
@@ -315,6 +364,15 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Failed to retrieve rate of an asset.
+		RateNotAvailable,
+
+		/// Rate sources must be either empty or provided for each pool asset.
+		InvalidRateSources,
+
+		/// Minimum fee is greater than maximum fee or amplification is zero.
+		InvalidDynamicFeeParams,
 	}
 
 	#[pallet::call]
@@ -468,7 +526,8 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
@@ -505,7 +564,8 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn add_liquidity_shares(
 			origin: OriginFor<T>,
@@ -544,7 +604,8 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -573,9 +634,7 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 
 			ensure!(
@@ -585,6 +644,7 @@ pub mod pallet {
 			);
 
 			let amplification = Self::get_amplification(&pool);
+			let pool_fee = Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 
 			//Calculate how much asset user will receive. Note that the fee is already subtracted from the amount.
 			let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
@@ -593,7 +653,7 @@ pub mod pallet {
 				asset_idx,
 				share_issuance,
 				amplification,
-				pool.fee,
+				pool_fee,
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -634,7 +694,8 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_asset_amount()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn withdraw_asset_amount(
 			origin: OriginFor<T>,
@@ -655,11 +716,10 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
+			let pool_fee = Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 
 			// Calculate how much shares user needs to provide to receive `amount` of asset.
 			let shares = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
@@ -668,7 +728,7 @@ pub mod pallet {
 				amount,
 				amplification,
 				share_issuance,
-				pool.fee,
+				pool_fee,
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -713,7 +773,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -745,9 +806,8 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
+			Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 
 			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
@@ -769,7 +829,11 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(
+				pool_id,
+				&initial_reserves,
+				Self::get_pool_fee(pool_id, &pool, &initial_reserves),
+			);
 
 			Ok(())
 		}
@@ -788,7 +852,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::dynamic_fee_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -815,9 +880,8 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
+			Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 
 			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
 
@@ -847,7 +911,11 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(
+				pool_id,
+				&initial_reserves,
+				Self::get_pool_fee(pool_id, &pool, &initial_reserves),
+			);
 
 			Ok(())
 		}
@@ -878,6 +946,96 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Update rate sources of pool assets.
+		///
+		/// Rate of yield-bearing asset is value of one unit of the asset in its underlying asset.
+		/// Reserves are scaled by the rates when pool invariant, trade amounts and shares are calculated.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `rate_sources`: rate source of each pool asset in the order of pool assets. Empty list removes all rate sources.
+		///
+		/// Emits `RateSourcesUpdated` event if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_rate_sources())]
+		#[transactional]
+		pub fn update_rate_sources(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			rate_sources: Vec<Option<RateSource<T::AssetId>>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(
+					rate_sources.is_empty() || rate_sources.len() == pool.assets.len(),
+					Error::<T>::InvalidRateSources
+				);
+
+				for (asset, source) in pool.assets.iter().zip(rate_sources.iter()) {
+					if let Some(source) = source {
+						if let RateSource::Oracle { underlying, .. } = source {
+							ensure!(underlying != asset, Error::<T>::InvalidRateSources);
+						}
+						ensure!(
+							Self::retrieve_rate(*asset, source).is_some(),
+							Error::<T>::RateNotAvailable
+						);
+					}
+				}
+
+				pool.rate_sources = rate_sources
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::InvalidRateSources)?;
+
+				Self::deposit_event(Event::RateSourcesUpdated { pool_id, rate_sources });
+				Ok(())
+			})
+		}
+
+		/// Set or remove dynamic fee parameters of a pool.
+		///
+		/// When set, the pool fee is recalculated once per block from the volume traded in the pool and from the
+		/// pool imbalance, within the given minimum and maximum fee. When removed, static pool fee is used again.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `params`: dynamic fee parameters. `None` disables dynamic fee of the pool.
+		///
+		/// Emits `DynamicFeeParamsUpdated` event if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_dynamic_fee_params())]
+		#[transactional]
+		pub fn set_dynamic_fee_params(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			params: Option<DynamicFeeParams>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+			match params {
+				Some(p) => {
+					ensure!(
+						p.min_fee <= p.max_fee && !p.amplification.is_zero(),
+						Error::<T>::InvalidDynamicFeeParams
+					);
+					DynamicFeeParameters::<T>::insert(pool_id, p);
+				}
+				None => DynamicFeeParameters::<T>::remove(pool_id),
+			}
+			DynamicFees::<T>::remove(pool_id);
+
+			Self::deposit_event(Event::DynamicFeeParamsUpdated { pool_id, params });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -904,9 +1062,7 @@ impl<T: Config> Pallet<T> {
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 		ensure!(
@@ -915,13 +1071,14 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let amplification = Self::get_amplification(&pool);
+		let pool_fee = Self::get_pool_fee(pool_id, &pool, &initial_reserves);
 		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			index_in,
 			index_out,
 			amount_in,
 			amplification,
-			pool.fee,
+			pool_fee,
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
@@ -940,9 +1097,7 @@ impl<T: Config> Pallet<T> {
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 
 		ensure!(
 			initial_reserves[index_out].amount > amount_out,
@@ -951,19 +1106,20 @@ impl<T: Config> Pallet<T> {
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
+		let pool_fee = Self::get_pool_fee(pool_id, &pool, &initial_reserves);
 		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			index_in,
 			index_out,
 			amount_out,
 			amplification,
-			pool.fee,
+			pool_fee,
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -992,6 +1148,7 @@ impl<T: Config> Pallet<T> {
 			initial_block: block_number,
 			final_block: block_number,
 			fee,
+			rate_sources: Default::default(),
 		};
 		ensure!(pool.is_valid(), Error::<T>::IncorrectAssets);
 		ensure!(
@@ -1035,29 +1192,17 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let pool_account = Self::pool_account(pool_id);
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
 		let mut added_amounts = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				updated_reserves.push(reserve.with_amount(inc_reserve));
 				added_amounts.push(liq_added);
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 				added_amounts.push(0);
 			}
 		}
@@ -1066,12 +1211,13 @@ impl<T: Config> Pallet<T> {
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		let pool_fee = Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			pool_fee,
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1115,22 +1261,21 @@ impl<T: Config> Pallet<T> {
 		let share_issuance = T::Currency::total_issuance(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 
 		// Ensure that initial liquidity has been already provided
 		for reserve in initial_reserves.iter() {
 			ensure!(!reserve.amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
 		}
 
+		let pool_fee = Self::update_dynamic_fee(pool_id, &pool, &initial_reserves);
 		let (amount_in, _) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			shares,
 			asset_idx,
 			share_issuance,
 			amplification,
-			pool.fee,
+			pool_fee,
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1159,10 +1304,70 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
+	/// Returns current fee of a pool.
+	///
+	/// Static pool fee is returned if the pool has no dynamic fee parameters. Otherwise the dynamic fee is
+	/// recalculated from the previous fee, net traded volume and given reserves, unless it was already calculated in
+	/// current block.
+	pub fn get_pool_fee(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		reserves: &[AssetReserve],
+	) -> Permill {
+		let Some(params) = DynamicFeeParameters::<T>::get(pool_id) else {
+			return pool.fee;
+		};
+
+		let block_number = T::BlockNumberProvider::current_block_number();
+		let entry = DynamicFees::<T>::get(pool_id).unwrap_or(DynamicFeeEntry {
+			fee: params.min_fee,
+			updated_at: BlockNumberFor::<T>::default(),
+		});
+
+		if entry.updated_at == block_number {
+			return entry.fee;
+		}
+
+		let delta_blocks: u128 = block_number.saturating_sub(entry.updated_at).saturated_into();
+		let volumes: Vec<(Balance, Balance)> = pool
+			.assets
+			.iter()
+			.map(|asset_id| T::VolumeProvider::asset_volume(pool_id, *asset_id).unwrap_or_default())
+			.collect();
+
+		hydra_dx_math::stableswap::recalculate_pool_fee(reserves, &volumes, entry.fee, delta_blocks, params.into())
+			.unwrap_or(entry.fee)
+	}
+
+	/// Recalculates dynamic fee of a pool and stores it, so it is not changed within current block.
+	/// Returns current fee of the pool.
+	fn update_dynamic_fee(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		reserves: &[AssetReserve],
+	) -> Permill {
+		let fee = Self::get_pool_fee(pool_id, pool, reserves);
+		if DynamicFeeParameters::<T>::contains_key(pool_id) {
+			DynamicFees::<T>::insert(
+				pool_id,
+				DynamicFeeEntry {
+					fee,
+					updated_at: T::BlockNumberProvider::current_block_number(),
+				},
+			);
+		}
+		fee
+	}
+
+	/// Weight of dynamic fee recalculation.
+	pub fn dynamic_fee_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2 + MAX_ASSETS_IN_POOL as u64, 1)
+	}
+
 	#[inline]
 	pub(crate) fn get_amplification(pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>) -> u128 {
 		hydra_dx_math::stableswap::calculate_amplification(
@@ -1178,6 +1383,62 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	#[inline]
+	pub(crate) fn retrieve_rate(asset_id: T::AssetId, source: &RateSource<T::AssetId>) -> Option<(Balance, Balance)> {
+		T::RateProvider::rate(asset_id, source).filter(|(n, d)| !n.is_zero() && !d.is_zero())
+	}
+
+	/// Add new asset to an existing pool.
+	///
+	/// Asset is inserted so that pool assets remain sorted. If pool has rate sources, the new asset has no rate source.
+	/// Note that this does not provide any liquidity of the asset.
+	#[require_transactional]
+	pub fn add_asset_to_pool(pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+		ensure!(T::AssetInspection::exists(asset_id), Error::<T>::AssetNotRegistered);
+		ensure!(asset_id != pool_id, Error::<T>::ShareAssetInPoolAssets);
+
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::IncorrectAssets);
+
+			let idx = pool.assets.partition_point(|a| *a < asset_id);
+			pool.assets
+				.try_insert(idx, asset_id)
+				.map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			if !pool.rate_sources.is_empty() {
+				pool.rate_sources
+					.try_insert(idx, None)
+					.map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Calculates amount of shares which needs to be burned to withdraw exact `amount` of `asset_id` from the pool.
+	pub fn calculate_shares_for_amount(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+		let initial_reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id))?;
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		let amplification = Self::get_amplification(&pool);
+
+		let pool_fee = Self::get_pool_fee(pool_id, &pool, &initial_reserves);
+
+		hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
+			&initial_reserves,
+			asset_idx,
+			amount,
+			amplification,
+			share_issuance,
+			pool_fee,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -1205,38 +1466,27 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				updated_reserves.push(reserve.with_amount(inc_reserve));
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 			}
 		}
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
+		let pool_fee = Self::get_pool_fee(pool_id, &pool, &initial_reserves);
 		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			pool_fee,
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1276,9 +1526,7 @@ impl<T: Config> Pallet<T> {
 		let pool_account = Self::pool_account(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
-		let updated_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let updated_reserves = pool.reserves_with_decimals::<T>(&pool_account)?;
 		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 			&updated_reserves,
 			amplification,
@@ -0,0 +1,116 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{ConstU32, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::Saturating;
+
+/// The log target.
+const TARGET: &str = "runtime::stableswap::migration::v1";
+
+pub mod v0 {
+	use super::*;
+
+	/// Pool info without rate sources.
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PoolInfo<AssetId, BlockNumber> {
+		pub assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>>,
+		pub initial_amplification: NonZeroU16,
+		pub final_amplification: NonZeroU16,
+		pub initial_block: BlockNumber,
+		pub final_block: BlockNumber,
+		pub fee: Permill,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Adds empty rate sources to all existing pools.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let pools_count = Pools::<T>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "Stableswap migration: PRE checks successful!");
+
+			Ok(pools_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::warn!(
+					target: TARGET,
+					"Stableswap migration to v1 skipped because storage version is not 0"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for Stableswap");
+
+			let mut translated: u64 = 0;
+			Pools::<T>::translate::<v0::PoolInfo<T::AssetId, BlockNumberFor<T>>, _>(|_, pool| {
+				translated.saturating_inc();
+				Some(PoolInfo {
+					assets: pool.assets,
+					initial_amplification: pool.initial_amplification,
+					final_amplification: pool.final_amplification,
+					initial_block: pool.initial_block,
+					final_block: pool.final_block,
+					fee: pool.fee,
+					rate_sources: BoundedVec::default(),
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: TARGET, "Stableswap migration to v1 migrated {:?} pools", translated);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let previous_count = <u64 as Decode>::decode(&mut state.as_slice()).unwrap();
+			let pools = Pools::<T>::iter_values().collect::<Vec<_>>();
+
+			assert_eq!(
+				pools.len() as u64,
+				previous_count,
+				"Migrated pools don't match the pools prior migration!"
+			);
+			assert!(pools.iter().all(|pool| pool.rate_sources.is_empty()));
+
+			log::info!(target: TARGET, "Stableswap migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
@@ -46,6 +46,7 @@ fn update_amplification_should_work_when_correct_params_are_provided() {
 					initial_block: 10,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_sources: Default::default(),
 				}
 			);
 		});
@@ -181,6 +182,7 @@ fn update_amplification_should_work_when_current_change_is_in_progress() {
 					initial_block: 10,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_sources: Default::default(),
 				}
 			);
 			System::set_block_number(500);
@@ -202,6 +204,7 @@ fn update_amplification_should_work_when_current_change_is_in_progress() {
 					initial_block: 501,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_sources: Default::default(),
 				}
 			);
 		});
@@ -32,6 +32,7 @@ fn spot_price_calculation_should_work_when_asset_in_is_share_with_6_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -101,6 +102,7 @@ fn spot_price_calculation_should_work_when_asset_in_is_share_with_12_decimals()
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -177,6 +179,7 @@ fn spot_price_calculation_should_work_when_asset_in_is_share_with_18_decimals()
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -264,6 +267,7 @@ fn spot_price_calculation_should_work_when_asset_out_is_share_with_12_decimals()
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(3),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -340,6 +344,7 @@ fn spot_price_calculation_should_work_when_asset_out_is_share_with_18_decimals()
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(3),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -409,6 +414,7 @@ fn spot_price_calculation_should_work_for_two_stableassets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -483,6 +489,7 @@ fn spot_price_calculation_should_work_for_two_stableassets_on_different_position
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(2),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -567,6 +574,7 @@ mod invariants {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(1),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity {
 					account: ALICE,
@@ -637,6 +645,7 @@ mod invariants {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity {
 					account: ALICE,
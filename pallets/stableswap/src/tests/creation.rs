@@ -36,6 +36,7 @@ fn create_two_asset_pool_should_work_when_assets_are_registered() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				}
 			);
 		});
@@ -105,6 +106,7 @@ fn create_pool_should_store_assets_correctly_when_input_is_not_sorted() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(5),
+					rate_sources: Default::default(),
 				}
 			);
 		});
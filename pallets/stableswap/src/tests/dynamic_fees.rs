@@ -0,0 +1,365 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, DynamicFeeEntry, DynamicFeeParams, PoolInfo};
+use crate::{DynamicFees, Error, Pallet, Pools};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, FixedU128, Permill};
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+fn fee_params() -> DynamicFeeParams {
+	DynamicFeeParams {
+		min_fee: Permill::from_rational(1u32, 1000u32),
+		max_fee: Permill::from_percent(5),
+		decay: FixedU128::from(0),
+		amplification: FixedU128::from(1),
+	}
+}
+
+fn builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+				],
+			},
+		)
+}
+
+fn current_pool_fee(pool_id: AssetId) -> Permill {
+	let pool = Pools::<Test>::get(pool_id).unwrap();
+	let reserves = pool.reserves_with_decimals::<Test>(&pool_account(pool_id)).unwrap();
+	Pallet::<Test>::get_pool_fee(pool_id, &pool, &reserves)
+}
+
+#[test]
+fn set_dynamic_fee_params_should_work_when_params_are_valid() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+
+		assert_eq!(Stableswap::dynamic_fee_params(pool_id), Some(fee_params()));
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_remove_params_when_none_is_provided() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+
+		assert_ok!(Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), pool_id, None));
+
+		assert_eq!(Stableswap::dynamic_fee_params(pool_id), None);
+		assert_eq!(current_pool_fee(pool_id), Permill::from_percent(1));
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_called_by_non_authority_origin() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::signed(ALICE), pool_id, Some(fee_params())),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_pool_does_not_exist() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), ASSET_A, Some(fee_params())),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_min_fee_is_greater_than_max_fee() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let params = DynamicFeeParams {
+			min_fee: Permill::from_percent(10),
+			max_fee: Permill::from_percent(5),
+			..fee_params()
+		};
+
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), pool_id, Some(params)),
+			Error::<Test>::InvalidDynamicFeeParams
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_fail_when_amplification_is_zero() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let params = DynamicFeeParams {
+			amplification: FixedU128::from(0),
+			..fee_params()
+		};
+
+		assert_noop!(
+			Stableswap::set_dynamic_fee_params(RuntimeOrigin::root(), pool_id, Some(params)),
+			Error::<Test>::InvalidDynamicFeeParams
+		);
+	});
+}
+
+#[test]
+fn pool_fee_should_be_static_fee_when_dynamic_fee_params_are_not_set() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		System::set_block_number(1);
+		set_volume(pool_id, ASSET_A, (0, 50 * ONE));
+
+		assert_eq!(current_pool_fee(pool_id), Permill::from_percent(1));
+	});
+}
+
+#[test]
+fn pool_fee_should_be_min_fee_when_pool_is_balanced_and_there_is_no_volume() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+
+		assert_eq!(current_pool_fee(pool_id), fee_params().min_fee);
+	});
+}
+
+#[test]
+fn pool_fee_should_increase_when_net_outflow_increases() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		set_volume(pool_id, ASSET_A, (2 * ONE, 0));
+		set_volume(pool_id, ASSET_B, (0, 2 * ONE));
+
+		// min fee + 2 / 200
+		assert_eq!(current_pool_fee(pool_id), Permill::from_rational(11u32, 1000u32));
+	});
+}
+
+#[test]
+fn pool_fee_should_not_increase_when_volume_cancels_out() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		set_volume(pool_id, ASSET_A, (20 * ONE, 20 * ONE));
+		set_volume(pool_id, ASSET_B, (20 * ONE, 20 * ONE));
+
+		assert_eq!(current_pool_fee(pool_id), fee_params().min_fee);
+	});
+}
+
+#[test]
+fn pool_fee_should_not_exceed_max_fee() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		set_volume(pool_id, ASSET_A, (0, 100 * ONE));
+
+		assert_eq!(current_pool_fee(pool_id), fee_params().max_fee);
+	});
+}
+
+#[test]
+fn pool_fee_should_increase_when_pool_is_imbalanced() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			30 * ONE,
+			25 * ONE,
+		));
+
+		System::set_block_number(2);
+
+		assert!(current_pool_fee(pool_id) > fee_params().min_fee);
+	});
+}
+
+#[test]
+fn pool_fee_should_not_keep_increasing_when_pool_stays_imbalanced() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			30 * ONE,
+			25 * ONE,
+		));
+
+		System::set_block_number(2);
+		let fee = current_pool_fee(pool_id);
+
+		for block in 3..10 {
+			System::set_block_number(block);
+			let pool = Pools::<Test>::get(pool_id).unwrap();
+			let reserves = pool.reserves_with_decimals::<Test>(&pool_account(pool_id)).unwrap();
+			Pallet::<Test>::update_dynamic_fee(pool_id, &pool, &reserves);
+		}
+
+		assert_eq!(current_pool_fee(pool_id), fee);
+	});
+}
+
+#[test]
+fn sell_should_store_pool_fee_when_dynamic_fee_params_are_set() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		set_volume(pool_id, ASSET_A, (2 * ONE, 0));
+		set_volume(pool_id, ASSET_B, (0, 2 * ONE));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			5 * ONE,
+		));
+
+		assert_eq!(
+			DynamicFees::<Test>::get(pool_id),
+			Some(DynamicFeeEntry {
+				fee: Permill::from_rational(11u32, 1000u32),
+				updated_at: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn pool_fee_should_not_change_within_same_block_when_already_updated() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			5 * ONE,
+		));
+		let fee = current_pool_fee(pool_id);
+
+		set_volume(pool_id, ASSET_A, (0, 50 * ONE));
+
+		assert_eq!(current_pool_fee(pool_id), fee);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_params_should_reset_stored_pool_fee() {
+	builder().build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+		System::set_block_number(1);
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			5 * ONE,
+		));
+
+		assert_ok!(Stableswap::set_dynamic_fee_params(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(fee_params())
+		));
+
+		assert_eq!(DynamicFees::<Test>::get(pool_id), None);
+	});
+}
@@ -29,6 +29,7 @@ fn add_liquidity_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -99,6 +100,7 @@ fn add_liquidity_shares_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -172,6 +174,7 @@ fn removing_liquidity_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -254,6 +257,7 @@ fn withdraw_asset_amount_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -336,6 +340,7 @@ fn sell_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -412,6 +417,7 @@ fn buy_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
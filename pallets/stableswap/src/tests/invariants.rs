@@ -74,6 +74,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -147,6 +148,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -221,6 +223,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -295,6 +298,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -380,6 +384,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 					assets:	vec![
@@ -470,6 +475,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -582,6 +588,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -691,6 +698,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -822,6 +830,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -924,6 +933,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -1026,6 +1036,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -1132,6 +1143,7 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_sources: Default::default(),
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
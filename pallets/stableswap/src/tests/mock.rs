@@ -73,6 +73,8 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = RefCell::new(None);
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = RefCell::new(None);
+	pub static RATES: RefCell<HashMap<AssetId, (Balance, Balance)>> = RefCell::new(HashMap::default());
+	pub static VOLUMES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -181,6 +183,8 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type RateProvider = DummyRateProvider;
+	type VolumeProvider = DummyVolumeProvider;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -210,6 +214,9 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		RATES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -236,6 +243,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_rate(self, asset: AssetId, rate: (Balance, Balance)) -> Self {
+		set_rate(asset, rate);
+		self
+	}
+
 	pub fn with_pool(
 		mut self,
 		who: AccountId,
@@ -295,6 +307,14 @@ impl ExtBuilder {
 					v.borrow_mut().push(pool_id);
 				});
 
+				if !pool.rate_sources.is_empty() {
+					assert_ok!(Stableswap::update_rate_sources(
+						RuntimeOrigin::root(),
+						pool_id,
+						pool.rate_sources.clone().into(),
+					));
+				}
+
 				if initial_liquid.assets.len() as u128 > Balance::zero() {
 					assert_ok!(Stableswap::add_liquidity(
 						RuntimeOrigin::signed(initial_liquid.account),
@@ -311,7 +331,7 @@ impl ExtBuilder {
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
-use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use crate::types::{AssetAmount, PoolInfo, PoolState, RateProvider, RateSource, StableswapHooks, VolumeProvider};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::{AccountIdFor, Inspect};
 use sp_runtime::traits::Zero;
@@ -366,6 +386,11 @@ impl BenchmarkHelper<AssetId> for DummyRegistry {
 
 		Ok(())
 	}
+
+	fn prepare_rate_source(asset_id: AssetId, _underlying: AssetId) -> Result<RateSource<AssetId>, DispatchError> {
+		set_rate(asset_id, (2, 1));
+		Ok(RateSource::Custom)
+	}
 }
 
 pub struct AccountIdConstructor;
@@ -436,3 +461,31 @@ pub(crate) fn last_liquidity_changed_hook_state() -> Option<(AssetId, PoolState<
 pub(crate) fn last_trade_hook_state() -> Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)> {
 	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
 }
+
+pub struct DummyRateProvider;
+
+impl RateProvider<AssetId> for DummyRateProvider {
+	fn rate(asset_id: AssetId, _source: &RateSource<AssetId>) -> Option<(Balance, Balance)> {
+		RATES.with(|v| v.borrow().get(&asset_id).copied())
+	}
+}
+
+pub(crate) fn set_rate(asset_id: AssetId, rate: (Balance, Balance)) {
+	RATES.with(|v| {
+		v.borrow_mut().insert(asset_id, rate);
+	});
+}
+
+pub struct DummyVolumeProvider;
+
+impl VolumeProvider<AssetId> for DummyVolumeProvider {
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)> {
+		VOLUMES.with(|v| v.borrow().get(&(pool_id, asset_id)).copied())
+	}
+}
+
+pub(crate) fn set_volume(pool_id: AssetId, asset_id: AssetId, volume: (Balance, Balance)) {
+	VOLUMES.with(|v| {
+		v.borrow_mut().insert((pool_id, asset_id), volume);
+	});
+}
@@ -6,10 +6,12 @@ mod add_liquidity;
 mod amplification;
 mod calculate_spot_price;
 mod creation;
+mod dynamic_fees;
 mod hooks;
 mod invariants;
 pub(crate) mod mock;
 mod price;
+mod rates;
 mod remove_liquidity;
 mod trades;
 mod update_pool;
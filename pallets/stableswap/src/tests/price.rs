@@ -29,6 +29,7 @@ fn test_spot_price_in_sell() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -96,6 +97,7 @@ fn test_spot_price_in_buy() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -159,6 +161,7 @@ fn test_share_price_in_add_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -232,6 +235,7 @@ fn test_share_price_in_add_shares_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -307,6 +311,7 @@ fn test_share_price_case() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -0,0 +1,271 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo, RateSource};
+use crate::{assert_balance, Error, Pools};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::OraclePeriod;
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+fn oracle_source(underlying: AssetId) -> RateSource<AssetId> {
+	RateSource::Oracle {
+		underlying,
+		period: OraclePeriod::Short,
+	}
+}
+
+// Pool of yield bearing asset A ( worth 2 units of B ) and its underlying asset B.
+fn pool_with_rate(rate_sources: Vec<Option<RateSource<AssetId>>>) -> PoolInfo<AssetId, u64> {
+	PoolInfo::<AssetId, u64> {
+		assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+		initial_amplification: NonZeroU16::new(100).unwrap(),
+		final_amplification: NonZeroU16::new(100).unwrap(),
+		initial_block: 0,
+		final_block: 0,
+		fee: Permill::from_percent(0),
+		rate_sources: rate_sources.try_into().unwrap(),
+	}
+}
+
+fn initial_liquidity() -> InitialLiquidity {
+	InitialLiquidity {
+		account: ALICE,
+		assets: vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 200 * ONE),
+		],
+	}
+}
+
+fn builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_B, 100 * ONE),
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 400 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_rate(ASSET_A, (2, 1))
+}
+
+#[test]
+fn update_rate_sources_should_work_when_rates_are_available() {
+	builder()
+		.with_pool(ALICE, pool_with_rate(vec![]), initial_liquidity())
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::update_rate_sources(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![Some(oracle_source(ASSET_B)), None],
+			));
+
+			assert_eq!(
+				Pools::<Test>::get(pool_id).unwrap().rate_sources.into_inner(),
+				vec![Some(oracle_source(ASSET_B)), None]
+			);
+		});
+}
+
+#[test]
+fn update_rate_sources_should_remove_rates_when_empty_list_is_provided() {
+	builder()
+		.with_pool(
+			ALICE,
+			pool_with_rate(vec![Some(RateSource::Custom), None]),
+			initial_liquidity(),
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::update_rate_sources(RuntimeOrigin::root(), pool_id, vec![]));
+
+			assert!(Pools::<Test>::get(pool_id).unwrap().rate_sources.is_empty());
+		});
+}
+
+#[test]
+fn update_rate_sources_should_fail_when_called_by_non_authority() {
+	builder()
+		.with_pool(ALICE, pool_with_rate(vec![]), initial_liquidity())
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::update_rate_sources(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					vec![Some(oracle_source(ASSET_B)), None]
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn update_rate_sources_should_fail_when_not_provided_for_each_asset() {
+	builder()
+		.with_pool(ALICE, pool_with_rate(vec![]), initial_liquidity())
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::update_rate_sources(RuntimeOrigin::root(), pool_id, vec![Some(oracle_source(ASSET_B))]),
+				Error::<Test>::InvalidRateSources
+			);
+		});
+}
+
+#[test]
+fn update_rate_sources_should_fail_when_underlying_asset_is_the_same_asset() {
+	builder()
+		.with_pool(ALICE, pool_with_rate(vec![]), initial_liquidity())
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::update_rate_sources(
+					RuntimeOrigin::root(),
+					pool_id,
+					vec![Some(oracle_source(ASSET_A)), None]
+				),
+				Error::<Test>::InvalidRateSources
+			);
+		});
+}
+
+#[test]
+fn update_rate_sources_should_fail_when_rate_is_not_available() {
+	builder()
+		.with_pool(ALICE, pool_with_rate(vec![]), initial_liquidity())
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::update_rate_sources(
+					RuntimeOrigin::root(),
+					pool_id,
+					vec![None, Some(oracle_source(ASSET_A))]
+				),
+				Error::<Test>::RateNotAvailable
+			);
+		});
+}
+
+#[test]
+fn sell_should_account_for_rate_of_asset_in() {
+	builder()
+		.with_pool(
+			ALICE,
+			pool_with_rate(vec![Some(oracle_source(ASSET_B)), None]),
+			initial_liquidity(),
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				ASSET_A,
+				ASSET_B,
+				10 * ONE,
+				19 * ONE,
+			));
+
+			let received = Tokens::free_balance(ASSET_B, &BOB) - 100 * ONE;
+			assert!(received < 20 * ONE);
+			assert_balance!(BOB, ASSET_A, 90 * ONE);
+		});
+}
+
+#[test]
+fn buy_should_account_for_rate_of_asset_out() {
+	builder()
+		.with_pool(
+			ALICE,
+			pool_with_rate(vec![Some(oracle_source(ASSET_B)), None]),
+			initial_liquidity(),
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::buy(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				ASSET_A,
+				ASSET_B,
+				10 * ONE,
+				21 * ONE,
+			));
+
+			let paid = 100 * ONE - Tokens::free_balance(ASSET_B, &BOB);
+			assert!(paid > 20 * ONE);
+			assert_balance!(BOB, ASSET_A, 110 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_should_give_same_shares_for_same_underlying_value() {
+	let shares_for = |asset_id: AssetId, amount: Balance| -> Balance {
+		let mut shares = 0;
+		builder()
+			.with_pool(
+				ALICE,
+				pool_with_rate(vec![Some(oracle_source(ASSET_B)), None]),
+				initial_liquidity(),
+			)
+			.build()
+			.execute_with(|| {
+				let pool_id = get_pool_id_at(0);
+
+				assert_ok!(Stableswap::add_liquidity(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					vec![AssetAmount::new(asset_id, amount)],
+				));
+
+				shares = Tokens::free_balance(pool_id, &BOB);
+			});
+		shares
+	};
+
+	let shares_for_yield_bearing_asset = shares_for(ASSET_A, 10 * ONE);
+	let shares_for_underlying_asset = shares_for(ASSET_B, 20 * ONE);
+
+	assert!(shares_for_yield_bearing_asset > 0);
+	assert_eq!(shares_for_yield_bearing_asset, shares_for_underlying_asset);
+}
+
+#[test]
+fn trade_should_fail_when_rate_becomes_unavailable() {
+	builder()
+		.with_pool(
+			ALICE,
+			pool_with_rate(vec![Some(oracle_source(ASSET_B)), None]),
+			initial_liquidity(),
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			RATES.with(|v| v.borrow_mut().clear());
+
+			assert_noop!(
+				Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, ASSET_A, ASSET_B, 10 * ONE, 0),
+				Error::<Test>::RateNotAvailable
+			);
+		});
+}
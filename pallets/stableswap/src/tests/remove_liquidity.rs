@@ -30,6 +30,7 @@ fn remove_liquidity_should_work_when_withdrawing_all_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -98,6 +99,7 @@ fn remove_liquidity_should_apply_fee_when_withdrawing_all_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -225,6 +227,7 @@ fn remove_liquidity_should_fail_when_requested_asset_not_in_pool() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -281,6 +284,7 @@ fn remove_liquidity_should_fail_when_remaining_shares_below_min_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -346,6 +350,7 @@ fn verify_remove_liquidity_against_research_impl() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.003),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -416,6 +421,7 @@ fn remove_liquidity_fail_when_desired_min_limit_is_not_reached() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -478,6 +484,7 @@ fn scenario_add_remove_with_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -545,6 +552,7 @@ fn scenario_sell_with_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -597,6 +605,7 @@ fn specific_scenario_to_verify_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -650,6 +659,7 @@ fn specific_scenario_to_verify_withdrawal_exact_amount() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -705,6 +715,7 @@ fn specific_scenario_to_verify_difference() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -757,6 +768,7 @@ fn scenario_3_trade() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0001),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -810,6 +822,7 @@ fn removing_liquidity_with_exact_amount_should_work() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -879,6 +892,7 @@ fn removing_liquidity_should_not_give_more_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -948,6 +962,7 @@ fn removing_liquidity_with_exact_amount_should_apply_fee() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -23,6 +23,7 @@ fn sell_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -73,6 +74,7 @@ fn buy_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -124,6 +126,7 @@ fn sell_with_fee_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(10),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -172,6 +175,7 @@ fn sell_should_work_when_fee_is_small() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_rational(3u32, 1000u32),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -220,6 +224,7 @@ fn buy_should_work_when_fee_is_set() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(10),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -273,6 +278,7 @@ fn sell_should_fail_when_insufficient_amount_is_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -359,6 +365,7 @@ fn buy_should_fail_when_insufficient_amount_is_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -461,6 +468,7 @@ fn sell_should_work_when_pool_have_asset_with_various_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -520,6 +528,7 @@ fn buy_should_work_when_pool_have_asset_with_various_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -578,6 +587,7 @@ fn sell_should_work_when_assets_have_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -634,6 +644,7 @@ fn buy_should_work_when_assets_have_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -684,6 +695,7 @@ fn sell_should_fail_when_trading_same_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -727,6 +739,7 @@ fn buy_should_fail_when_trading_same_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_sources: Default::default(),
 			},
 			InitialLiquidity {
 				account: ALICE,
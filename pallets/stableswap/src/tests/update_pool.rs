@@ -41,6 +41,7 @@ fn update_pool_should_work_when_all_parames_are_updated() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(10),
+					rate_sources: Default::default(),
 				}
 			);
 		});
@@ -82,6 +83,7 @@ fn update_pool_should_work_when_only_fee_is_updated() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(20),
+					rate_sources: Default::default(),
 				}
 			);
 		});
@@ -1,11 +1,12 @@
 use crate::types::AssetAmount;
 use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
 use hydra_dx_math::stableswap::types::AssetReserve;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
 use orml_traits::MultiCurrency;
 use sp_core::Get;
 use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
 where
@@ -31,15 +32,20 @@ where
 					let pool_account = Self::pool_account(pool_id);
 					let balances = pool
 						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+						.map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 
 					let amplification = Self::get_amplification(&pool);
-					let (amount, _) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<
-						D_ITERATIONS,
-						Y_ITERATIONS,
-					>(&balances, amount_in, asset_idx, share_issuance, amplification, pool.fee)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					let (amount, _) =
+						hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+							&balances,
+							amount_in,
+							asset_idx,
+							share_issuance,
+							amplification,
+							Self::get_pool_fee(pool_id, &pool, &balances),
+						)
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
 					Ok(amount)
 				} else if asset_out == pool_id {
@@ -82,7 +88,7 @@ where
 					let pool_account = Self::pool_account(pool_id);
 					let balances = pool
 						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+						.map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -92,7 +98,7 @@ where
 						asset_idx,
 						share_issuance,
 						amplification,
-						pool.fee,
+						Self::get_pool_fee(pool_id, &pool, &balances),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -106,7 +112,7 @@ where
 					let pool_account = Self::pool_account(pool_id);
 					let balances = pool
 						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+						.map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -119,7 +125,7 @@ where
 						amount_out,
 						amplification,
 						share_issuance,
-						pool.fee,
+						Self::get_pool_fee(pool_id, &pool, &balances),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -216,7 +222,7 @@ where
 					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
 				let balances = pool
 					.reserves_with_decimals::<T>(&pool_account)
-					.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					.map_err(ExecutorError::Error)?;
 
 				let assets_with_reserves = pool
 					.assets
@@ -236,7 +242,7 @@ where
 					asset_b.into(),
 					share_issuance,
 					min_trade_limit,
-					Some(pool.fee),
+					Some(Self::get_pool_fee(pool_id, &pool, &balances)),
 				)
 				.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -246,3 +252,16 @@ where
 		}
 	}
 }
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter()
+			.map(|(pool_id, pool)| {
+				let mut assets = pool.assets.to_vec();
+				// Share asset can be traded as well by adding or removing liquidity
+				assets.push(pool_id);
+				(PoolType::Stableswap(pool_id), assets)
+			})
+			.collect()
+	}
+}
@@ -3,8 +3,8 @@
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Config, Pallet, MAX_ASSETS_IN_POOL};
-use sp_runtime::Permill;
+use crate::{Config, Error, Pallet, MAX_ASSETS_IN_POOL};
+use sp_runtime::{FixedU128, Permill};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
@@ -13,17 +13,20 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
+use hydra_dx_math::dynamic_fees::types::FeeParams;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::OraclePeriod;
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
-use sp_runtime::DispatchResult;
+use sp_runtime::{DispatchError, DispatchResult};
 pub(crate) type Balance = u128;
 
 /// Pool properties for 2-asset pool (v1)
 /// `assets`: pool assets
 /// `amplification`: amp parameter
 /// `fee`: trade fee to be withdrawn on sell/buy
+/// `rate_sources`: optional rate source of each pool asset. Either empty or one entry per asset.
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PoolInfo<AssetId, BlockNumber> {
@@ -33,6 +36,75 @@ pub struct PoolInfo<AssetId, BlockNumber> {
 	pub initial_block: BlockNumber,
 	pub final_block: BlockNumber,
 	pub fee: Permill,
+	pub rate_sources: BoundedVec<Option<RateSource<AssetId>>, ConstU32<MAX_ASSETS_IN_POOL>>,
+}
+
+/// Source of the rate of a yield-bearing pool asset.
+///
+/// Rate is the value of one unit of the asset expressed in its underlying asset. Reserves are scaled by the rate
+/// before pool invariant is calculated.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RateSource<AssetId> {
+	/// Price of the asset denominated in `underlying` asset, provided by the EMA oracle for given period.
+	Oracle { underlying: AssetId, period: OraclePeriod },
+	/// Rate provided by custom implementation of `Config::RateProvider`.
+	Custom,
+}
+
+/// Interface to retrieve rates of yield-bearing pool assets.
+pub trait RateProvider<AssetId> {
+	/// Returns rate of `asset_id` from given source as (numerator, denominator).
+	fn rate(asset_id: AssetId, source: &RateSource<AssetId>) -> Option<(Balance, Balance)>;
+}
+
+impl<AssetId> RateProvider<AssetId> for () {
+	fn rate(_asset_id: AssetId, _source: &RateSource<AssetId>) -> Option<(Balance, Balance)> {
+		None
+	}
+}
+
+/// Dynamic fee parameters of a pool.
+///
+/// The fee rises with the volume traded in the pool and with the pool imbalance, both relative to the pool liquidity,
+/// and decays towards `min_fee` by `decay` every block.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DynamicFeeParams {
+	pub min_fee: Permill,
+	pub max_fee: Permill,
+	pub decay: FixedU128,
+	pub amplification: FixedU128,
+}
+
+impl From<DynamicFeeParams> for FeeParams<Permill> {
+	fn from(value: DynamicFeeParams) -> Self {
+		FeeParams {
+			min_fee: value.min_fee,
+			max_fee: value.max_fee,
+			decay: value.decay,
+			amplification: value.amplification,
+		}
+	}
+}
+
+/// Last calculated dynamic fee of a pool and the block in which it was calculated.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DynamicFeeEntry<BlockNumber> {
+	pub fee: Permill,
+	pub updated_at: BlockNumber,
+}
+
+/// Interface to retrieve traded volume of pool assets, used to calculate dynamic fees.
+pub trait VolumeProvider<AssetId> {
+	/// Returns volume of `asset_id` going in and out of pool `pool_id`, as `(volume_in, volume_out)`.
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)>;
+}
+
+impl<AssetId> VolumeProvider<AssetId> for () {
+	fn asset_volume(_pool_id: AssetId, _asset_id: AssetId) -> Option<(Balance, Balance)> {
+		None
+	}
 }
 
 fn has_unique_elements<T>(iter: &mut T) -> bool
@@ -56,19 +128,29 @@ where
 		self.assets.len() >= 2 && has_unique_elements(&mut self.assets.iter())
 	}
 
-	pub(crate) fn reserves_with_decimals<T: Config>(&self, account: &T::AccountId) -> Option<Vec<AssetReserve>>
-	where
-		T::AssetId: From<AssetId>,
-	{
+	pub(crate) fn rate_source(&self, idx: usize) -> Option<&RateSource<AssetId>> {
+		self.rate_sources.get(idx).and_then(|v| v.as_ref())
+	}
+
+	/// Returns reserves of pool assets together with their decimals and rates.
+	pub(crate) fn reserves_with_decimals<T: Config<AssetId = AssetId>>(
+		&self,
+		account: &T::AccountId,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
 		self.assets
 			.iter()
-			.map(|asset| {
-				let reserve = T::Currency::free_balance((*asset).into(), account);
-				let decimals = Pallet::<T>::retrieve_decimals((*asset).into())?;
-				Some(AssetReserve {
-					amount: reserve,
-					decimals,
-				})
+			.enumerate()
+			.map(|(idx, asset)| {
+				let reserve = T::Currency::free_balance(*asset, account);
+				let decimals = Pallet::<T>::retrieve_decimals(*asset).ok_or(Error::<T>::UnknownDecimals)?;
+				let asset_reserve = AssetReserve::new(reserve, decimals);
+				match self.rate_source(idx) {
+					Some(source) => {
+						let rate = Pallet::<T>::retrieve_rate(*asset, source).ok_or(Error::<T>::RateNotAvailable)?;
+						Ok(asset_reserve.with_rate(rate))
+					}
+					None => Ok(asset_reserve),
+				}
 			})
 			.collect()
 	}
@@ -123,6 +205,9 @@ impl Default for Tradability {
 #[cfg(feature = "runtime-benchmarks")]
 pub trait BenchmarkHelper<AssetId> {
 	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
+
+	/// Make the rate of `asset_id` in `underlying` available and return its source.
+	fn prepare_rate_source(asset_id: AssetId, underlying: AssetId) -> Result<RateSource<AssetId>, DispatchError>;
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
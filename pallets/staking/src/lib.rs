@@ -31,12 +31,15 @@ use hydra_dx_math::staking as math;
 use orml_traits::{GetByKey, MultiCurrency, MultiLockableCurrency};
 use sp_core::Get;
 use sp_runtime::traits::{AccountIdConversion, CheckedAdd, One};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, DispatchError, FixedPointNumber, FixedU128, Rounding,
+};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Zero},
 	Perbill, Permill, SaturatedConversion,
 };
-use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
 use sp_std::num::NonZeroU128;
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -135,6 +138,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxVotes: Get<u32>;
 
+		/// Max amount of staking positions a single account can own.
+		#[pallet::constant]
+		type MaxPositionsPerAccount: Get<u32>;
+
 		/// NFT collection id.
 		#[pallet::constant]
 		type NFTCollectionId: Get<Self::CollectionId>;
@@ -265,6 +272,19 @@ pub mod pallet {
 			accumulated_rps: FixedU128,
 			total_stake: Balance,
 		},
+
+		/// Part of the staked amount was withdrawn from the position.
+		PartiallyUnstaked {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			unlocked_stake: Balance,
+			remaining_stake: Balance,
+			locked_rewards: Balance,
+			unlocked_rewards: Balance,
+			slashed_points: Point,
+			slashed_unpaid_rewards: Balance,
+			payable_percentage: FixedU128,
+		},
 	}
 
 	#[pallet::error]
@@ -308,6 +328,12 @@ pub mod pallet {
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
+
+		/// Account reached max number of staking positions.
+		MaxPositionsReached,
+
+		/// Stake remaining in the position after partial unstake would be lower than `MinStake`.
+		RemainingStakeTooLow,
 	}
 
 	// NOTE: these errors should never happen.
@@ -322,9 +348,6 @@ pub mod pallet {
 		/// Calculated `accumulated_unpaid_rewards` are less than 0.
 		NegativeUnpaidRewards,
 
-		/// Multiple positions exits for single account.
-		TooManyPositions,
-
 		/// Arithmetic error.
 		Arithmetic,
 	}
@@ -374,6 +397,7 @@ pub mod pallet {
 		/// `stake` locks specified `amount` into staking and creates new NFT representing staking
 		/// position.
 		/// Users can stake `NativeAssetId` balance which is not vested or already staked.
+		/// Single account can own up to `MaxPositionsPerAccount` independent staking positions.
 		///
 		/// Staking pallet must be initialized otherwise extrinsic will fail with error.
 		///
@@ -393,18 +417,18 @@ pub mod pallet {
 			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
 
 			ensure!(
-				Self::get_user_position_id(&who)?.is_none(),
-				Error::<T>::PositionAlreadyExists
+				(Self::get_user_position_ids(&who).len() as u32) < T::MaxPositionsPerAccount::get(),
+				Error::<T>::MaxPositionsReached
 			);
 
 			Staking::<T>::try_mutate(|staking| {
 				Self::update_rewards(staking)?;
 
-				Self::ensure_stakeable_balance(&who, amount, None)?;
+				Self::ensure_stakeable_balance(&who, amount)?;
 				let position_id =
 					Self::create_position_and_mint_nft(&who, amount, staking.accumulated_reward_per_stake)?;
 
-				T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who, amount)?;
+				Self::update_staking_lock(&who, None)?;
 
 				staking.add_stake(amount)?;
 
@@ -459,7 +483,7 @@ pub mod pallet {
 						.as_mut()
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
 
-					Self::ensure_stakeable_balance(&who, amount, Some(position))?;
+					Self::ensure_stakeable_balance(&who, amount)?;
 
 					Self::process_votes(&who, position_id, position)?;
 
@@ -514,12 +538,7 @@ pub mod pallet {
 
 					staking.add_stake(amount)?;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
-						&who,
-						position.get_total_locked()?,
-					)?;
+					Self::update_staking_lock(&who, Some((position_id, &*position)))?;
 
 					Self::deposit_event(Event::StakeAdded {
 						who,
@@ -614,12 +633,7 @@ pub mod pallet {
 						};
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
-						&who,
-						position.get_total_locked()?,
-					)?;
+					Self::update_staking_lock(&who, Some((position_id, &*position)))?;
 
 					staking.pot_reserved_balance = staking
 						.pot_reserved_balance
@@ -711,7 +725,7 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
-					T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who)?;
+					Self::update_staking_lock(&who, None)?;
 
 					Self::deposit_event(Event::RewardsClaimed {
 						who: who.clone(),
@@ -737,6 +751,146 @@ pub mod pallet {
 				})
 			})
 		}
+
+		/// Withdraw `amount` of the staked tokens from the staking position specified by `position_id`.
+		///
+		/// Function pays and locks rewards accumulated until this point to the position, same as
+		/// `increase_stake`. Position's locked rewards, unpaid rewards and points are then
+		/// reduced in the same proportion as the stake. Unlocked part of the locked rewards is
+		/// released to the user and slashed part of the unpaid rewards is returned to the Staking
+		/// for redistribution.
+		///
+		/// Position can't contain registered or processed votes and stake remaining in the position
+		/// can't be lower than `MinStake`. Use `unstake` to withdraw the whole position.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position to withdraw stake from.
+		/// - `amount`: Amount of staked tokens to withdraw from the position.
+		///
+		/// Emits `PartiallyUnstaked` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::partial_unstake())]
+		pub fn partial_unstake(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::InsufficientStake);
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			use frame_support::StorageDoubleMap;
+			ensure!(
+				PositionVotes::<T>::get(position_id).votes.is_empty() && !ProcessedVotes::<T>::contains_prefix(&who),
+				Error::<T>::ExistingVotes
+			);
+
+			Staking::<T>::try_mutate(|staking| {
+				Self::update_rewards(staking)?;
+
+				Positions::<T>::try_mutate(position_id, |maybe_position| {
+					let position = maybe_position
+						.as_mut()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+					let remaining_stake = position
+						.stake
+						.checked_sub(amount)
+						.ok_or(Error::<T>::RemainingStakeTooLow)?;
+					ensure!(remaining_stake >= T::MinStake::get(), Error::<T>::RemainingStakeTooLow);
+
+					let current_period = Self::get_current_period()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+					let created_at = Self::get_period_number(position.created_at)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					let (rewards, unpaid_rewards, payable_percentage) = Self::calculate_rewards(
+						position,
+						staking.accumulated_reward_per_stake,
+						current_period,
+						created_at,
+					)
+					.ok_or(Error::<T>::Arithmetic)?;
+
+					if !rewards.is_zero() {
+						let pot = Self::pot_account_id();
+						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
+
+						position.accumulated_locked_rewards = position
+							.accumulated_locked_rewards
+							.checked_add(rewards)
+							.ok_or(Error::<T>::Arithmetic)?;
+					}
+					position.reward_per_stake = staking.accumulated_reward_per_stake;
+
+					// Rewards and points are reduced proportionally to the withdrawn stake. Slashed
+					// points are rounded up, unlocked and returned rewards are rounded down.
+					let rewards_to_unlock = multiply_by_rational_with_rounding(
+						position.accumulated_locked_rewards,
+						amount,
+						position.stake,
+						Rounding::Down,
+					)
+					.ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_locked_rewards = position
+						.accumulated_locked_rewards
+						.checked_sub(rewards_to_unlock)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					let slashed_unpaid_rewards =
+						multiply_by_rational_with_rounding(unpaid_rewards, amount, position.stake, Rounding::Down)
+							.ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_unpaid_rewards = unpaid_rewards
+						.checked_sub(slashed_unpaid_rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					let points =
+						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+					let points_to_slash =
+						multiply_by_rational_with_rounding(points, amount, position.stake, Rounding::Up)
+							.ok_or(Error::<T>::Arithmetic)?;
+					position.accumulated_slash_points = position
+						.accumulated_slash_points
+						.checked_add(points_to_slash)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					position.stake = remaining_stake;
+
+					staking.total_stake = staking
+						.total_stake
+						.checked_sub(amount)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					staking.pot_reserved_balance = staking
+						.pot_reserved_balance
+						.checked_sub(rewards)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?
+						.checked_sub(slashed_unpaid_rewards)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					Self::update_staking_lock(&who, Some((position_id, &*position)))?;
+
+					Self::deposit_event(Event::PartiallyUnstaked {
+						who,
+						position_id,
+						unlocked_stake: amount,
+						remaining_stake,
+						locked_rewards: rewards,
+						unlocked_rewards: rewards_to_unlock,
+						slashed_points: points_to_slash,
+						slashed_unpaid_rewards,
+						payable_percentage,
+					});
+
+					Ok(())
+				})
+			})
+		}
 	}
 
 	#[pallet::hooks]
@@ -749,15 +903,14 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
-	fn ensure_stakeable_balance(
-		who: &T::AccountId,
-		stake: Balance,
-		position: Option<&Position<BlockNumberFor<T>>>,
-	) -> Result<(), DispatchError> {
+	fn ensure_stakeable_balance(who: &T::AccountId, stake: Balance) -> Result<(), DispatchError> {
 		let free_balance = T::Currency::free_balance(T::NativeAssetId::get(), who);
-		let staked = position
-			.map(|p| p.stake.saturating_add(p.accumulated_locked_rewards))
-			.unwrap_or_default();
+		let staked = Self::get_user_position_ids(who)
+			.into_iter()
+			.filter_map(Positions::<T>::get)
+			.fold(Balance::zero(), |acc, p| {
+				acc.saturating_add(p.stake.saturating_add(p.accumulated_locked_rewards))
+			});
 		let vested = T::Vesting::locked(who.clone());
 
 		//NOTE: locks overlay so vested + staked can be bigger than free_balance
@@ -771,19 +924,46 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Returns staking `PositionItemId` for `who` or `None`.
+	/// Returns `PositionItemId` of the oldest staking position of `who` or `None`.
 	pub fn get_user_position_id(who: &T::AccountId) -> Result<Option<T::PositionItemId>, DispatchError> {
-		let mut user_position_ids = T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), who);
+		Ok(Self::get_user_position_ids(who).first().copied())
+	}
 
-		let position_id = user_position_ids.next();
-		if position_id.is_some() {
-			ensure!(
-				user_position_ids.next().is_none(),
-				Error::<T>::InconsistentState(InconsistentStateError::TooManyPositions)
-			);
+	/// Returns `PositionItemId`s of all staking positions of `who` sorted from the oldest one.
+	pub fn get_user_position_ids(who: &T::AccountId) -> Vec<T::PositionItemId> {
+		let mut position_ids: Vec<T::PositionItemId> =
+			T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), who).collect();
+		position_ids.sort();
+
+		position_ids
+	}
+
+	/// Sets staking lock of `who` to the sum of total locked amounts of all user's positions.
+	/// Lock is removed if user doesn't have any locked amount.
+	///
+	/// `updated_position` is used instead of the stored position with the same id. This is
+	/// necessary when position was modified but not stored yet.
+	fn update_staking_lock(
+		who: &T::AccountId,
+		updated_position: Option<(T::PositionItemId, &Position<BlockNumberFor<T>>)>,
+	) -> DispatchResult {
+		let mut total_locked = Balance::zero();
+		for position_id in Self::get_user_position_ids(who) {
+			let locked = match updated_position {
+				Some((id, position)) if id == position_id => position.get_total_locked()?,
+				_ => Positions::<T>::get(position_id)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?
+					.get_total_locked()?,
+			};
+
+			total_locked = total_locked.checked_add(locked).ok_or(Error::<T>::Arithmetic)?;
 		}
 
-		Ok(position_id)
+		if total_locked.is_zero() {
+			T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who)
+		} else {
+			T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who, total_locked)
+		}
 	}
 
 	fn is_owner(who: &T::AccountId, id: T::PositionItemId) -> bool {
@@ -967,7 +1147,11 @@ impl<T: Config> Pallet<T> {
 					let points = Self::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
 					position.action_points = position.action_points.saturating_add(points);
 					// We need to keep the vote info to determine if the vote should be locked when removed.
-					ProcessedVotes::<T>::insert(who, *ref_idx, vote);
+					// Votes from multiple user's positions are merged because vote's lock is per account.
+					ProcessedVotes::<T>::mutate(who, *ref_idx, |maybe_vote| match maybe_vote {
+						Some(processed) => processed.amount = processed.amount.saturating_add(vote.amount),
+						None => *maybe_vote = Some(*vote),
+					});
 					false
 				} else {
 					true
@@ -196,6 +196,7 @@ parameter_types! {
 	pub const UnclaimablePeriods: BlockNumber = 10;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15,100);
 	pub const MaxVotes: u32 = 10;
+	pub const MaxPositionsPerAccount: u32 = 3;
 }
 
 impl pallet_staking::Config for Test {
@@ -220,6 +221,7 @@ impl pallet_staking::Config for Test {
 
 	type PayablePercentage = SigmoidPercentage<PointPercentage, ConstU32<40_000>>;
 	type MaxVotes = MaxVotes;
+	type MaxPositionsPerAccount = MaxPositionsPerAccount;
 	type MaxPointsPerAction = DummyMaxPointsPerAction;
 	type ReferendumInfo = DummyReferendumStatus;
 	type Vesting = DummyVesting;
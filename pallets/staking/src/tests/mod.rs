@@ -8,6 +8,7 @@ use orml_tokens::BalanceLock;
 mod claim;
 mod increase_stake;
 pub(crate) mod mock;
+mod partial_unstake;
 mod stake;
 #[allow(clippy::module_inception)]
 mod tests;
@@ -0,0 +1,362 @@
+use crate::types::{Conviction, Vote};
+
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn partial_unstake_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(DAVE), bob_position_id, 10_000 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_not_work_when_staking_is_not_initialized() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 250_000 * ONE)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let bob_position_id = 0;
+
+			//Act & assert
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(BOB), bob_position_id, 10_000 * ONE),
+				Error::<Test>::NotInitialized
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_not_work_when_amount_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(BOB), bob_position_id, 0),
+				Error::<Test>::InsufficientStake
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_not_work_when_remaining_stake_is_lower_than_min_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::partial_unstake(
+					RuntimeOrigin::signed(BOB),
+					bob_position_id,
+					120_000 * ONE - MinStake::get() + 1
+				),
+				Error::<Test>::RemainingStakeTooLow
+			);
+
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(BOB), bob_position_id, 120_000 * ONE),
+				Error::<Test>::RemainingStakeTooLow
+			);
+
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(BOB), bob_position_id, 120_000 * ONE + 1),
+				Error::<Test>::RemainingStakeTooLow
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_work_when_remaining_stake_is_min_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::partial_unstake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				120_000 * ONE - MinStake::get()
+			));
+
+			//Assert
+			assert_eq!(Staking::positions(bob_position_id).unwrap().stake, MinStake::get());
+		});
+}
+
+#[test]
+fn partial_unstake_should_work_when_staking_position_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::partial_unstake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				30_000 * ONE
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PartiallyUnstaked {
+				who: BOB,
+				position_id: bob_position_id,
+				unlocked_stake: 30_000 * ONE,
+				remaining_stake: 90_000 * ONE,
+				locked_rewards: 334_912_244_857_841_u128,
+				unlocked_rewards: 83_728_061_214_460_u128,
+				slashed_points: 10,
+				slashed_unpaid_rewards: 2_584_199_420_199_391_u128,
+				payable_percentage: FixedU128::from_inner(31_383_184_812_088_337_u128)
+			}
+			.into());
+
+			assert_eq!(
+				Staking::positions(bob_position_id).unwrap(),
+				Position {
+					stake: 90_000 * ONE,
+					action_points: 0,
+					reward_per_stake: FixedU128::from_inner(2_088_930_916_047_128_389_u128),
+					created_at: 1_452_987,
+					accumulated_slash_points: 10,
+					accumulated_unpaid_rewards: 7_752_598_260_598_174_u128,
+					accumulated_locked_rewards: 251_184_183_643_381_u128,
+				}
+			);
+
+			assert_unlocked_balance!(&BOB, HDX, 160_083_728_061_214_460_u128);
+			assert_hdx_lock!(BOB, 90_251_184_183_643_381_u128, STAKING_LOCK);
+
+			assert_staking_data!(
+				200_010 * ONE,
+				FixedU128::from_inner(2_088_930_916_047_128_389_u128),
+				217_080_888_334_942_769_u128 + NON_DUSTABLE_BALANCE
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_not_pay_rewards_when_called_during_unclaimable_periods() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_470_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::partial_unstake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				30_000 * ONE
+			));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PartiallyUnstaked {
+				who: BOB,
+				position_id: bob_position_id,
+				unlocked_stake: 30_000 * ONE,
+				remaining_stake: 90_000 * ONE,
+				locked_rewards: 0,
+				unlocked_rewards: 0,
+				slashed_points: 1,
+				slashed_unpaid_rewards: 2_667_927_481_413_851_u128,
+				payable_percentage: FixedU128::from(0_u128)
+			}
+			.into());
+
+			assert_eq!(
+				Staking::positions(bob_position_id).unwrap(),
+				Position {
+					stake: 90_000 * ONE,
+					action_points: 0,
+					reward_per_stake: FixedU128::from_inner(2_088_930_916_047_128_389_u128),
+					created_at: 1_452_987,
+					accumulated_slash_points: 1,
+					accumulated_unpaid_rewards: 8_003_782_444_241_555_u128,
+					accumulated_locked_rewards: 0,
+				}
+			);
+
+			assert_unlocked_balance!(&BOB, HDX, 160_000 * ONE);
+			assert_hdx_lock!(BOB, 90_000 * ONE, STAKING_LOCK);
+
+			assert_staking_data!(
+				200_010 * ONE,
+				FixedU128::from_inner(2_088_930_916_047_128_389_u128),
+				217_332_072_518_586_150_u128 + NON_DUSTABLE_BALANCE
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_keep_locks_of_other_positions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_500_000);
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(BOB), 50_000 * ONE));
+			let position_ids = Staking::get_user_position_ids(&BOB);
+			assert_eq!(position_ids.len(), 2);
+
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+
+			//Act
+			assert_ok!(Staking::partial_unstake(
+				RuntimeOrigin::signed(BOB),
+				position_ids[0],
+				30_000 * ONE
+			));
+
+			//Assert
+			let first_position = Staking::positions(position_ids[0]).unwrap();
+			let second_position = Staking::positions(position_ids[1]).unwrap();
+
+			assert_eq!(first_position.stake, 90_000 * ONE);
+			assert_eq!(second_position.stake, 50_000 * ONE);
+			assert_eq!(second_position.created_at, 1_500_000);
+			assert_hdx_lock!(
+				BOB,
+				first_position.get_total_locked().unwrap() + second_position.get_total_locked().unwrap(),
+				STAKING_LOCK
+			);
+		});
+}
+
+#[test]
+fn partial_unstake_should_fail_when_position_has_existing_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.with_votings(vec![(
+			0,
+			vec![(
+				1_u32,
+				Vote {
+					amount: 10_000 * ONE,
+					conviction: Conviction::Locked4x,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let alice_position_id = 0;
+
+			assert!(crate::PositionVotes::<Test>::contains_key(alice_position_id));
+
+			//Act & assert
+			assert_noop!(
+				Staking::partial_unstake(RuntimeOrigin::signed(ALICE), alice_position_id, 10_000 * ONE),
+				Error::<Test>::ExistingVotes
+			);
+		});
+}
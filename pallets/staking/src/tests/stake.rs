@@ -149,7 +149,42 @@ fn stake_should_work_when_staking_position_doesnt_exists() {
 }
 
 #[test]
-fn stake_should_not_work_when_staking_position_exits() {
+fn stake_should_create_new_position_when_staking_position_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 100_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let pending_rewards = 200_000 * ONE;
+			set_pending_rewards(pending_rewards);
+			let staked_amount = 40_000 * ONE;
+			set_block_number(1_500_000);
+
+			//Act
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(ALICE), staked_amount));
+
+			//Assert
+			assert_last_event!(Event::<Test>::PositionCreated {
+				who: ALICE,
+				position_id: 1,
+				stake: staked_amount
+			}
+			.into());
+			assert_eq!(Staking::get_user_position_ids(&ALICE), vec![0, 1]);
+			assert_eq!(Staking::get_user_position_id(&ALICE).unwrap(), Some(0));
+			assert_eq!(Staking::positions(0).unwrap().stake, 100_000 * ONE);
+			assert_eq!(Staking::positions(0).unwrap().created_at, 1_452_987);
+			assert_eq!(Staking::positions(1).unwrap().stake, staked_amount);
+			assert_eq!(Staking::positions(1).unwrap().created_at, 1_500_000);
+			assert_hdx_lock!(ALICE, 100_000 * ONE + staked_amount, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn stake_should_not_work_when_sum_of_positions_exceeds_stakeable_balance() {
 	ExtBuilder::default()
 		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
 		.with_initialized_staking()
@@ -159,12 +194,35 @@ fn stake_should_not_work_when_staking_position_exits() {
 			//Arrange
 			let pending_rewards = 200_000 * ONE;
 			set_pending_rewards(pending_rewards);
-			let staked_amount = 100_000 * ONE;
 
 			//Act & assert
 			assert_noop!(
-				Staking::stake(RuntimeOrigin::signed(ALICE), staked_amount),
-				Error::<Test>::PositionAlreadyExists
+				Staking::stake(RuntimeOrigin::signed(ALICE), 50_000 * ONE + 1),
+				Error::<Test>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn stake_should_not_work_when_max_positions_per_account_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.with_stakes(vec![(ALICE, 10_000 * ONE, 1_452_987, 100_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(ALICE), 10_000 * ONE));
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(ALICE), 10_000 * ONE));
+			assert_eq!(
+				Staking::get_user_position_ids(&ALICE).len() as u32,
+				MaxPositionsPerAccount::get()
+			);
+
+			//Act & assert
+			assert_noop!(
+				Staking::stake(RuntimeOrigin::signed(ALICE), 10_000 * ONE),
+				Error::<Test>::MaxPositionsReached
 			);
 		});
 }
@@ -0,0 +1,214 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::types::{AssetAmount, PoolAsset};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ASSET_ID_OFFSET: u32 = 2_000;
+const POOL_ID: u32 = 1_000;
+
+const INITIAL_LIQUIDITY: Balance = 1_000_000_000_000_000;
+const TRADE_AMOUNT: Balance = 10_000_000_000_000;
+
+/// Registers `n` pool assets and the share asset.
+/// Weights are split equally, the remainder is added to the first asset.
+fn register_pool_assets<T: Config>(n: u32) -> Result<Vec<PoolAsset<T::AssetId>>, DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	T::BenchmarkHelper::register_asset(POOL_ID.into(), 18)?;
+
+	let weight = MAX_WEIGHT / n;
+	let mut assets = Vec::new();
+	for idx in 0..n {
+		let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+		T::BenchmarkHelper::register_asset(asset_id, 12)?;
+		assets.push(PoolAsset { asset_id, weight });
+	}
+	assets[0].weight = weight.saturating_add(MAX_WEIGHT % n);
+
+	Ok(assets)
+}
+
+/// Creates a pool of `n` assets and adds initial liquidity of each asset.
+fn create_pool_with_liquidity<T: Config>(n: u32) -> Result<Vec<T::AssetId>, DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let assets = register_pool_assets::<T>(n)?;
+	let asset_ids: Vec<T::AssetId> = assets.iter().map(|a| a.asset_id).collect();
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::create_pool(successful_origin, POOL_ID.into(), assets, Permill::from_percent(1))?;
+
+	let caller: T::AccountId = account("caller", 0, 1);
+	let mut initial = Vec::new();
+	for asset_id in asset_ids.iter() {
+		T::Currency::update_balance(*asset_id, &caller, INITIAL_LIQUIDITY as i128)?;
+		initial.push(AssetAmount::new(*asset_id, INITIAL_LIQUIDITY));
+	}
+	Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(), POOL_ID.into(), initial)?;
+
+	Ok(asset_ids)
+}
+
+benchmarks! {
+	where_clause { where T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+
+	create_pool {
+		let n in 2..MAX_ASSETS_IN_POOL;
+
+		let assets = register_pool_assets::<T>(n)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, POOL_ID.into(), assets, Permill::from_percent(1))
+	verify {
+		assert!(Pools::<T>::get::<T::AssetId>(POOL_ID.into()).is_some());
+	}
+
+	update_pool_fee {
+		create_pool_with_liquidity::<T>(2)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, POOL_ID.into(), Permill::from_percent(2))
+	verify {
+		assert_eq!(Pools::<T>::get::<T::AssetId>(POOL_ID.into()).unwrap().fee, Permill::from_percent(2));
+	}
+
+	add_liquidity {
+		let n in 2..MAX_ASSETS_IN_POOL;
+
+		// Worst case is adding additional liquidity and not initial liquidity
+		let asset_ids = create_pool_with_liquidity::<T>(n)?;
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let mut added = Vec::new();
+		for asset_id in asset_ids.iter() {
+			T::Currency::update_balance(*asset_id, &lp_provider, TRADE_AMOUNT as i128)?;
+			added.push(AssetAmount::new(*asset_id, TRADE_AMOUNT));
+		}
+	}: _(RawOrigin::Signed(lp_provider.clone()), POOL_ID.into(), added)
+	verify {
+		assert_eq!(T::Currency::free_balance(POOL_ID.into(), &lp_provider), TRADE_AMOUNT);
+	}
+
+	remove_liquidity {
+		let n in 2..MAX_ASSETS_IN_POOL;
+
+		let asset_ids = create_pool_with_liquidity::<T>(n)?;
+
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let mut added = Vec::new();
+		for asset_id in asset_ids.iter() {
+			T::Currency::update_balance(*asset_id, &lp_provider, TRADE_AMOUNT as i128)?;
+			added.push(AssetAmount::new(*asset_id, TRADE_AMOUNT));
+		}
+		Pallet::<T>::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), POOL_ID.into(), added)?;
+		let shares = T::Currency::free_balance(POOL_ID.into(), &lp_provider);
+	}: _(RawOrigin::Signed(lp_provider.clone()), POOL_ID.into(), shares)
+	verify {
+		assert!(T::Currency::free_balance(POOL_ID.into(), &lp_provider).is_zero());
+	}
+
+	sell {
+		let asset_ids = create_pool_with_liquidity::<T>(MAX_ASSETS_IN_POOL)?;
+		let asset_in = *asset_ids.last().unwrap();
+		let asset_out = *asset_ids.first().unwrap();
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(asset_in, &seller, TRADE_AMOUNT as i128)?;
+		let buy_min_amount = 1_000u128;
+	}: _(RawOrigin::Signed(seller.clone()), POOL_ID.into(), asset_in, asset_out, TRADE_AMOUNT, buy_min_amount)
+	verify {
+		assert!(T::Currency::free_balance(asset_in, &seller).is_zero());
+		assert!(T::Currency::free_balance(asset_out, &seller) >= buy_min_amount);
+	}
+
+	buy {
+		let asset_ids = create_pool_with_liquidity::<T>(MAX_ASSETS_IN_POOL)?;
+		let asset_in = *asset_ids.last().unwrap();
+		let asset_out = *asset_ids.first().unwrap();
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(asset_in, &buyer, (2 * TRADE_AMOUNT) as i128)?;
+	}: _(RawOrigin::Signed(buyer.clone()), POOL_ID.into(), asset_out, asset_in, TRADE_AMOUNT, 2 * TRADE_AMOUNT)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_out, &buyer), TRADE_AMOUNT);
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// number of times calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let asset_ids = create_pool_with_liquidity::<T>(MAX_ASSETS_IN_POOL)?;
+		let asset_in = *asset_ids.last().unwrap();
+		let asset_out = *asset_ids.first().unwrap();
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		T::Currency::update_balance(asset_in, &seller, TRADE_AMOUNT as i128)?;
+		let buy_min_amount = 1_000u128;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::Weighted(POOL_ID.into()), asset_in, asset_out, TRADE_AMOUNT).is_ok());
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::Weighted(POOL_ID.into()), asset_in, asset_out, TRADE_AMOUNT, buy_min_amount).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert!(T::Currency::free_balance(asset_in, &seller).is_zero());
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..3;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let asset_ids = create_pool_with_liquidity::<T>(MAX_ASSETS_IN_POOL)?;
+		let asset_in = *asset_ids.last().unwrap();
+		let asset_out = *asset_ids.first().unwrap();
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		T::Currency::update_balance(asset_in, &buyer, (2 * TRADE_AMOUNT) as i128)?;
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Weighted(POOL_ID.into()), asset_in, asset_out, TRADE_AMOUNT).is_ok());
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::Weighted(POOL_ID.into()), asset_in, asset_out, TRADE_AMOUNT, 2 * TRADE_AMOUNT).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(asset_out, &buyer), TRADE_AMOUNT);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let asset_ids = create_pool_with_liquidity::<T>(MAX_ASSETS_IN_POOL)?;
+		let asset_a = *asset_ids.last().unwrap();
+		let asset_b = *asset_ids.first().unwrap();
+	}: {
+		assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Weighted(POOL_ID.into()), asset_a, asset_b).is_ok());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,874 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Weighted pool pallet
+//!
+//! Balancer style AMM with fixed asset weights.
+//!
+//! ## Overview
+//!
+//! Weighted pool holds up to 8 assets, each with a fixed weight. Value of each asset reserve in the pool is
+//! proportional to its weight. Trade amounts are calculated by the weighted math of the LBP pallet,
+//! but the weights do not change over time and the pool is permanent.
+//!
+//! Each pool has its own share token, which is also the pool identifier.
+//!
+//! ### Oracle
+//!
+//! Pool feeds the EMA oracle via `AMMHandler` with the price of the share token in each pool asset.
+//! Price of an asset pair is obtained by combining the prices of both assets against the share token.
+//!
+//! ### Terminology
+//!
+//! * **LP** - liquidity provider
+//! * **Share Token** - a token representing share asset of specific pool. Each pool has its own share token.
+//! * **Weight** - portion of the pool value held in given asset. Weights of all pool assets sum up to `MAX_WEIGHT`.
+//!
+//! ## Assumptions
+//!
+//! Maximum number of assets in pool is 8.
+//!
+//! A pool can be created only by allowed `AuthorityOrigin`.
+//!
+//! Liquidity is added and removed in proportion to the pool reserves, so it does not change the asset prices.
+//!
+//! First LP sets the initial prices by the amounts provided and receives the amount of the first pool asset in shares.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::{ensure, require_transactional, transactional};
+use hydra_dx_math::ratio::Ratio;
+use hydra_dx_math::support::rational::{round_to_rational, Rounding};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{registry::Inspect, AccountIdFor, OnLiquidityChangedHandler, OnTradeHandler, Source};
+use orml_traits::MultiCurrency;
+pub use pallet::*;
+use primitive_types::U256;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, Permill};
+use sp_std::prelude::*;
+use sp_std::vec;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{AssetAmount, AssetWeight, Balance, PoolAsset, PoolInfo};
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// Weighted pool account id identifier.
+/// Used as identifier to create pool account id.
+pub const POOL_IDENTIFIER: &[u8] = b"wtp";
+
+pub const MAX_ASSETS_IN_POOL: u32 = 8;
+
+/// Sum of weights of all pool assets.
+pub const MAX_WEIGHT: AssetWeight = 100_000_000;
+
+/// Minimum weight of a pool asset (2%).
+pub const MIN_WEIGHT: AssetWeight = MAX_WEIGHT / 50;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Account ID constructor - pool account are derived from unique pool id
+		type ShareAccountId: AccountIdFor<Self::AssetId, AccountId = Self::AccountId>;
+
+		/// Asset registry mechanism to check if asset is registered.
+		type AssetInspection: Inspect<AssetId = Self::AssetId>;
+
+		/// The origin which can create a new pool and update its fee.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// AMM handler executed on trades and liquidity changes. Used to feed the oracle.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Minimum pool liquidity
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Max fraction of pool reserve to sell in single transaction
+		#[pallet::constant]
+		type MaxInRatio: Get<u128>;
+
+		/// Max fraction of pool reserve to buy in single transaction
+		#[pallet::constant]
+		type MaxOutRatio: Get<u128>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Existing pools
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, PoolInfo<T::AssetId>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			pool_id: T::AssetId,
+			assets: Vec<PoolAsset<T::AssetId>>,
+			fee: Permill,
+		},
+		/// Pool fee has been updated.
+		FeeUpdated { pool_id: T::AssetId, fee: Permill },
+		/// Liquidity was added to a pool.
+		LiquidityAdded {
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			assets: Vec<AssetAmount<T::AssetId>>,
+		},
+		/// Liquidity removed.
+		LiquidityRemoved {
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			amounts: Vec<AssetAmount<T::AssetId>>,
+		},
+		/// Sell trade executed. Trade fee paid in asset leaving the pool (already subtracted from amount_out).
+		SellExecuted {
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+		/// Buy trade executed. Trade fee paid in asset entering the pool (already included in amount_in).
+		BuyExecuted {
+			who: T::AccountId,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool with same assets or less than 2 assets is not allowed.
+		IncorrectAssets,
+
+		/// Maximum number of assets has been exceeded.
+		MaxAssetsExceeded,
+
+		/// Asset weights must be at least `MIN_WEIGHT` and sum up to `MAX_WEIGHT`.
+		InvalidWeights,
+
+		/// A pool with given assets does not exist.
+		PoolNotFound,
+
+		/// A pool with given assets already exists.
+		PoolExists,
+
+		/// Asset is not in the pool.
+		AssetNotInPool,
+
+		/// Share asset is not registered in Registry.
+		ShareAssetNotRegistered,
+
+		/// Share asset is amount assets when creating a pool.
+		ShareAssetInPoolAssets,
+
+		/// One or more assets are not registered in AssetRegistry
+		AssetNotRegistered,
+
+		/// Invalid asset amount provided. Amount must be greater than zero.
+		InvalidAssetAmount,
+
+		/// Balance of an asset is not sufficient to perform a trade.
+		InsufficientBalance,
+
+		/// Balance of a share asset is not sufficient to withdraw liquidity.
+		InsufficientShares,
+
+		/// Pool has no liquidity of an asset.
+		InsufficientLiquidity,
+
+		/// Amount is less than the minimum trading amount configured.
+		InsufficientTradingAmount,
+
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+
+		/// Trade amount is too high compared to the reserve of asset in.
+		MaxInRatioExceeded,
+
+		/// Trade amount is too high compared to the reserve of asset out.
+		MaxOutRatioExceeded,
+
+		/// Remaining balance of share asset is below minimum pool liquidity.
+		InsufficientShareBalance,
+
+		/// Not allowed to perform an operation on given asset.
+		NotAllowed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a weighted pool with given list of assets and their weights.
+		///
+		/// All assets must be correctly registered in `T::AssetRegistry`.
+		/// Note that this does not seed the pool with liquidity. Use `add_liquidity` to provide
+		/// initial liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `assets`: List of assets with their weights. Weights must sum up to `MAX_WEIGHT`.
+		/// - `fee`: fee to be applied on trades
+		///
+		/// Emits `PoolCreated` event if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool(assets.len() as u32))]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			share_asset: T::AssetId,
+			assets: Vec<PoolAsset<T::AssetId>>,
+			fee: Permill,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Self::do_create_pool(share_asset, &assets, fee)?;
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id: share_asset,
+				assets: pool.assets.into_inner(),
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Update pool's fee.
+		///
+		/// if pool does not exist, `PoolNotFound` is returned.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `fee`: new pool fee
+		///
+		/// Emits `FeeUpdated` event if successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		#[transactional]
+		pub fn update_pool_fee(origin: OriginFor<T>, pool_id: T::AssetId, fee: Permill) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				pool.fee = fee;
+				Self::deposit_event(Event::FeeUpdated { pool_id, fee });
+				Ok(())
+			})
+		}
+
+		/// Add liquidity to selected pool.
+		///
+		/// Amount of each pool asset must be provided. Liquidity is added in proportion to the pool reserves -
+		/// provided amounts are the maximum amounts taken from the LP. Initial liquidity is taken as provided
+		/// and sets the initial prices of pool assets.
+		///
+		/// LP must have sufficient amount of each asset.
+		///
+		/// Origin is given corresponding amount of shares.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `assets`: maximum amount of each pool asset to add
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity(MAX_ASSETS_IN_POOL)
+							.saturating_add(Pallet::<T>::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			assets: Vec<AssetAmount<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (shares, added) = Self::do_add_liquidity(&who, pool_id, &assets)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				pool_id,
+				who,
+				shares,
+				assets: added,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from selected pool.
+		///
+		/// LP receives amount of each pool asset in proportion to the pool reserves.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `share_amount`: amount of shares to withdraw
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity(MAX_ASSETS_IN_POOL)
+							.saturating_add(Pallet::<T>::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: T::AssetId, share_amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+
+			let remaining = current_share_balance.saturating_sub(share_amount);
+			ensure!(
+				remaining.is_zero() || remaining >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			let mut amounts = Vec::with_capacity(pool.assets.len());
+			for asset in pool.assets.iter() {
+				let reserve = T::Currency::free_balance(asset.asset_id, &pool_account);
+				let amount = multiply_by_rational_with_rounding(
+					share_amount,
+					reserve,
+					share_issuance,
+					sp_runtime::Rounding::Down,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+				T::Currency::transfer(asset.asset_id, &pool_account, &who, amount)?;
+				amounts.push(AssetAmount::new(asset.asset_id, amount));
+			}
+
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+
+			Self::call_on_liquidity_change_hook(pool_id, &pool, &amounts, share_amount)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares: share_amount,
+				amounts,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to put in.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: Id of a pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset to be sold to the pool
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+							.saturating_add(Pallet::<T>::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
+			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			let pool_account = Self::pool_account(pool_id);
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::call_on_trade_hook(pool_id, asset_in, asset_out, amount_in, amount_out)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				fee: fee_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to get out.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: Id of a pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: Amount of asset to receive from the pool
+		/// - `max_sell_amount`: Maximum amount allowed to be sold
+		///
+		/// Emits `BuyExecuted` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+							.saturating_add(Pallet::<T>::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::call_on_trade_hook(pool_id, asset_in, asset_out, amount_in, amount_out)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				fee: fee_amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	#[inline]
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
+	}
+
+	/// Loads pool, weights and reserves of given asset pair.
+	/// Returns (pool, (reserve_in, weight_in), (reserve_out, weight_out)).
+	#[allow(clippy::type_complexity)]
+	fn load_pair(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<(PoolInfo<T::AssetId>, (Balance, AssetWeight), (Balance, AssetWeight)), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		let (_, weight_in) = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let (_, weight_out) = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+
+		let pool_account = Self::pool_account(pool_id);
+		let reserve_in = T::Currency::free_balance(asset_in, &pool_account);
+		let reserve_out = T::Currency::free_balance(asset_out, &pool_account);
+
+		ensure!(!reserve_in.is_zero(), Error::<T>::InsufficientLiquidity);
+		ensure!(!reserve_out.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		Ok((pool, (reserve_in, weight_in), (reserve_out, weight_out)))
+	}
+
+	/// Calculates out amount given in amount.
+	/// Returns (out_amount, fee_amount) on success. Note that fee amount is already subtracted from the out amount.
+	pub(crate) fn calculate_out_amount(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (pool, (reserve_in, weight_in), (reserve_out, weight_out)) = Self::load_pair(pool_id, asset_in, asset_out)?;
+
+		ensure!(
+			amount_in
+				<= reserve_in
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let amount_out =
+			hydra_dx_math::lbp::calculate_out_given_in(reserve_in, reserve_out, weight_in, weight_out, amount_in)
+				.map_err(|_| ArithmeticError::Overflow)?;
+
+		let fee_amount = pool.fee.mul_ceil(amount_out);
+		let amount_out = amount_out.saturating_sub(fee_amount);
+
+		ensure!(
+			amount_out
+				<= reserve_out
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Ok((amount_out, fee_amount))
+	}
+
+	/// Calculates in amount given out amount.
+	/// Returns (in_amount, fee_amount) on success. Note that fee amount is already added to the in amount.
+	pub(crate) fn calculate_in_amount(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (pool, (reserve_in, weight_in), (reserve_out, weight_out)) = Self::load_pair(pool_id, asset_in, asset_out)?;
+
+		ensure!(
+			amount_out
+				<= reserve_out
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxOutRatioExceeded
+		);
+		ensure!(amount_out < reserve_out, Error::<T>::InsufficientLiquidity);
+
+		let amount_in =
+			hydra_dx_math::lbp::calculate_in_given_out(reserve_in, reserve_out, weight_in, weight_out, amount_out)
+				.map_err(|_| ArithmeticError::Overflow)?;
+
+		let fee_amount = pool.fee.mul_ceil(amount_in);
+		let amount_in = amount_in.checked_add(fee_amount).ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			amount_in
+				<= reserve_in
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Ok((amount_in, fee_amount))
+	}
+
+	#[require_transactional]
+	fn do_create_pool(
+		share_asset: T::AssetId,
+		assets: &[PoolAsset<T::AssetId>],
+		fee: Permill,
+	) -> Result<PoolInfo<T::AssetId>, DispatchError> {
+		ensure!(!Pools::<T>::contains_key(share_asset), Error::<T>::PoolExists);
+		ensure!(
+			T::AssetInspection::exists(share_asset),
+			Error::<T>::ShareAssetNotRegistered
+		);
+
+		ensure!(
+			!assets.iter().any(|v| v.asset_id == share_asset),
+			Error::<T>::ShareAssetInPoolAssets
+		);
+
+		let mut pool_assets = assets.to_vec();
+		pool_assets.sort_by_key(|v| v.asset_id);
+
+		let pool = PoolInfo {
+			assets: pool_assets.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?,
+			fee,
+		};
+		ensure!(pool.has_valid_assets(), Error::<T>::IncorrectAssets);
+		ensure!(pool.has_valid_weights(), Error::<T>::InvalidWeights);
+
+		for asset in pool.assets.iter() {
+			ensure!(
+				T::AssetInspection::exists(asset.asset_id),
+				Error::<T>::AssetNotRegistered
+			);
+		}
+
+		Pools::<T>::insert(share_asset, pool.clone());
+		T::DustAccountHandler::add_account(&Self::pool_account(share_asset))?;
+		Ok(pool)
+	}
+
+	/// Adds liquidity in proportion to the pool reserves.
+	/// Returns amount of shares minted and amounts of assets added.
+	#[require_transactional]
+	fn do_add_liquidity(
+		who: &T::AccountId,
+		pool_id: T::AssetId,
+		assets: &[AssetAmount<T::AssetId>],
+	) -> Result<(Balance, Vec<AssetAmount<T::AssetId>>), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(assets.len() == pool.assets.len(), Error::<T>::IncorrectAssets);
+
+		let pool_account = Self::pool_account(pool_id);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+
+		// Provided amounts and current reserves, in order of pool assets
+		let mut provided = Vec::with_capacity(pool.assets.len());
+		for pool_asset in pool.assets.iter() {
+			let amount = assets
+				.iter()
+				.find(|v| v.asset_id == pool_asset.asset_id)
+				.map(|v| v.amount)
+				.ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			let reserve = T::Currency::free_balance(pool_asset.asset_id, &pool_account);
+			provided.push((pool_asset.asset_id, amount, reserve));
+		}
+
+		let (share_amount, added) = if share_issuance.is_zero() {
+			let share_amount = provided.first().map(|(_, amount, _)| *amount).unwrap_or_default();
+			let added: Vec<AssetAmount<T::AssetId>> = provided
+				.iter()
+				.map(|(asset_id, amount, _)| AssetAmount::new(*asset_id, *amount))
+				.collect();
+			(share_amount, added)
+		} else {
+			let mut share_amount = Balance::MAX;
+			for (_, amount, reserve) in provided.iter() {
+				ensure!(!reserve.is_zero(), Error::<T>::InsufficientLiquidity);
+				let shares =
+					multiply_by_rational_with_rounding(*amount, share_issuance, *reserve, sp_runtime::Rounding::Down)
+						.ok_or(ArithmeticError::Overflow)?;
+				share_amount = share_amount.min(shares);
+			}
+
+			let mut added = Vec::with_capacity(provided.len());
+			for (asset_id, _, reserve) in provided.iter() {
+				let amount = multiply_by_rational_with_rounding(
+					share_amount,
+					*reserve,
+					share_issuance,
+					sp_runtime::Rounding::Up,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+				added.push(AssetAmount::new(*asset_id, amount));
+			}
+			(share_amount, added)
+		};
+
+		ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
+
+		let current_share_balance = T::Currency::free_balance(pool_id, who);
+		ensure!(
+			current_share_balance.saturating_add(share_amount) >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientShareBalance
+		);
+
+		for asset in added.iter() {
+			ensure!(
+				T::Currency::free_balance(asset.asset_id, who) >= asset.amount,
+				Error::<T>::InsufficientBalance
+			);
+			T::Currency::transfer(asset.asset_id, who, &pool_account, asset.amount)?;
+		}
+
+		T::Currency::deposit(pool_id, who, share_amount)?;
+
+		Self::call_on_liquidity_change_hook(pool_id, &pool, &added, share_amount)?;
+
+		Ok((share_amount, added))
+	}
+
+	/// Price of one share denominated in pool asset - value of the pool in the asset divided by share issuance.
+	fn share_price(reserve: Balance, weight: AssetWeight, share_issuance: Balance) -> Result<Ratio, DispatchError> {
+		let n = U256::from(reserve)
+			.checked_mul(U256::from(MAX_WEIGHT))
+			.ok_or(ArithmeticError::Overflow)?;
+		let d = U256::from(weight)
+			.checked_mul(U256::from(share_issuance))
+			.ok_or(ArithmeticError::Overflow)?;
+		let (n, d) = round_to_rational((n, d), Rounding::Nearest);
+		Ok(Ratio::new(n, d))
+	}
+
+	fn call_on_trade_hook(
+		pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let pool_account = Self::pool_account(pool_id);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		if share_issuance.is_zero() {
+			return Ok(());
+		}
+
+		for (asset_id, amount) in [(asset_in, amount_in), (asset_out, amount_out)] {
+			let (_, weight) = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			let reserve = T::Currency::free_balance(asset_id, &pool_account);
+			T::AMMHandler::on_trade(
+				T::OracleSource::get(),
+				asset_id,
+				pool_id,
+				amount,
+				0,
+				reserve,
+				share_issuance,
+				Self::share_price(reserve, weight, share_issuance)?,
+			)
+			.map_err(|(_, e)| e)?;
+		}
+
+		Ok(())
+	}
+
+	fn call_on_liquidity_change_hook(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId>,
+		amounts: &[AssetAmount<T::AssetId>],
+		share_amount: Balance,
+	) -> DispatchResult {
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		// Share price is not defined when the last liquidity has been removed.
+		if share_issuance.is_zero() {
+			return Ok(());
+		}
+
+		let pool_account = Self::pool_account(pool_id);
+		for (asset, amount) in pool.assets.iter().zip(amounts.iter()) {
+			let reserve = T::Currency::free_balance(asset.asset_id, &pool_account);
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset.asset_id,
+				pool_id,
+				amount.amount,
+				share_amount,
+				reserve,
+				share_issuance,
+				Self::share_price(reserve, asset.weight, share_issuance)?,
+			)
+			.map_err(|(_, e)| e)?;
+		}
+
+		Ok(())
+	}
+
+	pub(crate) fn on_trade_weight() -> frame_support::weights::Weight {
+		<T::AMMHandler as OnTradeHandler<T::AssetId, Balance, Ratio>>::on_trade_weight().saturating_mul(2)
+	}
+
+	pub(crate) fn on_liquidity_changed_weight() -> frame_support::weights::Weight {
+		<T::AMMHandler as OnLiquidityChangedHandler<T::AssetId, Balance, Ratio>>::on_liquidity_changed_weight()
+			.saturating_mul(MAX_ASSETS_IN_POOL as u64)
+	}
+}
@@ -0,0 +1,189 @@
+use crate::tests::mock::*;
+use crate::types::{PoolAsset, PoolInfo};
+use crate::{Error, Event, Pools, MAX_WEIGHT};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+#[test]
+fn create_pool_should_work_when_assets_and_weights_are_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(WeightedPool::create_pool(
+			RuntimeOrigin::root(),
+			POOL,
+			vec![pool_asset(DOT, 20), pool_asset(HDX, 50), pool_asset(DAI, 30)],
+			Permill::from_percent(1),
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(POOL).unwrap(),
+			PoolInfo {
+				assets: default_pool_assets().try_into().unwrap(),
+				fee: Permill::from_percent(1),
+			}
+		);
+		assert!(DUSTER_WHITELIST.with(|v| v.borrow().contains(&pool_account())));
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id: POOL,
+			assets: default_pool_assets(),
+			fee: Permill::from_percent(1),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				default_pool_assets(),
+				Permill::from_percent(1)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_weights_do_not_sum_up_to_max_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 50), pool_asset(DAI, 40)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::InvalidWeights
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_weight_is_below_minimum() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 1), pool_asset(DAI, 99)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::InvalidWeights
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_not_unique() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 50), pool_asset(HDX, 50)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::IncorrectAssets
+		);
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![PoolAsset {
+					asset_id: HDX,
+					weight: MAX_WEIGHT
+				}],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_max_assets_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let assets = (10..19).map(|asset_id| pool_asset(asset_id, 11)).collect();
+		assert_noop!(
+			WeightedPool::create_pool(RuntimeOrigin::root(), POOL, assets, Permill::from_percent(1)),
+			Error::<Test>::MaxAssetsExceeded
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_share_asset_is_pool_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 50), pool_asset(POOL, 50)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::ShareAssetInPoolAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 50), pool_asset(50, 50)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_exists() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::create_pool(
+				RuntimeOrigin::root(),
+				POOL,
+				vec![pool_asset(HDX, 50), pool_asset(ETH, 50)],
+				Permill::from_percent(1)
+			),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::update_pool_fee(
+			RuntimeOrigin::root(),
+			POOL,
+			Permill::from_percent(3)
+		));
+
+		assert_eq!(Pools::<Test>::get(POOL).unwrap().fee, Permill::from_percent(3));
+		expect_events(vec![Event::FeeUpdated {
+			pool_id: POOL,
+			fee: Permill::from_percent(3),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::update_pool_fee(RuntimeOrigin::root(), POOL, Permill::from_percent(3)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
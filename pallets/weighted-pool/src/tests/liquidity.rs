@@ -0,0 +1,194 @@
+use crate::tests::mock::*;
+use crate::types::AssetAmount;
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn initial_liquidity_should_mint_shares_equal_to_first_asset_amount() {
+	default_builder().build().execute_with(|| {
+		assert_balance!(ALICE, POOL, 50_000 * ONE);
+		assert_balance!(pool_account(), HDX, 50_000 * ONE);
+		assert_balance!(pool_account(), DAI, 3_000 * ONE);
+		assert_balance!(pool_account(), DOT, 400 * ONE);
+
+		expect_events(vec![Event::LiquidityAdded {
+			pool_id: POOL,
+			who: ALICE,
+			shares: 50_000 * ONE,
+			assets: default_initial_liquidity(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_take_amounts_in_proportion_to_reserves() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			vec![
+				AssetAmount::new(HDX, 1_000 * ONE),
+				AssetAmount::new(DAI, 100 * ONE),
+				AssetAmount::new(DOT, 10 * ONE),
+			]
+		));
+
+		// HDX is the limiting asset - 2% of the pool
+		assert_balance!(BOB, POOL, 1_000 * ONE);
+		assert_balance!(BOB, HDX, 9_000 * ONE);
+		assert_balance!(BOB, DAI, 940 * ONE);
+		assert_balance!(BOB, DOT, 92 * ONE);
+		assert_balance!(pool_account(), HDX, 51_000 * ONE);
+		assert_balance!(pool_account(), DAI, 3_060 * ONE);
+		assert_balance!(pool_account(), DOT, 408 * ONE);
+
+		expect_events(vec![Event::LiquidityAdded {
+			pool_id: POOL,
+			who: BOB,
+			shares: 1_000 * ONE,
+			assets: vec![
+				AssetAmount::new(HDX, 1_000 * ONE),
+				AssetAmount::new(DAI, 60 * ONE),
+				AssetAmount::new(DOT, 8 * ONE),
+			],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_not_all_pool_assets_are_provided() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				POOL,
+				vec![AssetAmount::new(HDX, 1_000 * ONE), AssetAmount::new(DAI, 100 * ONE)]
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_asset_is_not_in_pool() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				POOL,
+				vec![
+					AssetAmount::new(HDX, 1_000 * ONE),
+					AssetAmount::new(DAI, 100 * ONE),
+					AssetAmount::new(ETH, 10 * ONE),
+				]
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_not_sufficient() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				POOL,
+				vec![
+					AssetAmount::new(HDX, 20_000 * ONE),
+					AssetAmount::new(DAI, 2_000 * ONE),
+					AssetAmount::new(DOT, 200 * ONE),
+				]
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::add_liquidity(RuntimeOrigin::signed(BOB), POOL, default_initial_liquidity()),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_return_amounts_in_proportion_to_reserves() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			5_000 * ONE
+		));
+
+		assert_balance!(ALICE, POOL, 45_000 * ONE);
+		assert_balance!(ALICE, HDX, 55_000 * ONE);
+		assert_balance!(ALICE, DAI, 7_300 * ONE);
+		assert_balance!(ALICE, DOT, 640 * ONE);
+		assert_eq!(Tokens::total_issuance(POOL), 45_000 * ONE);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			pool_id: POOL,
+			who: ALICE,
+			shares: 5_000 * ONE,
+			amounts: vec![
+				AssetAmount::new(HDX, 5_000 * ONE),
+				AssetAmount::new(DAI, 300 * ONE),
+				AssetAmount::new(DOT, 40 * ONE),
+			],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_empty_pool_when_all_shares_are_removed() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			50_000 * ONE
+		));
+
+		assert_balance!(ALICE, POOL, 0);
+		assert_balance!(pool_account(), HDX, 0);
+		assert_balance!(pool_account(), DAI, 0);
+		assert_balance!(pool_account(), DOT, 0);
+		assert_eq!(Tokens::total_issuance(POOL), 0);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_not_sufficient() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::remove_liquidity(RuntimeOrigin::signed(BOB), POOL, ONE),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_shares_are_below_minimum() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL, 50_000 * ONE - 1),
+			Error::<Test>::InsufficientShareBalance
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_amount_is_zero() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL, 0),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
@@ -0,0 +1,414 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Weighted pool pallet.
+#![allow(clippy::type_complexity)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_weighted_pool;
+use crate::types::{AssetAmount, PoolAsset};
+use crate::Config;
+
+use frame_support::assert_ok;
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AccountIdFor, Inspect, OnLiquidityChangedHandler, OnTradeHandler, Source};
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 1;
+pub const DOT: AssetId = 2;
+pub const ETH: AssetId = 3;
+pub const POOL: AssetId = 100;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const WEIGHTED_SOURCE: Source = *b"weighted";
+
+/// Oracle activity recorded by `OracleHandlerMock` - (asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price)
+pub type OracleEntry = (AssetId, AssetId, Balance, Balance, Balance, Ratio);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static ORACLE_TRADES: RefCell<Vec<OracleEntry>> = RefCell::new(Vec::new());
+	pub static ORACLE_LIQUIDITY: RefCell<Vec<OracleEntry>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		WeightedPool: pallet_weighted_pool,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1_000_000;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub const MaxInRatio: u128 = 3;
+	pub const MaxOutRatio: u128 = 3;
+	pub const OracleSource: Source = WEIGHTED_SOURCE;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Whitelist;
+	type AMMHandler = OracleHandlerMock;
+	type OracleSource = OracleSource;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type MinTradingLimit = MinimumTradingLimit;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pool: Option<(Vec<PoolAsset<AssetId>>, Permill, Vec<AssetAmount<AssetId>>)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		ORACLE_TRADES.with(|v| v.borrow_mut().clear());
+		ORACLE_LIQUIDITY.with(|v| v.borrow_mut().clear());
+		Self {
+			endowed_accounts: vec![],
+			pool: None,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	/// Creates pool `POOL` and adds initial liquidity by ALICE.
+	pub fn with_pool(
+		mut self,
+		assets: Vec<PoolAsset<AssetId>>,
+		fee: Permill,
+		initial_liquidity: Vec<AssetAmount<AssetId>>,
+	) -> Self {
+		self.pool = Some((assets, fee, initial_liquidity));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		for asset in [HDX, DAI, DOT, ETH, POOL] {
+			REGISTERED_ASSETS.with(|v| {
+				v.borrow_mut().insert(asset, 12);
+			});
+		}
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			if let Some((assets, fee, initial_liquidity)) = self.pool {
+				assert_ok!(WeightedPool::create_pool(RuntimeOrigin::root(), POOL, assets, fee));
+				if !initial_liquidity.is_empty() {
+					assert_ok!(WeightedPool::add_liquidity(
+						RuntimeOrigin::signed(ALICE),
+						POOL,
+						initial_liquidity
+					));
+				}
+				ORACLE_TRADES.with(|v| v.borrow_mut().clear());
+				ORACLE_LIQUIDITY.with(|v| v.borrow_mut().clear());
+			}
+		});
+
+		r
+	}
+}
+
+pub fn pool_asset(asset_id: AssetId, weight_percent: u32) -> PoolAsset<AssetId> {
+	PoolAsset {
+		asset_id,
+		weight: crate::MAX_WEIGHT / 100 * weight_percent,
+	}
+}
+
+/// HDX/DAI/DOT pool with 50/30/20 weights and prices HDX = 0.1 DAI, DOT = 5 DAI.
+pub fn default_pool_assets() -> Vec<PoolAsset<AssetId>> {
+	vec![pool_asset(HDX, 50), pool_asset(DAI, 30), pool_asset(DOT, 20)]
+}
+
+pub fn default_initial_liquidity() -> Vec<AssetAmount<AssetId>> {
+	vec![
+		AssetAmount::new(HDX, 50_000 * ONE),
+		AssetAmount::new(DAI, 3_000 * ONE),
+		AssetAmount::new(DOT, 400 * ONE),
+	]
+}
+
+pub fn default_builder() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 100_000 * ONE),
+			(ALICE, DAI, 10_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, HDX, 10_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+			(BOB, DOT, 100 * ONE),
+		])
+		.with_pool(
+			default_pool_assets(),
+			Permill::from_percent(1),
+			default_initial_liquidity(),
+		)
+}
+
+pub fn pool_account() -> AccountId {
+	WeightedPool::pool_account(POOL)
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|x| x != account));
+		Ok(())
+	}
+}
+
+pub struct OracleHandlerMock;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for OracleHandlerMock {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		_amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		assert_eq!(source, WEIGHTED_SOURCE);
+		ORACLE_TRADES.with(|v| {
+			v.borrow_mut()
+				.push((asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price))
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, Ratio> for OracleHandlerMock {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		_amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		assert_eq!(source, WEIGHTED_SOURCE);
+		ORACLE_LIQUIDITY.with(|v| {
+			v.borrow_mut()
+				.push((asset_a, asset_b, amount_a, liquidity_a, liquidity_b, price))
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyRegistry;
+
+impl Inspect for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<hydradx_traits::AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+		Ok(())
+	}
+}
@@ -0,0 +1,13 @@
+mod creation;
+mod liquidity;
+pub(crate) mod mock;
+mod oracle;
+mod trade_execution;
+mod trades;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
@@ -0,0 +1,129 @@
+use crate::tests::mock::*;
+use crate::types::AssetAmount;
+use crate::MAX_WEIGHT;
+use frame_support::assert_ok;
+use hydra_dx_math::ratio::Ratio;
+
+fn share_price(reserve: Balance, weight_percent: u32, issuance: Balance) -> Ratio {
+	Ratio::new(
+		reserve * MAX_WEIGHT as u128,
+		(MAX_WEIGHT / 100 * weight_percent) as u128 * issuance,
+	)
+}
+
+#[test]
+fn sell_should_update_oracle_for_both_assets_against_share_asset() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			DAI,
+			HDX,
+			100 * ONE,
+			0
+		));
+
+		let issuance = 50_000 * ONE;
+		let hdx_reserve = Tokens::free_balance(HDX, &pool_account());
+		let amount_out = 50_000 * ONE - hdx_reserve;
+
+		let trades = ORACLE_TRADES.with(|v| v.borrow().clone());
+		assert_eq!(
+			trades,
+			vec![
+				(
+					DAI,
+					POOL,
+					100 * ONE,
+					3_100 * ONE,
+					issuance,
+					share_price(3_100 * ONE, 30, issuance)
+				),
+				(
+					HDX,
+					POOL,
+					amount_out,
+					hdx_reserve,
+					issuance,
+					share_price(hdx_reserve, 50, issuance)
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_update_oracle_for_all_pool_assets() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			vec![
+				AssetAmount::new(HDX, 1_000 * ONE),
+				AssetAmount::new(DAI, 100 * ONE),
+				AssetAmount::new(DOT, 10 * ONE),
+			]
+		));
+
+		let issuance = 51_000 * ONE;
+		let entries = ORACLE_LIQUIDITY.with(|v| v.borrow().clone());
+		assert_eq!(
+			entries,
+			vec![
+				(
+					HDX,
+					POOL,
+					1_000 * ONE,
+					51_000 * ONE,
+					issuance,
+					share_price(51_000 * ONE, 50, issuance)
+				),
+				(
+					DAI,
+					POOL,
+					60 * ONE,
+					3_060 * ONE,
+					issuance,
+					share_price(3_060 * ONE, 30, issuance)
+				),
+				(
+					DOT,
+					POOL,
+					8 * ONE,
+					408 * ONE,
+					issuance,
+					share_price(408 * ONE, 20, issuance)
+				),
+			]
+		);
+	});
+}
+
+#[test]
+fn share_price_should_reflect_pool_value_in_asset() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			5_000 * ONE
+		));
+
+		// Pool value is 9_000 DAI and there are 45_000 shares after removal - one share is worth 0.2 DAI
+		let entries = ORACLE_LIQUIDITY.with(|v| v.borrow().clone());
+		let (_, _, _, _, _, dai_price) = entries.iter().find(|e| e.0 == DAI).copied().unwrap();
+		assert_eq!(dai_price.n * 5, dai_price.d);
+	});
+}
+
+#[test]
+fn remove_all_liquidity_should_not_update_oracle() {
+	default_builder().build().execute_with(|| {
+		assert_ok!(WeightedPool::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			50_000 * ONE
+		));
+
+		assert!(ORACLE_LIQUIDITY.with(|v| v.borrow().is_empty()));
+	});
+}
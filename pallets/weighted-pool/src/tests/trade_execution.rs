@@ -0,0 +1,148 @@
+use crate::assert_balance;
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
+use sp_runtime::traits::CheckedDiv;
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
+
+#[test]
+fn calculate_sell_should_return_same_amount_as_executed_sell() {
+	default_builder().build().execute_with(|| {
+		let expected = <WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+			PoolType::Weighted(POOL),
+			DAI,
+			HDX,
+			100 * ONE,
+		)
+		.unwrap();
+
+		assert_ok!(<WeightedPool as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Weighted(POOL),
+			DAI,
+			HDX,
+			100 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, HDX, 10_000 * ONE + expected);
+		assert_balance!(BOB, DAI, 900 * ONE);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_same_amount_as_executed_buy() {
+	default_builder().build().execute_with(|| {
+		let expected = <WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+			PoolType::Weighted(POOL),
+			DAI,
+			DOT,
+			10 * ONE,
+		)
+		.unwrap();
+
+		assert_ok!(<WeightedPool as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Weighted(POOL),
+			DAI,
+			DOT,
+			10 * ONE,
+			expected,
+		));
+
+		assert_balance!(BOB, DOT, 110 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE - expected);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	default_builder().build().execute_with(|| {
+		for pool_type in [
+			PoolType::XYK,
+			PoolType::LBP,
+			PoolType::Omnipool,
+			PoolType::Stableswap(POOL),
+		] {
+			assert_eq!(
+				<WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+					pool_type,
+					DAI,
+					HDX,
+					100 * ONE,
+				),
+				Err(ExecutorError::NotSupported)
+			);
+			assert_eq!(
+				<WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::execute_sell(
+					RuntimeOrigin::signed(BOB),
+					pool_type,
+					DAI,
+					HDX,
+					100 * ONE,
+					0,
+				),
+				Err(ExecutorError::NotSupported)
+			);
+		}
+	});
+}
+
+#[test]
+fn pools_should_return_pool_with_its_assets() {
+	default_builder().build().execute_with(|| {
+		assert_eq!(
+			<WeightedPool as PoolsProvider<AssetId>>::pools(),
+			vec![(PoolType::Weighted(POOL), vec![HDX, DAI, DOT])]
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_account_for_weights() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 100_000 * ONE),
+			(ALICE, DAI, 10_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+		])
+		.with_pool(default_pool_assets(), Permill::zero(), default_initial_liquidity())
+		.build()
+		.execute_with(|| {
+			// 10 HDX per 1 DAI
+			assert_eq!(
+				<WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+					PoolType::Weighted(POOL),
+					HDX,
+					DAI,
+				),
+				Ok(FixedU128::from(10))
+			);
+		});
+}
+
+#[test]
+fn spot_price_should_include_fee() {
+	default_builder().build().execute_with(|| {
+		assert_eq!(
+			<WeightedPool as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+				PoolType::Weighted(POOL),
+				HDX,
+				DAI,
+			),
+			Ok(FixedU128::from(10)
+				.checked_div(&FixedU128::from_rational(99, 100))
+				.unwrap())
+		);
+	});
+}
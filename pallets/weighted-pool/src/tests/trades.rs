@@ -0,0 +1,192 @@
+use crate::tests::mock::*;
+use crate::types::AssetWeight;
+use crate::{assert_balance, Error, Event, MAX_WEIGHT};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+
+fn weight(percent: u32) -> AssetWeight {
+	MAX_WEIGHT / 100 * percent
+}
+
+#[test]
+fn sell_should_work_when_assets_are_in_pool() {
+	default_builder().build().execute_with(|| {
+		let expected_out =
+			hydra_dx_math::lbp::calculate_out_given_in(3_000 * ONE, 50_000 * ONE, weight(30), weight(50), 100 * ONE)
+				.unwrap();
+		let fee = Permill::from_percent(1).mul_ceil(expected_out);
+
+		assert_ok!(WeightedPool::sell(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			DAI,
+			HDX,
+			100 * ONE,
+			900 * ONE
+		));
+
+		assert_balance!(BOB, DAI, 900 * ONE);
+		assert_balance!(BOB, HDX, 10_000 * ONE + expected_out - fee);
+		assert_balance!(pool_account(), DAI, 3_100 * ONE);
+		assert_balance!(pool_account(), HDX, 50_000 * ONE - expected_out + fee);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			pool_id: POOL,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 100 * ONE,
+			amount_out: expected_out - fee,
+			fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_respect_weights_when_calculating_amount_out() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 100_000 * ONE),
+			(ALICE, DAI, 10_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(default_pool_assets(), Permill::zero(), default_initial_liquidity())
+		.build()
+		.execute_with(|| {
+			assert_ok!(WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, HDX, ONE, 0));
+
+			// Spot price is 10 HDX per DAI given by weights, not 50_000 / 3_000 given by reserves only.
+			let received = Tokens::free_balance(HDX, &BOB);
+			assert!(received < 10 * ONE);
+			assert!(received > 10 * ONE - 10 * ONE / 1_000);
+		});
+}
+
+#[test]
+fn buy_should_work_when_assets_are_in_pool() {
+	default_builder().build().execute_with(|| {
+		let amount_in =
+			hydra_dx_math::lbp::calculate_in_given_out(3_000 * ONE, 400 * ONE, weight(30), weight(20), 10 * ONE)
+				.unwrap();
+		let fee = Permill::from_percent(1).mul_ceil(amount_in);
+
+		assert_ok!(WeightedPool::buy(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			DOT,
+			DAI,
+			10 * ONE,
+			60 * ONE
+		));
+
+		assert_balance!(BOB, DOT, 110 * ONE);
+		assert_balance!(BOB, DAI, 1_000 * ONE - amount_in - fee);
+		assert_balance!(pool_account(), DOT, 390 * ONE);
+		assert_balance!(pool_account(), DAI, 3_000 * ONE + amount_in + fee);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			pool_id: POOL,
+			asset_in: DAI,
+			asset_out: DOT,
+			amount_in: amount_in + fee,
+			amount_out: 10 * ONE,
+			fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, HDX, 100 * ONE, 1_000 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::buy(RuntimeOrigin::signed(BOB), POOL, DOT, DAI, 10 * ONE, 50 * ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_max_in_ratio_is_exceeded() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::sell(RuntimeOrigin::signed(ALICE), POOL, HDX, DAI, 20_000 * ONE, 0),
+			Error::<Test>::MaxInRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_max_out_ratio_is_exceeded() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::buy(RuntimeOrigin::signed(ALICE), POOL, DOT, DAI, 200 * ONE, 10_000 * ONE),
+			Error::<Test>::MaxOutRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_assets_are_same() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, DAI, 100 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_asset_is_not_in_pool() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, ETH, 100 * ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+		assert_noop!(
+			WeightedPool::buy(RuntimeOrigin::signed(BOB), POOL, ETH, DAI, ONE, 100 * ONE),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_no_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 1_000 * ONE)])
+		.with_pool(default_pool_assets(), Permill::from_percent(1), vec![])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, HDX, 100 * ONE, 0),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_minimum_trading_limit() {
+	default_builder().build().execute_with(|| {
+		assert_noop!(
+			WeightedPool::sell(RuntimeOrigin::signed(BOB), POOL, DAI, HDX, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			WeightedPool::buy(RuntimeOrigin::signed(BOB), POOL, HDX, DAI, 999, ONE),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
@@ -0,0 +1,145 @@
+use crate::types::Balance;
+use crate::{Config, Error, Pallet, Pools};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{CheckedDiv, CheckedSub, One};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				let (amount_out, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				let (amount_in, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		_asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				let pool_account = Self::pool_account(pool_id);
+				Ok(T::Currency::free_balance(asset_a, &pool_account))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Weighted(pool_id) => {
+				let pool =
+					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				let (_, weight_a) = pool
+					.find_asset(asset_a)
+					.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
+				let (_, weight_b) = pool
+					.find_asset(asset_b)
+					.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
+
+				let pool_account = Self::pool_account(pool_id);
+				let reserve_a = T::Currency::free_balance(asset_a, &pool_account);
+				let reserve_b = T::Currency::free_balance(asset_b, &pool_account);
+
+				// Price of asset_b denominated in asset_a. Fee is paid in asset leaving the pool,
+				// so the price paid for one unit of asset_b is increased by the fee.
+				let spot_price = FixedU128::checked_from_rational(
+					reserve_a
+						.checked_mul(weight_b.into())
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?,
+					reserve_b
+						.checked_mul(weight_a.into())
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?,
+				)
+				.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))?;
+
+				let fee_multiplier = FixedU128::one()
+					.checked_sub(&FixedU128::from(pool.fee))
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
+				let spot_price_with_fee = spot_price
+					.checked_div(&fee_multiplier)
+					.ok_or_else(|| ExecutorError::Error(ArithmeticError::DivisionByZero.into()))?;
+
+				Ok(spot_price_with_fee)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+}
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn pools() -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		Pools::<T>::iter()
+			.map(|(pool_id, pool)| (PoolType::Weighted(pool_id), pool.asset_ids()))
+			.collect()
+	}
+}
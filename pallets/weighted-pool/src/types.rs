@@ -0,0 +1,94 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{MAX_ASSETS_IN_POOL, MAX_WEIGHT, MIN_WEIGHT};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::ConstU32;
+use frame_support::BoundedVec;
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::prelude::*;
+
+pub type Balance = u128;
+
+/// Weight of a pool asset. Weights of all pool assets sum up to `MAX_WEIGHT`.
+pub type AssetWeight = hydra_dx_math::types::LBPWeight;
+
+/// Pool asset with its fixed weight.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolAsset<AssetId> {
+	pub asset_id: AssetId,
+	pub weight: AssetWeight,
+}
+
+/// Pool properties
+/// `assets`: pool assets with their weights, sorted by asset id
+/// `fee`: trade fee to be withdrawn on sell/buy
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AssetId> {
+	pub assets: BoundedVec<PoolAsset<AssetId>, ConstU32<MAX_ASSETS_IN_POOL>>,
+	pub fee: Permill,
+}
+
+impl<AssetId> PoolInfo<AssetId>
+where
+	AssetId: Ord + Copy,
+{
+	/// Returns index and weight of given asset.
+	pub(crate) fn find_asset(&self, asset: AssetId) -> Option<(usize, AssetWeight)> {
+		self.assets
+			.iter()
+			.position(|v| v.asset_id == asset)
+			.map(|idx| (idx, self.assets[idx].weight))
+	}
+
+	pub fn asset_ids(&self) -> Vec<AssetId> {
+		self.assets.iter().map(|v| v.asset_id).collect()
+	}
+
+	/// Pool must contain at least 2 unique assets.
+	pub(crate) fn has_valid_assets(&self) -> bool {
+		let mut uniq = BTreeSet::new();
+		self.assets.len() >= 2 && self.assets.iter().all(|v| uniq.insert(v.asset_id))
+	}
+
+	/// Weight of each asset must be at least `MIN_WEIGHT` and the weights must sum up to `MAX_WEIGHT`.
+	pub(crate) fn has_valid_weights(&self) -> bool {
+		let total_weight = self
+			.assets
+			.iter()
+			.try_fold(0 as AssetWeight, |acc, v| acc.checked_add(v.weight));
+
+		self.assets.iter().all(|v| v.weight >= MIN_WEIGHT) && total_weight == Some(MAX_WEIGHT)
+	}
+}
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Default)]
+pub struct AssetAmount<AssetId> {
+	pub asset_id: AssetId,
+	pub amount: Balance,
+}
+
+impl<AssetId: Default> AssetAmount<AssetId> {
+	pub fn new(asset_id: AssetId, amount: Balance) -> Self {
+		Self { asset_id, amount }
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult;
+}
@@ -0,0 +1,232 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_weighted_pool`
+//!
+//! Values are placeholders until the pallet benchmarks are run with the benchmark CLI
+//! on the reference hardware. Storage access is listed as the benchmarks are expected to record it.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_weighted_pool.
+pub trait WeightInfo {
+	fn create_pool(n: u32) -> Weight;
+	fn update_pool_fee() -> Weight;
+	fn add_liquidity(n: u32) -> Weight;
+	fn remove_liquidity(n: u32) -> Weight;
+	fn sell() -> Weight;
+	fn buy() -> Weight;
+	fn router_execution_sell(c: u32, e: u32) -> Weight;
+	fn router_execution_buy(c: u32, e: u32) -> Weight;
+	fn calculate_spot_price_with_fee() -> Weight;
+}
+
+/// Weights for pallet_weighted_pool using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `WeightedPool::Pools` (r:1 w:1)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:1)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 8]`.
+	fn create_pool(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `4687 + n * (2600 ±0)`
+		// Minimum execution time: 28_410_000 picoseconds.
+		Weight::from_parts(27_305_000, 4687)
+			.saturating_add(Weight::from_parts(2_514_000, 2600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:1)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn update_pool_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3599`
+		// Minimum execution time: 19_680_000 picoseconds.
+		Weight::from_parts(20_114_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:17 w:17)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 8]`.
+	fn add_liquidity(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1624 + n * (210 ±0)`
+		//  Estimated: `7401 + n * (5183 ±0)`
+		// Minimum execution time: 139_210_000 picoseconds.
+		Weight::from_parts(66_842_000, 7401)
+			.saturating_add(Weight::from_parts(38_917_000, 5183).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:17 w:17)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 8]`.
+	fn remove_liquidity(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1624 + n * (210 ±0)`
+		//  Estimated: `7401 + n * (5183 ±0)`
+		// Minimum execution time: 132_770_000 picoseconds.
+		Weight::from_parts(64_105_000, 7401)
+			.saturating_add(Weight::from_parts(36_242_000, 5183).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	fn sell() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2318`
+		//  Estimated: `11322`
+		// Minimum execution time: 142_106_000 picoseconds.
+		Weight::from_parts(143_520_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2318`
+		//  Estimated: `11322`
+		// Minimum execution time: 141_883_000 picoseconds.
+		Weight::from_parts(143_217_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_sell(c: u32, e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1722 + e * (596 ±0)`
+		//  Estimated: `6156 + e * (5166 ±0)`
+		// Minimum execution time: 27_340_000 picoseconds.
+		Weight::from_parts(22_918_000, 6156)
+			.saturating_add(Weight::from_parts(5_211_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(119_473_000, 5166).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(e.into())))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6411, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 3]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_buy(c: u32, e: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1722 + e * (596 ±0)`
+		//  Estimated: `6156 + e * (5166 ±0)`
+		// Minimum execution time: 27_861_000 picoseconds.
+		Weight::from_parts(21_706_000, 6156)
+			.saturating_add(Weight::from_parts(6_187_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(119_952_000, 5166).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(e.into())))
+	}
+	/// Storage: `WeightedPool::Pools` (r:1 w:0)
+	/// Proof: `WeightedPool::Pools` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn calculate_spot_price_with_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1722`
+		//  Estimated: `6156`
+		// Minimum execution time: 24_893_000 picoseconds.
+		Weight::from_parts(25_372_000, 6156).saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+}
@@ -46,8 +46,8 @@ pub mod weights;
 pub use crate::weights::WeightInfo;
 pub use pallet::*;
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
-use frame_support::{ensure, sp_runtime::traits::Zero, PalletId};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
+use frame_support::{ensure, sp_runtime::traits::Zero, storage::with_storage_layer, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::liquidity_mining::{
 	GlobalFarmId, Inspect as LiquidityMiningInspect, Mutate as LiquidityMiningMutate, YieldFarmId,
@@ -57,17 +57,27 @@ use pallet_xyk::types::{AssetId, AssetPair, Balance};
 
 use frame_support::{pallet_prelude::*, sp_runtime::traits::AccountIdConversion};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use hydradx_traits::{registry::Inspect as RegistryInspect, AMMPosition, AMM};
+use hydradx_traits::{registry::Inspect as RegistryInspect, AMMAddLiquidity, AMMPosition, AMM};
 use orml_traits::MultiCurrency;
 use primitives::{CollectionId, ItemId as DepositId};
 use sp_arithmetic::{FixedU128, Perquintill};
 use sp_std::{
 	convert::{From, Into, TryInto},
 	vec,
+	vec::Vec,
 };
 
 type PeriodOf<T> = BlockNumberFor<T>;
 
+/// Max number of counterpart assets with compound limits in `claim_all_rewards`.
+pub const MAX_COMPOUND_LIMITS: u32 = 10;
+
+/// Max amounts of pools' counterpart assets which can be taken to compound claimed rewards.
+pub type CompoundLimits = BoundedVec<(AssetId, Balance), ConstU32<MAX_COMPOUND_LIMITS>>;
+
+/// Estimated weight of comparing one compound limit when the limit of a counterpart asset is looked up.
+const COMPOUND_LIMIT_LOOKUP_WEIGHT: Weight = Weight::from_parts(50_000, 0);
+
 #[frame_support::pallet]
 #[allow(clippy::too_many_arguments)]
 pub mod pallet {
@@ -113,7 +123,8 @@ pub mod pallet {
 
 		/// AMM helper functions.
 		type AMM: AMM<Self::AccountId, AssetId, AssetPair, Balance>
-			+ AMMPosition<AssetId, Balance, Error = DispatchError>;
+			+ AMMPosition<AssetId, Balance, Error = DispatchError>
+			+ AMMAddLiquidity<Self::AccountId, AssetId, Balance>;
 
 		/// The origin account that can create new liquidity mining program.
 		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -129,6 +140,7 @@ pub mod pallet {
 		type NFTHandler: Mutate<Self::AccountId>
 			+ Create<Self::AccountId>
 			+ Inspect<Self::AccountId, ItemId = DepositId, CollectionId = CollectionId>
+			+ InspectEnumerable<Self::AccountId, ItemId = DepositId, CollectionId = CollectionId>
 			+ Transfer<Self::AccountId>;
 
 		/// Liquidity mining handler for managing liquidity mining functionalities
@@ -189,6 +201,9 @@ pub mod pallet {
 
 		/// Failed to calculate `pot`'s account.
 		FailToGetPotId,
+
+		/// Max amount of pool's counterpart asset to compound rewards with was not provided.
+		CompoundLimitNotProvided,
 	}
 
 	#[pallet::event]
@@ -308,6 +323,13 @@ pub mod pallet {
 
 		/// NFT representing deposit has been destroyed
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards from all deposits of the account were claimed.
+		AllRewardsClaimed {
+			who: T::AccountId,
+			claims: Vec<(GlobalFarmId, YieldFarmId, AssetId, Balance)>,
+			compounded: Vec<(AssetId, Balance)>,
+		},
 	}
 
 	#[pallet::call]
@@ -892,6 +914,122 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Claim rewards from all yield farm entries of all deposits owned by `origin`.
+		///
+		/// Deposits are processed until `max_entries` yield farm entries were visited. Entries which
+		/// can't be claimed(e.g. rewards were already claimed in this period) are skipped.
+		///
+		/// Claimed rewards are added back as liquidity to the xyk pool of the deposit if max amount
+		/// of pool's counterpart asset is provided in `compound_limits`. Counterpart asset is taken
+		/// from `origin`'s account and all compounding together can't take more than the provided
+		/// max amount. Rewards in asset which is not in the deposit's pool or which can't be added
+		/// to the pool within the limit stay in `origin`'s account.
+		///
+		/// Every deposit has at least one yield farm entry so at most `max_entries` deposits are
+		/// visited.
+		///
+		/// Unlike in omnipool liquidity mining, where rewards are added to the pool as single asset
+		/// liquidity, compounding into xyk pool takes the pool's counterpart asset too, so its max
+		/// amounts are provided instead of a flag.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposits(nfts).
+		/// - `max_entries`: max number of yield farm entries to claim rewards from.
+		/// - `compound_limits`: max amounts of counterpart assets which can be taken from `origin`'s
+		/// account to add claimed rewards back to the xyk pools. Empty `compound_limits` disables
+		/// compounding. At most `MAX_COMPOUND_LIMITS` counterpart assets can be provided.
+		///
+		/// Emits `AllRewardsClaimed` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::claim_all_rewards_weight(*max_entries, *max_entries, compound_limits.len() as u32))]
+		pub fn claim_all_rewards(
+			origin: OriginFor<T>,
+			max_entries: u32,
+			compound_limits: CompoundLimits,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut claims: Vec<(GlobalFarmId, YieldFarmId, AssetId, Balance)> = Vec::new();
+			let mut claims_per_pool: Vec<(T::AccountId, AssetId, Balance)> = Vec::new();
+			let mut visited_deposits: u32 = 0;
+			let mut processed_entries: u32 = 0;
+
+			let deposits = T::NFTHandler::owned_in_collection(&T::NFTCollectionId::get(), &who);
+			'deposits: for deposit_id in deposits.take(max_entries as usize) {
+				visited_deposits = visited_deposits.saturating_add(1);
+
+				let amm_pool_id = match T::LiquidityMiningHandler::get_amm_pool_id(deposit_id) {
+					Some(amm_pool_id) => amm_pool_id,
+					None => continue,
+				};
+				let yield_farm_ids = T::LiquidityMiningHandler::get_yield_farm_ids(deposit_id).unwrap_or_default();
+
+				for yield_farm_id in yield_farm_ids {
+					if processed_entries >= max_entries {
+						break 'deposits;
+					}
+					processed_entries = processed_entries.saturating_add(1);
+
+					let claim_result = with_storage_layer(|| -> Result<_, DispatchError> {
+						let (global_farm_id, reward_currency, claimed, _) =
+							T::LiquidityMiningHandler::claim_rewards(who.clone(), deposit_id, yield_farm_id)?;
+
+						ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+						Ok((global_farm_id, reward_currency, claimed))
+					});
+
+					if let Ok((global_farm_id, reward_currency, claimed)) = claim_result {
+						match claims
+							.iter_mut()
+							.find(|(g, y, c, _)| *g == global_farm_id && *y == yield_farm_id && *c == reward_currency)
+						{
+							Some((_, _, _, total)) => *total = total.saturating_add(claimed),
+							None => claims.push((global_farm_id, yield_farm_id, reward_currency, claimed)),
+						}
+
+						match claims_per_pool
+							.iter_mut()
+							.find(|(p, c, _)| *p == amm_pool_id && *c == reward_currency)
+						{
+							Some((_, _, total)) => *total = total.saturating_add(claimed),
+							None => claims_per_pool.push((amm_pool_id.clone(), reward_currency, claimed)),
+						}
+					}
+				}
+			}
+
+			ensure!(!claims.is_empty(), Error::<T>::ZeroClaimedRewards);
+
+			let compound_limits_len = compound_limits.len() as u32;
+			let mut compound_limits = compound_limits;
+			let mut compounded: Vec<(AssetId, Balance)> = Vec::new();
+			if !compound_limits.is_empty() {
+				for (amm_pool_id, reward_currency, amount) in claims_per_pool {
+					if Self::compound_rewards(&who, &amm_pool_id, reward_currency, amount, &mut compound_limits).is_ok()
+					{
+						match compounded.iter_mut().find(|(c, _)| *c == reward_currency) {
+							Some((_, total)) => *total = total.saturating_add(amount),
+							None => compounded.push((reward_currency, amount)),
+						}
+					}
+				}
+			}
+
+			Self::deposit_event(Event::AllRewardsClaimed {
+				who,
+				claims,
+				compounded,
+			});
+
+			Ok(Some(Self::claim_all_rewards_weight(
+				visited_deposits,
+				processed_entries,
+				compound_limits_len,
+			))
+			.into())
+		}
 	}
 }
 
@@ -943,6 +1081,66 @@ impl<T: Config> Pallet<T> {
 		Ok(liquidity_b)
 	}
 
+	/// This function adds `amount` of `reward_currency` claimed by `who` to the xyk pool
+	/// identified by `amm_pool_id`. Amount of pool's counterpart asset taken from `who` is limited
+	/// by the asset's remaining limit in `compound_limits` and the limit is reduced by the taken
+	/// amount. Storage changes are reverted if adding of liquidity fails.
+	fn compound_rewards(
+		who: &T::AccountId,
+		amm_pool_id: &T::AccountId,
+		reward_currency: AssetId,
+		amount: Balance,
+		compound_limits: &mut [(AssetId, Balance)],
+	) -> Result<(), DispatchError> {
+		let assets = T::AMM::get_pool_assets(amm_pool_id).ok_or(Error::<T>::CantGetXykAssets)?;
+		ensure!(assets.contains(&reward_currency), Error::<T>::AssetNotInAssetPair);
+
+		let other_asset = if assets[0] == reward_currency {
+			assets[1]
+		} else {
+			assets[0]
+		};
+
+		let (_, limit) = compound_limits
+			.iter_mut()
+			.find(|(asset, _)| *asset == other_asset)
+			.ok_or(Error::<T>::CompoundLimitNotProvided)?;
+
+		let balance_before = T::Currencies::free_balance(other_asset, who);
+		with_storage_layer(|| {
+			T::AMM::add_liquidity(who.clone(), reward_currency, other_asset, amount, *limit).map(|_| ())
+		})?;
+
+		let taken = balance_before.saturating_sub(T::Currencies::free_balance(other_asset, who));
+		*limit = limit.saturating_sub(taken);
+
+		Ok(())
+	}
+
+	/// Weight of the `claim_all_rewards` for `deposits` visited deposits, `entries` yield farm
+	/// entries and `compound_limits` provided compound limits. Every visited deposit is read from
+	/// the account's deposits enumeration and its pool and yield farm entries are read. Every entry
+	/// can be compounded into different pool so compounding, including the lookup of the
+	/// counterpart asset's limit, is accounted for each entry.
+	fn claim_all_rewards_weight(deposits: u32, entries: u32, compound_limits: u32) -> Weight {
+		// Enumeration reads one more key to find out there are no more deposits.
+		let enumeration_weight = T::DbWeight::get().reads(1);
+		let deposit_weight = T::DbWeight::get().reads(3);
+
+		let mut entry_weight = <T as Config>::WeightInfo::claim_rewards();
+		if compound_limits > 0 {
+			// Pool assets are read and counterpart asset's balance is read before and after adding liquidity.
+			entry_weight = entry_weight
+				.saturating_add(T::AMM::add_liquidity_weight())
+				.saturating_add(T::DbWeight::get().reads(3))
+				.saturating_add(COMPOUND_LIMIT_LOOKUP_WEIGHT.saturating_mul(compound_limits.into()));
+		}
+
+		enumeration_weight
+			.saturating_add(deposit_weight.saturating_mul(deposits.into()))
+			.saturating_add(entry_weight.saturating_mul(entries.into()))
+	}
+
 	fn ensure_xyk(asset_pair: AssetPair) -> Result<T::AccountId, Error<T>> {
 		ensure!(T::AMM::exists(asset_pair), Error::<T>::XykPoolDoesntExist);
 
@@ -0,0 +1,242 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::bounded_vec;
+
+fn ext_with_deposits() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+			(CHARLIE, BSX, 100_000_000 * ONE),
+			(CHARLIE, KSM, 100_000_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			ACA,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.with_deposit(CHARLIE, 2, 4, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+}
+
+#[test]
+fn claim_all_rewards_should_claim_rewards_from_all_deposits() {
+	ext_with_deposits().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			10,
+			bounded_vec![]
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(1, 3, BSX, 20_000_000 * ONE), (2, 4, ACA, 20_000_000 * ONE)],
+			compounded: vec![],
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_not_claim_more_than_max_entries() {
+	ext_with_deposits().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			1,
+			bounded_vec![]
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(1, 3, BSX, 20_000_000 * ONE)],
+			compounded: vec![],
+		}
+		.into());
+
+		//Already claimed entry is skipped and remaining entry is claimed.
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			2,
+			bounded_vec![]
+		));
+
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(2, 4, ACA, 20_000_000 * ONE)],
+			compounded: vec![],
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_add_rewards_to_deposit_pool_when_compound_limit_is_provided() {
+	ext_with_deposits().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		let charlie_shares = Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE);
+		let charlie_ksm = Tokens::free_balance(KSM, &CHARLIE);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			10,
+			bounded_vec![(KSM, 20_000_000 * ONE)]
+		));
+
+		//Assert
+		//NOTE: ACA is not in the deposit's pool so it's not compounded.
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(1, 3, BSX, 20_000_000 * ONE), (2, 4, ACA, 20_000_000 * ONE)],
+			compounded: vec![(BSX, 20_000_000 * ONE)],
+		}
+		.into());
+
+		assert_eq!(
+			Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE),
+			charlie_shares + 20_000_000 * ONE
+		);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), charlie_ksm - 20_000_000 * ONE);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_not_compound_when_compound_limit_is_too_low() {
+	ext_with_deposits().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		let charlie_shares = Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE);
+		let charlie_ksm = Tokens::free_balance(KSM, &CHARLIE);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			10,
+			bounded_vec![(KSM, 20_000_000 * ONE - 1)]
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(1, 3, BSX, 20_000_000 * ONE), (2, 4, ACA, 20_000_000 * ONE)],
+			compounded: vec![],
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE), charlie_shares);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), charlie_ksm);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_not_compound_when_compound_limit_is_not_provided_for_counterpart_asset() {
+	ext_with_deposits().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		let charlie_shares = Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE);
+		let charlie_ksm = Tokens::free_balance(KSM, &CHARLIE);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all_rewards(
+			Origin::signed(CHARLIE),
+			10,
+			bounded_vec![(BSX, 100_000_000 * ONE)]
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claims: vec![(1, 3, BSX, 20_000_000 * ONE), (2, 4, ACA, 20_000_000 * ONE)],
+			compounded: vec![],
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE), charlie_shares);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), charlie_ksm);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_fail_when_nothing_was_claimed() {
+	ext_with_deposits().execute_with(|| {
+		//Rewards can't be claimed in the same period as deposit was created.
+		assert_noop!(
+			LiquidityMining::claim_all_rewards(Origin::signed(CHARLIE), 10, bounded_vec![]),
+			Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Account without deposits.
+		set_block_number(10_000);
+		assert_noop!(
+			LiquidityMining::claim_all_rewards(Origin::signed(BOB), 10, bounded_vec![]),
+			Error::<Test>::ZeroClaimedRewards
+		);
+
+		//Zero `max_entries`.
+		assert_noop!(
+			LiquidityMining::claim_all_rewards(Origin::signed(CHARLIE), 0, bounded_vec![]),
+			Error::<Test>::ZeroClaimedRewards
+		);
+	});
+}
+
+#[test]
+fn claim_all_rewards_should_fail_when_origin_is_not_signed() {
+	ext_with_deposits().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::claim_all_rewards(Origin::none(), 10, bounded_vec![]),
+			BadOrigin
+		);
+	});
+}
@@ -42,7 +42,7 @@ fn create_yield_farm_should_work_when_global_farm_exist() {
 		.build()
 		.execute_with(|| {
 			let multiplier = One::one();
-			let loyalty_curve = Some(LoyaltyCurve {
+			let loyalty_curve = Some(LoyaltyCurve::Hyperbolic {
 				initial_reward_percentage: FixedU128::from_float(0.558),
 				scale_coef: 20,
 			});
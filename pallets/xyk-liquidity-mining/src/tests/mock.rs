@@ -27,7 +27,7 @@ use frame_support::{
 };
 
 use frame_system as system;
-use hydradx_traits::{pools::DustRemovalAccountWhitelist, AMMPosition, AMM};
+use hydradx_traits::{pools::DustRemovalAccountWhitelist, AMMAddLiquidity, AMMPosition, AMM};
 use orml_traits::parameter_type_with_key;
 use pallet_liquidity_mining::{FarmMultiplier, YieldFarmId};
 use pallet_xyk::types::{AssetId, AssetPair, Balance};
@@ -321,6 +321,39 @@ impl AMMPosition<AssetId, Balance> for DummyAMM {
 	}
 }
 
+impl AMMAddLiquidity<AccountId, AssetId, Balance> for DummyAMM {
+	fn add_liquidity(
+		who: AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+		let amm_pool_id = DummyAMM::get_pair_id(asset_pair);
+		let lp_token = DummyAMM::get_share_token(asset_pair);
+
+		//NOTE: dummy AMM requires assets in ratio 1:1 and mints 1 share for each unit of `asset_a`.
+		let amount_b = amount_a;
+		if amount_b > amount_b_max_limit {
+			return Err(sp_runtime::DispatchError::Other("Dummy AssetAmountExceededLimit"));
+		}
+
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_a, &who, &amm_pool_id, amount_a)?;
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_b, &who, &amm_pool_id, amount_b)?;
+		<Tokens as MultiCurrency<AccountId>>::deposit(lp_token, &who, amount_a)?;
+
+		Ok(amount_b)
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 parameter_types! {
 	pub const WarehouseLMPalletId: PalletId = PalletId(*b"WhouseLm");
 	pub const MinDeposit: Balance = 1;
@@ -391,7 +424,7 @@ where
 	}
 }
 
-use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer};
 pub struct DummyNFT;
 
 impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
@@ -455,6 +488,56 @@ impl Transfer<AccountId> for DummyNFT {
 	}
 }
 
+impl InspectEnumerable<AccountId> for DummyNFT {
+	type CollectionsIterator = Box<dyn Iterator<Item = CollectionId>>;
+	type ItemsIterator = Box<dyn Iterator<Item = ItemId>>;
+	type OwnedIterator = Box<dyn Iterator<Item = (CollectionId, ItemId)>>;
+	type OwnedInCollectionIterator = Box<dyn Iterator<Item = ItemId>>;
+
+	fn collections() -> Self::CollectionsIterator {
+		let mut collections: Vec<CollectionId> = NFTS.with(|v| v.borrow().keys().map(|(c, _)| *c).collect());
+		collections.sort();
+		collections.dedup();
+		Box::new(collections.into_iter())
+	}
+
+	fn items(collection: &Self::CollectionId) -> Self::ItemsIterator {
+		let mut items: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.keys()
+				.filter(|(c, _)| c == collection)
+				.map(|(_, i)| *i)
+				.collect()
+		});
+		items.sort();
+		Box::new(items.into_iter())
+	}
+
+	fn owned(who: &AccountId) -> Self::OwnedIterator {
+		let mut owned: Vec<(CollectionId, ItemId)> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|(_, owner)| *owner == who)
+				.map(|(key, _)| *key)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+
+	fn owned_in_collection(collection: &Self::CollectionId, who: &AccountId) -> Self::OwnedInCollectionIterator {
+		let mut owned: Vec<ItemId> = NFTS.with(|v| {
+			v.borrow()
+				.iter()
+				.filter(|((c, _), owner)| c == collection && *owner == who)
+				.map(|((_, i), _)| *i)
+				.collect()
+		});
+		owned.sort();
+		Box::new(owned.into_iter())
+	}
+}
+
 pub struct DummyLiquidityMining {}
 
 impl DummyLiquidityMining {
@@ -799,6 +882,25 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		DEPOSIT_ENTRIES.with(|v| v.borrow().get(&(deposit_id, yield_farm_id)).map(|d| d.global_farm_id))
 	}
 
+	fn get_yield_farm_ids(deposit_id: u128) -> Option<Vec<u32>> {
+		Self::get_amm_pool_id(deposit_id)?;
+
+		let mut yield_farm_ids: Vec<u32> = DEPOSIT_ENTRIES.with(|v| {
+			v.borrow()
+				.keys()
+				.filter(|(d, _)| *d == deposit_id)
+				.map(|(_, y)| *y)
+				.collect()
+		});
+		yield_farm_ids.sort();
+
+		Some(yield_farm_ids)
+	}
+
+	fn get_amm_pool_id(deposit_id: u128) -> Option<Self::AmmPoolId> {
+		DEPOSITS.with(|v| v.borrow().get(&deposit_id).map(|d| d.amm_pool_id))
+	}
+
 	fn create_global_farm_without_price_adjustment(
 		_total_rewards: Self::Balance,
 		_planned_yielding_periods: Self::Period,
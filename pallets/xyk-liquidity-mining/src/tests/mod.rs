@@ -40,6 +40,7 @@ pub fn has_event(event: mock::RuntimeEvent) -> bool {
 	System::events().iter().any(|record| record.event == event)
 }
 
+pub mod claim_all_rewards;
 pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
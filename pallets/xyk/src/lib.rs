@@ -29,12 +29,12 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use frame_support::sp_runtime::{traits::Zero, DispatchError};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, weights::Weight};
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{
-	AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler, OnLiquidityChangedHandler,
-	OnTradeHandler, AMM,
+	AMMAddLiquidity, AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler,
+	OnLiquidityChangedHandler, OnTradeHandler, AMM,
 };
 use sp_std::{vec, vec::Vec};
 
@@ -1118,3 +1118,29 @@ impl<T: Config> AMMPosition<AssetId, Balance> for Pallet<T> {
 			.map_err(|_| Error::<T>::RemoveAssetAmountInvalid.into())
 	}
 }
+
+impl<T: Config> AMMAddLiquidity<T::AccountId, AssetId, Balance> for Pallet<T> {
+	fn add_liquidity(
+		who: T::AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_b_balance = T::Currency::free_balance(asset_b, &who);
+
+		Self::add_liquidity(
+			frame_system::RawOrigin::Signed(who.clone()).into(),
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b_max_limit,
+		)?;
+
+		Ok(asset_b_balance.saturating_sub(T::Currency::free_balance(asset_b, &who)))
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		<T as Config>::WeightInfo::add_liquidity().saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+	}
+}
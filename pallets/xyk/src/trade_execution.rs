@@ -1,12 +1,14 @@
 use crate::types::{AssetId, AssetPair, Balance};
-use crate::{Config, Error, Pallet};
+use crate::{Config, Error, Pallet, PoolAssets};
 use frame_support::ensure;
 use frame_support::traits::Get;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -171,3 +173,11 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> PoolsProvider<AssetId> for Pallet<T> {
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		PoolAssets::<T>::iter_values()
+			.map(|(asset_a, asset_b)| (PoolType::XYK, vec![asset_a, asset_b]))
+			.collect()
+	}
+}
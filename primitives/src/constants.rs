@@ -95,6 +95,8 @@ pub mod chain {
 	pub const OMNIPOOL_SOURCE: [u8; 8] = *b"omnipool";
 	pub const STABLESWAP_SOURCE: [u8; 8] = *b"stablesw";
 	pub const XYK_SOURCE: [u8; 8] = *b"hydraxyk";
+	pub const WEIGHTED_POOL_SOURCE: [u8; 8] = *b"weighted";
+	pub const CONCENTRATED_LIQUIDITY_SOURCE: [u8; 8] = *b"concentr";
 }
 
 #[cfg(test)]
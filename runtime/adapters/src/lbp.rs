@@ -0,0 +1,153 @@
+// This file is part of hydradx-adapters.
+
+// Copyright (C) 2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{dispatch::DispatchResult, ensure, weights::Weight};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use pallet_lbp::{PostSaleAction, PostSaleHandler};
+use primitives::{AccountId, AssetId, Balance};
+use sp_runtime::{
+	traits::{CheckedMul, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill,
+};
+
+/// Moves liquidity of ended LBP sales into a new XYK pool or into the Omnipool.
+pub struct PostSaleLiquidityMigration<Runtime>(sp_std::marker::PhantomData<Runtime>);
+
+impl<Runtime> PostSaleHandler<AccountId> for PostSaleLiquidityMigration<Runtime>
+where
+	Runtime:
+		pallet_xyk::Config + pallet_omnipool::Config<AssetId = AssetId> + frame_system::Config<AccountId = AccountId>,
+{
+	fn migrate_liquidity(
+		action: PostSaleAction,
+		owner: &AccountId,
+		accumulated: (AssetId, Balance),
+		sold: (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult {
+		match action {
+			PostSaleAction::XykPool => Self::create_xyk_pool(owner, accumulated, sold, price),
+			PostSaleAction::Omnipool { weight_cap } => {
+				Self::add_omnipool_liquidity(owner, accumulated, sold, price, weight_cap)
+			}
+		}
+	}
+
+	fn migrate_liquidity_weight(action: PostSaleAction) -> Weight {
+		use pallet_omnipool::weights::WeightInfo as OmnipoolWeights;
+		use pallet_xyk::weights::WeightInfo as XykWeights;
+
+		match action {
+			PostSaleAction::XykPool => <Runtime as pallet_xyk::Config>::WeightInfo::create_pool(),
+			PostSaleAction::Omnipool { .. } => <Runtime as pallet_omnipool::Config>::WeightInfo::add_token()
+				.saturating_add(<Runtime as pallet_omnipool::Config>::WeightInfo::add_liquidity().saturating_mul(2)),
+		}
+	}
+}
+
+impl<Runtime> PostSaleLiquidityMigration<Runtime>
+where
+	Runtime:
+		pallet_xyk::Config + pallet_omnipool::Config<AssetId = AssetId> + frame_system::Config<AccountId = AccountId>,
+{
+	/// Creates XYK pool with the LBP sale price. Amount of the asset exceeding the price ratio stays with the owner.
+	fn create_xyk_pool(
+		owner: &AccountId,
+		(accumulated_asset, accumulated_amount): (AssetId, Balance),
+		(sold_asset, sold_amount): (AssetId, Balance),
+		price: FixedU128,
+	) -> DispatchResult {
+		let sold_needed = price
+			.reciprocal()
+			.and_then(|p| p.checked_mul_int(accumulated_amount))
+			.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
+
+		let (amount_a, amount_b) = if sold_needed <= sold_amount {
+			(accumulated_amount, sold_needed)
+		} else {
+			let accumulated_needed = price
+				.checked_mul_int(sold_amount)
+				.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
+			(accumulated_needed.min(accumulated_amount), sold_amount)
+		};
+
+		pallet_xyk::Pallet::<Runtime>::create_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			accumulated_asset,
+			amount_a,
+			sold_asset,
+			amount_b,
+		)
+	}
+
+	/// Adds sold asset to the Omnipool at the LBP sale price and provides the raised collateral as liquidity.
+	///
+	/// The action and its `weight_cap` are approved by the LBP `AuthorityOrigin` before the sale ends,
+	/// so the asset is listed with root origin.
+	fn add_omnipool_liquidity(
+		owner: &AccountId,
+		(accumulated_asset, accumulated_amount): (AssetId, Balance),
+		(sold_asset, sold_amount): (AssetId, Balance),
+		price: FixedU128,
+		weight_cap: Permill,
+	) -> DispatchResult {
+		ensure!(
+			pallet_omnipool::Pallet::<Runtime>::exists(accumulated_asset),
+			pallet_omnipool::Error::<Runtime>::AssetNotFound
+		);
+
+		if pallet_omnipool::Pallet::<Runtime>::exists(sold_asset) {
+			pallet_omnipool::Pallet::<Runtime>::add_liquidity(
+				RawOrigin::Signed(owner.clone()).into(),
+				sold_asset,
+				sold_amount,
+			)?;
+		} else {
+			let accumulated_state = pallet_omnipool::Pallet::<Runtime>::load_asset_state(accumulated_asset)?;
+			let initial_price = accumulated_state
+				.price()
+				.and_then(|hub_price| hub_price.checked_mul(&price))
+				.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
+
+			<Runtime as pallet_omnipool::Config>::Currency::transfer(
+				sold_asset,
+				owner,
+				&pallet_omnipool::Pallet::<Runtime>::protocol_account(),
+				sold_amount,
+			)?;
+
+			pallet_omnipool::Pallet::<Runtime>::add_token(
+				RawOrigin::Root.into(),
+				sold_asset,
+				initial_price,
+				weight_cap,
+				owner.clone(),
+			)?;
+		}
+
+		if !accumulated_amount.is_zero() {
+			pallet_omnipool::Pallet::<Runtime>::add_liquidity(
+				RawOrigin::Signed(owner.clone()).into(),
+				accumulated_asset,
+				accumulated_amount,
+			)?;
+		}
+
+		Ok(())
+	}
+}
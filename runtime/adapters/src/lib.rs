@@ -37,18 +37,20 @@ use hydra_dx_math::{
 use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade};
 use hydradx_traits::{
 	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle,
-	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
+	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle, Source,
 };
 use orml_traits::GetByKey;
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
 use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
-use pallet_stableswap::types::{PoolState, StableswapHooks};
+use pallet_stableswap::types::{PoolState, RateProvider, RateSource, StableswapHooks, VolumeProvider};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::v4::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{
+	CONCENTRATED_LIQUIDITY_SOURCE, STABLESWAP_SOURCE, WEIGHTED_POOL_SOURCE, XYK_SOURCE,
+};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::vec::Vec;
@@ -61,6 +63,7 @@ use xcm_executor::{
 };
 
 pub mod inspect;
+pub mod lbp;
 pub mod price;
 pub mod xcm_exchange;
 pub mod xcm_execute_filter;
@@ -413,6 +416,19 @@ where
 		let amount_in = *asset_in.delta_changes.delta_reserve;
 		let amount_out = *asset_out.delta_changes.delta_reserve;
 
+		ensure_oracle_price_deviation_limit::<Runtime>(
+			OMNIPOOL_SOURCE,
+			asset_in.asset_id,
+			Lrna::get(),
+			Price::new(asset_in.after.reserve, asset_in.after.hub_reserve),
+		)?;
+		ensure_oracle_price_deviation_limit::<Runtime>(
+			OMNIPOOL_SOURCE,
+			asset_out.asset_id,
+			Lrna::get(),
+			Price::new(asset_out.after.reserve, asset_out.after.hub_reserve),
+		)?;
+
 		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
 			asset_in.asset_id.into(),
 			asset_in.before.reserve.into(),
@@ -440,6 +456,13 @@ where
 
 		let amount_out = *asset.delta_changes.delta_reserve;
 
+		ensure_oracle_price_deviation_limit::<Runtime>(
+			OMNIPOOL_SOURCE,
+			asset.asset_id,
+			Lrna::get(),
+			Price::new(asset.after.reserve, asset.after.hub_reserve),
+		)?;
+
 		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
 			Lrna::get().into(),
 			Balance::zero().into(),
@@ -457,14 +480,22 @@ where
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_add_liquidity_limit()
 			.max(<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_remove_liquidity_limit());
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_liquidity_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		// a breach of the liquidity limit is recorded for the asset
+		let w4 = pallet_circuit_breaker::Pallet::<Runtime>::record_limit_breach_weight();
+		w1.saturating_add(w2).saturating_add(w3).saturating_add(w4)
 	}
 
 	fn on_trade_weight() -> Weight {
 		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		let w4 = oracle_price_deviation_limit_weight::<Runtime>().saturating_mul(2);
+		// a breach of the limits is recorded for both assets
+		let w5 = pallet_circuit_breaker::Pallet::<Runtime>::record_limit_breach_weight().saturating_mul(2);
+		w1.saturating_add(w2)
+			.saturating_add(w3)
+			.saturating_add(w4)
+			.saturating_add(w5)
 	}
 
 	fn on_trade_fee(
@@ -565,9 +596,14 @@ where
 
 					EmaPrice::new(rational_as_u128.0, rational_as_u128.1)
 				}
-				PoolType::Stableswap(pool_id) => {
-					let price_asset_a_vs_share =
-						AggregatedPriceGetter::get_price(asset_a, pool_id, period, STABLESWAP_SOURCE);
+				PoolType::Stableswap(pool_id) | PoolType::Weighted(pool_id) => {
+					// Both pool types feed the oracle with prices of pool assets vs the pool share asset
+					let source = match trade.pool {
+						PoolType::Weighted(_) => WEIGHTED_POOL_SOURCE,
+						_ => STABLESWAP_SOURCE,
+					};
+
+					let price_asset_a_vs_share = AggregatedPriceGetter::get_price(asset_a, pool_id, period, source);
 
 					let price_asset_a_vs_share = match price_asset_a_vs_share {
 						Ok(price) => price.0,
@@ -575,8 +611,7 @@ where
 						Err(_) => return None,
 					};
 
-					let price_share_vs_asset_b =
-						AggregatedPriceGetter::get_price(pool_id, asset_b, period, STABLESWAP_SOURCE);
+					let price_share_vs_asset_b = AggregatedPriceGetter::get_price(pool_id, asset_b, period, source);
 
 					let price_share_vs_asset_b = match price_share_vs_asset_b {
 						Ok(price) => price.0,
@@ -591,8 +626,13 @@ where
 
 					EmaPrice::new(rational_as_u128.0, rational_as_u128.1)
 				}
-				PoolType::XYK => {
-					let price_result = AggregatedPriceGetter::get_price(asset_a, asset_b, period, XYK_SOURCE);
+				PoolType::XYK | PoolType::Concentrated => {
+					let source = match trade.pool {
+						PoolType::Concentrated => CONCENTRATED_LIQUIDITY_SOURCE,
+						_ => XYK_SOURCE,
+					};
+
+					let price_result = AggregatedPriceGetter::get_price(asset_a, asset_b, period, source);
 
 					match price_result {
 						Ok(price) => price.0,
@@ -624,6 +664,34 @@ where
 	}
 }
 
+/// Provides rates of yield-bearing stableswap assets.
+///
+/// Oracle rate is the value of the asset in its underlying asset, derived from the Omnipool oracle prices.
+/// Custom rate sources are delegated to `CustomRates`.
+pub struct OracleRateProvider<Oracle, CustomRates>(PhantomData<(Oracle, CustomRates)>);
+
+impl<Oracle, CustomRates> RateProvider<AssetId> for OracleRateProvider<Oracle, CustomRates>
+where
+	Oracle: PriceOracle<AssetId, Price = EmaPrice>,
+	CustomRates: RateProvider<AssetId>,
+{
+	fn rate(asset_id: AssetId, source: &RateSource<AssetId>) -> Option<(Balance, Balance)> {
+		match source {
+			RateSource::Oracle { underlying, period } => {
+				let route = [Trade {
+					pool: PoolType::Omnipool,
+					asset_in: asset_id,
+					asset_out: *underlying,
+				}];
+				// Price is asset_id/underlying, so the rate is its inverse.
+				let price = Oracle::price(&route, *period)?;
+				Some((price.d, price.n))
+			}
+			RateSource::Custom => CustomRates::rate(asset_id, source),
+		}
+	}
+}
+
 pub struct PriceAdjustmentAdapter<Runtime, LMInstance, OracleSource>(PhantomData<(Runtime, LMInstance, OracleSource)>);
 
 impl<Runtime, LMInstance, OracleSource> PriceAdjustment<GlobalFarmData<Runtime, LMInstance>>
@@ -859,6 +927,22 @@ where
 	}
 }
 
+/// Provides volume of stableswap pool assets going in and out of the pool from the oracle.
+pub struct StableswapOracleVolumeProvider<Runtime, Period>(PhantomData<(Runtime, Period)>);
+
+impl<Runtime, Period> VolumeProvider<AssetId> for StableswapOracleVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config,
+	Period: Get<OraclePeriod>,
+{
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<(Balance, Balance)> {
+		let entry =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(asset_id, pool_id, Period::get(), STABLESWAP_SOURCE)
+				.ok()?;
+		Some((entry.volume.a_in, entry.volume.a_out))
+	}
+}
+
 pub struct VestingInfo<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> pallet_staking::traits::VestingDetails<AccountId, Balance> for VestingInfo<Runtime>
@@ -919,12 +1003,48 @@ where
 	}
 }
 
+/// Ensures that the post-trade spot price of `asset` denominated in `reference` does not deviate from the short
+/// oracle price of the given source more than the circuit breaker allows.
+/// The check is skipped if the oracle is not available yet.
+fn ensure_oracle_price_deviation_limit<Runtime>(
+	source: Source,
+	asset: AssetId,
+	reference: AssetId,
+	spot_price: Price,
+) -> DispatchResult
+where
+	Runtime: pallet_ema_oracle::Config + pallet_circuit_breaker::Config,
+{
+	let Ok(oracle_entry) =
+		pallet_ema_oracle::Pallet::<Runtime>::get_entry(asset, reference, OraclePeriod::Short, source)
+	else {
+		return Ok(());
+	};
+
+	pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+		asset.into(),
+		(spot_price.n.into(), spot_price.d.into()),
+		(oracle_entry.price.n.into(), oracle_entry.price.d.into()),
+	)?;
+
+	Ok(())
+}
+
+fn oracle_price_deviation_limit_weight<Runtime>() -> Weight
+where
+	Runtime: pallet_ema_oracle::Config + pallet_circuit_breaker::Config,
+{
+	let w1 = pallet_ema_oracle::Pallet::<Runtime>::get_entry_weight();
+	let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit();
+	w1.saturating_add(w2)
+}
+
 /// Passes on trade and liquidity changed data from the stableswap to the oracle.
 pub struct StableswapHooksAdapter<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> StableswapHooks<AssetId> for StableswapHooksAdapter<Runtime>
 where
-	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config,
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config + pallet_circuit_breaker::Config,
 {
 	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
@@ -964,12 +1084,7 @@ where
 		Ok(())
 	}
 
-	fn on_trade(
-		pool_id: AssetId,
-		_asset_in: AssetId,
-		_asset_out: AssetId,
-		state: PoolState<AssetId>,
-	) -> DispatchResult {
+	fn on_trade(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
 
 		// As we access by index, let's ensure correct vec lengths.
@@ -991,19 +1106,46 @@ where
 		);
 
 		for idx in 0..pool_size {
-			OnActivityHandler::<Runtime>::on_trade(
-				STABLESWAP_SOURCE,
-				state.assets[idx],
-				pool_id,
-				state.delta[idx],
-				0, // Correct
-				state.after[idx],
-				state.issuance_after,
-				Price::new(state.share_prices[idx].0, state.share_prices[idx].1),
-			)
+			let price = Price::new(state.share_prices[idx].0, state.share_prices[idx].1);
+			// The bought asset is reported as going out of the pool, so the oracle volume keeps the direction
+			// of the trade and net volume of pool assets can be calculated from it.
+			if state.assets[idx] == asset_out {
+				OnActivityHandler::<Runtime>::on_trade(
+					STABLESWAP_SOURCE,
+					pool_id,
+					state.assets[idx],
+					0,
+					state.delta[idx],
+					state.issuance_after,
+					state.after[idx],
+					price.inverted(),
+				)
+			} else {
+				OnActivityHandler::<Runtime>::on_trade(
+					STABLESWAP_SOURCE,
+					state.assets[idx],
+					pool_id,
+					state.delta[idx],
+					0, // Correct
+					state.after[idx],
+					state.issuance_after,
+					price,
+				)
+			}
 			.map_err(|(_, e)| e)?;
 		}
 
+		for asset in [asset_in, asset_out] {
+			if let Some(idx) = state.assets.iter().position(|a| *a == asset) {
+				ensure_oracle_price_deviation_limit::<Runtime>(
+					STABLESWAP_SOURCE,
+					asset,
+					pool_id,
+					Price::new(state.share_prices[idx].0, state.share_prices[idx].1),
+				)?;
+			}
+		}
+
 		Ok(())
 	}
 
@@ -1012,7 +1154,10 @@ where
 	}
 
 	fn on_trade_weight(n: usize) -> Weight {
-		OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(n as u64)
+		OnActivityHandler::<Runtime>::on_trade_weight()
+			.saturating_mul(n as u64)
+			.saturating_add(oracle_price_deviation_limit_weight::<Runtime>().saturating_mul(2))
+			.saturating_add(pallet_circuit_breaker::Pallet::<Runtime>::record_limit_breach_weight().saturating_mul(2))
 	}
 }
 
@@ -330,6 +330,7 @@ impl pallet_route_executor::Config for Test {
 	type AMM = Pools;
 	type EdToRefundCalculator = MockedEdCalculator;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type Pools = ();
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 }
@@ -20,8 +20,8 @@ use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
 	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider,
-	StableswapHooksAdapter, VestingInfo,
+	OracleAssetVolumeProvider, OracleRateProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider,
+	RelayChainBlockNumberProvider, StableswapHooksAdapter, StableswapOracleVolumeProvider, VestingInfo,
 };
 
 pub use hydradx_traits::{
@@ -29,16 +29,19 @@ pub use hydradx_traits::{
 	router::{inverse_route, PoolType, Trade},
 	AccountIdFor, AssetKind, AssetPairAccountIdFor, Liquidity, NativePriceOracle, OnTradeHandler, OraclePeriod, Source,
 };
+use pallet_concentrated_liquidity::weights::WeightInfo as ConcentratedLiquidityWeights;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_omnipool::{
 	traits::{EnsurePriceWithin, OmnipoolHooks},
+	types::Tradability,
 	weights::WeightInfo as OmnipoolWeights,
 };
 use pallet_otc::NamedReserveIdentifier;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
+use pallet_transaction_pause::weights::WeightInfo as TransactionPauseWeights;
 use primitives::constants::{
-	chain::{OMNIPOOL_SOURCE, XYK_SOURCE},
+	chain::{CONCENTRATED_LIQUIDITY_SOURCE, OMNIPOOL_SOURCE, STABLESWAP_SOURCE, WEIGHTED_POOL_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
 	time::DAYS,
 };
@@ -51,8 +54,8 @@ use frame_support::{
 	sp_runtime::traits::{One, PhantomData},
 	sp_runtime::{FixedU128, Perbill, Permill},
 	traits::{
-		AsEnsureOriginWithArg, ConstU32, Contains, Currency, Defensive, EnsureOrigin, Imbalance, LockIdentifier,
-		NeverEnsureOrigin, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstU32, Contains, Currency, Defensive, EnsureOrigin, Everything, Imbalance,
+		LockIdentifier, NeverEnsureOrigin, OnUnbalanced,
 	},
 	BoundedVec, PalletId,
 };
@@ -498,6 +501,118 @@ impl Contains<AccountId> for CircuitBreakerWhitelist {
 parameter_types! {
 	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
 	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
+	pub const DefaultMaxPriceDeviation: Option<(u32, u32)> = None;	// enabled per asset by technical committee
+	pub const DefaultMaxNetTradeVolumeLimitPerWindow: Option<(u32, u32)> = None;	// enabled per asset by technical committee
+	pub const CircuitBreakerRollingWindowLength: u32 = 100;	// 10 minutes with 6s blocks
+	pub const CircuitBreakerBreachThreshold: u32 = 5;
+	pub const CircuitBreakerBreachWindow: BlockNumber = 50;	// 5 minutes with 6s blocks
+}
+
+/// Freezes assets in the Omnipool, saving their tradable state so it can be restored,
+/// and pauses breaching calls via the transaction pause pallet.
+pub struct CircuitBreakerEmergencyHandler;
+
+impl pallet_circuit_breaker::EmergencyHandler<AssetId> for CircuitBreakerEmergencyHandler {
+	type AssetState = Tradability;
+
+	fn freeze_asset(asset_id: AssetId) -> Result<Option<Tradability>, DispatchError> {
+		let Some(state) = Omnipool::assets(asset_id) else {
+			return Ok(None);
+		};
+		Omnipool::set_asset_tradable_state(RawOrigin::Root.into(), asset_id, Tradability::FROZEN)?;
+		Ok(Some(state.tradable))
+	}
+
+	fn unfreeze_asset(asset_id: AssetId, previous_state: Tradability) -> DispatchResult {
+		if !Omnipool::exists(asset_id) {
+			return Ok(());
+		}
+		Omnipool::set_asset_tradable_state(RawOrigin::Root.into(), asset_id, previous_state)
+	}
+
+	fn pause_call(pallet_name: &[u8], function_name: &[u8]) -> Result<bool, DispatchError> {
+		let call = (
+			pallet_transaction_pause::BoundedName::try_from(pallet_name.to_vec())
+				.map_err(|_| pallet_transaction_pause::Error::<Runtime>::NameTooLong)?,
+			pallet_transaction_pause::BoundedName::try_from(function_name.to_vec())
+				.map_err(|_| pallet_transaction_pause::Error::<Runtime>::NameTooLong)?,
+		);
+		if TransactionPause::paused_transactions(call).is_some() {
+			return Ok(false);
+		}
+		TransactionPause::pause_transaction(RawOrigin::Root.into(), pallet_name.to_vec(), function_name.to_vec())?;
+		Ok(true)
+	}
+
+	fn unpause_call(pallet_name: &[u8], function_name: &[u8]) -> DispatchResult {
+		TransactionPause::unpause_transaction(RawOrigin::Root.into(), pallet_name.to_vec(), function_name.to_vec())
+	}
+
+	fn freeze_asset_weight() -> Weight {
+		<Runtime as pallet_omnipool::Config>::WeightInfo::set_asset_tradable_state()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+	}
+
+	fn unfreeze_asset_weight() -> Weight {
+		<Runtime as pallet_omnipool::Config>::WeightInfo::set_asset_tradable_state()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+	}
+
+	fn pause_call_weight() -> Weight {
+		<Runtime as pallet_transaction_pause::Config>::WeightInfo::pause_transaction()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+	}
+
+	fn unpause_call_weight() -> Weight {
+		<Runtime as pallet_transaction_pause::Config>::WeightInfo::unpause_transaction()
+	}
+}
+
+/// Assets sold (in) and bought (out) by trade and liquidity calls guarded by the circuit breaker.
+pub struct CircuitBreakerAffectedAssets;
+
+impl CircuitBreakerAffectedAssets {
+	fn route_assets<'a>(route: impl Iterator<Item = &'a Trade<AssetId>>) -> (Vec<AssetId>, Vec<AssetId>) {
+		route.map(|trade| (trade.asset_in, trade.asset_out)).unzip()
+	}
+
+	fn position_asset(position_id: &ItemId) -> Vec<AssetId> {
+		Omnipool::positions(position_id)
+			.map(|position| vec![position.asset_id])
+			.unwrap_or_default()
+	}
+}
+
+impl pallet_circuit_breaker::AffectedAssets<RuntimeCall, AssetId> for CircuitBreakerAffectedAssets {
+	fn affected_assets(call: &RuntimeCall) -> (Vec<AssetId>, Vec<AssetId>) {
+		match call {
+			RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::buy {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::buy {
+				asset_in, asset_out, ..
+			}) => (vec![*asset_in], vec![*asset_out]),
+			RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity { asset, .. })
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_with_limit { asset, .. }) => (vec![*asset], vec![]),
+			RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity { position_id, .. })
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity_with_limit { position_id, .. }) => {
+				(vec![], Self::position_asset(position_id))
+			}
+			RuntimeCall::Router(pallet_route_executor::Call::sell { route, .. })
+			| RuntimeCall::Router(pallet_route_executor::Call::buy { route, .. }) => Self::route_assets(route.iter()),
+			RuntimeCall::Router(pallet_route_executor::Call::sell_split { routes, .. })
+			| RuntimeCall::Router(pallet_route_executor::Call::buy_split { routes, .. }) => {
+				Self::route_assets(routes.iter().flat_map(|weighted| weighted.route.iter()))
+			}
+			_ => (vec![], vec![]),
+		}
+	}
 }
 
 impl pallet_circuit_breaker::Config for Runtime {
@@ -509,6 +624,13 @@ impl pallet_circuit_breaker::Config for Runtime {
 	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
+	type DefaultMaxPriceDeviation = DefaultMaxPriceDeviation;
+	type DefaultMaxNetTradeVolumeLimitPerWindow = DefaultMaxNetTradeVolumeLimitPerWindow;
+	type RollingWindowLength = CircuitBreakerRollingWindowLength;
+	type BreachThreshold = CircuitBreakerBreachThreshold;
+	type BreachWindow = CircuitBreakerBreachWindow;
+	type AffectedAssets = CircuitBreakerAffectedAssets;
+	type EmergencyHandler = CircuitBreakerEmergencyHandler;
 	type OmnipoolHubAsset = LRNA;
 	type WeightInfo = weights::pallet_circuit_breaker::HydraWeight<Runtime>;
 }
@@ -516,6 +638,24 @@ impl pallet_circuit_breaker::Config for Runtime {
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<{ pallet_ema_oracle::MAX_PERIODS }>> = BoundedVec::truncate_from(vec![
 		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+	pub const ExternalOracleUpdateInterval: BlockNumber = 10;
+	pub const MaxExternalOraclePriceDeviation: Permill = Permill::from_percent(10);
+	pub const ExternalOraclePriceReferencePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+}
+
+/// Oracle sources of local pallets that can't be assigned to external locations.
+pub struct LocalOracleSources;
+impl Contains<Source> for LocalOracleSources {
+	fn contains(source: &Source) -> bool {
+		[
+			OMNIPOOL_SOURCE,
+			STABLESWAP_SOURCE,
+			XYK_SOURCE,
+			WEIGHTED_POOL_SOURCE,
+			CONCENTRATED_LIQUIDITY_SOURCE,
+		]
+		.contains(source)
+	}
 }
 
 pub struct OracleWhitelist<Runtime>(PhantomData<Runtime>);
@@ -542,6 +682,13 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
 	/// 40 seems a decent upper bound for the foreseeable future.
 	type MaxUniqueEntries = ConstU32<40>;
+	/// External prices are pushed by other chains via XCM `Transact`, identified by their origin location.
+	type ExternalSourceLocation = polkadot_xcm::v4::Location;
+	type ExternalOrigin = pallet_xcm::EnsureXcm<Everything>;
+	type ExternalUpdateMinInterval = ExternalOracleUpdateInterval;
+	type MaxExternalPriceDeviation = MaxExternalOraclePriceDeviation;
+	type ExternalPriceReferencePeriod = ExternalOraclePriceReferencePeriod;
+	type ReservedSources = LocalOracleSources;
 	type WeightInfo = weights::pallet_ema_oracle::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Should take care of the overhead introduced by `OracleWhitelist`.
@@ -581,6 +728,8 @@ parameter_types! {
 	pub const MinPlannedYieldingPeriods: BlockNumber = 14_440;  //1d with 6s blocks
 	pub const MinTotalFarmRewards: Balance = NATIVE_EXISTENTIAL_DEPOSIT;
 	pub const OmnipoolLmOracle: [u8; 8] = OMNIPOOL_SOURCE;
+	//NOTE: used only to calculate farms' APR in runtime API.
+	pub const LmBlocksPerYear: BlockNumber = 5_256_000; //relay chain blocks(6s)
 }
 
 type OmnipoolLiquidityMiningInstance = warehouse_liquidity_mining::Instance1;
@@ -669,6 +818,60 @@ impl pallet_xyk_liquidity_mining::Config for Runtime {
 	type WeightInfo = weights::pallet_xyk_liquidity_mining::HydraWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const StableswapWarehouseLMPalletId: PalletId = PalletId(*b"stswWhLM");
+	#[derive(PartialEq, Eq)]
+	pub const StableswapLmMaxEntriesPerDeposit: u8 = 5; //NOTE: Rebenchmark when this change
+	pub const StableswapLmMaxYieldFarmsPerGlobalFarm: u8 = 50; //NOTE: Includes deleted/destroyed farms
+	pub const StableswapLmMinPlannedYieldingPeriods: BlockNumber = 14_440;  //1d with 6s blocks
+	pub const StableswapLmMinTotalFarmRewards: Balance = NATIVE_EXISTENTIAL_DEPOSIT * 100;
+	//NOTE: reward currency is usually not a stableswap asset so price adjustment uses omnipool's oracle.
+	pub const StableswapLmOracle: [u8; 8] = OMNIPOOL_SOURCE;
+}
+
+type StableswapLiquidityMiningInstance = warehouse_liquidity_mining::Instance3;
+impl warehouse_liquidity_mining::Config<StableswapLiquidityMiningInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Currencies;
+	type PalletId = StableswapWarehouseLMPalletId;
+	type TreasuryAccountId = TreasuryAccount;
+	type MinTotalFarmRewards = StableswapLmMinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = StableswapLmMinPlannedYieldingPeriods;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = StableswapLmMaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = StableswapLmMaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Duster;
+	type PriceAdjustment = PriceAdjustmentAdapter<Runtime, StableswapLiquidityMiningInstance, StableswapLmOracle>;
+}
+
+parameter_types! {
+	pub const StableswapLmPalletId: PalletId = PalletId(*b"stsw//LM");
+	pub const StableswapLmCollectionId: CollectionId = 7171_u128;
+	pub const StableswapLmOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const StableswapLmOracleSource: Source = STABLESWAP_SOURCE;
+}
+
+impl pallet_stableswap_liquidity_mining::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currencies = Currencies;
+	type CreateOrigin = AllTechnicalCommitteeMembers;
+	type PalletId = StableswapLmPalletId;
+	type NFTCollectionId = StableswapLmCollectionId;
+	type NFTHandler = Uniques;
+	type LiquidityMiningHandler = StableswapWarehouseLM;
+	type NonDustableWhitelistHandler = Duster;
+	type AssetRegistry = AssetRegistry;
+	type OracleSource = StableswapLmOracleSource;
+	type OraclePeriod = StableswapLmOraclePeriod;
+	type PriceOracle = EmaOracle;
+	type WeightInfo = weights::pallet_stableswap_liquidity_mining::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
 // The reason why there is difference between PROD and benchmark is that it is not possible
 // to set validation data in parachain system pallet in the benchmarks.
 // So for benchmarking, we mock it out and return some hardcoded parent hash
@@ -899,6 +1102,17 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_sell(c, e)
+						.saturating_add(<Runtime as pallet_weighted_pool::Config>::AMMHandler::on_trade_weight())
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight().saturating_mul((c + e).into()))
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -939,6 +1153,17 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_buy(c, e)
+						.saturating_add(<Runtime as pallet_weighted_pool::Config>::AMMHandler::on_trade_weight())
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight().saturating_mul((c + e).into()))
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -963,6 +1188,17 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_buy(c, e)
+						.saturating_add(<Runtime as pallet_weighted_pool::Config>::AMMHandler::on_trade_weight())
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight().saturating_mul((c + e).into()))
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -987,6 +1223,17 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_sell(c, e)
+						.saturating_add(<Runtime as pallet_weighted_pool::Config>::AMMHandler::on_trade_weight())
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight().saturating_mul((c + e).into()))
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1011,6 +1258,17 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_buy(c, e)
+						.saturating_add(<Runtime as pallet_weighted_pool::Config>::AMMHandler::on_trade_weight())
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight().saturating_mul((c + e).into()))
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1043,6 +1301,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_sell(1, 0)
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1056,6 +1321,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::router_execution_sell(1, 0)
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(ConcentratedLiquidity::max_swap_steps_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1080,6 +1352,12 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
+				PoolType::Weighted(_) => {
+					<Runtime as pallet_weighted_pool::Config>::WeightInfo::calculate_spot_price_with_fee()
+				}
+				PoolType::Concentrated => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1102,8 +1380,9 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, WeightedPool, ConcentratedLiquidity);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type Pools = (Omnipool, Stableswap, XYK, LBP, WeightedPool, ConcentratedLiquidity);
 	type NativeAssetId = NativeAssetId;
 	type InspectRegistry = AssetRegistry;
 	type TechnicalOrigin = SuperMajorityTechCommittee;
@@ -1114,6 +1393,16 @@ parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
 	pub const PricePrecision: FixedU128 = FixedU128::from_rational(1, 100);
 	pub MinProfitPercentage: Perbill = Perbill::from_rational(1u32, 100_000_u32); // 0.001%
+	pub LimitOrderFee: Permill = Permill::from_rational(1u32, 1000u32); // 0.1%
+	pub const OtcPalletId: PalletId = PalletId(*b"otcorder");
+}
+
+/// Author of the current block, who receives the fee of OTC limit orders filled in `on_idle`.
+pub struct BlockAuthor;
+impl Get<Option<AccountId>> for BlockAuthor {
+	fn get() -> Option<AccountId> {
+		Authorship::author()
+	}
 }
 
 impl pallet_otc::Config for Runtime {
@@ -1123,6 +1412,14 @@ impl pallet_otc::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = AssetRegistry;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Router = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type PalletId = OtcPalletId;
+	type BlockAuthor = BlockAuthor;
+	type LimitOrderFee = LimitOrderFee;
+	type RouterWeightInfo = RouterWeightInfo;
 	type WeightInfo = weights::pallet_otc::HydraWeight<Runtime>;
 }
 
@@ -1169,6 +1466,8 @@ impl pallet_dynamic_fees::Config for Runtime {
 	type Oracle = OracleAssetVolumeProvider<Runtime, LRNA, DynamicFeesOraclePeriod>;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::pallet_dynamic_fees::HydraWeight<Runtime>;
 }
 
 // Stableswap
@@ -1242,6 +1541,71 @@ impl<T: pallet_asset_registry::Config> BenchmarkHelper<AssetId> for RegisterAsse
 
 		Ok(())
 	}
+
+	fn prepare_rate_source(
+		asset_id: AssetId,
+		underlying: AssetId,
+	) -> Result<pallet_stableswap::types::RateSource<AssetId>, DispatchError> {
+		use frame_support::traits::OnFinalize;
+
+		// Oracle rate is derived from the Omnipool prices of both assets in LRNA
+		for asset in [asset_id, underlying] {
+			pallet_ema_oracle::OnActivityHandler::<Runtime>::on_trade(
+				OMNIPOOL_SOURCE,
+				asset,
+				LRNA,
+				1_000_000_000_000,
+				2_000_000_000_000,
+				1_000_000_000_000_000,
+				2_000_000_000_000_000,
+				EmaPrice::new(2_000_000_000_000, 1_000_000_000_000),
+			)
+			.map_err(|(_, e)| e)?;
+		}
+		EmaOracle::on_finalize(System::block_number());
+
+		Ok(pallet_stableswap::types::RateSource::Oracle {
+			underlying,
+			period: OraclePeriod::Short,
+		})
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_ema_oracle::Config> pallet_stableswap_liquidity_mining::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn set_share_price(asset_id: AssetId, pool_id: AssetId) -> DispatchResult {
+		use frame_support::traits::OnFinalize;
+		use hydradx_traits::OnLiquidityChangedHandler;
+
+		pallet_ema_oracle::OnActivityHandler::<Runtime>::on_liquidity_changed(
+			STABLESWAP_SOURCE,
+			asset_id,
+			pool_id,
+			1_000_000_000_000,
+			1_000_000_000_000,
+			1_000_000_000_000_000,
+			1_000_000_000_000_000,
+			EmaPrice::new(1_000_000_000_000, 1_000_000_000_000),
+		)
+		.map_err(|(_, e)| e)?;
+		EmaOracle::on_finalize(System::block_number());
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_weighted_pool::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1280,6 +1644,8 @@ impl pallet_stableswap::Config for Runtime {
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type RateProvider = OracleRateProvider<OraclePriceProvider<AssetId, EmaOracle, LRNA>, ()>;
+	type VolumeProvider = StableswapOracleVolumeProvider<Runtime, DynamicFeesOraclePeriod>;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
@@ -1288,6 +1654,64 @@ impl pallet_stableswap::Config for Runtime {
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::pallet_omnipool_subpools::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const WeightedPoolOracleSource: Source = WEIGHTED_POOL_SOURCE;
+}
+
+impl pallet_weighted_pool::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type ShareAccountId = StableswapAccountIdConstructor<Runtime>;
+	type AssetInspection = AssetRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Duster;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type OracleSource = WeightedPoolOracleSource;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type WeightInfo = weights::pallet_weighted_pool::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityOracleSource: Source = CONCENTRATED_LIQUIDITY_SOURCE;
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"clpool__");
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 6969u128;
+	pub const MaxSwapSteps: u32 = 16;
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type AssetInspection = AssetRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Duster;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type AssetPairAccountId = AssetPairAccountId<Self>;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type PositionItemId = ItemId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = Uniques;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxSwapSteps = MaxSwapSteps;
+	type WeightInfo = weights::pallet_concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
@@ -1327,6 +1751,7 @@ parameter_types! {
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 1;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(2,100);
+	pub const StakingMaxPositionsPerAccount: u32 = 10;
 }
 
 pub struct PointsPerAction;
@@ -1369,6 +1794,7 @@ impl pallet_staking::Config for Runtime {
 	type Collections = FreezableNFT<Runtime, Self::RuntimeOrigin>;
 	type NFTHandler = Uniques;
 	type MaxVotes = MaxVotes;
+	type MaxPositionsPerAccount = StakingMaxPositionsPerAccount;
 	type ReferendumInfo = pallet_staking::integrations::democracy::ReferendumStatus<Runtime>;
 	type MaxPointsPerAction = PointsPerAction;
 	type Vesting = VestingInfo<Runtime>;
@@ -1404,6 +1830,7 @@ impl pallet_lbp::Config for Runtime {
 	type MultiCurrency = Currencies;
 	type LockedBalance = MultiCurrencyLockedBalance<Runtime, NativeAssetId>;
 	type CreatePoolOrigin = SuperMajorityTechCommittee;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type LBPWeightFunction = pallet_lbp::LBPWeightFunction;
 	type AssetPairAccountId = AssetPairAccountId<Self>;
 	type WeightInfo = weights::pallet_lbp::HydraWeight<Runtime>;
@@ -1412,6 +1839,7 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type PostSaleHandler = hydradx_adapters::lbp::PostSaleLiquidityMigration<Runtime>;
 }
 
 parameter_types! {
@@ -35,8 +35,8 @@ use hydradx_traits::router::AssetPair;
 use hydradx_traits::router::PoolType;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
-use pallet_dca::types::{Order, Schedule, ScheduleId};
-use pallet_dca::{ScheduleIdsPerBlock, Schedules};
+use pallet_dca::types::{BudgetChange, Order, Schedule, ScheduleAmendment, ScheduleId};
+use pallet_dca::{PausedSchedules, RemainingAmounts, ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use scale_info::prelude::vec::Vec;
@@ -81,6 +81,7 @@ fn schedule_fake(
 				asset_out,
 			}]),
 		},
+		price_trigger: None,
 	};
 	schedule1
 }
@@ -113,6 +114,7 @@ fn schedule_buy_fake(
 				asset_out,
 			}]),
 		},
+		price_trigger: None,
 	};
 	schedule1
 }
@@ -141,6 +143,7 @@ fn schedule_sell_fake(
 				asset_out,
 			}]),
 		},
+		price_trigger: None,
 	};
 	schedule1
 }
@@ -361,6 +364,7 @@ runtime_benchmarks! {
 					asset_out: DAI,
 				}]),
 			},
+			price_trigger: None,
 		};
 
 		let execution_block = 100u32;
@@ -402,6 +406,90 @@ runtime_benchmarks! {
 		assert!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
 	}
 
+	pause {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+
+		//We fill the execution block of the paused schedule, leading to worst case of removing it from the block
+		for i in 0..MaxSchedulesPerBlock::get() {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+
+		assert_eq!(MaxSchedulesPerBlock::get() as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block).len());
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id, Option::Some(execution_block))
+	verify {
+		assert!(<PausedSchedules<Runtime>>::contains_key(schedule_id));
+		assert_eq!((MaxSchedulesPerBlock::get() - 1) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block).len());
+	}
+
+	resume {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		assert_ok!(DCA::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id, Option::Some(execution_block)));
+
+		//We fill blocks with schedules leaving only one place, so the resumed schedule searches for a free block
+		let number_of_all_schedules = MaxSchedulesPerBlock::get() + MaxSchedulesPerBlock::get() * RETRY_TO_SEARCH_FOR_FREE_BLOCK - 1;
+		for i in 0..number_of_all_schedules {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id, Option::Some(execution_block))
+	verify {
+		assert!(!<PausedSchedules<Runtime>>::contains_key(schedule_id));
+	}
+
+	amend {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000_000_000i128)?;
+
+		let amount_buy = 200 * ONE;
+		let mut schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_buy);
+		schedule1.total_amount = 100_000 * ONE;
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+		//Withdrawing from the budget of a buy order is the worst case, as the budget is checked against a single trade
+		let withdrawn_amount = 1_000 * ONE;
+		let amendment = ScheduleAmendment {
+			period: Some(5u32),
+			slippage: Some(Some(Permill::from_percent(10))),
+			stability_threshold: Some(Some(Permill::from_percent(5))),
+			route: Some(create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}])),
+			budget: Some(BudgetChange::Withdraw(withdrawn_amount)),
+		};
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id, amendment)
+	verify {
+		assert_eq!(<RemainingAmounts<Runtime>>::get(schedule_id), Some(100_000 * ONE - withdrawn_amount));
+		assert_eq!(get_named_reseve_balance(HDX, caller), 100_000 * ONE - withdrawn_amount);
+	}
+
 }
 
 pub const INITIAL_BALANCE: Balance = 10_000_000 * ONE;
@@ -30,7 +30,7 @@ pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
 	);
 }
 
-fn run_to_block(to: u32) {
+pub fn run_to_block(to: u32) {
 	while System::block_number() < to {
 		let b = System::block_number();
 
@@ -0,0 +1,176 @@
+use crate::{AccountId, AssetId, Balance, Omnipool, OmnipoolSubpools, RegisterAsset, Runtime};
+
+use super::omnipool::{init, run_to_block, update_balance};
+
+use frame_benchmarking::account;
+use frame_support::dispatch::DispatchResult;
+use frame_support::sp_runtime::{FixedU128, Permill};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use pallet_stableswap::BenchmarkHelper;
+
+const ASSET_A: AssetId = 100;
+const ASSET_B: AssetId = 101;
+const ASSET_C: AssetId = 102;
+const ASSET_D: AssetId = 103;
+const ASSET_E: AssetId = 104;
+const SHARE_ASSET_1: AssetId = 110;
+const SHARE_ASSET_2: AssetId = 111;
+
+const TOKEN_AMOUNT: Balance = 1_000_000_000_000_000u128;
+const AMPLIFICATION: u16 = 100;
+
+/// Registers stable assets and share assets and adds stable assets to the Omnipool.
+fn init_assets() -> DispatchResult {
+	init()?;
+
+	let acc = Omnipool::protocol_account();
+
+	for asset_id in [ASSET_A, ASSET_B, ASSET_C, ASSET_D, ASSET_E] {
+		<RegisterAsset<Runtime> as BenchmarkHelper<AssetId>>::register_asset(asset_id, 12)?;
+		update_balance(asset_id, &acc, TOKEN_AMOUNT);
+		Omnipool::add_token(
+			RawOrigin::Root.into(),
+			asset_id,
+			FixedU128::from((1, 2)),
+			Permill::from_percent(100),
+			account("owner", 0, 1),
+		)?;
+	}
+
+	for share_asset in [SHARE_ASSET_1, SHARE_ASSET_2] {
+		<RegisterAsset<Runtime> as BenchmarkHelper<AssetId>>::register_asset(share_asset, 18)?;
+	}
+
+	Ok(())
+}
+
+fn create_subpool(share_asset: AssetId, asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+	OmnipoolSubpools::create_subpool(
+		RawOrigin::Root.into(),
+		share_asset,
+		asset_a,
+		asset_b,
+		Permill::from_percent(100),
+		AMPLIFICATION,
+		Permill::from_percent(1),
+	)
+}
+
+/// Creates two subpools so that trades can route through both of them - the most expensive case.
+fn init_subpools() -> DispatchResult {
+	init_assets()?;
+	create_subpool(SHARE_ASSET_1, ASSET_A, ASSET_B)?;
+	create_subpool(SHARE_ASSET_2, ASSET_C, ASSET_D)
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_omnipool_subpools}
+
+	create_subpool {
+		init_assets()?;
+	}: _(RawOrigin::Root, SHARE_ASSET_1, ASSET_A, ASSET_B, Permill::from_percent(100), AMPLIFICATION, Permill::from_percent(1))
+	verify {
+		assert!(OmnipoolSubpools::is_subpool(SHARE_ASSET_1));
+		assert_eq!(OmnipoolSubpools::subpool_of(ASSET_A), Some(SHARE_ASSET_1));
+	}
+
+	migrate_asset_to_subpool {
+		init_subpools()?;
+	}: _(RawOrigin::Root, SHARE_ASSET_1, ASSET_E)
+	verify {
+		assert_eq!(OmnipoolSubpools::subpool_of(ASSET_E), Some(SHARE_ASSET_1));
+	}
+
+	migrate_position {
+		init_assets()?;
+
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(ASSET_A, &lp_provider, TOKEN_AMOUNT);
+
+		run_to_block(10);
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), ASSET_A, 10_000_000_000_000u128)?;
+
+		create_subpool(SHARE_ASSET_1, ASSET_A, ASSET_B)?;
+	}: _(RawOrigin::Signed(lp_provider), position_id)
+	verify {
+		assert_eq!(Omnipool::positions(position_id).map(|p| p.asset_id), Some(SHARE_ASSET_1));
+	}
+
+	sell {
+		init_subpools()?;
+
+		let seller: AccountId = account("seller", 2, 1);
+		update_balance(ASSET_A, &seller, TOKEN_AMOUNT);
+
+		let amount_sell = 1_000_000_000_000u128;
+		let buy_min_amount = 1_000_000_000u128;
+	}: _(RawOrigin::Signed(seller.clone()), ASSET_A, ASSET_C, amount_sell, buy_min_amount)
+	verify {
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(ASSET_C, &seller) >= buy_min_amount);
+	}
+
+	buy {
+		init_subpools()?;
+
+		let buyer: AccountId = account("buyer", 2, 1);
+		update_balance(ASSET_A, &buyer, TOKEN_AMOUNT);
+
+		let amount_buy = 1_000_000_000_000u128;
+		let max_sell_amount = 10_000_000_000_000u128;
+	}: _(RawOrigin::Signed(buyer.clone()), ASSET_C, ASSET_A, amount_buy, max_sell_amount)
+	verify {
+		assert_eq!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(ASSET_C, &buyer), amount_buy);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	const DAI: AssetId = 2;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<crate::Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![
+				(
+					Some(1),
+					Some(b"LRNA".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+				(
+					Some(DAI),
+					Some(b"DAI".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+			],
+			native_asset_name: b"HDX".to_vec().try_into().unwrap(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+			native_decimals: 12,
+			native_symbol: b"HDX".to_vec().try_into().unwrap(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -45,6 +45,7 @@ pub use xcm::*;
 
 use codec::{Decode, Encode};
 use hydradx_traits::evm::InspectEvmAccounts;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouterT, Trade};
 use sp_api::impl_runtime_apis;
 use sp_core::{ConstU128, Get, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
@@ -113,10 +114,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 245,
+	spec_version: 277,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -185,6 +186,9 @@ construct_runtime!(
 		LBP: pallet_lbp = 73,
 		XYK: pallet_xyk = 74,
 		Referrals: pallet_referrals = 75,
+		OmnipoolSubpools: pallet_omnipool_subpools = 76,
+		WeightedPool: pallet_weighted_pool = 78,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 80,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -200,6 +204,8 @@ construct_runtime!(
 
 		XYKLiquidityMining: pallet_xyk_liquidity_mining = 95,
 		XYKWarehouseLM: warehouse_liquidity_mining::<Instance2> = 96,
+		StableswapLiquidityMining: pallet_stableswap_liquidity_mining = 97,
+		StableswapWarehouseLM: warehouse_liquidity_mining::<Instance3> = 98,
 
 		RelayChainInfo: pallet_relaychain_info = 201,
 		//NOTE: DCA pallet should be declared before ParachainSystem pallet,
@@ -256,6 +262,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
+	pallet_circuit_breaker::RecordLimitBreach<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -274,6 +281,13 @@ pub type Executive = frame_executive::Executive<
 		frame_support::migrations::RemovePallet<XcmRateLimiterPalletName, <Runtime as frame_system::Config>::DbWeight>,
 		cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
 		pallet_identity::migration::versioned::V0ToV1<Runtime, 450u64>, // We have currently 379 identities in basllisk, so limit of 450 should be enough
+		pallet_dca::migration::v1::Migration<Runtime>,
+		pallet_stableswap::migration::v1::Migration<Runtime>,
+		pallet_lbp::migration::v1::Migration<Runtime>,
+		pallet_stableswap_liquidity_mining::migration::v1::Migration<Runtime>,
+		warehouse_liquidity_mining::migration::v1::Migration<Runtime, warehouse_liquidity_mining::Instance1>,
+		warehouse_liquidity_mining::migration::v1::Migration<Runtime, warehouse_liquidity_mining::Instance2>,
+		warehouse_liquidity_mining::migration::v1::Migration<Runtime, warehouse_liquidity_mining::Instance3>,
 	),
 >;
 
@@ -705,6 +719,135 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, Balance> for Runtime {
+		fn asset_states() -> Vec<(AssetId, pallet_omnipool::types::AssetReserveState<Balance>)> {
+			Omnipool::asset_states()
+		}
+
+		fn asset_state(asset_id: AssetId) -> Option<pallet_omnipool::types::AssetReserveState<Balance>> {
+			Omnipool::load_asset_state(asset_id).ok()
+		}
+
+		fn hub_asset_imbalance() -> pallet_omnipool::types::SimpleImbalance<Balance> {
+			Omnipool::current_imbalance()
+		}
+
+		fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<sp_runtime::FixedU128> {
+			<Omnipool as hydradx_traits::pools::SpotPriceProvider<AssetId>>::spot_price(asset_a, asset_b)
+		}
+
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, sp_runtime::DispatchError> {
+			Omnipool::quote_sell(asset_in, asset_out, amount_in)
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, sp_runtime::DispatchError> {
+			Omnipool::quote_buy(asset_in, asset_out, amount_out)
+		}
+	}
+
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn find_best_sell_route(
+			asset_pair: AssetPair<AssetId>,
+			amount_in: Balance,
+		) -> Option<(Vec<Trade<AssetId>>, Vec<AmountInAndOut<Balance>>)> {
+			Router::find_best_sell_route(asset_pair, amount_in)
+		}
+
+		fn find_best_buy_route(
+			asset_pair: AssetPair<AssetId>,
+			amount_out: Balance,
+		) -> Option<(Vec<Trade<AssetId>>, Vec<AmountInAndOut<Balance>>)> {
+			Router::find_best_buy_route(asset_pair, amount_out)
+		}
+
+		fn calculate_sell_trade_amounts(
+			route: Vec<Trade<AssetId>>,
+			amount_in: Balance,
+		) -> Result<Vec<AmountInAndOut<Balance>>, sp_runtime::DispatchError> {
+			<Router as RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>>>::
+				calculate_sell_trade_amounts(&route, amount_in)
+		}
+
+		fn calculate_buy_trade_amounts(
+			route: Vec<Trade<AssetId>>,
+			amount_out: Balance,
+		) -> Result<Vec<AmountInAndOut<Balance>>, sp_runtime::DispatchError> {
+			let mut amounts =
+				<Router as RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>>>::
+					calculate_buy_trade_amounts(&route, amount_out)?;
+			amounts.reverse();
+			Ok(amounts)
+		}
+	}
+
+	impl pallet_ema_oracle_rpc_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber> for Runtime {
+		fn get_entry(
+			source: hydradx_traits::Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: hydradx_traits::OraclePeriod,
+		) -> Result<
+			hydradx_traits::AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>,
+			pallet_ema_oracle::OracleError,
+		> {
+			<EmaOracle as hydradx_traits::AggregatedOracle<AssetId, Balance, BlockNumber, pallet_ema_oracle::Price>>::
+				get_entry(asset_a, asset_b, period, source)
+		}
+
+		fn tracked_pairs() -> Vec<(hydradx_traits::Source, (AssetId, AssetId))> {
+			EmaOracle::tracked_pairs()
+		}
+
+		fn route_price(
+			route: Vec<Trade<AssetId>>,
+			period: hydradx_traits::OraclePeriod,
+		) -> Option<pallet_ema_oracle::Price> {
+			<hydradx_adapters::OraclePriceProvider<AssetId, EmaOracle, LRNA> as hydradx_traits::PriceOracle<AssetId>>::
+				price(&route, period)
+		}
+	}
+
+	impl pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningApi<Block, AssetId> for Runtime {
+		fn deposit_rewards(
+			program: pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningProgram,
+			deposit_id: pallet_liquidity_mining_rpc_runtime_api::DepositId,
+		) -> Result<Vec<pallet_liquidity_mining_rpc_runtime_api::FarmEntryRewards<AssetId>>, sp_runtime::DispatchError> {
+			use pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningProgram;
+
+			match program {
+				LiquidityMiningProgram::Omnipool => OmnipoolWarehouseLM::deposit_rewards(deposit_id),
+				LiquidityMiningProgram::XYK => XYKWarehouseLM::deposit_rewards(deposit_id),
+				LiquidityMiningProgram::Stableswap => StableswapWarehouseLM::deposit_rewards(deposit_id),
+			}
+		}
+
+		fn global_farm_rewards_info(
+			program: pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningProgram,
+			global_farm_id: pallet_liquidity_mining_rpc_runtime_api::GlobalFarmId,
+		) -> Result<pallet_liquidity_mining_rpc_runtime_api::GlobalFarmRewardsInfo<AssetId>, sp_runtime::DispatchError> {
+			use pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningProgram;
+
+			let blocks_per_year = LmBlocksPerYear::get();
+			match program {
+				LiquidityMiningProgram::Omnipool => {
+					OmnipoolWarehouseLM::global_farm_rewards_info(global_farm_id, blocks_per_year)
+				}
+				LiquidityMiningProgram::XYK => XYKWarehouseLM::global_farm_rewards_info(global_farm_id, blocks_per_year),
+				LiquidityMiningProgram::Stableswap => {
+					StableswapWarehouseLM::global_farm_rewards_info(global_farm_id, blocks_per_year)
+				}
+			}
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -728,6 +871,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_list_benchmark!(list, extra, pallet_duster, benchmarking::duster);
 			orml_list_benchmark!(list, extra, pallet_omnipool, benchmarking::omnipool);
+			orml_list_benchmark!(list, extra, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
 			orml_list_benchmark!(list, extra, pallet_dca, benchmarking::dca);
 			orml_list_benchmark!(list, extra, pallet_xyk, benchmarking::xyk);
@@ -813,6 +957,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_add_benchmark!(params, batches, pallet_duster, benchmarking::duster);
 			orml_add_benchmark!(params, batches, pallet_omnipool, benchmarking::omnipool);
+			orml_add_benchmark!(params, batches, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
 			orml_add_benchmark!(params, batches, pallet_dca, benchmarking::dca);
 			orml_add_benchmark!(params, batches, pallet_xyk, benchmarking::xyk);
@@ -847,8 +992,12 @@ mod benches {
 		[pallet_transaction_pause, TransactionPause]
 		[pallet_ema_oracle, EmaOracle]
 		[pallet_circuit_breaker, CircuitBreaker]
+		[pallet_dynamic_fees, DynamicFees]
 		[pallet_bonds, Bonds]
 		[pallet_stableswap, Stableswap]
+		[pallet_stableswap_liquidity_mining, StableswapLiquidityMining]
+		[pallet_weighted_pool, WeightedPool]
+		[pallet_concentrated_liquidity, ConcentratedLiquidity]
 		[pallet_claims, Claims]
 		[pallet_staking, Staking]
 		[pallet_referrals, Referrals]
@@ -11,10 +11,12 @@ pub mod pallet_claims;
 pub mod pallet_collator_selection;
 pub mod pallet_collective_council;
 pub mod pallet_collective_technical_committee;
+pub mod pallet_concentrated_liquidity;
 pub mod pallet_currencies;
 pub mod pallet_dca;
 pub mod pallet_democracy;
 pub mod pallet_duster;
+pub mod pallet_dynamic_fees;
 pub mod pallet_dynamic_evm_fee;
 pub mod pallet_elections_phragmen;
 pub mod pallet_ema_oracle;
@@ -25,6 +27,7 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_omnipool;
 pub mod pallet_omnipool_liquidity_mining;
+pub mod pallet_omnipool_subpools;
 pub mod pallet_otc;
 pub mod pallet_otc_settlements;
 pub mod pallet_preimage;
@@ -33,6 +36,7 @@ pub mod pallet_referrals;
 pub mod pallet_route_executor;
 pub mod pallet_scheduler;
 pub mod pallet_stableswap;
+pub mod pallet_stableswap_liquidity_mining;
 pub mod pallet_staking;
 pub mod pallet_state_trie_migration;
 pub mod pallet_timestamp;
@@ -41,6 +45,7 @@ pub mod pallet_transaction_multi_payment;
 pub mod pallet_transaction_pause;
 pub mod pallet_treasury;
 pub mod pallet_utility;
+pub mod pallet_weighted_pool;
 pub mod pallet_xcm;
 pub mod pallet_xyk;
 pub mod pallet_xyk_liquidity_mining;
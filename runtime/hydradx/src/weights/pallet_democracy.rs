@@ -16,27 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_democracy`
+//! Weights for `pallet_democracy`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-05-29, STEPS: `10`, REPEAT: `30`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// target/release/hydradx
-// benchmark
-// pallet
-// --chain=dev
-// --steps=10
-// --repeat=30
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=scripts/pallet-weight-template.hbs
-// --pallet=pallet-democracy
-// --output=weights/democracy.rs
-// --extrinsic=*
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-05-29
+//! (STEPS: `10`, REPEAT: `30`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
+//! Placeholders assume the worst case of 10 staking positions per account (`MaxPositionsPerAccount` of the runtime).
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -88,20 +73,21 @@ impl<T: frame_system::Config> pallet_democracy::WeightInfo for HydraWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:2 w:0)
+	/// Storage: `Uniques::Account` (r:11 w:0)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:10)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::PositionVotes` (r:1 w:1)
+	/// Storage: `Staking::PositionVotes` (r:10 w:10)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
 	fn vote_new() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `15343`
+		//  Measured:  `36637`
 		//  Estimated: `268590`
-		// Minimum execution time: 415_574_000 picoseconds.
-		Weight::from_parts(419_090_000, 268590)
-			.saturating_add(T::DbWeight::get().reads(107_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		// Minimum execution time: 2_274_613_000 picoseconds.
+		Weight::from_parts(2_296_348_000, 268590)
+			.saturating_add(T::DbWeight::get().reads(134_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
 	}
 	/// Storage: `Democracy::ReferendumInfoOf` (r:100 w:1)
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
@@ -111,20 +97,21 @@ impl<T: frame_system::Config> pallet_democracy::WeightInfo for HydraWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:2 w:0)
+	/// Storage: `Uniques::Account` (r:11 w:0)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:10)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::PositionVotes` (r:1 w:1)
+	/// Storage: `Staking::PositionVotes` (r:10 w:10)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
 	fn vote_existing() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `15365`
+		//  Measured:  `36659`
 		//  Estimated: `268590`
-		// Minimum execution time: 415_249_000 picoseconds.
-		Weight::from_parts(422_003_000, 268590)
-			.saturating_add(T::DbWeight::get().reads(107_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		// Minimum execution time: 2_276_032_000 picoseconds.
+		Weight::from_parts(2_301_174_000, 268590)
+			.saturating_add(T::DbWeight::get().reads(134_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
 	}
 	/// Storage: `Democracy::ReferendumInfoOf` (r:1 w:1)
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
@@ -401,49 +388,51 @@ impl<T: frame_system::Config> pallet_democracy::WeightInfo for HydraWeight<T> {
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
 	/// Storage: `Democracy::VotingOf` (r:1 w:1)
 	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:2 w:0)
+	/// Storage: `Uniques::Account` (r:11 w:0)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::ProcessedVotes` (r:1 w:0)
 	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:10)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::PositionVotes` (r:1 w:1)
+	/// Storage: `Staking::PositionVotes` (r:10 w:1)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 100]`.
 	fn remove_vote(r: u32, ) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `1503 + r * (46 ±0)`
-		//  Estimated: `7260`
-		// Minimum execution time: 48_213_000 picoseconds.
-		Weight::from_parts(51_366_745, 7260)
+		//  Measured:  `4391 + r * (46 ±0)`
+		//  Estimated: `47080`
+		// Minimum execution time: 156_508_000 picoseconds.
+		Weight::from_parts(159_844_217, 47080)
 			// Standard Error: 4_640
 			.saturating_add(Weight::from_parts(131_667, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: `Democracy::ReferendumInfoOf` (r:1 w:1)
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
 	/// Storage: `Democracy::VotingOf` (r:1 w:1)
 	/// Proof: `Democracy::VotingOf` (`max_values`: None, `max_size`: Some(3795), added: 6270, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:2 w:0)
+	/// Storage: `Uniques::Account` (r:11 w:0)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::ProcessedVotes` (r:1 w:0)
 	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:10)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::PositionVotes` (r:1 w:1)
+	/// Storage: `Staking::PositionVotes` (r:10 w:1)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 100]`.
 	fn remove_other_vote(r: u32, ) -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `1503 + r * (46 ±0)`
-		//  Estimated: `7260`
-		// Minimum execution time: 48_122_000 picoseconds.
-		Weight::from_parts(51_591_726, 7260)
+		//  Measured:  `4391 + r * (46 ±0)`
+		//  Estimated: `47080`
+		// Minimum execution time: 156_371_000 picoseconds.
+		Weight::from_parts(160_102_385, 47080)
 			// Standard Error: 4_962
 			.saturating_add(Weight::from_parts(133_813, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: `Democracy::NextExternal` (r:1 w:0)
 	/// Proof: `Democracy::NextExternal` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
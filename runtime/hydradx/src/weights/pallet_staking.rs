@@ -16,27 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_staking`
+//! Weights for `pallet_staking`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-05-29, STEPS: `10`, REPEAT: `30`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// target/release/hydradx
-// benchmark
-// pallet
-// --chain=dev
-// --steps=10
-// --repeat=30
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=scripts/pallet-weight-template.hbs
-// --pallet=pallet-staking
-// --output=weights/staking.rs
-// --extrinsic=*
+//! Weights were generated with the benchmark CLI version 32.0.0 on 2024-05-29
+//! (STEPS: `10`, REPEAT: `30`, HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`),
+//! except for the functions marked as placeholders, which have to be regenerated before release.
+//! Placeholders assume the worst case of 10 staking positions per account (`MaxPositionsPerAccount` of the runtime).
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -71,7 +56,7 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
 	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:1 w:1)
+	/// Storage: `Uniques::Account` (r:11 w:1)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -87,15 +72,16 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Uniques::CollectionMaxSupply` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:0 w:1)
+	/// Storage: `Staking::Positions` (r:9 w:1)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	fn stake() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `1274`
-		//  Estimated: `6196`
-		// Minimum execution time: 89_837_000 picoseconds.
-		Weight::from_parts(90_522_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
+		//  Measured:  `4211`
+		//  Estimated: `29447`
+		// Minimum execution time: 130_706_000 picoseconds.
+		Weight::from_parts(131_404_000, 29447)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
@@ -106,7 +92,7 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:1)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
@@ -114,15 +100,18 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
 	/// Storage: `Democracy::ReferendumInfoOf` (r:100 w:0)
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:11 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn increase_stake() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `3379`
+		//  Measured:  `6592`
 		//  Estimated: `268590`
-		// Minimum execution time: 260_523_000 picoseconds.
-		Weight::from_parts(264_621_000, 268590)
-			.saturating_add(T::DbWeight::get().reads(109_u64))
+		// Minimum execution time: 298_237_000 picoseconds.
+		Weight::from_parts(302_118_000, 268590)
+			.saturating_add(T::DbWeight::get().reads(129_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
@@ -133,7 +122,7 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:1)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::PositionVotes` (r:1 w:1)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
@@ -141,15 +130,18 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Democracy::ReferendumInfoOf` (`max_values`: None, `max_size`: Some(201), added: 2676, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:11 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	fn claim() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `3379`
+		//  Measured:  `6592`
 		//  Estimated: `268590`
-		// Minimum execution time: 256_924_000 picoseconds.
-		Weight::from_parts(260_188_000, 268590)
-			.saturating_add(T::DbWeight::get().reads(109_u64))
+		// Minimum execution time: 294_512_000 picoseconds.
+		Weight::from_parts(297_655_000, 268590)
+			.saturating_add(T::DbWeight::get().reads(129_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Staking::Staking` (r:1 w:1)
@@ -158,7 +150,7 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::Positions` (r:1 w:1)
+	/// Storage: `Staking::Positions` (r:10 w:1)
 	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::PositionVotes` (r:1 w:1)
 	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
@@ -170,17 +162,46 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Storage: `Uniques::Account` (r:10 w:1)
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	fn unstake() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
 		// Proof Size summary in bytes:
-		//  Measured:  `1431`
-		//  Estimated: `6196`
-		// Minimum execution time: 136_034_000 picoseconds.
-		Weight::from_parts(137_189_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
+		//  Measured:  `4644`
+		//  Estimated: `27060`
+		// Minimum execution time: 177_331_000 picoseconds.
+		Weight::from_parts(178_927_000, 27060)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Storage: `Staking::Staking` (r:1 w:1)
+	/// Proof: `Staking::Staking` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::PositionVotes` (r:1 w:0)
+	/// Proof: `Staking::PositionVotes` (`max_values`: None, `max_size`: Some(2134), added: 4609, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::ProcessedVotes` (r:1 w:0)
+	/// Proof: `Staking::ProcessedVotes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Positions` (r:10 w:1)
+	/// Proof: `Staking::Positions` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:11 w:0)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn partial_unstake() -> Weight {
+		// Placeholder until regenerated with the benchmark CLI.
+		// Proof Size summary in bytes:
+		//  Measured:  `4644`
+		//  Estimated: `29447`
+		// Minimum execution time: 162_594_000 picoseconds.
+		Weight::from_parts(163_820_000, 29447)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
\ No newline at end of file
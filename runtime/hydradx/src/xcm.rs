@@ -467,6 +467,7 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 				| RuntimeCall::Treasury(..)
 				| RuntimeCall::Utility(pallet_utility::Call::as_derivative { .. })
 				| RuntimeCall::Vesting(..)
+				| RuntimeCall::EmaOracle(pallet_ema_oracle::Call::update_external_price { .. })
 				| RuntimeCall::Proxy(..)
 				| RuntimeCall::CollatorSelection(
 					pallet_collator_selection::Call::set_desired_candidates { .. }
@@ -257,6 +257,24 @@ pub trait AMMPosition<AssetId, Balance> {
 	) -> Result<(Balance, Balance), Self::Error>;
 }
 
+/// Implementers of this trait provide adding of liquidity to the AMM pool on behalf of `who`.
+pub trait AMMAddLiquidity<AccountId, AssetId, Balance> {
+	/// Add `amount_a` of `asset_a` and corresponding amount of `asset_b`, but not more than
+	/// `amount_b_max_limit`, to the pool.
+	///
+	/// Returns: amount of `asset_b` added to the pool
+	fn add_liquidity(
+		who: AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Weight of the `add_liquidity` operation.
+	fn add_liquidity_weight() -> Weight;
+}
+
 /// Provides account's fee payment asset
 pub trait AccountFeeCurrency<AccountId> {
 	type AssetId;
@@ -163,6 +163,12 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 
 	/// Returns `Some(global_farm_id)` for given `deposit_id` and `yield_farm_id` or `None`.
 	fn get_global_farm_id(deposit_id: DepositId, yield_farm_id: YieldFarmId) -> Option<u32>;
+
+	/// Returns `Some(yield_farm_ids)` of all farm entries in the deposit or `None` if deposit doesn't exist.
+	fn get_yield_farm_ids(deposit_id: DepositId) -> Option<Vec<YieldFarmId>>;
+
+	/// Returns `Some(amm_pool_id)` of the deposit or `None` if deposit doesn't exist.
+	fn get_amm_pool_id(deposit_id: DepositId) -> Option<Self::AmmPoolId>;
 }
 
 /// Implementers of this trait provide `price_adjustment` for given `GlobalFarm`.
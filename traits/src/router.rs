@@ -3,7 +3,9 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -61,11 +63,14 @@ pub trait RouteProvider<AssetId> {
 }
 
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PoolType<AssetId> {
 	XYK,
 	LBP,
 	Stableswap(AssetId),
 	Omnipool,
+	Weighted(AssetId),
+	Concentrated,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -76,13 +81,21 @@ pub enum ExecutorError<E> {
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
 	pub asset_in: AssetId,
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+///A route with the share of the trade amount executed through it, used in split trades
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
+pub struct WeightedRoute<AssetId> {
+	pub weight: Permill,
+	pub route: Vec<Trade<AssetId>>,
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
@@ -184,7 +197,7 @@ pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 }
 
 #[allow(clippy::redundant_clone)] //Needed as it complains about redundant clone, but clone is needed as Origin is moved and it is not copy type.
-#[impl_trait_for_tuples::impl_for_tuples(1, 5)]
+#[impl_trait_for_tuples::impl_for_tuples(1, 6)]
 impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	TradeExecution<Origin, AccountId, AssetId, Balance> for Tuple
 {
@@ -302,6 +315,21 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// Lists the pools of an AMM together with the assets which can be traded in them.
+/// Used by the router to discover possible routes between two assets.
+pub trait PoolsProvider<AssetId> {
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(6)]
+impl<AssetId> PoolsProvider<AssetId> for Tuple {
+	fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		let mut pools = Vec::new();
+		for_tuples!( #( pools.extend(Tuple::pools()); )* );
+		pools
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;